@@ -20,6 +20,10 @@ pub struct Command<'a> {
 
     head: Cow<'a, str>,
 
+    before: Cow<'a, str>,
+
+    after: Cow<'a, str>,
+
     blocks: Vec<Block<'a, Cow<'a, str>>>,
 
     stores: Vec<Store<'a>>,
@@ -33,6 +37,8 @@ impl<'a> Command<'a> {
             head: head.into(),
             hint: hint.into(),
             help: help.into(),
+            before: Cow::default(),
+            after: Cow::default(),
             blocks: vec![],
             stores: vec![],
         }
@@ -58,6 +64,16 @@ impl<'a> Command<'a> {
         self.foot.clone()
     }
 
+    /// The text displayed before the blocks, after the head.
+    pub fn before(&self) -> Cow<'a, str> {
+        self.before.clone()
+    }
+
+    /// The text displayed after the blocks, before the foot.
+    pub fn after(&self) -> Cow<'a, str> {
+        self.after.clone()
+    }
+
     pub fn block(&self) -> &Vec<Block<'a, Cow<'a, str>>> {
         &self.blocks
     }
@@ -118,6 +134,16 @@ impl<'a> Command<'a> {
         self
     }
 
+    pub fn set_before<S: Into<Cow<'a, str>>>(&mut self, help: S) -> &mut Self {
+        self.before = help.into();
+        self
+    }
+
+    pub fn set_after<S: Into<Cow<'a, str>>>(&mut self, help: S) -> &mut Self {
+        self.after = help.into();
+        self
+    }
+
     pub fn add_store<S: Into<Cow<'a, str>>>(
         &mut self,
         block: S,
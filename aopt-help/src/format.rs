@@ -4,6 +4,9 @@ use std::borrow::Cow;
 
 pub use self::policy::DefaultAppPolicy;
 pub use self::policy::DefaultPolicy;
+pub use self::policy::UsageStyle;
+pub use self::policy::OPTIONS_USAGE;
+pub use self::policy::USAGE_LABEL;
 
 pub trait HelpPolicy<'a, I> {
     fn format(&self, item: &I) -> Option<Cow<'a, str>>;
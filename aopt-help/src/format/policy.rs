@@ -10,6 +10,23 @@ use crate::wrapper::Wrapper;
 use crate::AppHelp;
 use crate::HelpPolicy;
 
+/// The default label printed before the usage.
+pub const USAGE_LABEL: &str = "Usage:";
+
+/// The placeholder of optional options in [`UsageStyle::Short`] usage.
+pub const OPTIONS_USAGE: &str = "[OPTIONS]";
+
+/// How the options are displayed in the usage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UsageStyle {
+    /// Collapse the optional options into [`OPTIONS_USAGE`], the required options are still listed.
+    #[default]
+    Short,
+
+    /// List every option in the usage.
+    Full,
+}
+
 // struct UsageDetail<'a> {
 //     store_usages: Vec<Cow<'a, str>>,
 
@@ -29,6 +46,12 @@ pub struct DefaultPolicy<'a, I> {
 
     usage_new_line: usize,
 
+    name_column: usize,
+
+    usage_label: Cow<'a, str>,
+
+    usage_style: UsageStyle,
+
     marker: PhantomData<&'a I>,
 }
 
@@ -41,6 +64,9 @@ impl<I> Default for DefaultPolicy<'_, I> {
             max_width: 0,
             hiding_pos: true,
             usage_new_line: 0,
+            name_column: 0,
+            usage_label: Cow::from(USAGE_LABEL),
+            usage_style: UsageStyle::default(),
             marker: Default::default(),
         }
     }
@@ -62,9 +88,30 @@ impl<'a, I> DefaultPolicy<'a, I> {
             max_width,
             hiding_pos,
             usage_new_line,
+            name_column: 0,
+            usage_label: Cow::from(USAGE_LABEL),
+            usage_style: UsageStyle::default(),
             marker: PhantomData,
         }
     }
+
+    /// Set the width of name column, the help column is aligned after it.
+    pub fn with_name_column(mut self, name_column: usize) -> Self {
+        self.name_column = name_column;
+        self
+    }
+
+    /// Set the label printed before the usage, default is `Usage:`.
+    pub fn with_usage_label<S: Into<Cow<'a, str>>>(mut self, usage_label: S) -> Self {
+        self.usage_label = usage_label.into();
+        self
+    }
+
+    /// Set how the options are displayed in the usage, default is [`UsageStyle::Short`].
+    pub fn with_usage_style(mut self, usage_style: UsageStyle) -> Self {
+        self.usage_style = usage_style;
+        self
+    }
 }
 
 impl<'a> DefaultPolicy<'a, Command<'a>> {
@@ -88,7 +135,11 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
                             args.push(format!("<{}>", hint));
                         }
                     } else if store.optional() {
-                        usages.push(format!("[{}]", hint));
+                        if self.usage_style == UsageStyle::Full {
+                            usages.push(format!("[{}]", hint));
+                        } else if !usages.iter().any(|v| v == OPTIONS_USAGE) {
+                            usages.push(OPTIONS_USAGE.to_owned());
+                        }
                     } else {
                         usages.push(format!("<{}>", hint));
                     }
@@ -103,24 +154,26 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
         let mut args = vec![];
         let mut block_hint = vec![];
 
+        let mut options = vec![];
+
         for block in item.block() {
-            let (block_usages, mut block_args) = self.get_block_usage(block, item);
-
-            if !block_usages.is_empty() {
-                for mut usage in block_usages {
-                    if self.usage_new_line > 0 && (usages.len() + 1) % self.usage_new_line == 0 {
-                        // add more space
-                        // same length as `Usage: `
-                        usage.push_str("\n      ");
-                    }
-                    usages.push(usage);
-                }
-            }
+            let (mut block_usages, mut block_args) = self.get_block_usage(block, item);
+
+            options.append(&mut block_usages);
             // if not omit args, using the args, otherwise using hint of block
             if !block_args.is_empty() {
                 args.append(&mut block_args);
             }
         }
+        for mut usage in collapse_options(options) {
+            if self.usage_new_line > 0 && (usages.len() + 1) % self.usage_new_line == 0 {
+                // add more space
+                // same length as usage label
+                usage.push('\n');
+                usage.push_str(&" ".repeat(self.usage_label.chars().count()));
+            }
+            usages.push(usage);
+        }
         for block in item.block() {
             if !block.is_empty() {
                 let arg = block.hint();
@@ -131,7 +184,7 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
             }
         }
 
-        let mut ret = String::from("Usage: ");
+        let mut ret = format!("{} ", self.usage_label);
         let usage = usages.join(" ");
         let block_hint = block_hint.join(" ");
         let args = args.join(" ");
@@ -196,7 +249,7 @@ impl<'a> DefaultPolicy<'a, Command<'a>> {
         if !any_filled {
             return "".into();
         }
-        let mut wrapper = Wrapper::new(&data);
+        let mut wrapper = Wrapper::new(&data).with_name_column(self.name_column);
 
         if !styles.is_empty() {
             wrapper.wrap_with(styles, self.max_width);
@@ -244,11 +297,16 @@ impl<'a> HelpPolicy<'a, Command<'a>> for DefaultPolicy<'a, Command<'a>> {
         let mut blocks = vec![usage];
         let head = item.head();
         let foot = item.foot();
+        let before = item.before();
+        let after = item.after();
         let block_spacing = "\n".repeat(1 + self.style.block_spacing);
 
         if !head.is_empty() {
             blocks.push(head);
         }
+        if !before.is_empty() {
+            blocks.push(before);
+        }
         for block in item.block() {
             if !block.is_empty() {
                 let help = self.get_block_help(block, item);
@@ -258,6 +316,9 @@ impl<'a> HelpPolicy<'a, Command<'a>> for DefaultPolicy<'a, Command<'a>> {
                 }
             }
         }
+        if !after.is_empty() {
+            blocks.push(after);
+        }
         if !foot.is_empty() {
             blocks.push(foot);
         }
@@ -276,6 +337,12 @@ pub struct DefaultAppPolicy<'a, I> {
 
     usage_new_line: usize,
 
+    name_column: usize,
+
+    usage_label: Cow<'a, str>,
+
+    usage_style: UsageStyle,
+
     marker: PhantomData<&'a I>,
 }
 
@@ -287,12 +354,15 @@ impl<I> Default for DefaultAppPolicy<'_, I> {
             show_global: true,
             hiding_pos: true,
             usage_new_line: 0,
+            name_column: 0,
+            usage_label: Cow::from(USAGE_LABEL),
+            usage_style: UsageStyle::default(),
             marker: Default::default(),
         }
     }
 }
 
-impl<I> DefaultAppPolicy<'_, I> {
+impl<'a, I> DefaultAppPolicy<'a, I> {
     pub fn new(
         styles: Vec<Style>,
         max_width: usize,
@@ -305,9 +375,30 @@ impl<I> DefaultAppPolicy<'_, I> {
             show_global,
             hiding_pos: true,
             usage_new_line,
+            name_column: 0,
+            usage_label: Cow::from(USAGE_LABEL),
+            usage_style: UsageStyle::default(),
             marker: PhantomData,
         }
     }
+
+    /// Set the width of name column, the help column is aligned after it.
+    pub fn with_name_column(mut self, name_column: usize) -> Self {
+        self.name_column = name_column;
+        self
+    }
+
+    /// Set the label printed before the usage, default is `Usage:`.
+    pub fn with_usage_label<S: Into<Cow<'a, str>>>(mut self, usage_label: S) -> Self {
+        self.usage_label = usage_label.into();
+        self
+    }
+
+    /// Set how the options are displayed in the usage, default is [`UsageStyle::Short`].
+    pub fn with_usage_style(mut self, usage_style: UsageStyle) -> Self {
+        self.usage_style = usage_style;
+        self
+    }
 }
 
 impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
@@ -331,7 +422,11 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
                             args.push(format!("<{}>", hint));
                         }
                     } else if store.optional() {
-                        usages.push(format!("[{}]", hint));
+                        if self.usage_style == UsageStyle::Full {
+                            usages.push(format!("[{}]", hint));
+                        } else if !usages.iter().any(|v| v == OPTIONS_USAGE) {
+                            usages.push(OPTIONS_USAGE.to_owned());
+                        }
                     } else {
                         usages.push(format!("<{}>", hint));
                     }
@@ -347,24 +442,26 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         let mut args = vec![];
         let mut block_hint = vec![];
 
+        let mut options = vec![];
+
         for block in global.block() {
-            let (block_usages, mut block_args) = self.get_block_usage(block, global);
-
-            if !block_usages.is_empty() {
-                for mut usage in block_usages {
-                    if self.usage_new_line > 0 && (usages.len() + 1) % self.usage_new_line == 0 {
-                        // add more space
-                        // same length as `Usage: `
-                        usage.push_str("\n      ");
-                    }
-                    usages.push(usage);
-                }
-            }
+            let (mut block_usages, mut block_args) = self.get_block_usage(block, global);
+
+            options.append(&mut block_usages);
             // if not omit args, using the args, otherwise using hint of block
             if !block_args.is_empty() {
                 args.append(&mut block_args);
             }
         }
+        for mut usage in collapse_options(options) {
+            if self.usage_new_line > 0 && (usages.len() + 1) % self.usage_new_line == 0 {
+                // add more space
+                // same length as usage label
+                usage.push('\n');
+                usage.push_str(&" ".repeat(self.usage_label.chars().count()));
+            }
+            usages.push(usage);
+        }
         for block in global.block() {
             if !block.is_empty() {
                 let arg = block.hint();
@@ -375,7 +472,7 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
             }
         }
 
-        let mut ret = String::from("Usage: ");
+        let mut ret = format!("{} ", self.usage_label);
         // all the option usage
         let global_usage = usages.join(" ");
         let block_hint = block_hint.join(" ");
@@ -442,7 +539,7 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         if !any_filled {
             return "".into();
         }
-        let mut wrapper = Wrapper::new(&data);
+        let mut wrapper = Wrapper::new(&data).with_name_column(self.name_column);
 
         if !styles.is_empty() {
             wrapper.wrap_with(styles, self.max_width);
@@ -531,7 +628,7 @@ impl<'a, W: Write> DefaultAppPolicy<'a, AppHelp<'a, W>> {
         if !any_filled {
             return "".into();
         }
-        let mut wrapper = Wrapper::new(&data);
+        let mut wrapper = Wrapper::new(&data).with_name_column(self.name_column);
 
         if !styles.is_empty() {
             wrapper.wrap_with(styles, self.max_width);
@@ -578,6 +675,8 @@ impl<'a, W: Write> HelpPolicy<'a, AppHelp<'a, W>> for DefaultAppPolicy<'a, AppHe
         let usage = self.get_app_usage(app);
         let head = app.head();
         let foot = app.foot();
+        let before = app.before();
+        let after = app.after();
         let block_spacing = "\n".repeat(1 + app.style().block_spacing);
         let mut usages = if usage.is_empty() {
             vec![]
@@ -588,6 +687,9 @@ impl<'a, W: Write> HelpPolicy<'a, AppHelp<'a, W>> for DefaultAppPolicy<'a, AppHe
         if !head.is_empty() {
             usages.push(head);
         }
+        if !before.is_empty() {
+            usages.push(before);
+        }
         for block in app.block().iter() {
             let block_help = self.get_block_help(block, app);
 
@@ -604,9 +706,23 @@ impl<'a, W: Write> HelpPolicy<'a, AppHelp<'a, W>> for DefaultAppPolicy<'a, AppHe
                 }
             }
         }
+        if !after.is_empty() {
+            usages.push(after);
+        }
         if !foot.is_empty() {
             usages.push(foot);
         }
         Some(usages.join(&block_spacing).into())
     }
 }
+
+/// Merge the [`OPTIONS_USAGE`] of blocks into one, and move it to the front.
+fn collapse_options(options: Vec<String>) -> Vec<String> {
+    let (collapsed, mut options): (Vec<_>, Vec<_>) =
+        options.into_iter().partition(|v| v == OPTIONS_USAGE);
+
+    if !collapsed.is_empty() {
+        options.insert(0, OPTIONS_USAGE.to_owned());
+    }
+    options
+}
@@ -18,6 +18,9 @@ pub mod prelude {
     pub use crate::format::DefaultPolicy;
     pub use crate::format::HelpDisplay;
     pub use crate::format::HelpPolicy;
+    pub use crate::format::UsageStyle;
+    pub use crate::format::OPTIONS_USAGE;
+    pub use crate::format::USAGE_LABEL;
     pub use crate::store::Store;
     pub use crate::style::Align;
     pub use crate::style::Style;
@@ -29,6 +32,8 @@ use crate::cmd::Command;
 use crate::format::DefaultAppPolicy;
 use crate::format::DefaultPolicy;
 use crate::format::HelpPolicy;
+use crate::format::UsageStyle;
+use crate::format::USAGE_LABEL;
 use crate::store::Store;
 use crate::style::Style;
 
@@ -50,6 +55,12 @@ pub struct AppHelp<'a, W> {
     wrap_max_width: usize,
 
     usage_new_line: usize,
+
+    name_column: usize,
+
+    usage_label: Cow<'a, str>,
+
+    usage_style: UsageStyle,
 }
 
 impl Default for AppHelp<'_, Stdout> {
@@ -62,6 +73,9 @@ impl Default for AppHelp<'_, Stdout> {
             global: 0,
             wrap_max_width: 0,
             usage_new_line: 0,
+            name_column: 0,
+            usage_label: Cow::from(USAGE_LABEL),
+            usage_style: UsageStyle::default(),
         }
     }
 }
@@ -84,6 +98,9 @@ impl<'a, W: Write> AppHelp<'a, W> {
             global: 0,
             wrap_max_width: max_width,
             usage_new_line,
+            name_column: 0,
+            usage_label: Cow::from(USAGE_LABEL),
+            usage_style: UsageStyle::default(),
         }
         .with_global(name, head, foot)
     }
@@ -109,6 +126,14 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self.global().head()
     }
 
+    pub fn before(&self) -> Cow<'a, str> {
+        self.global().before()
+    }
+
+    pub fn after(&self) -> Cow<'a, str> {
+        self.global().after()
+    }
+
     pub fn name(&self) -> Cow<'a, str> {
         self.global().name()
     }
@@ -125,6 +150,18 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self.usage_new_line
     }
 
+    pub fn name_column(&self) -> usize {
+        self.name_column
+    }
+
+    pub fn usage_label(&self) -> &str {
+        &self.usage_label
+    }
+
+    pub fn usage_style(&self) -> UsageStyle {
+        self.usage_style
+    }
+
     pub fn global(&self) -> &Command<'a> {
         &self.cmds[self.global]
     }
@@ -193,6 +230,37 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self
     }
 
+    /// Set the text displayed before the blocks.
+    pub fn with_before<S: Into<Cow<'a, str>>>(mut self, before: S) -> Self {
+        self.global_mut().set_before(before);
+        self
+    }
+
+    /// Set the text displayed after the blocks.
+    pub fn with_after<S: Into<Cow<'a, str>>>(mut self, after: S) -> Self {
+        self.global_mut().set_after(after);
+        self
+    }
+
+    /// Set the width of name column, the help column is aligned after it.
+    /// The name longer than it is placed on its own line, and the help starts from next line.
+    pub fn with_name_column(mut self, name_column: usize) -> Self {
+        self.name_column = name_column;
+        self
+    }
+
+    /// Set the label printed before the usage, default is `Usage:`.
+    pub fn with_usage_label<S: Into<Cow<'a, str>>>(mut self, usage_label: S) -> Self {
+        self.usage_label = usage_label.into();
+        self
+    }
+
+    /// Set how the options are displayed in the usage, default is [`UsageStyle::Short`].
+    pub fn with_usage_style(mut self, usage_style: UsageStyle) -> Self {
+        self.usage_style = usage_style;
+        self
+    }
+
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = style;
         self
@@ -218,6 +286,31 @@ impl<'a, W: Write> AppHelp<'a, W> {
         self
     }
 
+    pub fn set_before<S: Into<Cow<'a, str>>>(&mut self, before: S) -> &mut Self {
+        self.global_mut().set_before(before);
+        self
+    }
+
+    pub fn set_after<S: Into<Cow<'a, str>>>(&mut self, after: S) -> &mut Self {
+        self.global_mut().set_after(after);
+        self
+    }
+
+    pub fn set_name_column(&mut self, name_column: usize) -> &mut Self {
+        self.name_column = name_column;
+        self
+    }
+
+    pub fn set_usage_label<S: Into<Cow<'a, str>>>(&mut self, usage_label: S) -> &mut Self {
+        self.usage_label = usage_label.into();
+        self
+    }
+
+    pub fn set_usage_style(&mut self, usage_style: UsageStyle) -> &mut Self {
+        self.usage_style = usage_style;
+        self
+    }
+
     pub fn set_style(&mut self, style: Style) -> &mut Self {
         self.style = style;
         self
@@ -288,7 +381,10 @@ impl<'a, W: Write> AppHelp<'a, W> {
             self.wrap_max_width,
             show_global,
             self.usage_new_line,
-        );
+        )
+        .with_name_column(self.name_column)
+        .with_usage_label(self.usage_label.clone())
+        .with_usage_style(self.usage_style);
         let help = policy.format(self).ok_or_else(|| {
             Error::raise("Can not format app help with DefaultAppPolicy".to_string())
         })?;
@@ -326,7 +422,10 @@ impl<'a, W: Write> AppHelp<'a, W> {
             self.wrap_max_width,
             true,
             self.usage_new_line,
-        );
+        )
+        .with_name_column(self.name_column)
+        .with_usage_label(self.usage_label.clone())
+        .with_usage_style(self.usage_style);
         let help = policy
             .format(cmd)
             .ok_or_else(|| Error::raise("Can not format cmd help with given policy".to_string()))?;
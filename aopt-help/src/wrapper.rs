@@ -32,7 +32,7 @@ impl<'a> Wrapped<'a> {
         if line < self.cows.len() {
             let mut ret = " ".repeat(self.style.indent);
             let real_width = display_width(self.cows[line].as_ref());
-            let padding_width = self.get_wrap_width() - real_width;
+            let padding_width = self.get_wrap_width().saturating_sub(real_width);
 
             ret += self.cows[line].as_ref();
             match self.style.align {
@@ -67,6 +67,8 @@ pub struct Wrapper<'a, 'b> {
     data: &'a [Vec<Cow<'b, str>>],
 
     output: Vec<Vec<Wrapped<'b>>>,
+
+    name_column: usize,
 }
 
 impl<'a, 'b> Wrapper<'a, 'b>
@@ -77,9 +79,45 @@ where
         Self {
             data,
             output: vec![],
+            name_column: 0,
         }
     }
 
+    /// Fix the width of first column, the longer one is placed on its own line,
+    /// and other columns start from next line. The first column is not fixed if it is 0.
+    pub fn with_name_column(mut self, name_column: usize) -> Self {
+        self.name_column = name_column;
+        self
+    }
+
+    pub fn name_column(&self) -> usize {
+        self.name_column
+    }
+
+    fn wrap_line(&self, line: &'a [Cow<'b, str>], styles: &[Style]) -> Vec<Wrapped<'b>> {
+        let overflow = self.name_column > 0
+            && line
+                .first()
+                .is_some_and(|v| display_width(v) > self.name_column);
+
+        line.iter()
+            .zip(styles.iter())
+            .enumerate()
+            .map(|(idx, (col, style))| {
+                if overflow && idx == 0 {
+                    Wrapped::new(vec![Cow::Borrowed(col.as_ref())], style.clone())
+                } else if overflow {
+                    let mut cows = vec![Cow::Borrowed("")];
+
+                    cows.extend(wrap(col, style.wrap_width));
+                    Wrapped::new(cows, style.clone())
+                } else {
+                    Wrapped::new(wrap(col, style.wrap_width), style.clone())
+                }
+            })
+            .collect()
+    }
+
     pub fn wrap(&mut self, max_width: usize) {
         let data_len = self.data.iter().map(|v| v.len()).max().unwrap_or(0);
         let mut default_style = vec![Style::default(); data_len];
@@ -98,12 +136,11 @@ where
             }
         }
 
+        if let Some(style) = default_style.first_mut().filter(|_| self.name_column > 0) {
+            style.wrap_width = self.name_column;
+        }
         for line in self.data.iter() {
-            let mut wrapped = vec![];
-
-            for (col, style) in line.iter().zip(default_style.iter()) {
-                wrapped.push(Wrapped::new(wrap(col, style.wrap_width), style.clone()));
-            }
+            let wrapped = self.wrap_line(line, &default_style);
 
             self.output.push(wrapped);
         }
@@ -130,12 +167,12 @@ where
             }
         }
 
+        if let Some(style) = styles.first_mut().filter(|_| self.name_column > 0) {
+            style.wrap_width = self.name_column;
+        }
         for line in self.data.iter() {
-            let mut wrapped = vec![];
+            let wrapped = self.wrap_line(line, &styles);
 
-            for (col, style) in line.iter().zip(styles.iter()) {
-                wrapped.push(Wrapped::new(wrap(col, style.wrap_width), style.clone()));
-            }
             self.output.push(wrapped);
         }
     }
@@ -2,15 +2,24 @@ use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::fmt::Display;
+#[cfg(feature = "std")]
+use std::io::Read;
 use std::ops::Deref;
 
 use crate::parser::Return;
+#[cfg(feature = "std")]
+use crate::raise_error;
+#[cfg(feature = "std")]
+use crate::str::bytes_to_os;
 use crate::str::CowOsStrUtils;
 use crate::ARef;
 use crate::Error;
 
 const EQUAL: char = '=';
 
+/// The program name prepended by [`Args::without_bin`].
+pub const BIN_PLACEHOLDER: &str = "app";
+
 #[derive(Debug, Clone, Default)]
 pub struct ArgInfo<'a> {
     pub name: Cow<'a, str>,
@@ -65,17 +74,26 @@ impl<'a> ArgInfo<'a> {
     /// # }
     /// ```
     pub fn parse(val: &'a OsStr) -> Result<Self, Error> {
+        Self::parse_with(val, EQUAL)
+    }
+
+    /// Parse the input command line item like [`parse`](ArgInfo::parse),
+    /// the option name and value are separated by `assign` instead of `=`, such as `--foo:32`.
+    pub fn parse_with(val: &'a OsStr, assign: char) -> Result<Self, Error> {
         let arg_display = format!("{}", std::path::Path::new(val).display());
 
         crate::trace!("parsing command line argument {val:?}");
-        if let Some((name, value)) = crate::str::split_once(val, EQUAL) {
+        if let Some((name, value)) = crate::str::split_once(val, assign) {
             // - convert the name to &str, the name must be valid utf8
             let name = name
                 .to_str(|v| v.trim())
                 .ok_or_else(|| Error::arg(&arg_display, "failed convert OsStr to str"))?;
 
             if name.is_empty() {
-                return Err(Error::arg(arg_display, "can not be empty"));
+                return Err(Error::arg(
+                    arg_display,
+                    format!("expected an option name before `{assign}`"),
+                ));
             }
             Ok(Self {
                 name,
@@ -94,6 +112,11 @@ impl<'a> ArgInfo<'a> {
     }
 }
 
+/// The command line arguments.
+///
+/// The first argument is the name of program like [`args_os`](std::env::args_os()),
+/// the policies start parsing from the index 1.
+/// Use [`without_bin`](Args::without_bin) if the arguments do not include the program name.
 #[derive(Debug, Clone, Default)]
 pub struct Args {
     inner: ARef<Vec<OsString>>,
@@ -107,15 +130,92 @@ impl Args {
     }
 
     /// Create from [`args_os`](std::env::args_os()).
+    #[cfg(feature = "std")]
     pub fn from_env() -> Self {
         Self::new(std::env::args_os())
     }
 
+    /// Create from the arguments without the program name, such as `["--flag"]`,
+    /// the [`BIN_PLACEHOLDER`] is prepended as the program name.
+    pub fn without_bin<S: Into<OsString>>(args: impl IntoIterator<Item = S>) -> Self {
+        Self::new(
+            std::iter::once(OsString::from(BIN_PLACEHOLDER))
+                .chain(args.into_iter().map(Into::into)),
+        )
+    }
+
+    /// Read the arguments from stdin, see [`from_reader`](Args::from_reader).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// // merge the arguments of command line and stdin, such as `find . -print0 | app --verbose`
+    /// let stdin = Args::from_stdin(ArgSep::Nul)?;
+    /// let args = Args::from(Args::from_env().iter().chain(stdin.iter()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_stdin(sep: ArgSep) -> Result<Self, Error> {
+        Self::from_reader(std::io::stdin().lock(), sep)
+    }
+
+    /// Read all the data from `reader` and split it into arguments by `sep`.
+    ///
+    /// The separator at the end is ignored, for [`Newline`](ArgSep::Newline) the `\r` before `\n` is removed.
+    /// The name of program is not included in the result.
+    #[cfg(feature = "std")]
+    pub fn from_reader(mut reader: impl Read, sep: ArgSep) -> Result<Self, Error> {
+        let mut buf = vec![];
+
+        reader
+            .read_to_end(&mut buf)
+            .map_err(|e| raise_error!("can not read arguments: {e}"))?;
+        if buf.last() == Some(&sep.byte()) {
+            buf.pop();
+        }
+        if buf.is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(Self::new(buf.split(|v| *v == sep.byte()).map(|arg| {
+            let arg = match sep {
+                ArgSep::Newline => arg.strip_suffix(b"\r").unwrap_or(arg),
+                ArgSep::Nul => arg,
+            };
+
+            bytes_to_os(arg.to_vec())
+        })))
+    }
+
     pub fn unwrap_or_clone(self) -> Vec<OsString> {
         ARef::unwrap_or_clone(self.inner)
     }
 }
 
+/// The separator of arguments using in [`Args::from_reader`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ArgSep {
+    /// One argument per line.
+    #[default]
+    Newline,
+
+    /// The arguments are separated by `\0`, such as the output of `find -print0`.
+    Nul,
+}
+
+impl ArgSep {
+    pub fn byte(&self) -> u8 {
+        match self {
+            ArgSep::Newline => b'\n',
+            ArgSep::Nul => b'\0',
+        }
+    }
+}
+
 impl<T: Into<OsString>, I: IntoIterator<Item = T>> From<I> for Args {
     fn from(value: I) -> Self {
         Self::new(value.into_iter())
@@ -154,6 +254,125 @@ impl Deref for Args {
     }
 }
 
+/// Source of the arguments passed to parser, such as the process arguments,
+/// a line from interactive prompt, or a message received from socket.
+///
+/// The first item of [`Args`] is the name of program.
+pub trait ArgSource {
+    fn into_args(self) -> Result<Args, Error>;
+}
+
+/// The arguments of current process, see [`Args::from_env`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnvArgs;
+
+#[cfg(feature = "std")]
+impl ArgSource for EnvArgs {
+    fn into_args(self) -> Result<Args, Error> {
+        Ok(Args::from_env())
+    }
+}
+
+impl ArgSource for Args {
+    fn into_args(self) -> Result<Args, Error> {
+        Ok(self)
+    }
+}
+
+impl ArgSource for Vec<OsString> {
+    fn into_args(self) -> Result<Args, Error> {
+        Ok(Args::from(self))
+    }
+}
+
+/// Split the string like shell, see [`split_cmdline`].
+impl ArgSource for &str {
+    fn into_args(self) -> Result<Args, Error> {
+        split_cmdline(self).map(Args::from)
+    }
+}
+
+/// Split the string like shell, see [`split_cmdline`].
+impl ArgSource for String {
+    fn into_args(self) -> Result<Args, Error> {
+        self.as_str().into_args()
+    }
+}
+
+/// Split the command line string into arguments like shell.
+///
+/// The arguments are separated by whitespace.
+/// The characters inside single quotes are kept as is,
+/// the backslash escapes `"` and `\` inside double quotes, or any character outside quotes.
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::args::split_cmdline;
+/// # use aopt::Error;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// assert_eq!(
+///     split_cmdline(r#"app --name "Lily Smith" 'a b' c\ d"#)?,
+///     ["app", "--name", "Lily Smith", "a b", "c d"]
+/// );
+/// assert!(split_cmdline("app 'a b").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn split_cmdline(line: &str) -> Result<Vec<String>, Error> {
+    let mut ret = vec![];
+    let mut arg: Option<String> = None;
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                let ch = chars
+                    .next()
+                    .ok_or_else(|| Error::arg(line, "missing character after `\\`"))?;
+
+                arg.get_or_insert_with(String::default).push(ch);
+            }
+            '\'' => {
+                let arg = arg.get_or_insert_with(String::default);
+
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(ch) => arg.push(ch),
+                        None => return Err(Error::arg(line, "missing closing quote `'`")),
+                    }
+                }
+            }
+            '"' => {
+                let arg = arg.get_or_insert_with(String::default);
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(ch @ ('"' | '\\')) => arg.push(ch),
+                            Some(ch) => {
+                                arg.push('\\');
+                                arg.push(ch);
+                            }
+                            None => return Err(Error::arg(line, "missing closing quote `\"`")),
+                        },
+                        Some(ch) => arg.push(ch),
+                        None => return Err(Error::arg(line, "missing closing quote `\"`")),
+                    }
+                }
+            }
+            ch if ch.is_whitespace() => ret.extend(arg.take()),
+            ch => arg.get_or_insert_with(String::default).push(ch),
+        }
+    }
+    ret.extend(arg);
+    Ok(ret)
+}
+
 pub fn iter2<'a, 'b>(
     args: &'a [&'b OsStr],
 ) -> impl Iterator<Item = (&'a &'b OsStr, Option<&'a &'b OsStr>)> {
@@ -161,6 +380,77 @@ pub fn iter2<'a, 'b>(
         .scan(args.iter().skip(1), |i, e| Some((e, i.next())))
 }
 
+/// A cursor over the arguments, it keeps the index of next argument for custom [`Policy`](crate::parser::Policy).
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use std::ffi::OsStr;
+/// #
+/// let args = Args::from(["app", "-v", "--", "a", "b"]);
+/// let mut cursor = ArgCursor::new(&args);
+///
+/// assert_eq!(cursor.next().map(|(idx, _)| idx), Some(0));
+/// assert_eq!(cursor.peek(), Some(OsStr::new("-v")));
+/// assert_eq!(cursor.take_until(|arg| arg == "--").len(), 1);
+/// assert_eq!(cursor.next(), Some((2, OsStr::new("--"))));
+/// assert_eq!(cursor.remaining().len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ArgCursor<'a, T> {
+    args: &'a [T],
+
+    idx: usize,
+}
+
+impl<'a, T: AsRef<OsStr>> ArgCursor<'a, T> {
+    pub fn new(args: &'a [T]) -> Self {
+        Self { args, idx: 0 }
+    }
+
+    /// The index of next argument.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+
+    /// Return the next argument without advancing the cursor.
+    pub fn peek(&self) -> Option<&'a OsStr> {
+        self.args.get(self.idx).map(AsRef::as_ref)
+    }
+
+    /// Return the arguments not consumed, the cursor is not advanced.
+    pub fn remaining(&self) -> &'a [T] {
+        &self.args[self.idx..]
+    }
+
+    /// Consume the arguments until `pred` return true, the argument matched is not consumed.
+    /// All the rest arguments are consumed if no argument matched.
+    pub fn take_until(&mut self, mut pred: impl FnMut(&OsStr) -> bool) -> &'a [T] {
+        let start = self.idx;
+        let len = self
+            .remaining()
+            .iter()
+            .take_while(|arg| !pred(arg.as_ref()))
+            .count();
+
+        self.idx += len;
+        &self.args[start..self.idx]
+    }
+}
+
+impl<'a, T: AsRef<OsStr>> Iterator for ArgCursor<'a, T> {
+    type Item = (usize, &'a OsStr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let arg = self.peek()?;
+        let idx = self.idx;
+
+        self.idx += 1;
+        Some((idx, arg))
+    }
+}
+
 impl Display for Args {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -179,8 +469,16 @@ impl Display for Args {
 mod test {
 
     use std::ffi::OsStr;
+    use std::ffi::OsString;
 
+    use super::split_cmdline;
+    use super::ArgCursor;
+    #[cfg(feature = "std")]
+    use super::ArgSep;
+    use super::ArgSource;
     use super::Args;
+    use crate::prelude::*;
+    use crate::Error;
 
     #[test]
     fn test_args() {
@@ -216,4 +514,237 @@ mod test {
 
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_without_bin() {
+        assert!(test_without_bin_impl().is_ok());
+    }
+
+    fn test_without_bin_impl() -> Result<(), Error> {
+        let parse = |args: Args| -> Result<(bool, Vec<OsString>), Error> {
+            let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+            parser.add_opt("--flag=b")?;
+            parser.add_opt("pos=p@1")?;
+
+            let ret = parser.parse(args)?;
+
+            Ok((*parser.find_val::<bool>("--flag")?, ret.clone_args()))
+        };
+
+        assert_eq!(*Args::without_bin(["--flag"]), ["app", "--flag"]);
+        assert_eq!(
+            parse(Args::without_bin(["--flag", "a.txt"]))?,
+            parse(Args::from(["app", "--flag", "a.txt"]))?
+        );
+        assert!(parse(Args::without_bin(["--flag"]))?.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_memory_args() {
+        assert!(test_in_memory_args_impl().is_ok());
+    }
+
+    // no environment, file or stdio is accessed, it also passes without the `std` feature
+    fn test_in_memory_args_impl() -> Result<(), Error> {
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("-v;--verbose=b")?;
+        parser.add_opt("-j;--jobs=i")?;
+        parser.add_opt("build=c")?;
+        parser.add_opt("target=p@2")?.set_pos_type::<String>();
+
+        let ret = parser.parse(Args::from(["app", "-v", "build", "--jobs=4", "x86"]))?;
+
+        assert!(ret.status());
+        assert!(*parser.find_val::<bool>("--verbose")?);
+        assert_eq!(parser.find_val::<i64>("--jobs")?, &4);
+        assert!(*parser.find_val::<bool>("build")?);
+        assert_eq!(parser.find_val::<String>("target")?, "x86");
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_cmdline() {
+        assert!(test_split_cmdline_impl().is_ok());
+    }
+
+    fn test_split_cmdline_impl() -> Result<(), Error> {
+        assert_eq!(split_cmdline("  app  -a  b ")?, ["app", "-a", "b"]);
+        assert_eq!(split_cmdline("")?, Vec::<String>::new());
+        assert_eq!(split_cmdline(r#"a"b c"d"#)?, ["ab cd"]);
+        assert_eq!(
+            split_cmdline(r#""a \" \\ \n" '' """#)?,
+            [r#"a " \ \n"#, "", ""]
+        );
+        assert_eq!(split_cmdline(r#"'a \ "b"'"#)?, [r#"a \ "b""#]);
+        assert!(split_cmdline(r#"a "b"#).is_err());
+        assert!(split_cmdline(r#"a \"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arg_source() {
+        assert!(test_arg_source_impl().is_ok());
+    }
+
+    struct Fixture(&'static [&'static str]);
+
+    impl ArgSource for Fixture {
+        fn into_args(self) -> Result<Args, Error> {
+            Ok(Args::from(["app"].iter().chain(self.0)))
+        }
+    }
+
+    fn test_arg_source_impl() -> Result<(), Error> {
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("--name=s")?;
+        parser.add_opt("--count=i")?;
+
+        parser.parse_source(Fixture(&["--name", "foo", "--count=1"]))?;
+        assert_eq!(parser.find_val::<String>("--name")?, "foo");
+        assert_eq!(parser.find_val::<i64>("--count")?, &1);
+
+        parser.parse_source("app --name 'Lily Smith' --count 2")?;
+        assert_eq!(parser.find_val::<String>("--name")?, "Lily Smith");
+        assert_eq!(parser.find_val::<i64>("--count")?, &2);
+
+        parser.parse_source(vec!["app".into(), "--count=3".into()])?;
+        assert_eq!(parser.find_val::<i64>("--count")?, &3);
+        assert!(parser.parse_source("app --name 'Lily").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_reader() {
+        assert!(test_from_reader_impl().is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    fn test_from_reader_impl() -> Result<(), Error> {
+        let args = Args::from_reader(&b"--name\nLily Smith\r\n\n-c=1\n"[..], ArgSep::Newline)?;
+
+        assert_eq!(*args, ["--name", "Lily Smith", "", "-c=1"]);
+
+        let args = Args::from_reader(&b"a.txt\0b c.txt\0"[..], ArgSep::Nul)?;
+
+        assert_eq!(*args, ["a.txt", "b c.txt"]);
+        assert!(Args::from_reader(&b""[..], ArgSep::Nul)?.is_empty());
+        assert!(Args::from_reader(&b"\n"[..], ArgSep::Newline)?.is_empty());
+
+        #[cfg(target_family = "unix")]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            let args = Args::from_reader(&b"\xff\xfe\0ok"[..], ArgSep::Nul)?;
+
+            assert_eq!(args[0].as_bytes(), b"\xff\xfe");
+            assert_eq!(args[1], "ok");
+        }
+
+        let stdin = Args::from_reader(&b"--count=2\n"[..], ArgSep::Newline)?;
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("--count=i")?;
+        parser.parse(Args::from(
+            ["app"]
+                .into_iter()
+                .map(OsString::from)
+                .chain(stdin.iter().cloned()),
+        ))?;
+        assert_eq!(parser.find_val::<i64>("--count")?, &2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arg_cursor() {
+        assert!(test_arg_cursor_impl().is_ok());
+    }
+
+    /// Store `--name value` to the option, `--` stops the option parsing.
+    struct PairPolicy;
+
+    impl Policy for PairPolicy {
+        type Ret = Vec<OsString>;
+
+        type Set = ASet;
+
+        type Inv<'a> = ();
+
+        type Ser = ();
+
+        type Error = Error;
+
+        fn parse(
+            &mut self,
+            set: &mut ASet,
+            _: &mut (),
+            _: &mut (),
+            args: Args,
+        ) -> Result<Self::Ret, Error> {
+            let mut cursor = ArgCursor::new(&args);
+            let mut lefts = vec![];
+
+            cursor.next();
+            while let Some((idx, arg)) = cursor.next() {
+                if arg == "--" {
+                    lefts.extend(cursor.remaining().iter().cloned());
+                    break;
+                }
+                match arg.to_str().filter(|v| v.starts_with("--")) {
+                    Some(name) => {
+                        let value = cursor.next().map(|(_, v)| v);
+                        let opt = set.opt_mut(set.find_uid(name)?)?;
+                        let act = *opt.action();
+                        let ctx = Ctx::default().with_inner_ctx(
+                            InnerCtx::default()
+                                .with_uid(opt.uid())
+                                .with_idx(idx)
+                                .with_name(Some(name.into()))
+                                .with_style(Style::Argument),
+                        );
+
+                        opt.accessor_mut().store_all(value, &ctx, &act)?;
+                        opt.set_matched(true);
+                    }
+                    None => lefts.push(arg.to_os_string()),
+                }
+            }
+            Ok(lefts)
+        }
+    }
+
+    fn test_arg_cursor_impl() -> Result<(), Error> {
+        let args = ["app", "--a", "1", "b", "--", "--c"].map(OsStr::new);
+        let mut cursor = ArgCursor::new(&args);
+
+        assert_eq!(cursor.peek(), Some(OsStr::new("app")));
+        assert_eq!(cursor.next(), Some((0, OsStr::new("app"))));
+        assert_eq!(cursor.take_until(|arg| arg == "--"), &args[1..4]);
+        assert_eq!(cursor.idx(), 4);
+        assert_eq!(cursor.remaining(), &args[4..]);
+        assert_eq!(cursor.take_until(|arg| arg == "--x"), &args[4..]);
+        assert_eq!(cursor.peek(), None);
+        assert_eq!(cursor.next(), None);
+
+        let mut policy = PairPolicy;
+        let mut set = ASet::default();
+
+        set.add_opt("--count=i")?.run()?;
+        set.add_opt("--name=s")?.run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "--count", "3", "a", "--name", "foo", "--", "--count"]);
+        let lefts = policy.parse(&mut set, &mut (), &mut (), args)?;
+
+        assert_eq!(set.find_val::<i64>("--count")?, &3);
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert_eq!(lefts, ["a", "--count"]);
+        Ok(())
+    }
 }
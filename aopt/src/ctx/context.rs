@@ -1,10 +1,15 @@
 use std::borrow::Cow;
 use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::fmt::Display;
+use std::path::Path;
 
 use crate::args::Args;
 use crate::opt::Style;
 use crate::parser::Action;
+use crate::parser::GuessTrace;
+use crate::parser::Warning;
+use crate::parser::Warnings;
 use crate::str::display_of_osstr;
 use crate::str::display_of_str;
 use crate::value::RawValParser;
@@ -143,27 +148,55 @@ impl Display for InnerCtx<'_> {
 pub struct Ctx<'a> {
     pub(crate) orig: Args,
 
+    pub(crate) expanded: Option<Args>,
+
     pub(crate) args: Vec<&'a OsStr>,
 
     pub(crate) inner_ctx: Option<InnerCtx<'a>>,
 
+    pub(crate) warnings: Warnings,
+
+    pub(crate) guess_trace: Vec<GuessTrace>,
+
+    pub(crate) deferred_check: bool,
+
+    pub(crate) overload: bool,
+
+    pub(crate) pending_check: Vec<Uid>,
+
     #[cfg(not(feature = "sync"))]
     action: std::cell::RefCell<Action>,
 
     #[cfg(feature = "sync")]
     action: std::sync::Mutex<Action>,
+
+    #[cfg(not(feature = "sync"))]
+    expansion: std::cell::RefCell<Option<Vec<OsString>>>,
+
+    #[cfg(feature = "sync")]
+    expansion: std::sync::Mutex<Option<Vec<OsString>>>,
 }
 
 impl Clone for Ctx<'_> {
     fn clone(&self) -> Self {
         Self {
             orig: self.orig.clone(),
+            expanded: self.expanded.clone(),
             args: self.args.clone(),
             inner_ctx: self.inner_ctx.clone(),
+            warnings: self.warnings.clone(),
+            guess_trace: self.guess_trace.clone(),
+            deferred_check: self.deferred_check,
+            overload: self.overload,
+            pending_check: self.pending_check.clone(),
             #[cfg(not(feature = "sync"))]
             action: self.action.clone(),
             #[cfg(feature = "sync")]
             action: std::sync::Mutex::new(*self.action.lock().unwrap()),
+            #[cfg(not(feature = "sync"))]
+            expansion: self.expansion.clone(),
+            #[cfg(feature = "sync")]
+            expansion: std::sync::Mutex::new(self.expansion.lock().unwrap().clone()),
         }
     }
 }
@@ -179,10 +212,25 @@ impl<'a> Ctx<'a> {
         self
     }
 
+    pub fn with_expanded(mut self, expanded: Option<Args>) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
     pub fn with_inner_ctx(mut self, inner_ctx: InnerCtx<'a>) -> Self {
         self.inner_ctx = Some(inner_ctx);
         self
     }
+
+    pub fn with_deferred_check(mut self, deferred_check: bool) -> Self {
+        self.deferred_check = deferred_check;
+        self
+    }
+
+    pub fn with_overload(mut self, overload: bool) -> Self {
+        self.overload = overload;
+        self
+    }
 }
 
 impl<'a> Ctx<'a> {
@@ -196,11 +244,41 @@ impl<'a> Ctx<'a> {
         Ok(self.inner_ctx()?.idx())
     }
 
+    /// The index of matched option, same as [`idx`](Ctx::idx).
+    ///
+    /// For option it is the index in the arguments,
+    /// for NOA it is the index in the NOA arguments.
+    pub fn index(&self) -> Result<usize, Error> {
+        self.idx()
+    }
+
     /// The total number of arguments.
+    ///
+    /// For option it is the length of arguments,
+    /// for NOA it is the length of NOA arguments.
     pub fn total(&self) -> Result<usize, Error> {
         Ok(self.inner_ctx()?.total())
     }
 
+    /// Return true if the validators of options are deferred to the opt check phase,
+    /// see [`set_parallel_checks`](crate::parser::PolicySettings::set_parallel_checks).
+    pub fn deferred_check(&self) -> bool {
+        self.deferred_check
+    }
+
+    /// Return true if the repeated option can not be used more than once keeps the last value,
+    /// see [`set_overload`](crate::parser::PolicySettings::set_overload).
+    pub fn overload(&self) -> bool {
+        self.overload
+    }
+
+    /// Return true if the matched option is the last argument.
+    ///
+    /// The value of option is not counted if it is not in the same argument, such as `--opt value`.
+    pub fn is_last(&self) -> Result<bool, Error> {
+        Ok(self.idx()? + 1 == self.total()?)
+    }
+
     /// The name of matched option.
     /// For option it is the option name, for NOA it is the argument,
     /// which set in [`invoke`](crate::guess::InvokeGuess#method.invoke).
@@ -213,6 +291,33 @@ impl<'a> Ctx<'a> {
         Ok(self.inner_ctx()?.style())
     }
 
+    /// The warnings raised before current option matched.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    pub fn add_warning(&mut self, warning: Warning) -> &mut Self {
+        self.warnings.push(warning);
+        self
+    }
+
+    pub(crate) fn add_guess_trace(&mut self, trace: GuessTrace) -> &mut Self {
+        self.guess_trace.push(trace);
+        self
+    }
+
+    /// Mark the value of option `uid` is stored without validation in deferred mode.
+    pub(crate) fn add_pending_check(&mut self, uid: Uid) -> &mut Self {
+        if !self.pending_check.contains(&uid) {
+            self.pending_check.push(uid);
+        }
+        self
+    }
+
+    pub(crate) fn take_pending_check(&mut self) -> Vec<Uid> {
+        std::mem::take(&mut self.pending_check)
+    }
+
     /// The copy of [`Args`] when the option matched.
     /// It may be changing during parsing process.
     pub fn args(&self) -> &[&'a OsStr] {
@@ -224,6 +329,16 @@ impl<'a> Ctx<'a> {
         Ok(self.inner_ctx()?.arg())
     }
 
+    /// The argument as &[`OsStr`], it is lossless for the value which is not valid UTF-8.
+    pub fn arg_os(&self) -> Result<Option<&OsStr>, Error> {
+        Ok(self.arg()?.map(|v| v.as_ref()))
+    }
+
+    /// The argument as &[`Path`], it is lossless for the value which is not valid UTF-8.
+    pub fn arg_path(&self) -> Result<Option<&Path>, Error> {
+        Ok(self.arg_os()?.map(Path::new))
+    }
+
     pub fn inner_ctx(&self) -> Result<&InnerCtx<'a>, Error> {
         self.inner_ctx
             .as_ref()
@@ -241,6 +356,18 @@ impl<'a> Ctx<'a> {
         &self.orig
     }
 
+    /// The complete original arguments of current invocation, including the options and program name.
+    /// Unlike [`args`](Ctx::args), it is not changed during parsing process.
+    pub fn orig_args(&self) -> &Args {
+        &self.orig
+    }
+
+    /// The arguments being parsed after expanded by [`expand_args`](Ctx::expand_args),
+    /// `None` if nothing expanded.
+    pub fn expanded_args(&self) -> Option<&Args> {
+        self.expanded.as_ref()
+    }
+
     /// The current argument indexed by `self.idx()`.
     pub fn arg_at(&self, idx: usize) -> Result<Option<&'a OsStr>, Error> {
         Ok(self.args.get(idx).copied())
@@ -335,9 +462,88 @@ impl Ctx<'_> {
     pub fn reset_policy_act(&self) {
         *self.action.lock().unwrap() = Action::Null;
     }
+
+    /// Replace the arguments of matched option with `args` in the handler,
+    /// they are parsed as if the user had typed them at current position,
+    /// such as a `--preset fast` expands into `--threads 8 --opt-level 3`.
+    ///
+    /// The value of matched option is still saved, the expansions are limited by
+    /// [`EXPANSION_LIMIT`](crate::parser::EXPANSION_LIMIT) in one parsing.
+    /// It is supported in the handler of option by [`FwdPolicy`](crate::parser::FwdPolicy) and [`PrePolicy`](crate::parser::PrePolicy),
+    /// the parsing returns an error if it is used elsewhere, such as [`DelayPolicy`](crate::parser::DelayPolicy).
+    /// The original arguments are kept in [`orig_args`](Ctx::orig_args), see also [`expanded_args`](Ctx::expanded_args).
+    #[cfg(not(feature = "sync"))]
+    pub fn expand_args<I, T>(&self, args: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        *self.expansion.borrow_mut() = Some(args.into_iter().map(Into::into).collect());
+    }
+
+    /// Replace the arguments of matched option with `args` in the handler,
+    /// they are parsed as if the user had typed them at current position,
+    /// such as a `--preset fast` expands into `--threads 8 --opt-level 3`.
+    ///
+    /// The value of matched option is still saved, the expansions are limited by
+    /// [`EXPANSION_LIMIT`](crate::parser::EXPANSION_LIMIT) in one parsing.
+    /// It is supported in the handler of option by [`FwdPolicy`](crate::parser::FwdPolicy) and [`PrePolicy`](crate::parser::PrePolicy),
+    /// the parsing returns an error if it is used elsewhere, such as [`DelayPolicy`](crate::parser::DelayPolicy).
+    /// The original arguments are kept in [`orig_args`](Ctx::orig_args), see also [`expanded_args`](Ctx::expanded_args).
+    #[cfg(feature = "sync")]
+    pub fn expand_args<I, T>(&self, args: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        *self.expansion.lock().unwrap() = Some(args.into_iter().map(Into::into).collect());
+    }
+
+    #[cfg(not(feature = "sync"))]
+    pub(crate) fn take_expansion(&self) -> Option<Vec<OsString>> {
+        self.expansion.borrow_mut().take()
+    }
+
+    #[cfg(feature = "sync")]
+    pub(crate) fn take_expansion(&self) -> Option<Vec<OsString>> {
+        self.expansion.lock().unwrap().take()
+    }
 }
 
 impl Ctx<'_> {
+    /// Parse the argument of current matched option to `T`, no need to find the option by name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut policy = AFwdPolicy::default();
+    /// let mut set = policy.default_set();
+    /// let mut inv = policy.default_inv();
+    /// let mut ser = policy.default_ser();
+    ///
+    /// inv.entry(set.add_opt("--num=i")?.run()?)
+    ///     .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+    ///         let num = ctx.value::<i64>()?;
+    ///
+    ///         assert_eq!(num, 5);
+    ///         assert_eq!(ctx.value::<String>()?, "5");
+    ///         Ok(Some(num))
+    ///     });
+    /// for opt in set.iter_mut() {
+    ///     opt.init()?;
+    /// }
+    ///
+    /// let args = Args::from(["app", "--num", "5"]);
+    ///
+    /// assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
+    /// assert_eq!(set.find_val::<i64>("--num")?, &5);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn value<T: RawValParser>(&self) -> Result<T, Error> {
         let arg = self.arg()?.map(|v| v.as_ref());
         let uid = self.uid()?;
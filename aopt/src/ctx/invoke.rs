@@ -14,6 +14,7 @@ use crate::set::SetOpt;
 use crate::trace;
 use crate::Error;
 use crate::HashMap;
+use crate::ResultExt;
 use crate::Uid;
 
 /// Keep the variable length arguments handler in [`HashMap`] with key [`Uid`].
@@ -76,12 +77,15 @@ use crate::Uid;
 /// ```
 pub struct Invoker<'a, Set, Ser> {
     callbacks: HashMap<Uid, InvokeHandler<'a, Set, Ser, Error>>,
+
+    alias_callbacks: HashMap<Uid, AliasHandlers<'a, Set, Ser>>,
 }
 
 impl<Set, Ser> Debug for Invoker<'_, Set, Ser> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Invoker")
             .field("callbacks", &"{ ... }")
+            .field("alias_callbacks", &"{ ... }")
             .finish()
     }
 }
@@ -90,6 +94,7 @@ impl<Set, Ser> Default for Invoker<'_, Set, Ser> {
     fn default() -> Self {
         Self {
             callbacks: HashMap::default(),
+            alias_callbacks: HashMap::default(),
         }
     }
 }
@@ -98,6 +103,7 @@ impl<Set, Ser> Invoker<'_, Set, Ser> {
     pub fn new() -> Self {
         Self {
             callbacks: HashMap::default(),
+            alias_callbacks: HashMap::default(),
         }
     }
 }
@@ -138,7 +144,7 @@ where
     /// ```
     pub fn set_handler<H, O, S>(&mut self, uid: Uid, handler: H, store: S) -> &mut Self
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         S: Store<Set, Ser, O, Ret = bool, Error = Error> + 'a,
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
@@ -149,6 +155,27 @@ where
     pub fn has(&self, uid: Uid) -> bool {
         self.callbacks.contains_key(&uid)
     }
+
+    /// Register a callback that only called when the option matched by name `alias`,
+    /// replace the previous one if exists.
+    pub fn set_alias_raw<H>(&mut self, uid: Uid, alias: impl Into<String>, handler: H) -> &mut Self
+    where
+        H: FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a,
+    {
+        let alias = alias.into();
+        let handlers = self.alias_callbacks.entry(uid).or_default();
+
+        handlers.retain(|(name, _)| name != &alias);
+        handlers.push((alias, Box::new(handler)));
+        self
+    }
+
+    pub fn has_alias(&self, uid: Uid, alias: &str) -> bool {
+        self.alias_callbacks
+            .get(&uid)
+            .map(|v| v.iter().any(|(name, _)| name == alias))
+            .unwrap_or_default()
+    }
 }
 
 impl<'a, Set, Ser> Invoker<'a, Set, Ser>
@@ -158,7 +185,7 @@ where
 {
     pub fn entry<O, H>(&mut self, uid: Uid) -> HandlerEntry<'a, '_, Self, Set, Ser, H, O>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         HandlerEntry::new(self, uid)
@@ -176,7 +203,9 @@ where
         let act = *opt.action();
 
         trace!("invoke fallback for {}({act}) {{{ctx:?}}}", opt.name());
-        opt.accessor_mut().store_all(arg, ctx, &act)
+        let ret = opt.accessor_mut().store_all(arg, ctx, &act);
+
+        ret.option_context(opt.name())
     }
 }
 
@@ -184,6 +213,9 @@ where
 pub type InvokeHandler<'a, Set, Ser, Error> =
     Box<dyn FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a>;
 
+/// Handlers of option keyed by the alias name.
+pub type AliasHandlers<'a, Set, Ser> = Vec<(String, InvokeHandler<'a, Set, Ser, Error>)>;
+
 pub trait HandlerCollection<'a, Set, Ser>
 where
     Set: crate::set::Set,
@@ -196,6 +228,38 @@ where
 
     fn get_handler(&mut self, uid: &Uid) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>>;
 
+    /// Register the handler only called when option matched by name `alias`.
+    fn register_alias<H>(&mut self, uid: Uid, alias: &str, handler: H)
+    where
+        H: FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a;
+
+    /// Get the handler of option matched by name `name`.
+    fn get_alias_handler(
+        &mut self,
+        uid: &Uid,
+        name: &str,
+    ) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>>;
+
+    /// Remove the handler of `uid` and return it.
+    fn take_handler(&mut self, uid: &Uid) -> Option<InvokeHandler<'a, Set, Ser, Error>>;
+
+    /// Remove all the alias handlers of `uid` and return them.
+    fn take_alias_handlers(&mut self, uid: &Uid) -> AliasHandlers<'a, Set, Ser>;
+
+    /// Get the handler of `uid`, prefer the alias handler of the matched name in `ctx`.
+    fn get_ctx_handler(
+        &mut self,
+        uid: &Uid,
+        ctx: &Ctx,
+    ) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>> {
+        match ctx.name().ok().flatten() {
+            Some(name) if self.get_alias_handler(uid, name).is_some() => {
+                self.get_alias_handler(uid, name)
+            }
+            _ => self.get_handler(uid),
+        }
+    }
+
     /// Invoke the handler of given `uid`, will panic if handler not exist.
     fn invoke(
         &mut self,
@@ -205,7 +269,7 @@ where
         ctx: &mut Ctx,
     ) -> Result<bool, Error> {
         trace!("invoking callback of {} {:?}", uid, ctx);
-        if let Some(callback) = self.get_handler(uid) {
+        if let Some(callback) = self.get_ctx_handler(uid, ctx) {
             return (callback)(set, ser, ctx);
         }
         unreachable!(
@@ -222,7 +286,7 @@ where
         ser: &mut Ser,
         ctx: &mut Ctx,
     ) -> Result<bool, Error> {
-        if let Some(callback) = self.get_handler(uid) {
+        if let Some(callback) = self.get_ctx_handler(uid, ctx) {
             trace!("invoking(fb) callback of {} {:?}", uid, ctx);
             (callback)(set, ser, ctx)
         } else {
@@ -247,11 +311,38 @@ where
     fn get_handler(&mut self, uid: &Uid) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>> {
         self.callbacks.get_mut(uid)
     }
+
+    fn register_alias<H>(&mut self, uid: Uid, alias: &str, handler: H)
+    where
+        H: FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a,
+    {
+        self.set_alias_raw(uid, alias, handler);
+    }
+
+    fn get_alias_handler(
+        &mut self,
+        uid: &Uid,
+        name: &str,
+    ) -> Option<&mut InvokeHandler<'a, Set, Ser, Error>> {
+        self.alias_callbacks
+            .get_mut(uid)?
+            .iter_mut()
+            .find(|(alias, _)| alias == name)
+            .map(|(_, handler)| handler)
+    }
+
+    fn take_handler(&mut self, uid: &Uid) -> Option<InvokeHandler<'a, Set, Ser, Error>> {
+        self.callbacks.remove(uid)
+    }
+
+    fn take_alias_handlers(&mut self, uid: &Uid) -> AliasHandlers<'a, Set, Ser> {
+        self.alias_callbacks.remove(uid).unwrap_or_default()
+    }
 }
 
 pub struct HandlerEntry<'a, 'b, I, Set, Ser, H, O>
 where
-    O: ErasedTy,
+    O: ErasedTy + Clone,
     Set: crate::set::Set,
     SetOpt<Set>: Opt,
     I: HandlerCollection<'a, Set, Ser>,
@@ -266,7 +357,7 @@ where
 
 impl<'a, 'b, I, Set, Ser, H, O> HandlerEntry<'a, 'b, I, Set, Ser, H, O>
 where
-    O: ErasedTy,
+    O: ErasedTy + Clone,
     Set: crate::set::Set,
     SetOpt<Set>: Opt,
     I: HandlerCollection<'a, Set, Ser>,
@@ -295,7 +386,7 @@ where
 
 pub struct HandlerEntryThen<'a, 'b, I, Set, Ser, H, O>
 where
-    O: ErasedTy,
+    O: ErasedTy + Clone,
     Set: crate::set::Set,
     SetOpt<Set>: Opt,
     I: HandlerCollection<'a, Set, Ser>,
@@ -316,7 +407,7 @@ where
 
 impl<'a, 'b, I, Set, Ser, H, O> HandlerEntryThen<'a, 'b, I, Set, Ser, H, O>
 where
-    O: ErasedTy,
+    O: ErasedTy + Clone,
     Set: crate::set::Set,
     SetOpt<Set>: Opt,
     I: HandlerCollection<'a, Set, Ser>,
@@ -368,7 +459,7 @@ where
 
 impl<'a, I, Set, Ser, H, O> Drop for HandlerEntryThen<'a, '_, I, Set, Ser, H, O>
 where
-    O: ErasedTy,
+    O: ErasedTy + Clone,
     Set: crate::set::Set,
     SetOpt<Set>: Opt,
     I: HandlerCollection<'a, Set, Ser>,
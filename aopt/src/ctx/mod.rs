@@ -6,6 +6,7 @@ pub(crate) mod store;
 
 pub use self::context::Ctx;
 pub use self::context::InnerCtx;
+pub use self::invoke::AliasHandlers;
 pub use self::invoke::HandlerCollection;
 pub use self::invoke::HandlerEntry;
 pub use self::invoke::HandlerEntryThen;
@@ -16,6 +17,7 @@ pub use self::store::Store;
 pub use self::store::VecStore;
 
 use crate::opt::Opt;
+use crate::ser::ServicesValExt;
 use crate::set::SetExt;
 use crate::set::SetOpt;
 use crate::Error;
@@ -24,6 +26,7 @@ pub use __wrapper::wrap_handler;
 pub use __wrapper::wrap_handler_action;
 pub use __wrapper::wrap_handler_fallback;
 pub use __wrapper::wrap_handler_fallback_action;
+pub use __wrapper::wrap_handler_state;
 
 #[cfg(feature = "sync")]
 mod __wrapper {
@@ -36,7 +39,7 @@ mod __wrapper {
         mut handler: H,
     ) -> impl FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         E: Into<Error>,
         Set: crate::set::Set,
         SetOpt<Set>: Opt,
@@ -90,7 +93,7 @@ mod __wrapper {
         mut handler: H,
     ) -> impl FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         E: Into<Error>,
         Set: crate::set::Set,
         SetOpt<Set>: Opt,
@@ -124,6 +127,20 @@ mod __wrapper {
             store.process(uid, set, ser, arg, val).map_err(Into::into)
         })
     }
+
+    /// Wrap the handler which mutates the shared state `T` kept in services,
+    /// so that the handlers of several options can update the same state without [`RefCell`](std::cell::RefCell).
+    /// The state should be inserted by [`sve_insert`](crate::ser::ServicesValExt::sve_insert) before parsing.
+    pub fn wrap_handler_state<'a, T, Set, Ser, H, O>(
+        mut handler: H,
+    ) -> impl FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a
+    where
+        T: ErasedTy,
+        Ser: ServicesValExt,
+        H: FnMut(&mut T, &mut Set, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+    {
+        move |set: &mut Set, ser: &mut Ser, ctx: &Ctx| (handler)(ser.sve_val_mut::<T>()?, set, ctx)
+    }
 }
 
 #[cfg(not(feature = "sync"))]
@@ -137,7 +154,7 @@ mod __wrapper {
         mut handler: H,
     ) -> impl FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         E: Into<Error>,
         Set: crate::set::Set,
         SetOpt<Set>: Opt,
@@ -191,7 +208,7 @@ mod __wrapper {
         mut handler: H,
     ) -> impl FnMut(&mut Set, &mut Ser, &mut Ctx) -> Result<bool, Error> + 'a
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         E: Into<Error>,
         Set: crate::set::Set,
         SetOpt<Set>: Opt,
@@ -226,4 +243,18 @@ mod __wrapper {
             store.process(uid, set, ser, arg, val).map_err(Into::into)
         })
     }
+
+    /// Wrap the handler which mutates the shared state `T` kept in services,
+    /// so that the handlers of several options can update the same state without [`RefCell`](std::cell::RefCell).
+    /// The state should be inserted by [`sve_insert`](crate::ser::ServicesValExt::sve_insert) before parsing.
+    pub fn wrap_handler_state<'a, T, Set, Ser, H, O>(
+        mut handler: H,
+    ) -> impl FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a
+    where
+        T: ErasedTy,
+        Ser: ServicesValExt,
+        H: FnMut(&mut T, &mut Set, &Ctx) -> Result<Option<O>, Error> + 'a,
+    {
+        move |set: &mut Set, ser: &mut Ser, ctx: &Ctx| (handler)(ser.sve_val_mut::<T>()?, set, ctx)
+    }
 }
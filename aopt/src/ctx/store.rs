@@ -89,7 +89,7 @@ impl<Set, Ser, Value> Store<Set, Ser, Value> for NullStore {
 /// See [`Action`](crate::opt::Action) for default store.
 pub struct VecStore;
 
-impl<Set, Ser, Value: ErasedTy> Store<Set, Ser, Vec<Value>> for VecStore
+impl<Set, Ser, Value: ErasedTy + Clone> Store<Set, Ser, Vec<Value>> for VecStore
 where
     Set: crate::set::Set,
     SetOpt<Set>: Opt,
@@ -10,22 +10,30 @@ use crate::Uid;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The kind of [`Error`], it can be used to distinguish the failures, such as generate custom exit codes.
+#[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Kind {
+pub enum ErrorKind {
     MissingValue,
 
     PosRequired,
 
     OptRequired,
 
+    OptDuplicated,
+
     CmdRequired,
 
     OptionNotFound,
 
+    NoSuchOption,
+
     ExtractValue,
 
     RawValParse,
 
+    Validation,
+
     Arg,
 
     IndexParse,
@@ -43,12 +51,15 @@ pub enum Kind {
     ThreadLocalAccess,
 }
 
-impl Kind {
+/// Alias of [`ErrorKind`].
+pub type Kind = ErrorKind;
+
+impl ErrorKind {
     const fn desp(&self) -> Option<&'static str> {
         match self {
-            Kind::UnexceptedPos => Some("can not insert Pos@1 if Cmd exist"),
-            Kind::ThreadLocalAccess => Some("failed access thread local variable"),
-            Kind::NoParserMatched => Some("all parser passed to `getopt!` match failed"),
+            ErrorKind::UnexceptedPos => Some("can not insert Pos@1 if Cmd exist"),
+            ErrorKind::ThreadLocalAccess => Some("failed access thread local variable"),
+            ErrorKind::NoParserMatched => Some("all parser passed to `getopt!` match failed"),
             _ => None,
         }
     }
@@ -58,10 +69,14 @@ impl Kind {
 pub struct Error {
     uid: Option<Uid>,
 
-    kind: Kind,
+    kind: ErrorKind,
 
     desp: Option<String>,
 
+    snippet: Option<String>,
+
+    usage: Option<String>,
+
     cause: Option<Box<Error>>,
 }
 
@@ -92,11 +107,13 @@ impl Display for Error {
 }
 
 impl Error {
-    pub fn new(kind: Kind) -> Self {
+    pub fn new(kind: ErrorKind) -> Self {
         Self {
             kind,
             uid: None,
             desp: None,
+            snippet: None,
+            usage: None,
             cause: None,
         }
     }
@@ -120,11 +137,45 @@ impl Error {
         self
     }
 
+    /// Insert the context of option `name` into the error chain, right under the error,
+    /// such as ``while processing option `--num` ``.
+    /// The [`kind`](Error::kind) of context is same as the error, and the original cause is kept under it.
+    pub fn with_option(mut self, name: impl Into<String>) -> Self {
+        let desp = format!("while processing option `{}`", name.into());
+        let mut context = Self::new(self.kind).with_desp(desp);
+
+        context.cause = self.cause.take();
+        self.cause_by(context)
+    }
+
+    /// Set the snippet of arguments with a caret under the offending one,
+    /// see [`FailManager::render`](crate::parser::FailManager::render).
+    /// It is not included in the [`Display`] of error, get it by [`snippet`](Error::snippet).
+    pub fn with_snippet(mut self, snippet: String) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Set the usage line of command which failed,
+    /// such as the usage of sub command `app sub` when parsing it failed.
+    pub fn with_usage(mut self, usage: String) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
     pub fn uid(&self) -> Option<Uid> {
         self.uid
     }
 
-    pub fn kind(&self) -> &Kind {
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
+
+    pub fn usage(&self) -> Option<&str> {
+        self.usage.as_deref()
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
         &self.kind
     }
 
@@ -132,34 +183,79 @@ impl Error {
         self.cause.as_deref()
     }
 
+    /// Return the kind of root cause in the error chain,
+    /// the generic [`Failure`](ErrorKind::Failure) and [`Error`](ErrorKind::Error) are skipped.
+    pub fn specific_kind(&self) -> ErrorKind {
+        let mut kind = self.kind;
+        let mut error = Some(self);
+
+        while let Some(inner) = error {
+            if !matches!(inner.kind, ErrorKind::Failure | ErrorKind::Error) {
+                kind = inner.kind;
+            }
+            error = inner.caused_by();
+        }
+        kind
+    }
+
+    /// Return true if the error is caused by bad command line usage, such as unknown flags,
+    /// missing or invalid values, derived from [`specific_kind`](Error::specific_kind).
+    ///
+    /// Other errors are logic errors raised by the application.
+    pub fn is_usage(&self) -> bool {
+        matches!(
+            self.specific_kind(),
+            ErrorKind::MissingValue
+                | ErrorKind::PosRequired
+                | ErrorKind::OptRequired
+                | ErrorKind::OptDuplicated
+                | ErrorKind::CmdRequired
+                | ErrorKind::OptionNotFound
+                | ErrorKind::RawValParse
+                | ErrorKind::Validation
+                | ErrorKind::Arg
+        )
+    }
+
+    /// Return the exit code of process, it is `2` for [`usage`](Error::is_usage) errors, otherwise `1`.
+    pub fn exit_code(&self) -> i32 {
+        if self.is_usage() {
+            2
+        } else {
+            1
+        }
+    }
+
     /// The error can be moitted if [`is_failure`](Error::is_failure) return true.
     pub fn is_failure(&self) -> bool {
         let kind = &self.kind;
 
         matches!(
             kind,
-            Kind::RawValParse
-                | Kind::Failure
-                | Kind::ExtractValue
-                | Kind::OptionNotFound
-                | Kind::CmdRequired
-                | Kind::PosRequired
-                | Kind::OptRequired
-                | Kind::MissingValue
+            ErrorKind::RawValParse
+                | ErrorKind::Validation
+                | ErrorKind::Failure
+                | ErrorKind::ExtractValue
+                | ErrorKind::OptionNotFound
+                | ErrorKind::CmdRequired
+                | ErrorKind::PosRequired
+                | ErrorKind::OptRequired
+                | ErrorKind::OptDuplicated
+                | ErrorKind::MissingValue
         )
     }
 
     /// No Pos@1 allowed if the option set has cmd.
     pub fn unexcepted_pos() -> Self {
-        Self::new(Kind::UnexceptedPos)
+        Self::new(ErrorKind::UnexceptedPos)
     }
 
     pub fn thread_local_access() -> Self {
-        Self::new(Kind::ThreadLocalAccess)
+        Self::new(ErrorKind::ThreadLocalAccess)
     }
 
     pub fn no_parser_matched() -> Self {
-        Self::new(Kind::NoParserMatched)
+        Self::new(ErrorKind::NoParserMatched)
     }
 
     pub fn from<E: std::error::Error + Display>(error: E) -> Self {
@@ -169,19 +265,44 @@ impl Error {
     pub fn arg(arg: impl Into<String>, hint: impl Into<String>) -> Self {
         let desp = format!("invalid argument `{}`: {}", arg.into(), hint.into());
 
-        Self::new(Kind::Arg).with_desp(desp)
+        Self::new(ErrorKind::Arg).with_desp(desp)
     }
 
     pub fn sp_rawval(val: Option<&OsStr>, hint: impl Into<String>) -> Self {
         let desp = format!("invalid value `{}`: {}", display_of_osstr(val), hint.into());
 
-        Self::new(Kind::RawValParse).with_desp(desp)
+        Self::new(ErrorKind::RawValParse).with_desp(desp)
+    }
+
+    pub fn sp_validation(msg: impl Into<String>) -> Self {
+        let desp = format!("value check failed: `{}`", msg.into());
+
+        Self::new(ErrorKind::Validation).with_desp(desp)
+    }
+
+    /// The value `val` of `target` failed the check, such as
+    /// ``invalid value `abc` for option `--num`: value must be a number``.
+    pub fn sp_invalid_value(
+        val: Option<&OsStr>,
+        target: impl std::fmt::Display,
+        reason: impl Into<String>,
+    ) -> Self {
+        let desp = match val {
+            Some(val) => format!(
+                "invalid value `{}` for {target}: {}",
+                std::path::Path::new(val).display(),
+                reason.into()
+            ),
+            None => format!("invalid value for {target}: {}", reason.into()),
+        };
+
+        Self::new(ErrorKind::Validation).with_desp(desp)
     }
 
     pub fn index_parse(pat: impl Into<String>, hint: impl Into<String>) -> Self {
         let desp = format!("invalid index string `{}`: {}", pat.into(), hint.into());
 
-        Self::new(Kind::IndexParse).with_desp(desp)
+        Self::new(ErrorKind::IndexParse).with_desp(desp)
     }
 
     pub fn create_str(pat: impl Into<String>, hint: impl Into<String>) -> Self {
@@ -191,21 +312,21 @@ impl Error {
             hint.into()
         );
 
-        Self::new(Kind::CreateStrParse).with_desp(desp)
+        Self::new(ErrorKind::CreateStrParse).with_desp(desp)
     }
 
     pub fn raise_error(msg: impl Into<String>) -> Self {
-        Self::new(Kind::Error).with_desp(msg.into())
+        Self::new(ErrorKind::Error).with_desp(msg.into())
     }
 
     pub fn raise_failure(msg: impl Into<String>) -> Self {
-        Self::new(Kind::Failure).with_desp(msg.into())
+        Self::new(ErrorKind::Failure).with_desp(msg.into())
     }
 
     pub fn sp_missing_value(name: impl Into<String>) -> Self {
         let desp = format!("missing value for option `{}`", name.into());
 
-        Self::new(Kind::MissingValue).with_desp(desp)
+        Self::new(ErrorKind::MissingValue).with_desp(desp)
     }
 
     pub fn sp_pos_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -219,7 +340,7 @@ impl Error {
             }
         };
 
-        Self::new(Kind::PosRequired).with_desp(desp)
+        Self::new(ErrorKind::PosRequired).with_desp(desp)
     }
 
     pub fn sp_opt_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -233,7 +354,37 @@ impl Error {
             }
         };
 
-        Self::new(Kind::OptRequired).with_desp(desp)
+        Self::new(ErrorKind::OptRequired).with_desp(desp)
+    }
+
+    /// Exactly one of the options `names` in `group` is required, but `count` of them are set.
+    pub fn sp_exactly_one<S: Into<String>>(group: &str, names: Vec<S>, count: usize) -> Self {
+        let names: Vec<_> = names.into_iter().map(Into::into).collect();
+        let desp = match count {
+            0 => format!(
+                "exactly one of option `{}` in group `{group}` is required, but none is set",
+                names.join(", ")
+            ),
+            _ => format!(
+                "exactly one of option `{}` in group `{group}` is required, but {count} are set",
+                names.join(", ")
+            ),
+        };
+
+        Self::new(ErrorKind::OptRequired).with_desp(desp)
+    }
+
+    /// The `target` is used more than once, such as ``option `--output` used more than once``.
+    pub fn sp_duplicate(target: impl std::fmt::Display) -> Self {
+        let desp = format!("{target} used more than once");
+
+        Self::new(ErrorKind::OptDuplicated).with_desp(desp)
+    }
+
+    pub fn sp_max_occurrences(target: impl std::fmt::Display, max: usize) -> Self {
+        let desp = format!("{target} used more than {max} times");
+
+        Self::new(ErrorKind::OptDuplicated).with_desp(desp)
     }
 
     pub fn sp_cmd_require<S: Into<String>>(names: Vec<S>) -> Self {
@@ -247,19 +398,27 @@ impl Error {
             }
         };
 
-        Self::new(Kind::CmdRequired).with_desp(desp)
+        Self::new(ErrorKind::CmdRequired).with_desp(desp)
     }
 
     pub fn sp_not_found(name: impl Into<String>) -> Self {
         let desp = format!("can not find option `{}`", name.into());
 
-        Self::new(Kind::OptionNotFound).with_desp(desp)
+        Self::new(ErrorKind::OptionNotFound).with_desp(desp)
+    }
+
+    /// No option named `name` is registered in the set,
+    /// it is distinct from the option exists but has no value.
+    pub fn no_such_option(name: impl Into<String>) -> Self {
+        let desp = format!("no option named `{}`", name.into());
+
+        Self::new(ErrorKind::NoSuchOption).with_desp(desp)
     }
 
     pub fn sp_extract(msg: impl Into<String>) -> Self {
         let desp = format!("extract value failed: `{}`", msg.into());
 
-        Self::new(Kind::ExtractValue).with_desp(desp)
+        Self::new(ErrorKind::ExtractValue).with_desp(desp)
     }
 }
 
@@ -281,6 +440,17 @@ impl From<AccessError> for Error {
     }
 }
 
+/// Attach the option context to the error of [`Result`], see [`Error::with_option`].
+pub trait ResultExt<T> {
+    fn option_context(self, name: impl Into<String>) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> ResultExt<T> for std::result::Result<T, E> {
+    fn option_context(self, name: impl Into<String>) -> Result<T> {
+        self.map_err(|e| e.into().with_option(name))
+    }
+}
+
 #[macro_export]
 macro_rules! raise_error {
     ($($arg:tt)*) => {
@@ -294,3 +464,82 @@ macro_rules! raise_failure {
         $crate::Error::raise_failure(format!($($arg)*))
     };
 }
+
+#[cfg(test)]
+mod test {
+    use super::Error;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_exit_code() {
+        assert!(test_exit_code_impl().is_ok());
+    }
+
+    fn test_exit_code_impl() -> Result<(), Error> {
+        assert_eq!(Error::sp_missing_value("--name").exit_code(), 2);
+        assert_eq!(Error::sp_opt_require(vec!["--name"]).exit_code(), 2);
+        assert_eq!(Error::sp_validation("--level").exit_code(), 2);
+        assert_eq!(Error::raise_error("can not open file").exit_code(), 1);
+        assert_eq!(Error::index_parse("1..a", "invalid").exit_code(), 1);
+        assert_eq!(
+            Error::raise_failure("parsing failed")
+                .cause_by(Error::sp_not_found("--unknown"))
+                .exit_code(),
+            2
+        );
+        assert!(Error::sp_validation("--level").is_usage());
+        assert!(!Error::raise_error("can not open file").is_usage());
+        Ok(())
+    }
+
+    #[test]
+    fn test_option_context() {
+        assert!(test_option_context_impl().is_ok());
+    }
+
+    fn test_option_context_impl() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--num=i")?;
+        parser
+            .add_opt("--level".infer::<i64>())?
+            .set_validator(ValValidator::range_from(1));
+        parser.add_opt("file=p!@1")?.set_pos_type::<i64>();
+
+        let contexts = |error: &Error| {
+            let mut cause = error.caused_by();
+            let mut descs = vec![];
+
+            while let Some(error) = cause {
+                descs.push(error.to_string());
+                cause = error.caused_by();
+            }
+            descs
+        };
+
+        for (args, name) in [
+            (["app", "--num", "abc", "42"], "--num"),
+            (["app", "--level", "0", "42"], "--level"),
+            (["app", "--num", "1", "abc"], "file"),
+        ] {
+            let ret = parser.parse(Args::from(args))?;
+            let error = ret.failure().unwrap();
+
+            assert!(
+                contexts(error).contains(&format!("while processing option `{name}`")),
+                "{:?}",
+                contexts(error)
+            );
+        }
+
+        let error = Error::sp_rawval(None, "not a number")
+            .cause_by(Error::raise_failure("inner"))
+            .with_option("--num");
+        let context = error.caused_by().unwrap();
+
+        assert_eq!(error.kind(), context.kind());
+        assert_eq!(context.to_string(), "while processing option `--num`");
+        assert_eq!(context.caused_by().unwrap().to_string(), "inner");
+        Ok(())
+    }
+}
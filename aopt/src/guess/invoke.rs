@@ -4,9 +4,14 @@ use std::ffi::OsStr;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
+use crate::opt::EmptyValuePolicy;
+use crate::opt::Opt;
 use crate::opt::Style;
 use crate::opt::BOOL_TRUE;
+use crate::parser::check_before_invoke;
+use crate::parser::deferred_style;
 use crate::parser::FailManager;
+use crate::parser::GuessResult;
 use crate::parser::UserStyle;
 use crate::set::OptValidator;
 use crate::str::CowStrUtils;
@@ -115,6 +120,32 @@ impl<'a, 'b, Set, Inv, Ser> InvokeGuess<'a, 'b, Set, Inv, Ser> {
         self
     }
 
+    /// Replace the name and argument with `ret` resolved by custom style,
+    /// call `f` with the style matching them, then restore the name and argument.
+    ///
+    /// The style is [`EqualWithValue`](UserStyle::EqualWithValue) if the value exists,
+    /// otherwise [`Boolean`](UserStyle::Boolean).
+    pub fn with_custom<R>(
+        &mut self,
+        ret: GuessResult,
+        f: impl FnOnce(&mut Self, &UserStyle) -> R,
+    ) -> R {
+        let style = if ret.value.is_some() {
+            UserStyle::EqualWithValue
+        } else {
+            UserStyle::Boolean
+        };
+        let name = self.name.replace(Cow::Owned(ret.name));
+        let arg = std::mem::replace(&mut self.arg, ret.value.map(Cow::Owned));
+        let next = self.next.take();
+        let ret = f(self, &style);
+
+        self.name = name;
+        self.arg = arg;
+        self.next = next;
+        ret
+    }
+
     pub fn set_next(&mut self, next: Option<Cow<'b, OsStr>>) -> &mut Self {
         self.next = next;
         self
@@ -217,7 +248,13 @@ where
                     GuessPolicy::<EqualWithValuStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, consume)? {
-                        matched = self.invoke(&mut policy, false)?;
+                        let unset = self.process_empty_value(&mut policy)?;
+
+                        if policy.matched() {
+                            matched = self.invoke(&mut policy, false)?;
+                        } else {
+                            matched = unset;
+                        }
                     }
                 }
             }
@@ -227,6 +264,7 @@ where
                 {
                     consume = true;
                     if self.r#match(&mut policy, overload, consume)? {
+                        consume = !self.process_optional_value(&mut policy);
                         matched = self.invoke(&mut policy, false)?;
                     }
                 }
@@ -280,6 +318,8 @@ where
                     }
                 }
             }
+            // custom styles only resolve the name and value of option
+            UserStyle::Custom(_) => {}
         }
         trace!(
             "guess style = {:?}, overload = {} ---> matched = {}, consume = {}",
@@ -337,9 +377,14 @@ where
                     GuessPolicy::<EqualWithValuStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, false)? {
+                        let unset = self.process_empty_value(&mut policy)?;
+
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default().with_policy_ctx(vec![inner_ctx])
                         });
+                        if ret.is_none() && unset {
+                            ret = Some(InnerCtxSaver::default());
+                        }
                     }
                 }
             }
@@ -348,10 +393,12 @@ where
                     GuessPolicy::<ArgumentStyle, SingleOpt<Set>>::guess_policy(self)?
                 {
                     if self.r#match(&mut policy, overload, true)? {
+                        let consume = !self.process_optional_value(&mut policy);
+
                         ret = policy.collect_ctx().map(|inner_ctx| {
                             InnerCtxSaver::default()
                                 .with_policy_ctx(vec![inner_ctx])
-                                .with_consume(true)
+                                .with_consume(consume)
                         });
                     }
                 }
@@ -411,6 +458,8 @@ where
                     }
                 }
             }
+            // custom styles only resolve the name and value of option
+            UserStyle::Custom(_) => {}
         }
         if ret.is_some() {
             trace!(
@@ -454,7 +503,9 @@ where
     type Error = Error;
 
     fn guess_policy(&mut self) -> Result<Option<T>, Self::Error> {
-        if self.arg.is_none() && self.next.is_some() {
+        // keep guessing if next argument is none, report missing value for the argument option,
+        // or the option has optional value can be matched without next argument
+        if self.arg.is_none() {
             if let Some(name) = &self.name {
                 return Ok(Some(
                     T::default()
@@ -606,19 +657,21 @@ where
     type Error = Error;
 
     fn guess_policy(&mut self) -> Result<Option<T>, Self::Error> {
-        let arg = Some(Cow::Borrowed(OsStr::new(BOOL_TRUE)));
-
-        if self.arg.is_none() {
-            if let Some(name) = &self.name {
-                return Ok(Some(
-                    T::default()
-                        .with_idx(self.idx)
-                        .with_tot(self.total)
-                        .with_name(Some(name.clone()))
-                        .with_arg(arg)
-                        .with_style(Style::Boolean),
-                ));
-            }
+        // the value of `--bool=false` will be parsed by the storer of option
+        let arg = self
+            .arg
+            .clone()
+            .or(Some(Cow::Borrowed(OsStr::new(BOOL_TRUE))));
+
+        if let Some(name) = &self.name {
+            return Ok(Some(
+                T::default()
+                    .with_idx(self.idx)
+                    .with_tot(self.total)
+                    .with_name(Some(name.clone()))
+                    .with_arg(arg)
+                    .with_style(Style::Boolean),
+            ));
         }
         Ok(None)
     }
@@ -786,6 +839,61 @@ where
         Ok(policy.matched())
     }
 
+    /// Apply the [`EmptyValuePolicy`] of matched options if the value after `=` is empty or whitespace.
+    /// Return true if any option want leave it unset.
+    fn process_empty_value(&mut self, policy: &mut SingleOpt<'b, Set>) -> Result<bool, Error> {
+        let empty = policy
+            .arg()
+            .map(|arg| arg.to_str().is_some_and(|v| v.trim().is_empty()))
+            .unwrap_or_default();
+        let mut unset = false;
+
+        if empty {
+            let uids = policy.uids().to_vec();
+
+            policy.reset();
+            for uid in uids {
+                if let Some(opt) = self.set.get(uid) {
+                    match opt.empty_value() {
+                        EmptyValuePolicy::Error => {
+                            return Err(Error::sp_missing_value(opt.hint()).with_uid(uid));
+                        }
+                        EmptyValuePolicy::Unset => {
+                            unset = true;
+                        }
+                        _ => policy.set_uid(uid),
+                    }
+                }
+            }
+        }
+        Ok(unset)
+    }
+
+    /// Use the optional value of matched options instead of the next argument.
+    /// Return true if any option has an optional value, the next argument is not consumed.
+    fn process_optional_value(&mut self, policy: &mut SingleOpt<'b, Set>) -> bool {
+        let optional: Vec<_> = policy
+            .uids()
+            .iter()
+            .filter_map(|uid| {
+                self.set
+                    .get(*uid)
+                    .and_then(|opt| opt.optional_value().map(|v| (*uid, v.to_os_string())))
+            })
+            .collect();
+
+        if let Some((_, value)) = optional.first() {
+            policy.set_arg(Some(Cow::Owned(value.clone())));
+            policy.reset();
+            for (uid, _) in optional {
+                policy.set_uid(uid);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn invoke<T>(&mut self, policy: &mut T, all: bool) -> Result<bool, Error>
     where
         T: PolicyConfig<'b> + MatchPolicy<Set = Set>,
@@ -797,22 +905,27 @@ where
             .with_arg(policy.arg().cloned())
             .with_style(policy.style());
         let uids = policy.uids().to_vec();
+        let deferred = deferred_style(self.ctx, policy.style());
         let mut result = false;
 
         for uid in uids {
             self.ctx
                 .set_inner_ctx(Some(inner_ctx.clone().with_uid(uid)));
+            check_before_invoke(self.set, self.inv, self.ctx, uid)?;
 
             // invoke the handler of `uid`
             let invoke_ret = self.inv.invoke_fb(&uid, self.set, self.ser, self.ctx);
             let when_fail = |e| {
-                self.fail.push(e);
+                self.fail.push_ctx(e, self.ctx, self.set);
                 Ok(())
             };
 
             // return first index if handler success
             if process_handler_ret(invoke_ret, |_| Ok(()), when_fail)? {
                 result = true;
+                if deferred {
+                    self.ctx.add_pending_check(uid);
+                }
                 policy.apply(uid, self.set).map_err(Into::into)?;
                 if !all {
                     // may return if first matched, for option
@@ -4,6 +4,7 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 
 use crate::ctx::InnerCtx;
+use crate::opt::Index;
 use crate::opt::Opt;
 use crate::opt::Style;
 use crate::set::Set;
@@ -204,9 +205,13 @@ where
                 if !opt.ignore_name() {
                     matched = matched && opt.mat_name(self.name().map(|v| v.as_ref()));
                 }
-                if !opt.ignore_alias() && opt.alias().is_some() {
+                if !opt.ignore_alias()
+                    && (opt.alias().is_some() || opt.deprecated_alias().is_some())
+                {
                     if let Some(name) = &self.name {
-                        matched = matched || opt.mat_alias(name);
+                        if !matched && opt.mat_alias(name) {
+                            matched = true;
+                        }
                     }
                 }
                 if !opt.ignore_index() {
@@ -218,6 +223,15 @@ where
                         }
                     };
                 }
+                if matched
+                    && set.skip_claimed_pos()
+                    && self.style == Style::Pos
+                    && opt.index().is_some_and(Index::is_anywhere)
+                {
+                    // bind the first position not claimed by fixed position `Pos`
+                    matched = (!opt.action().is_single() || !opt.matched())
+                        && !claimed_by_fixed_pos(set, uid, self.index, self.total);
+                }
             }
             if matched {
                 self.set_uid(uid);
@@ -228,3 +242,16 @@ where
         }
     }
 }
+
+fn claimed_by_fixed_pos<S>(set: &S, uid: Uid, index: usize, total: usize) -> bool
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    set.iter().any(|opt| {
+        opt.uid() != uid
+            && opt.mat_style(Style::Pos)
+            && opt.index().is_some_and(Index::is_fixed)
+            && opt.mat_index(Some((index, total)))
+    })
+}
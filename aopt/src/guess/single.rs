@@ -146,6 +146,10 @@ impl<'a, S> SingleOpt<'a, S> {
     pub fn set_uid(&mut self, uid: Uid) {
         self.uids.push(uid);
     }
+
+    pub fn set_arg(&mut self, arg: Option<Cow<'a, OsStr>>) {
+        self.arg = arg;
+    }
 }
 
 impl<S> MatchPolicy for SingleOpt<'_, S>
@@ -208,10 +212,13 @@ where
                     if !opt.ignore_name() {
                         matched = opt.mat_name(self.name().map(|v| v.as_ref()));
                     }
-                    if !opt.ignore_alias() && opt.alias().is_some() {
+                    if !opt.ignore_alias()
+                        && (opt.alias().is_some() || opt.deprecated_alias().is_some())
+                    {
                         if let Some(name) = self.name.as_ref() {
-                            // FIXME remove unwrap
-                            matched = matched || opt.mat_alias(name)
+                            if !matched && opt.mat_alias(name) {
+                                matched = true;
+                            }
                         }
                     }
                     if !opt.ignore_index() {
@@ -225,7 +232,7 @@ where
                     }
                 }
                 if matched {
-                    if consume && self.arg.is_none() {
+                    if consume && self.arg.is_none() && opt.optional_value().is_none() {
                         return Err(Error::sp_missing_value(opt.hint()).with_uid(uid));
                     }
                     self.set_uid(uid);
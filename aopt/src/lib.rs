@@ -1,4 +1,5 @@
-#![doc = include_str!("../README.md")]
+// The examples of README read the process arguments, which requires the `std` feature.
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
 pub mod args;
 pub mod ctx;
 pub mod err;
@@ -7,6 +8,7 @@ pub mod guess;
 pub mod map;
 pub mod opt;
 pub mod parser;
+pub mod quick;
 pub mod ser;
 pub mod set;
 #[cfg(feature = "shell")]
@@ -34,7 +36,9 @@ pub(crate) mod log {
 }
 
 pub use crate::err::Error;
+pub use crate::err::ErrorKind;
 pub use crate::err::Result;
+pub use crate::err::ResultExt;
 
 use std::any::TypeId;
 
@@ -218,11 +222,99 @@ macro_rules! getopt {
     };
 }
 
+/// Parse the common options with `common` first, then parse the arguments left with
+/// the given parsers same as [`getopt!`].
+///
+/// The common options, such as `--verbose`, are declared once in `common` and parsed
+/// regardless of which parser matches, so `common` should be a parser ignoring the unknown options,
+/// i.e. [`APreParser`](crate::prelude::APreParser).
+/// The parsers are tried in the given order with the arguments left by `common`,
+/// the first one parsing successed is matched.
+/// So when only common options are present, the first parser accepting the empty arguments is matched.
+///
+/// Return the failure of `common` if it parsing failed, otherwise return the result of [`getopt!`].
+///
+/// # Example
+///
+/// ```rust
+/// # use aopt::err::Result;
+/// # use aopt::prelude::*;
+/// #
+/// # fn main() -> Result<()> {
+/// let mut common = APreParser::default();
+/// let mut build = AFwdParser::default();
+/// let mut list = AFwdParser::default();
+///
+/// common.add_opt("-v;--verbose=b")?;
+/// build.add_opt("build=c")?;
+/// build.add_opt("--release=b")?;
+/// list.add_opt("--all=b")?;
+///
+/// let ret = getopt_with_common!(
+///     Args::from(["app", "build", "-v", "--release"]),
+///     &mut common;
+///     "build" => &mut build,
+///     "list" => &mut list
+/// )?;
+///
+/// assert_eq!(ret.parser, "build");
+/// assert_eq!(common.find_val::<bool>("--verbose")?, &true);
+/// assert_eq!(build.find_val::<bool>("--release")?, &true);
+///
+/// common.reset()?;
+/// build.reset()?;
+///
+/// // `build` failed because the command is missing, so `list` is matched
+/// let ret = getopt_with_common!(Args::from(["app", "--verbose"]), &mut common; &mut build, &mut list)?;
+///
+/// assert!(ret.ret.status());
+/// assert_eq!(common.find_val::<bool>("--verbose")?, &true);
+/// assert_eq!(list.find_val::<bool>("--all")?, &false);
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! getopt_with_common {
+    ($args:expr, $common:path; $($parsers:tt)+) => {
+        $crate::getopt_with_common!($args, &mut $common; $($parsers)+)
+    };
+    ($args:expr, &mut $common:path; $($parsers:tt)+) => {
+        {
+            fn __check_c<'a, 'b, P: $crate::prelude::Policy<Error = $crate::Error>>
+                (p: &'b mut $crate::prelude::Parser<'a, P>) -> &'b mut $crate::prelude::Parser<'a, P>
+                { p }
+            fn __check_a(a: $crate::prelude::Args) -> $crate::prelude::Args { a }
+
+            let common = __check_c(&mut $common);
+
+            match $crate::parser::Parser::parse(common, __check_a($args)) {
+                Ok(mut common_ret) => {
+                    if let Some(error) = common_ret.take_failure() {
+                        Err(error)
+                    }
+                    else {
+                        $crate::getopt!($crate::prelude::Args::from(common_ret), $($parsers)+)
+                    }
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+}
+
 pub mod prelude {
+    pub use crate::args::ArgCursor;
+    pub use crate::args::ArgSep;
+    pub use crate::args::ArgSource;
     pub use crate::args::Args;
+    #[cfg(feature = "std")]
+    pub use crate::args::EnvArgs;
+    pub use crate::args::BIN_PLACEHOLDER;
     pub use crate::ctx::wrap_handler;
     pub use crate::ctx::wrap_handler_action;
     pub use crate::ctx::wrap_handler_fallback_action;
+    pub use crate::ctx::wrap_handler_state;
     pub use crate::ctx::Ctx;
     pub use crate::ctx::HandlerCollection;
     pub use crate::ctx::InnerCtx;
@@ -230,8 +322,10 @@ pub mod prelude {
     pub use crate::ctx::NullStore;
     pub use crate::ctx::Store;
     pub use crate::ctx::VecStore;
+    pub use crate::err::ResultExt;
     pub use crate::ext::*;
     pub use crate::getopt;
+    pub use crate::getopt_with_common;
     pub use crate::map::ErasedTy;
     pub use crate::opt::AOpt;
     pub use crate::opt::Action;
@@ -245,11 +339,13 @@ pub mod prelude {
     pub use crate::opt::ConfigValue;
     pub use crate::opt::ConstrctInfo;
     pub use crate::opt::Creator;
+    pub use crate::opt::EmptyValuePolicy;
     pub use crate::opt::Help;
     pub use crate::opt::Index;
     pub use crate::opt::Information;
     pub use crate::opt::Main;
     pub use crate::opt::MutOpt;
+    pub use crate::opt::NumberFormat;
     pub use crate::opt::Opt;
     pub use crate::opt::OptConfig;
     pub use crate::opt::OptParser;
@@ -259,11 +355,21 @@ pub mod prelude {
     pub use crate::opt::Serde;
     pub use crate::opt::StrParser;
     pub use crate::opt::Style;
+    #[cfg(feature = "std")]
+    pub use crate::parser::read_prompt;
+    #[cfg(feature = "std")]
+    pub use crate::parser::stdin_prompt;
+    pub use crate::parser::AmbiguityMode;
+    pub use crate::parser::CheckPhase;
+    pub use crate::parser::CustomCheck;
     pub use crate::parser::DefaultSetChecker;
     pub use crate::parser::DelayPolicy;
     pub use crate::parser::FwdPolicy;
+    pub use crate::parser::GuessResult;
+    pub use crate::parser::GuessTrace;
     pub use crate::parser::HCOptSet;
     pub use crate::parser::OptStyleManager;
+    pub use crate::parser::ParseStatus;
     pub use crate::parser::Parser;
     pub use crate::parser::ParserCommit;
     pub use crate::parser::ParserCommitWithValue;
@@ -271,7 +377,11 @@ pub mod prelude {
     pub use crate::parser::PolicyParser;
     pub use crate::parser::PolicySettings;
     pub use crate::parser::PrePolicy;
+    pub use crate::parser::PromptHandler;
     pub use crate::parser::Return;
+    pub use crate::parser::SetSnapshot;
+    pub use crate::parser::UnknownAction;
+    pub use crate::parser::UnknownHandler;
     pub use crate::parser::UserStyle;
     pub use crate::ser::AppServices;
     pub use crate::ser::ServicesValExt;
@@ -296,8 +406,10 @@ pub mod prelude {
     pub use crate::set::SetValueFindExt;
     pub use crate::value::AnyValue;
     pub use crate::value::ErasedValue;
+    pub use crate::value::FromStdin;
     pub use crate::value::Infer;
     pub use crate::value::InitializeValue;
+    pub use crate::value::ParserRegistry;
     pub use crate::value::RawValParser;
     pub use crate::value::ValAccessor;
     pub use crate::value::ValInitializer;
@@ -19,6 +19,12 @@ pub enum Action {
     /// Set and replace current value of [`AnyValue`]
     Set,
 
+    /// Same as [`Set`](Action::Set), the last occurrence of option wins
+    SetLast,
+
+    /// Set the value of first occurrence, the later occurrences of option are ignored
+    SetFirst,
+
     /// Append value into [`AnyValue`]
     App,
 
@@ -41,6 +47,20 @@ impl Action {
         matches!(self, Self::Set)
     }
 
+    pub fn is_set_last(&self) -> bool {
+        matches!(self, Self::SetLast)
+    }
+
+    pub fn is_set_first(&self) -> bool {
+        matches!(self, Self::SetFirst)
+    }
+
+    /// Return true if the action keeps only one value: [`Set`](Action::Set),
+    /// [`SetLast`](Action::SetLast) or [`SetFirst`](Action::SetFirst).
+    pub fn is_single(&self) -> bool {
+        matches!(self, Self::Set | Self::SetLast | Self::SetFirst)
+    }
+
     pub fn is_app(&self) -> bool {
         matches!(self, Self::App)
     }
@@ -62,7 +82,10 @@ impl Action {
     }
 
     /// Save the value in [`handler`](AnyValue).
-    pub fn store1<U: ErasedTy>(&self, val: Option<U>, handler: &mut AnyValue) -> bool {
+    ///
+    /// The [`SetFirst`](Action::SetFirst) is same as [`Set`](Action::Set) here,
+    /// the later occurrences are skipped by the caller which knows the occurrences of option.
+    pub fn store1<U: ErasedTy + Clone>(&self, val: Option<U>, handler: &mut AnyValue) -> bool {
         crate::trace!(
             "saving value {:?}({:?}) [ty = {}] = {:?} in store1",
             val,
@@ -72,7 +95,7 @@ impl Action {
         );
         if let Some(val) = val {
             match self {
-                Action::Set => {
+                Action::Set | Action::SetLast | Action::SetFirst => {
                     handler.set(vec![val]);
                 }
                 Action::App => {
@@ -99,7 +122,7 @@ impl Action {
     }
 
     /// Save the value in [`handler`](AnyValue) and raw value in `raw_handler`.
-    pub fn store2<U: ErasedTy>(
+    pub fn store2<U: ErasedTy + Clone>(
         &self,
         raw: Option<&OsStr>,
         val: Option<U>,
@@ -124,6 +147,10 @@ impl Action {
 ///
 /// * [`Action::Set`] : Set the option value to `vec![ val ]`.
 ///
+/// * [`Action::SetLast`] : Same as [`Action::Set`].
+///
+/// * [`Action::SetFirst`] : Set the option value to `vec![ val ]` if the option has not stored any value.
+///
 /// * [`Action::App`] : Append the value to value vector.
 ///
 /// * [`Action::Pop`] : Pop last value from value vector.
@@ -135,7 +162,7 @@ impl Action {
 /// * [`Action::Null`] : Do nothing.
 impl<Set, Ser, Val> Store<Set, Ser, Val> for Action
 where
-    Val: ErasedTy,
+    Val: ErasedTy + Clone,
     SetOpt<Set>: Opt,
     Set: crate::set::Set,
 {
@@ -154,9 +181,21 @@ where
         let opt = set.opt_mut(uid)?;
 
         crate::trace!("storing value of {} = `{:?}`", opt.name(), raw);
-        let (raw_handler, handler) = opt.accessor_mut().handlers();
+        if self.is_set_first() && opt.accessor().occurrences() > 0 {
+            // keep the value of first occurrence
+            return Ok(val.is_some());
+        }
+        let accessor = opt.accessor_mut();
+        let (raw_handler, handler) = accessor.handlers();
         // Set the value if return Some(Value)
-        Ok(self.store2(raw, val, raw_handler, handler))
+        let ret = self.store2(raw, val, raw_handler, handler);
+
+        if ret {
+            let occurrences = accessor.occurrences();
+
+            accessor.set_occurrences(occurrences + 1);
+        }
+        Ok(ret)
     }
 }
 
@@ -166,6 +205,12 @@ impl std::fmt::Display for Action {
             Action::Set => {
                 write!(f, "Action::Set")
             }
+            Action::SetLast => {
+                write!(f, "Action::SetLast")
+            }
+            Action::SetFirst => {
+                write!(f, "Action::SetFirst")
+            }
             Action::App => {
                 write!(f, "Action::App")
             }
@@ -184,3 +229,42 @@ impl std::fmt::Display for Action {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_set_last_first() {
+        assert!(test_set_last_first_impl().is_ok());
+    }
+
+    fn test_set_last_first_impl() -> Result<(), Error> {
+        let args = ["app", "--n", "1", "--n", "2", "--m", "1", "--m", "2"];
+
+        for (act, n) in [(Action::SetLast, 2), (Action::SetFirst, 1)] {
+            let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+            parser.add_opt("--n=i")?.set_action(act).set_value_t(0i64);
+            let uid = parser.add_opt("--m=i")?.set_action(act).run()?;
+
+            parser
+                .entry(uid)?
+                .on(|_, _, ctx| Ok(Some(ctx.value::<i64>()? * 10)));
+
+            assert!(parser.parse(Args::from(args))?.status());
+            // the handler stores the value through `Action::process`
+            assert_eq!(parser.find_val::<i64>("--n")?, &n);
+            assert_eq!(parser.find_val::<i64>("--m")?, &(n * 10));
+            assert_eq!(parser.find_vals::<i64>("--n")?.len(), 1);
+            assert_eq!(
+                parser.find("--n")?.rawval()?,
+                &OsString::from(n.to_string())
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,10 +1,15 @@
 use std::any::TypeId;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
 
 use crate::opt::Action;
 #[allow(unused)]
 use crate::opt::Cmd;
 #[allow(unused)]
 use crate::opt::Creator;
+use crate::opt::EmptyValuePolicy;
 use crate::opt::Help;
 use crate::opt::Index;
 #[allow(unused)]
@@ -14,11 +19,14 @@ use crate::opt::Opt;
 use crate::opt::Pos;
 use crate::opt::Style;
 use crate::raise_error;
+use crate::typeid;
 use crate::value::ErasedValue;
 use crate::value::ValAccessor;
 use crate::Error;
 use crate::Uid;
 
+use super::config::alias_eq;
+use super::config::dedup_alias;
 use super::ConfigValue;
 use super::OptConfig;
 
@@ -46,7 +54,7 @@ use super::OptConfig;
 /// | [`Cmd`] | [`Action::Set`] | `false` | [`Style::Cmd`] | [`Forward(1)`](Index::Forward)  | true  |false | `c` | [`Cmd`] |
 /// | [`Pos`] | [`Action::App`] | `true` | [`Style::Pos`] | yes | false | None | `p` | [`Pos`] |
 /// | [`Main`] | [`Action::Null`] | `true` | [`Style::Main`] | [`AnyWhere`](Index::AnyWhere) | false | None | `m` | [`Main`] |
-/// | [`Stdin`](std::io::Stdin) | [`Action::Set`] | [`false`] | [`Style::Boolean`] | None | true | None | None | [`Stdin`](std::io::Stdin) |
+/// | [`Stdin`](std::io::Stdin) | [`Action::Set`] | [`false`] | [`Style::Boolean`] | None | true | None | None | [`FromStdin`](crate::value::FromStdin) |
 /// | [`Stop`](crate::value::Stop) | [`Action::Set`] | [`false`] | [`Style::Boolean`] | None |  true | None | None | [`Stop`](crate::value::Stop) |
 ///
 /// For the value parser support, see [`RawValParser`](crate::value::RawValParser).
@@ -60,6 +68,12 @@ pub struct AOpt {
 
     help: Help,
 
+    env: Option<String>,
+
+    group: Option<String>,
+
+    toggle_prefix: Option<String>,
+
     styles: Vec<Style>,
 
     index: Option<Index>,
@@ -68,17 +82,43 @@ pub struct AOpt {
 
     alias: Option<Vec<String>>,
 
+    deprecated_alias: Option<Vec<(String, String)>>,
+
     action: Action,
 
+    empty_value: EmptyValuePolicy,
+
+    optional_value: Option<OsString>,
+
+    value_count: Option<RangeInclusive<usize>>,
+
     matched: bool,
 
     force: bool,
 
+    priority: i32,
+
+    display_order: i32,
+
     ignore_name: bool,
 
     ignore_alias: bool,
 
     ignore_index: bool,
+
+    trailing_raw: bool,
+
+    conflicts_with_positional: bool,
+
+    standalone: bool,
+
+    greedy: bool,
+
+    ignore_case: bool,
+
+    secret: bool,
+
+    prompt: Option<String>,
 }
 
 impl AOpt {
@@ -88,16 +128,32 @@ impl AOpt {
             name,
             r#type: type_id,
             help: Default::default(),
+            env: None,
+            group: None,
+            toggle_prefix: None,
             matched: false,
             force: false,
+            priority: 0,
+            display_order: 0,
             action: Default::default(),
+            empty_value: Default::default(),
+            optional_value: None,
+            value_count: None,
             styles: vec![],
             index: None,
             accessor,
             alias: None,
+            deprecated_alias: None,
             ignore_name: false,
             ignore_alias: false,
             ignore_index: false,
+            trailing_raw: false,
+            conflicts_with_positional: false,
+            standalone: false,
+            greedy: false,
+            ignore_case: false,
+            secret: false,
+            prompt: None,
         }
     }
 
@@ -137,6 +193,52 @@ impl AOpt {
         self
     }
 
+    /// If the arguments after the command are collected verbatim.
+    pub fn with_trailing_raw(mut self, trailing_raw: bool) -> Self {
+        self.trailing_raw = trailing_raw;
+        self
+    }
+
+    /// If the option can not be set together with positional arguments.
+    pub fn with_conflicts_with_positional(mut self, conflicts: bool) -> Self {
+        self.conflicts_with_positional = conflicts;
+        self
+    }
+
+    /// If the option can not be set together with any other arguments.
+    pub fn with_standalone(mut self, standalone: bool) -> Self {
+        self.standalone = standalone;
+        self
+    }
+
+    /// If the option consumes the following arguments until next option.
+    pub fn with_greedy(mut self, greedy: bool) -> Self {
+        self.greedy = greedy;
+        self
+    }
+
+    /// If the name and alias are matched case-insensitively.
+    pub fn with_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// If the value of option is masked when logging the arguments.
+    pub fn with_secret(mut self, secret: bool) -> Self {
+        self.secret = secret;
+        self
+    }
+
+    /// Set the text displayed when reading the value of missing force required option.
+    pub fn with_prompt(mut self, prompt: Option<String>) -> Self {
+        self.prompt = prompt;
+        self
+    }
+
+    fn mat_str(&self, left: &str, right: &str) -> bool {
+        alias_eq(left, right, self.ignore_case)
+    }
+
     /// Set the hint of option, such as `--option`.
     pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
         self.help.set_hint(hint);
@@ -149,12 +251,48 @@ impl AOpt {
         self
     }
 
+    /// Set the name of environment variable associated with option.
+    pub fn with_env(mut self, env: Option<String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Set the help group of option.
+    pub fn with_group(mut self, group: Option<String>) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Set the prefix of the name disabling the boolean option.
+    pub fn with_toggle_prefix(mut self, prefix: Option<String>) -> Self {
+        self.toggle_prefix = prefix;
+        self
+    }
+
     /// Set the value action of option.
     pub fn with_action(mut self, action: Action) -> Self {
         self.action = action;
         self
     }
 
+    /// Set how the empty value after `=` is processed.
+    pub fn with_empty_value(mut self, empty_value: EmptyValuePolicy) -> Self {
+        self.empty_value = empty_value;
+        self
+    }
+
+    /// Set the value used when the option appears without a value.
+    pub fn with_optional_value(mut self, optional_value: Option<OsString>) -> Self {
+        self.optional_value = optional_value;
+        self
+    }
+
+    /// Set the range of value count accepted by the option.
+    pub fn with_value_count(mut self, value_count: Option<RangeInclusive<usize>>) -> Self {
+        self.value_count = value_count;
+        self
+    }
+
     /// Set the help of option.
     pub fn with_opt_help(mut self, help: Help) -> Self {
         self.help = help;
@@ -179,12 +317,30 @@ impl AOpt {
         self
     }
 
+    /// Set the priority of option handler.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set the order of option in help message.
+    pub fn with_display_order(mut self, order: i32) -> Self {
+        self.display_order = order;
+        self
+    }
+
     /// Set the alias of option.
     pub fn with_alias(mut self, alias: Option<Vec<String>>) -> Self {
         self.alias = alias;
         self
     }
 
+    /// Set the deprecated alias of option, they are not displayed in the hint.
+    pub fn with_deprecated_alias(mut self, alias: Option<Vec<(String, String)>>) -> Self {
+        self.deprecated_alias = alias;
+        self
+    }
+
     /// Set the value accessor of option, it will used by [`Policy`](crate::parser::Policy);
     pub fn with_accessor(mut self, value: ValAccessor) -> Self {
         self.accessor = value;
@@ -239,17 +395,21 @@ impl AOpt {
     }
 
     pub fn add_alias(&mut self, name: impl Into<String>) -> &mut Self {
-        if let Some(alias) = &mut self.alias {
-            alias.push(name.into());
+        let name = name.into();
+        let ignore_case = self.ignore_case;
+        let alias = self.alias.get_or_insert(vec![]);
+
+        if !alias.iter().any(|v| alias_eq(v, &name, ignore_case)) {
+            alias.push(name);
         }
         self
     }
 
     pub fn rem_alias(&mut self, name: &str) -> &mut Self {
+        let ignore_case = self.ignore_case;
+
         if let Some(alias) = &mut self.alias {
-            if let Some((i, _)) = alias.iter().enumerate().find(|(_, v)| v == &name) {
-                alias.remove(i);
-            }
+            alias.retain(|v| !alias_eq(v, name, ignore_case));
         }
         self
     }
@@ -280,6 +440,18 @@ impl Opt for AOpt {
         self.help.help()
     }
 
+    fn env(&self) -> Option<&str> {
+        self.env.as_deref()
+    }
+
+    fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    fn toggle_prefix(&self) -> Option<&str> {
+        self.toggle_prefix.as_deref()
+    }
+
     fn valid(&self) -> bool {
         !self.force() || self.matched()
     }
@@ -292,10 +464,30 @@ impl Opt for AOpt {
         self.force
     }
 
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn display_order(&self) -> i32 {
+        self.display_order
+    }
+
     fn action(&self) -> &Action {
         &self.action
     }
 
+    fn empty_value(&self) -> EmptyValuePolicy {
+        self.empty_value
+    }
+
+    fn optional_value(&self) -> Option<&OsStr> {
+        self.optional_value.as_deref()
+    }
+
+    fn value_count(&self) -> Option<&RangeInclusive<usize>> {
+        self.value_count.as_ref()
+    }
+
     fn index(&self) -> Option<&Index> {
         self.index.as_ref()
     }
@@ -304,6 +496,10 @@ impl Opt for AOpt {
         self.alias.as_ref()
     }
 
+    fn deprecated_alias(&self) -> Option<&Vec<(String, String)>> {
+        self.deprecated_alias.as_ref()
+    }
+
     fn accessor(&self) -> &ValAccessor {
         &self.accessor
     }
@@ -324,6 +520,34 @@ impl Opt for AOpt {
         self.ignore_index
     }
 
+    fn trailing_raw(&self) -> bool {
+        self.trailing_raw
+    }
+
+    fn conflicts_with_positional(&self) -> bool {
+        self.conflicts_with_positional
+    }
+
+    fn standalone(&self) -> bool {
+        self.standalone
+    }
+
+    fn greedy(&self) -> bool {
+        self.greedy
+    }
+
+    fn ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    fn secret(&self) -> bool {
+        self.secret
+    }
+
+    fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
     fn set_uid(&mut self, uid: Uid) {
         self.uid = uid;
     }
@@ -341,15 +565,23 @@ impl Opt for AOpt {
     }
 
     fn mat_name(&self, name: Option<&str>) -> bool {
-        name == Some(self.name())
+        name.is_some_and(|name| self.mat_str(self.name(), name))
     }
 
     fn mat_alias(&self, name: &str) -> bool {
         if let Some(alias) = &self.alias {
-            alias.iter().any(|v| v == name)
-        } else {
-            false
+            if alias.iter().any(|v| self.mat_str(v, name)) {
+                return true;
+            }
         }
+        self.mat_deprecated_alias(name).is_some()
+    }
+
+    fn mat_deprecated_alias(&self, name: &str) -> Option<&str> {
+        self.deprecated_alias
+            .as_ref()
+            .and_then(|v| v.iter().find(|(alias, _)| self.mat_str(alias, name)))
+            .map(|(_, message)| message.as_str())
     }
 
     fn mat_index(&self, index: Option<(usize, usize)>) -> bool {
@@ -368,7 +600,12 @@ impl Opt for AOpt {
     }
 }
 
-fn gen_hint(
+/// Generate the default hint of option from its name, index and alias, such as `-c, --count` or `file@1`.
+pub fn default_hint(n: &str, idx: Option<&Index>, alias: Option<&Vec<String>>) -> String {
+    gen_hint(None::<&str>, n, idx, alias)
+}
+
+pub(crate) fn gen_hint(
     hint: Option<impl Into<String>>,
     n: &str,
     idx: Option<&Index>,
@@ -411,29 +648,87 @@ impl TryFrom<OptConfig> for AOpt {
         let r#type = value.take_type();
         let name = value.take_name();
         let force = value.take_force();
+        let priority = value.take_priority().unwrap_or_default();
+        let display_order = value.take_display_order().unwrap_or_default();
         let index = value.take_index();
         let alias = value.take_alias();
+        let deprecated_alias = value.take_deprecated_alias();
         let hint = value.take_hint();
         let help = value.take_help();
+        let env = value.take_env();
+        let group = value.take_group();
+        let toggle_prefix = value.take_toggle_prefix();
         let action = value.take_action();
+        let empty_value = value.take_empty_value().unwrap_or_default();
+        let optional_value = value.take_optional_value();
+        let value_count = value.take_value_count();
         let storer = value.take_storer();
         let styles = value.take_style();
         let initializer = value.take_initializer();
         let ignore_name = value.ignore_name();
         let ignore_alias = value.ignore_alias();
         let ignore_index = value.ignore_index();
+        let trailing_raw = value.trailing_raw();
+        let conflicts_with_positional = value.conflicts_with_positional();
+        let standalone = value.standalone();
+        let greedy = value.greedy();
+        let multiple = value.multiple();
+        let max_occurrences = value.max_occurrences();
+        let ignore_case = value.ignore_case();
+        let secret = value.secret();
+        let prompt = value.take_prompt();
+        // the ignore_case may be set after the alias added
+        let alias = alias.map(|alias| dedup_alias(alias, ignore_case));
 
         let force = force.unwrap_or(false);
         let action = action.unwrap_or(Action::App);
         let storer =
             storer.ok_or_else(|| raise_error!("incomplete configuration: missing ValStorer"))?;
+        let storer = match value.take_bool_literals() {
+            Some((trues, falses)) if r#type == Some(typeid::<bool>()) => {
+                storer.with_bool_literals(trues, falses)
+            }
+            _ => storer,
+        };
+        // the path and os string are sensitive to whitespace, never trim them
+        let storer = match r#type {
+            Some(type_id)
+                if value.trim()
+                    && type_id != typeid::<PathBuf>()
+                    && type_id != typeid::<OsString>() =>
+            {
+                storer.with_trim()
+            }
+            _ => storer,
+        };
+        let storer = match value.value_delimiter() {
+            Some(delimiter) => storer.with_value_delimiter(delimiter, value.delimiter_escape()),
+            None => storer,
+        };
+        #[cfg(feature = "std")]
+        let storer = match value.take_file_indirection() {
+            Some(marker) => storer.with_file_indirection(marker),
+            None => storer,
+        };
+        let storer = match value.take_stdin_marker() {
+            Some(marker) => storer.with_stdin_marker(marker),
+            None => storer,
+        };
         let initializer = initializer
             .ok_or_else(|| raise_error!("incomplete configuration: missing ValInitializer"))?;
         let styles =
             styles.ok_or_else(|| raise_error!("incomplete configuration: missing Style"))?;
         let name =
             name.ok_or_else(|| raise_error!("incomplete configuration: missing option name"))?;
-        let hint = gen_hint(hint.as_ref(), &name, index.as_ref(), alias.as_ref());
+        let number_format = value.take_number_format();
+        let hint = match (hint, number_format) {
+            (None, Some(format)) => format!(
+                "{} {}",
+                gen_hint(None::<&str>, &name, index.as_ref(), alias.as_ref()),
+                format.metavar()
+            ),
+            (hint, _) => gen_hint(hint.as_ref(), &name, index.as_ref(), alias.as_ref()),
+        };
         let help = help.unwrap_or_default();
         let r#type = r#type
             .ok_or_else(|| raise_error!("incomplete configuration: missing option value type"))?;
@@ -465,17 +760,37 @@ impl TryFrom<OptConfig> for AOpt {
                     name
                 );
         }
-        Ok(
-            AOpt::new(name, r#type, ValAccessor::new(storer, initializer))
-                .with_force(force)
-                .with_idx(index)
-                .with_action(action)
-                .with_alias(alias)
-                .with_style(styles)
-                .with_opt_help(help)
-                .with_ignore_name(ignore_name)
-                .with_ignore_alias(ignore_alias)
-                .with_ignore_index(ignore_index),
+        Ok(AOpt::new(
+            name,
+            r#type,
+            ValAccessor::new(storer, initializer)
+                .with_multiple(multiple)
+                .with_max_occurrences(max_occurrences),
         )
+        .with_force(force)
+        .with_priority(priority)
+        .with_display_order(display_order)
+        .with_idx(index)
+        .with_action(action)
+        .with_empty_value(empty_value)
+        .with_optional_value(optional_value)
+        .with_value_count(value_count)
+        .with_alias(alias)
+        .with_deprecated_alias(deprecated_alias)
+        .with_style(styles)
+        .with_opt_help(help)
+        .with_env(env)
+        .with_group(group)
+        .with_toggle_prefix(toggle_prefix)
+        .with_ignore_name(ignore_name)
+        .with_ignore_alias(ignore_alias)
+        .with_ignore_index(ignore_index)
+        .with_trailing_raw(trailing_raw)
+        .with_conflicts_with_positional(conflicts_with_positional)
+        .with_standalone(standalone)
+        .with_greedy(greedy)
+        .with_ignore_case(ignore_case)
+        .with_secret(secret)
+        .with_prompt(prompt))
     }
 }
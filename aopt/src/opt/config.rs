@@ -1,20 +1,62 @@
 use std::any::TypeId;
+use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::ops::RangeInclusive;
 
 use crate::err::Error;
 use crate::opt::Action;
+use crate::opt::EmptyValuePolicy;
 use crate::opt::Index;
 use crate::opt::Information;
+use crate::opt::NumberFormat;
 use crate::opt::OptParser;
 use crate::typeid;
 use crate::value::Placeholder;
 use crate::value::ValInitializer;
 use crate::value::ValStorer;
+use crate::value::STDIN_MARKER;
 
 use super::Cid;
 use super::Style;
 
+fn lowercase(value: &str) -> impl Iterator<Item = char> + '_ {
+    value.chars().flat_map(char::to_lowercase)
+}
+
+/// Compare two alias, ignore the case if `ignore_case` is true.
+pub(crate) fn alias_eq(left: &str, right: &str, ignore_case: bool) -> bool {
+    if ignore_case {
+        lowercase(left).eq(lowercase(right))
+    } else {
+        left == right
+    }
+}
+
+/// Return true if the alias starts with `prefix`, ignore the case if `ignore_case` is true.
+pub(crate) fn alias_starts_with(alias: &str, prefix: &str, ignore_case: bool) -> bool {
+    if ignore_case {
+        let mut alias = lowercase(alias);
+
+        lowercase(prefix).all(|ch| alias.next() == Some(ch))
+    } else {
+        alias.starts_with(prefix)
+    }
+}
+
+/// Remove the duplicate alias, keep the first one and the insertion order.
+pub(crate) fn dedup_alias(alias: Vec<String>, ignore_case: bool) -> Vec<String> {
+    let mut ret: Vec<String> = Vec::with_capacity(alias.len());
+
+    for alias in alias {
+        if !ret.iter().any(|v| alias_eq(v, &alias, ignore_case)) {
+            ret.push(alias);
+        }
+    }
+    ret
+}
+
 pub trait ConfigBuild<C> {
     type Val;
 
@@ -122,17 +164,71 @@ where
         merge!(has_type, set_type_id, take_type);
         merge!(has_name, set_name, take_name);
         merge!(has_force, set_force, take_force);
+        merge!(has_priority, set_priority, take_priority);
+        merge!(has_display_order, set_display_order, take_display_order);
         merge!(has_index, set_index, take_index);
         merge!(has_alias, set_alias, take_alias);
+        merge!(
+            has_deprecated_alias,
+            set_deprecated_alias,
+            take_deprecated_alias
+        );
         merge!(has_hint, set_hint, take_hint);
+        merge!(has_env, set_env, take_env);
+        merge!(has_group, set_group, take_group);
+        merge!(has_toggle_prefix, set_toggle_prefix, take_toggle_prefix);
         merge!(has_help, set_help, take_help);
         merge!(has_action, set_action, take_action);
+        merge!(has_empty_value, set_empty_value, take_empty_value);
+        merge!(has_number_format, set_number_format, take_number_format);
+        merge!(
+            has_strip_thousands,
+            set_strip_thousands,
+            take_strip_thousands
+        );
+        merge!(has_time_format, set_time_format, take_time_format);
+        merge!(has_prompt, set_prompt, take_prompt);
+        #[cfg(feature = "std")]
+        merge!(
+            has_file_indirection,
+            set_file_indirection,
+            take_file_indirection
+        );
+        merge!(
+            has_value_delimiter,
+            set_value_delimiter,
+            take_value_delimiter
+        );
+        merge!(
+            has_delimiter_escape,
+            set_delimiter_escape,
+            take_delimiter_escape
+        );
+        merge!(has_stdin_marker, set_stdin_marker, take_stdin_marker);
+        merge!(has_bool_literals, set_bool_literals, take_bool_literals);
+        merge!(has_multiple, set_multiple, take_multiple);
+        merge!(
+            has_max_occurrences,
+            set_max_occurrences,
+            take_max_occurrences
+        );
+        merge!(has_optional_value, set_optional_value, take_optional_value);
+        merge!(has_value_count, set_value_count, take_value_count);
         merge!(has_storer, set_storer, take_storer);
         merge!(has_style, set_style, take_style);
         merge!(has_initializer, set_initializer, take_initializer);
         config.set_ignore_name(config.ignore_name() || init.ignore_name());
         config.set_ignore_alias(config.ignore_alias() || init.ignore_alias());
         config.set_ignore_index(config.ignore_index() || init.ignore_index());
+        config.set_trailing_raw(config.trailing_raw() || init.trailing_raw());
+        config.set_conflicts_with_positional(
+            config.conflicts_with_positional() || init.conflicts_with_positional(),
+        );
+        config.set_standalone(config.standalone() || init.standalone());
+        config.set_greedy(config.greedy() || init.greedy());
+        config.set_ignore_case(config.ignore_case() || init.ignore_case());
+        config.set_trim(config.trim() || init.trim());
+        config.set_secret(config.secret() || init.secret());
         Ok(config)
     }
 }
@@ -150,21 +246,129 @@ pub trait ConfigValue {
     /// If the option is force required.
     fn force(&self) -> Option<bool>;
 
+    /// The priority of option handler, higher first.
+    fn priority(&self) -> Option<i32> {
+        None
+    }
+
+    /// The order of option in help message, lower first.
+    fn display_order(&self) -> Option<i32> {
+        None
+    }
+
     /// The index configuration of option.
     fn index(&self) -> Option<&Index>;
 
     /// The alias name and prefix of option.
     fn alias(&self) -> Option<&Vec<String>>;
 
+    /// The deprecated alias of option, and the warning message of each alias.
+    fn deprecated_alias(&self) -> Option<&Vec<(String, String)>> {
+        None
+    }
+
     /// The hint message used in usage of option.
     fn hint(&self) -> Option<&str>;
 
     /// The help message of option.
     fn help(&self) -> Option<&str>;
 
+    /// The name of environment variable associated with option,
+    /// the value of option is parsed from it if it is set when the option is inferred.
+    fn env(&self) -> Option<&str> {
+        None
+    }
+
+    /// The help group of option.
+    fn group(&self) -> Option<&str> {
+        None
+    }
+
+    /// The prefix of the name disabling the boolean option, such as `+` for `+x` of `-x`.
+    fn toggle_prefix(&self) -> Option<&str> {
+        None
+    }
+
     /// Value action of option.
     fn action(&self) -> Option<&Action>;
 
+    /// How the empty value after `=` is processed.
+    fn empty_value(&self) -> Option<&EmptyValuePolicy> {
+        None
+    }
+
+    /// Which base is accepted when parsing the integer value.
+    fn number_format(&self) -> Option<&NumberFormat> {
+        None
+    }
+
+    /// The thousands separator removed from the number value before parsing, such as `,` in `1,000`.
+    fn strip_thousands(&self) -> Option<char> {
+        None
+    }
+
+    /// The format description parsing the date and time value, such as `[year]/[month]/[day]`,
+    /// only used by the types of `time` feature.
+    fn time_format(&self) -> Option<&str> {
+        None
+    }
+
+    /// The text displayed when reading the value of missing force required option from terminal.
+    fn prompt(&self) -> Option<&str> {
+        None
+    }
+
+    /// The marker of value read from file, such as `@` in `--token @/run/secrets/token`.
+    #[cfg(feature = "std")]
+    fn file_indirection(&self) -> Option<char> {
+        None
+    }
+
+    /// The delimiter splitting the value into multiple values, such as `,` in `--tag a,b,c`.
+    fn value_delimiter(&self) -> Option<char> {
+        None
+    }
+
+    /// The escape character makes the [`value_delimiter`](ConfigValue::value_delimiter) literal,
+    /// such as `\` in `--tag a\,b,c`, default is `\`.
+    fn delimiter_escape(&self) -> Option<char> {
+        Some('\\')
+    }
+
+    /// The marker of value read from stdin, such as `-` in `cat -`.
+    fn stdin_marker(&self) -> Option<&str> {
+        None
+    }
+
+    /// The extra literals of `true` and `false` accepted by boolean option, such as `yes` and `no`.
+    fn bool_literals(&self) -> Option<&(Vec<String>, Vec<String>)> {
+        None
+    }
+
+    /// Return true if the option can be used more than once, default is true.
+    ///
+    /// The option of scalar value, which type [`infers`](crate::value::Infer::infer_act) the [`Action::Set`],
+    /// can not be used more than once by default, except the flags such as boolean option.
+    /// Setting the [`Action::Set`] explicitly keeps the last value of repeated option.
+    fn multiple(&self) -> bool {
+        true
+    }
+
+    /// The maximum times the option can be used.
+    fn max_occurrences(&self) -> Option<usize> {
+        None
+    }
+
+    /// Value stored when the option appears without a value, such as `--color`.
+    fn optional_value(&self) -> Option<&OsStr> {
+        None
+    }
+
+    /// The range of value count accepted by the option, such as `2..=3`.
+    fn value_count(&self) -> Option<&RangeInclusive<usize>> {
+        None
+    }
+
     /// Value validator for option.
     fn storer(&self) -> Option<&ValStorer>;
 
@@ -192,6 +396,9 @@ pub trait ConfigValue {
     /// The alias name and prefix of option.
     fn alias_mut(&mut self) -> Option<&mut Vec<String>>;
 
+    /// The deprecated alias of option, and the warning message of each alias.
+    fn deprecated_alias_mut(&mut self) -> Option<&mut Vec<(String, String)>>;
+
     /// The hint message used in usage of option.
     fn hint_mut(&mut self) -> Option<&mut String>;
 
@@ -216,6 +423,42 @@ pub trait ConfigValue {
 
     fn ignore_index(&self) -> bool;
 
+    /// If the arguments after the command, or after the option terminator for [`Pos`](crate::opt::Pos),
+    /// are collected verbatim.
+    fn trailing_raw(&self) -> bool {
+        false
+    }
+
+    /// If the option can not be set together with positional arguments.
+    fn conflicts_with_positional(&self) -> bool {
+        false
+    }
+
+    /// If the option can not be set together with any other arguments.
+    fn standalone(&self) -> bool {
+        false
+    }
+
+    /// If the option consumes the following arguments until next option.
+    fn greedy(&self) -> bool {
+        false
+    }
+
+    /// If the name and alias of option are matched case-insensitively.
+    fn ignore_case(&self) -> bool {
+        false
+    }
+
+    /// If the leading and trailing whitespace of value are trimmed before parsing.
+    fn trim(&self) -> bool {
+        false
+    }
+
+    /// If the value of option is masked when logging the arguments.
+    fn secret(&self) -> bool {
+        false
+    }
+
     fn has_ctor(&self) -> bool;
 
     fn has_type(&self) -> bool;
@@ -224,16 +467,97 @@ pub trait ConfigValue {
 
     fn has_force(&self) -> bool;
 
+    fn has_priority(&self) -> bool {
+        self.priority().is_some()
+    }
+
+    fn has_display_order(&self) -> bool {
+        self.display_order().is_some()
+    }
+
     fn has_index(&self) -> bool;
 
     fn has_hint(&self) -> bool;
 
     fn has_help(&self) -> bool;
 
+    fn has_env(&self) -> bool {
+        self.env().is_some()
+    }
+
+    fn has_group(&self) -> bool {
+        self.group().is_some()
+    }
+
+    fn has_toggle_prefix(&self) -> bool {
+        self.toggle_prefix().is_some()
+    }
+
     fn has_alias(&self) -> bool;
 
+    fn has_deprecated_alias(&self) -> bool {
+        self.deprecated_alias().is_some()
+    }
+
     fn has_action(&self) -> bool;
 
+    fn has_empty_value(&self) -> bool {
+        self.empty_value().is_some()
+    }
+
+    fn has_number_format(&self) -> bool {
+        self.number_format().is_some()
+    }
+
+    fn has_strip_thousands(&self) -> bool {
+        self.strip_thousands().is_some()
+    }
+
+    fn has_time_format(&self) -> bool {
+        self.time_format().is_some()
+    }
+
+    fn has_prompt(&self) -> bool {
+        self.prompt().is_some()
+    }
+
+    #[cfg(feature = "std")]
+    fn has_file_indirection(&self) -> bool {
+        self.file_indirection().is_some()
+    }
+
+    fn has_value_delimiter(&self) -> bool {
+        self.value_delimiter().is_some()
+    }
+
+    fn has_delimiter_escape(&self) -> bool {
+        false
+    }
+
+    fn has_stdin_marker(&self) -> bool {
+        self.stdin_marker().is_some()
+    }
+
+    fn has_bool_literals(&self) -> bool {
+        self.bool_literals().is_some()
+    }
+
+    fn has_multiple(&self) -> bool {
+        false
+    }
+
+    fn has_max_occurrences(&self) -> bool {
+        self.max_occurrences().is_some()
+    }
+
+    fn has_optional_value(&self) -> bool {
+        self.optional_value().is_some()
+    }
+
+    fn has_value_count(&self) -> bool {
+        self.value_count().is_some()
+    }
+
     fn has_storer(&self) -> bool;
 
     fn has_style(&self) -> bool;
@@ -250,22 +574,107 @@ pub trait ConfigValue {
 
     fn set_force(&mut self, force: bool) -> &mut Self;
 
+    /// Set the priority of option handler, it only has an effect under [`DelayPolicy`](crate::parser::DelayPolicy),
+    /// [`FwdPolicy`](crate::parser::FwdPolicy) and [`PrePolicy`](crate::parser::PrePolicy) ignore it.
+    fn set_priority(&mut self, priority: i32) -> &mut Self;
+
+    fn set_display_order(&mut self, order: i32) -> &mut Self;
+
     fn set_index(&mut self, index: Index) -> &mut Self;
 
+    /// Set the alias of option, the duplicate alias are removed.
     fn set_alias(&mut self, alias: Vec<impl Into<String>>) -> &mut Self;
 
+    /// Remove all the alias of option.
     fn clr_alias(&mut self) -> &mut Self;
 
+    /// Add an alias to the end of alias list if it not exists,
+    /// the alias is compared case-insensitively if [`ignore_case`](ConfigValue::ignore_case) is set.
     fn add_alias(&mut self, alias: impl Into<String>) -> &mut Self;
 
+    /// Remove all the alias matched `alias`.
     fn rem_alias(&mut self, alias: impl AsRef<str>) -> &mut Self;
 
+    fn set_deprecated_alias(&mut self, alias: Vec<(String, String)>) -> &mut Self;
+
+    /// Add an alias which is still matched but deprecated,
+    /// the `message` will be printed to stderr when the alias is used.
+    fn add_deprecated_alias(
+        &mut self,
+        alias: impl Into<String>,
+        message: impl Into<String>,
+    ) -> &mut Self;
+
     fn set_hint(&mut self, hint: impl Into<String>) -> &mut Self;
 
     fn set_help(&mut self, help: impl Into<String>) -> &mut Self;
 
+    fn set_env(&mut self, env: impl Into<String>) -> &mut Self;
+
+    fn set_group(&mut self, group: impl Into<String>) -> &mut Self;
+
+    fn set_toggle_prefix(&mut self, prefix: impl Into<String>) -> &mut Self;
+
     fn set_action(&mut self, action: Action) -> &mut Self;
 
+    fn set_empty_value(&mut self, empty_value: EmptyValuePolicy) -> &mut Self;
+
+    fn set_number_format(&mut self, number_format: NumberFormat) -> &mut Self;
+
+    fn set_strip_thousands(&mut self, separator: char) -> &mut Self;
+
+    fn set_time_format(&mut self, format: impl Into<String>) -> &mut Self;
+
+    /// Set the `prompt` displayed when the force required option is missing,
+    /// the value is read from stdin if it is a terminal, otherwise an error is returned as before.
+    /// See [`set_prompt_handler`](crate::parser::PolicySettings::set_prompt_handler).
+    fn set_prompt(&mut self, prompt: impl Into<Option<String>>) -> &mut Self;
+
+    /// Set the `marker`, the value begins with it is replaced by the content of the file.
+    ///
+    /// The path is the rest of value, and the content is trimmed before parsing.
+    /// It is different from the response files, which expand the file into arguments.
+    #[cfg(feature = "std")]
+    fn set_file_indirection(&mut self, marker: char) -> &mut Self;
+
+    /// Split the value by `delimiter`, each part is parsed and stored as a separate value.
+    ///
+    /// The value is split before the value validator and [`Action`] applied,
+    /// using [`Action::App`] keep all the parts.
+    fn set_value_delimiter(&mut self, delimiter: char) -> &mut Self;
+
+    /// Set the escape character of [`value_delimiter`](ConfigValue::value_delimiter),
+    /// `None` disables the escaping.
+    fn set_delimiter_escape(&mut self, escape: Option<char>) -> &mut Self;
+
+    /// Recognize the value equal to `marker` as stdin, [`STDIN_MARKER`](crate::value::STDIN_MARKER) is used if it is `None`.
+    ///
+    /// The value is parsed and stored as usual, and a [`FromStdin`](crate::value::FromStdin) is stored along with it.
+    /// Check it with [`value_is_stdin`](crate::set::SetValueFindExt::value_is_stdin).
+    fn set_stdin_marker(&mut self, marker: impl Into<Option<String>>) -> &mut Self;
+
+    /// Set the extra literals of `true` and `false` accepted by boolean option,
+    /// such as `--flag=yes` or `--flag=no`.
+    ///
+    /// The `true` and `false` are always accepted, the literals are compared case-sensitively.
+    fn set_bool_literals(&mut self, literals: (Vec<String>, Vec<String>)) -> &mut Self;
+
+    /// Set if the option can be used more than once.
+    ///
+    /// If it is false, the second occurrence of option raise a failure such as
+    /// ``option `--output` used more than once``, instead of overwriting the value.
+    fn set_multiple(&mut self, multiple: bool) -> &mut Self;
+
+    /// Set the maximum times the option can be used, such as `-vvv` at most.
+    ///
+    /// The occurrence exceeding the limit raise a failure such as
+    /// ``option `-v` used more than 3 times``.
+    fn set_max_occurrences(&mut self, max: usize) -> &mut Self;
+
+    fn set_optional_value(&mut self, optional_value: impl Into<OsString>) -> &mut Self;
+
+    fn set_value_count(&mut self, value_count: RangeInclusive<usize>) -> &mut Self;
+
     fn set_storer(&mut self, storer: ValStorer) -> &mut Self;
 
     fn set_style(&mut self, styles: Vec<Style>) -> &mut Self;
@@ -278,6 +687,25 @@ pub trait ConfigValue {
 
     fn set_ignore_index(&mut self, ignore_index: bool) -> &mut Self;
 
+    fn set_trailing_raw(&mut self, trailing_raw: bool) -> &mut Self;
+
+    fn set_conflicts_with_positional(&mut self, conflicts: bool) -> &mut Self;
+
+    fn set_standalone(&mut self, standalone: bool) -> &mut Self;
+
+    fn set_greedy(&mut self, greedy: bool) -> &mut Self;
+
+    fn set_ignore_case(&mut self, ignore_case: bool) -> &mut Self;
+
+    /// Trim the leading and trailing whitespace of value before parsing,
+    /// the value of [`PathBuf`](std::path::PathBuf) and [`OsString`](std::ffi::OsString) is never trimmed.
+    fn set_trim(&mut self, trim: bool) -> &mut Self;
+
+    /// Mask the value of option by `***` in [`normalized_args`](crate::parser::Return::normalized_args)
+    /// and [`rebuild_command_line`](crate::parser::Return::rebuild_command_line),
+    /// such as passwords and tokens. The value stored is not changed.
+    fn set_secret(&mut self, secret: bool) -> &mut Self;
+
     fn take_ctor(&mut self) -> Option<String>;
 
     fn take_type(&mut self) -> Option<TypeId>;
@@ -286,16 +714,57 @@ pub trait ConfigValue {
 
     fn take_force(&mut self) -> Option<bool>;
 
+    fn take_priority(&mut self) -> Option<i32>;
+
+    fn take_display_order(&mut self) -> Option<i32>;
+
     fn take_index(&mut self) -> Option<Index>;
 
     fn take_alias(&mut self) -> Option<Vec<String>>;
 
+    fn take_deprecated_alias(&mut self) -> Option<Vec<(String, String)>>;
+
     fn take_hint(&mut self) -> Option<String>;
 
     fn take_help(&mut self) -> Option<String>;
 
+    fn take_env(&mut self) -> Option<String>;
+
+    fn take_group(&mut self) -> Option<String>;
+
+    fn take_toggle_prefix(&mut self) -> Option<String>;
+
     fn take_action(&mut self) -> Option<Action>;
 
+    fn take_empty_value(&mut self) -> Option<EmptyValuePolicy>;
+
+    fn take_number_format(&mut self) -> Option<NumberFormat>;
+
+    fn take_strip_thousands(&mut self) -> Option<char>;
+
+    fn take_time_format(&mut self) -> Option<String>;
+
+    fn take_prompt(&mut self) -> Option<String>;
+
+    #[cfg(feature = "std")]
+    fn take_file_indirection(&mut self) -> Option<char>;
+
+    fn take_value_delimiter(&mut self) -> Option<char>;
+
+    fn take_delimiter_escape(&mut self) -> Option<Option<char>>;
+
+    fn take_stdin_marker(&mut self) -> Option<String>;
+
+    fn take_bool_literals(&mut self) -> Option<(Vec<String>, Vec<String>)>;
+
+    fn take_multiple(&mut self) -> Option<bool>;
+
+    fn take_max_occurrences(&mut self) -> Option<usize>;
+
+    fn take_optional_value(&mut self) -> Option<OsString>;
+
+    fn take_value_count(&mut self) -> Option<RangeInclusive<usize>>;
+
     fn take_storer(&mut self) -> Option<ValStorer>;
 
     fn take_style(&mut self) -> Option<Vec<Style>>;
@@ -308,6 +777,26 @@ pub trait ConfigValue {
 
     fn with_force(self, force: bool) -> Self;
 
+    fn with_priority(self, priority: i32) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_priority(priority);
+        this
+    }
+
+    fn with_display_order(self, order: i32) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_display_order(order);
+        this
+    }
+
     fn with_ctor(self, ctor: impl Into<String>) -> Self;
 
     fn with_name(self, name: impl Into<String>) -> Self;
@@ -318,94 +807,490 @@ pub trait ConfigValue {
 
     fn with_help(self, help: impl Into<String>) -> Self;
 
-    fn with_alias(self, alias: Vec<impl Into<String>>) -> Self;
-
-    fn with_style(self, styles: Vec<Style>) -> Self;
+    fn with_env(self, env: impl Into<String>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    fn with_action(self, action: Action) -> Self;
+        this.set_env(env);
+        this
+    }
 
-    fn with_storer(self, storer: ValStorer) -> Self;
+    fn with_group(self, group: impl Into<String>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    fn with_ignore_alias(self, ignore_alias: bool) -> Self;
+        this.set_group(group);
+        this
+    }
 
-    fn with_ignore_index(self, ignore_index: bool) -> Self;
+    fn with_toggle_prefix(self, prefix: impl Into<String>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    fn with_ignore_name(self, ignore_name: bool) -> Self;
+        this.set_toggle_prefix(prefix);
+        this
+    }
 
-    fn with_initializer(self, initializer: ValInitializer) -> Self;
-}
+    fn with_alias(self, alias: Vec<impl Into<String>>) -> Self;
 
-/// Contain the information used for create option instance.
-#[derive(Debug, Default)]
-pub struct OptConfig {
-    ctor: Option<String>,
+    fn with_deprecated_alias(self, alias: Vec<(String, String)>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    r#type: Option<TypeId>,
+        this.set_deprecated_alias(alias);
+        this
+    }
 
-    name: Option<String>,
+    fn with_style(self, styles: Vec<Style>) -> Self;
 
-    force: Option<bool>,
+    fn with_action(self, action: Action) -> Self;
 
-    index: Option<Index>,
+    fn with_empty_value(self, empty_value: EmptyValuePolicy) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    alias: Option<Vec<String>>,
+        this.set_empty_value(empty_value);
+        this
+    }
 
-    hint: Option<String>,
+    fn with_number_format(self, number_format: NumberFormat) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    help: Option<String>,
+        this.set_number_format(number_format);
+        this
+    }
 
-    action: Option<Action>,
+    fn with_strip_thousands(self, separator: char) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    storer: Option<ValStorer>,
+        this.set_strip_thousands(separator);
+        this
+    }
 
-    initializer: Option<ValInitializer>,
+    fn with_time_format(self, format: impl Into<String>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    ignore_name: bool,
+        this.set_time_format(format);
+        this
+    }
 
-    ignore_alias: bool,
+    fn with_prompt(self, prompt: impl Into<Option<String>>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    ignore_index: bool,
+        this.set_prompt(prompt);
+        this
+    }
 
-    styles: Option<Vec<Style>>,
-}
+    #[cfg(feature = "std")]
+    fn with_file_indirection(self, marker: char) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-impl ConfigValue for OptConfig {
-    fn ctor(&self) -> Option<&str> {
-        self.ctor.as_deref()
+        this.set_file_indirection(marker);
+        this
     }
 
-    fn r#type(&self) -> Option<&TypeId> {
-        self.r#type.as_ref()
-    }
+    fn with_value_delimiter(self, delimiter: char) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    fn name(&self) -> Option<&str> {
-        self.name.as_deref()
+        this.set_value_delimiter(delimiter);
+        this
     }
 
-    fn force(&self) -> Option<bool> {
-        self.force
-    }
+    fn with_delimiter_escape(self, escape: Option<char>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
 
-    fn index(&self) -> Option<&Index> {
-        self.index.as_ref()
+        this.set_delimiter_escape(escape);
+        this
     }
 
-    fn alias(&self) -> Option<&Vec<String>> {
-        self.alias.as_ref()
+    fn with_stdin_marker(self, marker: impl Into<Option<String>>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_stdin_marker(marker);
+        this
     }
 
-    fn hint(&self) -> Option<&str> {
-        self.help.as_deref()
+    fn with_bool_literals(self, literals: (Vec<String>, Vec<String>)) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_bool_literals(literals);
+        this
     }
 
-    fn help(&self) -> Option<&str> {
+    fn with_multiple(self, multiple: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_multiple(multiple);
+        this
+    }
+
+    fn with_max_occurrences(self, max: usize) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_max_occurrences(max);
+        this
+    }
+
+    fn with_optional_value(self, optional_value: impl Into<OsString>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_optional_value(optional_value);
+        this
+    }
+
+    fn with_value_count(self, value_count: RangeInclusive<usize>) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_value_count(value_count);
+        this
+    }
+
+    fn with_storer(self, storer: ValStorer) -> Self;
+
+    fn with_ignore_alias(self, ignore_alias: bool) -> Self;
+
+    fn with_ignore_index(self, ignore_index: bool) -> Self;
+
+    fn with_ignore_name(self, ignore_name: bool) -> Self;
+
+    fn with_trailing_raw(self, trailing_raw: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_trailing_raw(trailing_raw);
+        this
+    }
+
+    fn with_conflicts_with_positional(self, conflicts: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_conflicts_with_positional(conflicts);
+        this
+    }
+
+    fn with_standalone(self, standalone: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_standalone(standalone);
+        this
+    }
+
+    fn with_greedy(self, greedy: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_greedy(greedy);
+        this
+    }
+
+    fn with_ignore_case(self, ignore_case: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_ignore_case(ignore_case);
+        this
+    }
+
+    fn with_trim(self, trim: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_trim(trim);
+        this
+    }
+
+    fn with_secret(self, secret: bool) -> Self
+    where
+        Self: Sized,
+    {
+        let mut this = self;
+
+        this.set_secret(secret);
+        this
+    }
+
+    fn with_initializer(self, initializer: ValInitializer) -> Self;
+}
+
+/// Contain the information used for create option instance.
+///
+/// With feature `serde`, the type, storer and initializer are not serialized,
+/// they are inferred from the `ctor` when the config committed by
+/// [`add_opt_from_config`](crate::parser::HCOptSet::add_opt_from_config).
+/// So the default value is lost, set the initializer again after deserialization.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default)]
+pub struct OptConfig {
+    ctor: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    r#type: Option<TypeId>,
+
+    name: Option<String>,
+
+    force: Option<bool>,
+
+    priority: Option<i32>,
+
+    display_order: Option<i32>,
+
+    index: Option<Index>,
+
+    alias: Option<Vec<String>>,
+
+    deprecated_alias: Option<Vec<(String, String)>>,
+
+    hint: Option<String>,
+
+    help: Option<String>,
+
+    env: Option<String>,
+
+    group: Option<String>,
+
+    toggle_prefix: Option<String>,
+
+    action: Option<Action>,
+
+    empty_value: Option<EmptyValuePolicy>,
+
+    number_format: Option<NumberFormat>,
+
+    strip_thousands: Option<char>,
+
+    time_format: Option<String>,
+
+    prompt: Option<String>,
+
+    #[cfg(feature = "std")]
+    file_indirection: Option<char>,
+
+    value_delimiter: Option<char>,
+
+    delimiter_escape: Option<Option<char>>,
+
+    stdin_marker: Option<String>,
+
+    bool_literals: Option<(Vec<String>, Vec<String>)>,
+
+    multiple: Option<bool>,
+
+    max_occurrences: Option<usize>,
+
+    optional_value: Option<OsString>,
+
+    value_count: Option<RangeInclusive<usize>>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    storer: Option<ValStorer>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    initializer: Option<ValInitializer>,
+
+    ignore_name: bool,
+
+    ignore_alias: bool,
+
+    ignore_index: bool,
+
+    trailing_raw: bool,
+
+    conflicts_with_positional: bool,
+
+    standalone: bool,
+
+    greedy: bool,
+
+    ignore_case: bool,
+
+    trim: bool,
+
+    secret: bool,
+
+    styles: Option<Vec<Style>>,
+}
+
+impl ConfigValue for OptConfig {
+    fn ctor(&self) -> Option<&str> {
+        self.ctor.as_deref()
+    }
+
+    fn r#type(&self) -> Option<&TypeId> {
+        self.r#type.as_ref()
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    fn force(&self) -> Option<bool> {
+        self.force
+    }
+
+    fn priority(&self) -> Option<i32> {
+        self.priority
+    }
+
+    fn display_order(&self) -> Option<i32> {
+        self.display_order
+    }
+
+    fn index(&self) -> Option<&Index> {
+        self.index.as_ref()
+    }
+
+    fn alias(&self) -> Option<&Vec<String>> {
+        self.alias.as_ref()
+    }
+
+    fn deprecated_alias(&self) -> Option<&Vec<(String, String)>> {
+        self.deprecated_alias.as_ref()
+    }
+
+    fn hint(&self) -> Option<&str> {
         self.help.as_deref()
     }
 
+    fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    fn env(&self) -> Option<&str> {
+        self.env.as_deref()
+    }
+
+    fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    fn toggle_prefix(&self) -> Option<&str> {
+        self.toggle_prefix.as_deref()
+    }
+
     fn action(&self) -> Option<&Action> {
         self.action.as_ref()
     }
 
+    fn empty_value(&self) -> Option<&EmptyValuePolicy> {
+        self.empty_value.as_ref()
+    }
+
+    fn number_format(&self) -> Option<&NumberFormat> {
+        self.number_format.as_ref()
+    }
+
+    fn strip_thousands(&self) -> Option<char> {
+        self.strip_thousands
+    }
+
+    fn time_format(&self) -> Option<&str> {
+        self.time_format.as_deref()
+    }
+
+    fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    #[cfg(feature = "std")]
+    fn file_indirection(&self) -> Option<char> {
+        self.file_indirection
+    }
+
+    fn value_delimiter(&self) -> Option<char> {
+        self.value_delimiter
+    }
+
+    fn delimiter_escape(&self) -> Option<char> {
+        self.delimiter_escape.unwrap_or(Some('\\'))
+    }
+
+    fn stdin_marker(&self) -> Option<&str> {
+        self.stdin_marker.as_deref()
+    }
+
+    fn bool_literals(&self) -> Option<&(Vec<String>, Vec<String>)> {
+        self.bool_literals.as_ref()
+    }
+
+    fn multiple(&self) -> bool {
+        self.multiple.unwrap_or(true)
+    }
+
+    fn max_occurrences(&self) -> Option<usize> {
+        self.max_occurrences
+    }
+
+    fn optional_value(&self) -> Option<&OsStr> {
+        self.optional_value.as_deref()
+    }
+
+    fn value_count(&self) -> Option<&RangeInclusive<usize>> {
+        self.value_count.as_ref()
+    }
+
     fn storer(&self) -> Option<&ValStorer> {
         self.storer.as_ref()
     }
@@ -442,6 +1327,10 @@ impl ConfigValue for OptConfig {
         self.alias.as_mut()
     }
 
+    fn deprecated_alias_mut(&mut self) -> Option<&mut Vec<(String, String)>> {
+        self.deprecated_alias.as_mut()
+    }
+
     fn hint_mut(&mut self) -> Option<&mut String> {
         self.hint.as_mut()
     }
@@ -478,6 +1367,34 @@ impl ConfigValue for OptConfig {
         self.ignore_index
     }
 
+    fn trailing_raw(&self) -> bool {
+        self.trailing_raw
+    }
+
+    fn conflicts_with_positional(&self) -> bool {
+        self.conflicts_with_positional
+    }
+
+    fn standalone(&self) -> bool {
+        self.standalone
+    }
+
+    fn greedy(&self) -> bool {
+        self.greedy
+    }
+
+    fn ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    fn trim(&self) -> bool {
+        self.trim
+    }
+
+    fn secret(&self) -> bool {
+        self.secret
+    }
+
     fn has_ctor(&self) -> bool {
         self.ctor.is_some()
     }
@@ -494,6 +1411,14 @@ impl ConfigValue for OptConfig {
         self.force.is_some()
     }
 
+    fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    fn has_display_order(&self) -> bool {
+        self.display_order.is_some()
+    }
+
     fn has_index(&self) -> bool {
         self.index.is_some()
     }
@@ -506,14 +1431,87 @@ impl ConfigValue for OptConfig {
         self.help.is_some()
     }
 
+    fn has_env(&self) -> bool {
+        self.env.is_some()
+    }
+
+    fn has_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    fn has_toggle_prefix(&self) -> bool {
+        self.toggle_prefix.is_some()
+    }
+
     fn has_alias(&self) -> bool {
         self.alias.is_some()
     }
 
+    fn has_deprecated_alias(&self) -> bool {
+        self.deprecated_alias.is_some()
+    }
+
     fn has_action(&self) -> bool {
         self.action.is_some()
     }
 
+    fn has_empty_value(&self) -> bool {
+        self.empty_value.is_some()
+    }
+
+    fn has_number_format(&self) -> bool {
+        self.number_format.is_some()
+    }
+
+    fn has_strip_thousands(&self) -> bool {
+        self.strip_thousands.is_some()
+    }
+
+    fn has_time_format(&self) -> bool {
+        self.time_format.is_some()
+    }
+
+    fn has_prompt(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    #[cfg(feature = "std")]
+    fn has_file_indirection(&self) -> bool {
+        self.file_indirection.is_some()
+    }
+
+    fn has_value_delimiter(&self) -> bool {
+        self.value_delimiter.is_some()
+    }
+
+    fn has_delimiter_escape(&self) -> bool {
+        self.delimiter_escape.is_some()
+    }
+
+    fn has_stdin_marker(&self) -> bool {
+        self.stdin_marker.is_some()
+    }
+
+    fn has_bool_literals(&self) -> bool {
+        self.bool_literals.is_some()
+    }
+
+    fn has_multiple(&self) -> bool {
+        self.multiple.is_some()
+    }
+
+    fn has_max_occurrences(&self) -> bool {
+        self.max_occurrences.is_some()
+    }
+
+    fn has_optional_value(&self) -> bool {
+        self.optional_value.is_some()
+    }
+
+    fn has_value_count(&self) -> bool {
+        self.value_count.is_some()
+    }
+
     fn has_storer(&self) -> bool {
         self.storer.is_some()
     }
@@ -551,42 +1549,71 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn set_priority(&mut self, priority: i32) -> &mut Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    fn set_display_order(&mut self, order: i32) -> &mut Self {
+        self.display_order = Some(order);
+        self
+    }
+
     fn set_index(&mut self, index: Index) -> &mut Self {
         self.index = Some(index);
         self
     }
 
     fn set_alias(&mut self, alias: Vec<impl Into<String>>) -> &mut Self {
-        self.alias = Some(alias.into_iter().map(Into::into).collect::<Vec<_>>());
+        self.alias = None;
+        for alias in alias {
+            self.add_alias(alias);
+        }
         self
     }
 
     fn clr_alias(&mut self) -> &mut Self {
-        if let Some(alias) = self.alias.as_mut() {
-            alias.clear();
-        }
+        self.alias = None;
         self
     }
 
     fn add_alias(&mut self, alias: impl Into<String>) -> &mut Self {
-        self.alias.get_or_insert(vec![]).push(alias.into());
+        let alias = alias.into();
+        let ignore_case = self.ignore_case;
+        let aliases = self.alias.get_or_insert(vec![]);
+
+        if !aliases.iter().any(|v| alias_eq(v, &alias, ignore_case)) {
+            aliases.push(alias);
+        }
         self
     }
 
     fn rem_alias(&mut self, alias: impl AsRef<str>) -> &mut Self {
         let alias = alias.as_ref();
+        let ignore_case = self.ignore_case;
 
         if let Some(v) = self.alias.as_mut() {
-            for (index, value) in v.iter().enumerate() {
-                if value == alias {
-                    v.remove(index);
-                    break;
-                }
-            }
+            v.retain(|value| !alias_eq(value, alias, ignore_case));
         }
         self
     }
 
+    fn set_deprecated_alias(&mut self, alias: Vec<(String, String)>) -> &mut Self {
+        self.deprecated_alias = Some(alias);
+        self
+    }
+
+    fn add_deprecated_alias(
+        &mut self,
+        alias: impl Into<String>,
+        message: impl Into<String>,
+    ) -> &mut Self {
+        self.deprecated_alias
+            .get_or_insert(vec![])
+            .push((alias.into(), message.into()));
+        self
+    }
+
     fn set_hint(&mut self, hint: impl Into<String>) -> &mut Self {
         self.hint = Some(hint.into());
         self
@@ -597,11 +1624,97 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn set_env(&mut self, env: impl Into<String>) -> &mut Self {
+        self.env = Some(env.into());
+        self
+    }
+
+    fn set_group(&mut self, group: impl Into<String>) -> &mut Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    fn set_toggle_prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.toggle_prefix = Some(prefix.into());
+        self
+    }
+
     fn set_action(&mut self, action: Action) -> &mut Self {
         self.action = Some(action);
         self
     }
 
+    fn set_empty_value(&mut self, empty_value: EmptyValuePolicy) -> &mut Self {
+        self.empty_value = Some(empty_value);
+        self
+    }
+
+    fn set_number_format(&mut self, number_format: NumberFormat) -> &mut Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
+    fn set_strip_thousands(&mut self, separator: char) -> &mut Self {
+        self.strip_thousands = Some(separator);
+        self
+    }
+
+    fn set_time_format(&mut self, format: impl Into<String>) -> &mut Self {
+        self.time_format = Some(format.into());
+        self
+    }
+
+    fn set_prompt(&mut self, prompt: impl Into<Option<String>>) -> &mut Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn set_file_indirection(&mut self, marker: char) -> &mut Self {
+        self.file_indirection = Some(marker);
+        self
+    }
+
+    fn set_value_delimiter(&mut self, delimiter: char) -> &mut Self {
+        self.value_delimiter = Some(delimiter);
+        self
+    }
+
+    fn set_delimiter_escape(&mut self, escape: Option<char>) -> &mut Self {
+        self.delimiter_escape = Some(escape);
+        self
+    }
+
+    fn set_stdin_marker(&mut self, marker: impl Into<Option<String>>) -> &mut Self {
+        self.stdin_marker = Some(marker.into().unwrap_or_else(|| STDIN_MARKER.to_owned()));
+        self
+    }
+
+    fn set_bool_literals(&mut self, literals: (Vec<String>, Vec<String>)) -> &mut Self {
+        self.bool_literals = Some(literals);
+        self
+    }
+
+    fn set_multiple(&mut self, multiple: bool) -> &mut Self {
+        self.multiple = Some(multiple);
+        self
+    }
+
+    fn set_max_occurrences(&mut self, max: usize) -> &mut Self {
+        self.max_occurrences = Some(max);
+        self
+    }
+
+    fn set_optional_value(&mut self, optional_value: impl Into<OsString>) -> &mut Self {
+        self.optional_value = Some(optional_value.into());
+        self
+    }
+
+    fn set_value_count(&mut self, value_count: RangeInclusive<usize>) -> &mut Self {
+        self.value_count = Some(value_count);
+        self
+    }
+
     fn set_storer(&mut self, storer: ValStorer) -> &mut Self {
         self.storer = Some(storer);
         self
@@ -632,6 +1745,41 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn set_trailing_raw(&mut self, trailing_raw: bool) -> &mut Self {
+        self.trailing_raw = trailing_raw;
+        self
+    }
+
+    fn set_conflicts_with_positional(&mut self, conflicts: bool) -> &mut Self {
+        self.conflicts_with_positional = conflicts;
+        self
+    }
+
+    fn set_standalone(&mut self, standalone: bool) -> &mut Self {
+        self.standalone = standalone;
+        self
+    }
+
+    fn set_greedy(&mut self, greedy: bool) -> &mut Self {
+        self.greedy = greedy;
+        self
+    }
+
+    fn set_ignore_case(&mut self, ignore_case: bool) -> &mut Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    fn set_trim(&mut self, trim: bool) -> &mut Self {
+        self.trim = trim;
+        self
+    }
+
+    fn set_secret(&mut self, secret: bool) -> &mut Self {
+        self.secret = secret;
+        self
+    }
+
     fn take_ctor(&mut self) -> Option<String> {
         self.ctor.take()
     }
@@ -648,6 +1796,14 @@ impl ConfigValue for OptConfig {
         self.force.take()
     }
 
+    fn take_priority(&mut self) -> Option<i32> {
+        self.priority.take()
+    }
+
+    fn take_display_order(&mut self) -> Option<i32> {
+        self.display_order.take()
+    }
+
     fn take_index(&mut self) -> Option<Index> {
         self.index.take()
     }
@@ -656,6 +1812,10 @@ impl ConfigValue for OptConfig {
         self.alias.take()
     }
 
+    fn take_deprecated_alias(&mut self) -> Option<Vec<(String, String)>> {
+        self.deprecated_alias.take()
+    }
+
     fn take_hint(&mut self) -> Option<String> {
         self.hint.take()
     }
@@ -664,10 +1824,79 @@ impl ConfigValue for OptConfig {
         self.help.take()
     }
 
+    fn take_env(&mut self) -> Option<String> {
+        self.env.take()
+    }
+
+    fn take_group(&mut self) -> Option<String> {
+        self.group.take()
+    }
+
+    fn take_toggle_prefix(&mut self) -> Option<String> {
+        self.toggle_prefix.take()
+    }
+
     fn take_action(&mut self) -> Option<Action> {
         self.action.take()
     }
 
+    fn take_empty_value(&mut self) -> Option<EmptyValuePolicy> {
+        self.empty_value.take()
+    }
+
+    fn take_number_format(&mut self) -> Option<NumberFormat> {
+        self.number_format.take()
+    }
+
+    fn take_strip_thousands(&mut self) -> Option<char> {
+        self.strip_thousands.take()
+    }
+
+    fn take_time_format(&mut self) -> Option<String> {
+        self.time_format.take()
+    }
+
+    fn take_prompt(&mut self) -> Option<String> {
+        self.prompt.take()
+    }
+
+    #[cfg(feature = "std")]
+    fn take_file_indirection(&mut self) -> Option<char> {
+        self.file_indirection.take()
+    }
+
+    fn take_value_delimiter(&mut self) -> Option<char> {
+        self.value_delimiter.take()
+    }
+
+    fn take_delimiter_escape(&mut self) -> Option<Option<char>> {
+        self.delimiter_escape.take()
+    }
+
+    fn take_stdin_marker(&mut self) -> Option<String> {
+        self.stdin_marker.take()
+    }
+
+    fn take_bool_literals(&mut self) -> Option<(Vec<String>, Vec<String>)> {
+        self.bool_literals.take()
+    }
+
+    fn take_multiple(&mut self) -> Option<bool> {
+        self.multiple.take()
+    }
+
+    fn take_max_occurrences(&mut self) -> Option<usize> {
+        self.max_occurrences.take()
+    }
+
+    fn take_optional_value(&mut self) -> Option<OsString> {
+        self.optional_value.take()
+    }
+
+    fn take_value_count(&mut self) -> Option<RangeInclusive<usize>> {
+        self.value_count.take()
+    }
+
     fn take_storer(&mut self) -> Option<ValStorer> {
         self.storer.take()
     }
@@ -681,24 +1910,9 @@ impl ConfigValue for OptConfig {
     }
 
     fn infer_builtin_ty(&mut self) {
-        if let Some(ctor) = self.ctor() {
-            let cid = Cid::from(ctor);
-
-            self.set_type_id(match cid {
-                Cid::Int => typeid::<i64>(),
-                Cid::Str => typeid::<String>(),
-                Cid::Flt => typeid::<f64>(),
-                Cid::Uint => typeid::<u64>(),
-                Cid::Bool => typeid::<bool>(),
-                Cid::Cmd => typeid::<crate::opt::Cmd>(),
-                Cid::Pos => typeid::<crate::opt::Pos<bool>>(),
-                Cid::Main => typeid::<crate::opt::Main>(),
-                Cid::Any => typeid::<crate::opt::AnyOpt>(),
-                Cid::Raw => typeid::<std::ffi::OsString>(),
-                _ => {
-                    unreachable!("creator `{ctor}` can't infer any type")
-                }
-            });
+        // the type of custom creator is unknown here
+        if let Some(type_id) = self.ctor().and_then(|ctor| Cid::from(ctor).type_id()) {
+            self.set_type_id(type_id);
         }
     }
 
@@ -712,6 +1926,16 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    fn with_display_order(mut self, order: i32) -> Self {
+        self.display_order = Some(order);
+        self
+    }
+
     fn with_ctor(mut self, ctor: impl Into<String>) -> Self {
         self.ctor = Some(ctor.into());
         self
@@ -737,8 +1961,28 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn with_env(mut self, env: impl Into<String>) -> Self {
+        self.env = Some(env.into());
+        self
+    }
+
+    fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    fn with_toggle_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.toggle_prefix = Some(prefix.into());
+        self
+    }
+
     fn with_alias(mut self, alias: Vec<impl Into<String>>) -> Self {
-        self.alias = Some(alias.into_iter().map(|v| v.into()).collect());
+        self.set_alias(alias);
+        self
+    }
+
+    fn with_deprecated_alias(mut self, alias: Vec<(String, String)>) -> Self {
+        self.deprecated_alias = Some(alias);
         self
     }
 
@@ -752,6 +1996,77 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn with_empty_value(mut self, empty_value: EmptyValuePolicy) -> Self {
+        self.empty_value = Some(empty_value);
+        self
+    }
+
+    fn with_number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
+    fn with_strip_thousands(mut self, separator: char) -> Self {
+        self.strip_thousands = Some(separator);
+        self
+    }
+
+    fn with_time_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = Some(format.into());
+        self
+    }
+
+    fn with_prompt(mut self, prompt: impl Into<Option<String>>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn with_file_indirection(mut self, marker: char) -> Self {
+        self.file_indirection = Some(marker);
+        self
+    }
+
+    fn with_value_delimiter(mut self, delimiter: char) -> Self {
+        self.value_delimiter = Some(delimiter);
+        self
+    }
+
+    fn with_delimiter_escape(mut self, escape: Option<char>) -> Self {
+        self.delimiter_escape = Some(escape);
+        self
+    }
+
+    fn with_stdin_marker(mut self, marker: impl Into<Option<String>>) -> Self {
+        self.stdin_marker = Some(marker.into().unwrap_or_else(|| STDIN_MARKER.to_owned()));
+        self
+    }
+
+    fn with_bool_literals(mut self, literals: (Vec<String>, Vec<String>)) -> Self {
+        self.bool_literals = Some(literals);
+        self
+    }
+
+    fn with_multiple(mut self, multiple: bool) -> Self {
+        self.multiple = Some(multiple);
+        self
+    }
+
+    fn with_max_occurrences(mut self, max: usize) -> Self {
+        self.max_occurrences = Some(max);
+        self
+    }
+
+    fn with_optional_value(mut self, optional_value: impl Into<OsString>) -> Self {
+        self.optional_value = Some(optional_value.into());
+        self
+    }
+
+    fn with_value_count(mut self, value_count: RangeInclusive<usize>) -> Self {
+        self.value_count = Some(value_count);
+        self
+    }
+
     fn with_storer(mut self, storer: ValStorer) -> Self {
         self.storer = Some(storer);
         self
@@ -772,6 +2087,41 @@ impl ConfigValue for OptConfig {
         self
     }
 
+    fn with_trailing_raw(mut self, trailing_raw: bool) -> Self {
+        self.trailing_raw = trailing_raw;
+        self
+    }
+
+    fn with_conflicts_with_positional(mut self, conflicts: bool) -> Self {
+        self.conflicts_with_positional = conflicts;
+        self
+    }
+
+    fn with_standalone(mut self, standalone: bool) -> Self {
+        self.standalone = standalone;
+        self
+    }
+
+    fn with_greedy(mut self, greedy: bool) -> Self {
+        self.greedy = greedy;
+        self
+    }
+
+    fn with_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    fn with_secret(mut self, secret: bool) -> Self {
+        self.secret = secret;
+        self
+    }
+
     fn with_initializer(mut self, initializer: ValInitializer) -> Self {
         self.initializer = Some(initializer);
         self
@@ -1,9 +1,11 @@
+use std::any::TypeId;
 use std::fmt::Debug;
 
 use crate::opt::ConfigValue;
 use crate::opt::Opt;
 use crate::set::Ctor;
 use crate::trace;
+use crate::typeid;
 use crate::Error;
 
 #[cfg(feature = "sync")]
@@ -170,6 +172,24 @@ impl Cid {
             Cid::Name(name) => s == name.as_str(),
         }
     }
+
+    /// Return the value type of options created by built-in creator,
+    /// the type of [`Fallback`](Cid::Fallback) and custom creator is unknown.
+    pub fn type_id(&self) -> Option<TypeId> {
+        match self {
+            Cid::Int => Some(typeid::<i64>()),
+            Cid::Str => Some(typeid::<String>()),
+            Cid::Flt => Some(typeid::<f64>()),
+            Cid::Uint => Some(typeid::<u64>()),
+            Cid::Bool => Some(typeid::<bool>()),
+            Cid::Cmd => Some(typeid::<crate::opt::Cmd>()),
+            Cid::Pos => Some(typeid::<crate::opt::Pos<bool>>()),
+            Cid::Main => Some(typeid::<crate::opt::Main>()),
+            Cid::Any => Some(typeid::<crate::opt::AnyOpt>()),
+            Cid::Raw => Some(typeid::<std::ffi::OsString>()),
+            Cid::Fallback | Cid::Name(_) => None,
+        }
+    }
 }
 
 impl From<String> for Cid {
@@ -0,0 +1,183 @@
+use std::any::TypeId;
+use std::ffi::OsStr;
+
+use time::error::Parse;
+use time::format_description::well_known::Iso8601;
+use time::format_description::well_known::Rfc3339;
+use time::Date;
+use time::OffsetDateTime;
+
+use crate::ctx::Ctx;
+use crate::opt::Action;
+use crate::raise_error;
+use crate::typeid;
+use crate::value::raw2str;
+use crate::value::AnyValue;
+use crate::value::RawValParser;
+use crate::value::ValStorer;
+use crate::Error;
+
+/// Parse the date and time value from `raw` using `parse`, the `format` is displayed in error message.
+fn parse_time<T>(
+    raw: Option<&OsStr>,
+    ctx: &Ctx,
+    format: &str,
+    parse: impl FnOnce(&str) -> Result<T, Parse>,
+) -> Result<T, Error> {
+    let val = raw2str(raw)?;
+    let uid = ctx.uid()?;
+
+    parse(val).map_err(|e| {
+        Error::sp_rawval(
+            raw,
+            format!(
+                "not a valid value of type {} in format `{}`: {}",
+                std::any::type_name::<T>(),
+                format,
+                e
+            ),
+        )
+        .with_uid(uid)
+    })
+}
+
+/// Parse the value in RFC3339 format, such as `2024-05-01T08:30:00+08:00`.
+impl RawValParser for OffsetDateTime {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        parse_time(raw, ctx, "RFC3339", |val| {
+            OffsetDateTime::parse(val, &Rfc3339)
+        })
+    }
+}
+
+/// Parse the value in ISO 8601 calendar date format, such as `2024-05-01`.
+impl RawValParser for Date {
+    type Error = Error;
+
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        parse_time(raw, ctx, "[year]-[month]-[day]", |val| {
+            Date::parse(val, &Iso8601::DATE)
+        })
+    }
+}
+
+/// Create a [`ValStorer`] parsing the date and time value using the format description
+/// [`time_format`](crate::opt::ConfigValue::time_format),
+/// return `None` if the format is not set or `type_id` is not a date and time type.
+pub(crate) fn time_storer(type_id: TypeId, format: Option<&str>) -> Option<ValStorer> {
+    let format = format?;
+
+    macro_rules! storer {
+        ($($ty:ty),+) => {
+            $(
+                if type_id == typeid::<$ty>() {
+                    let items = time::format_description::parse_owned::<2>(format).map_err(|e| e.to_string());
+                    let format = format.to_owned();
+
+                    return Some(ValStorer::new(Box::new(
+                        move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                            let items = items
+                                .as_ref()
+                                .map_err(|e| raise_error!("invalid time format `{}`: {}", format, e))?;
+                            let val = parse_time(raw, ctx, &format, |val| <$ty>::parse(val, items))?;
+
+                            crate::trace!("in time value storer, parsing {:?} -> {:?}", raw, val);
+                            act.store1(Some(val), handler);
+                            Ok(())
+                        },
+                    )));
+                }
+            )+
+        };
+    }
+
+    storer!(OffsetDateTime, Date);
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use time::Date;
+    use time::Month;
+    use time::OffsetDateTime;
+    use time::UtcOffset;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_time_value() {
+        assert!(test_time_value_impl().is_ok());
+    }
+
+    fn parse(args: &[&str], format: Option<&str>) -> Result<(Return, ASet), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        match format {
+            Some(format) => {
+                set.add_opt("--at".infer::<OffsetDateTime>())?
+                    .set_time_format(format)
+                    .run()?;
+                set.add_opt("--day".infer::<Date>())?
+                    .set_time_format(format)
+                    .run()?;
+            }
+            None => {
+                set.add_opt("--at".infer::<OffsetDateTime>())?.run()?;
+                set.add_opt("--day".infer::<Date>())?.run()?;
+            }
+        }
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app"].into_iter().chain(args.iter().copied()));
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        Ok((ret, set))
+    }
+
+    fn test_time_value_impl() -> Result<(), Error> {
+        let (ret, set) = parse(
+            &["--at", "2024-05-01T08:30:00+08:00", "--day=2024-05-02"],
+            None,
+        )?;
+        let at = set.find_val::<OffsetDateTime>("--at")?;
+
+        assert!(ret.status());
+        assert_eq!(
+            at.date(),
+            Date::from_calendar_date(2024, Month::May, 1).unwrap()
+        );
+        assert_eq!((at.hour(), at.minute()), (8, 30));
+        assert_eq!(at.offset(), UtcOffset::from_hms(8, 0, 0).unwrap());
+        assert_eq!(
+            set.find_val::<Date>("--day")?,
+            &Date::from_calendar_date(2024, Month::May, 2).unwrap()
+        );
+
+        let (ret, set) = parse(&["--day", "02/05/2024"], Some("[day]/[month]/[year]"))?;
+
+        assert!(ret.status());
+        assert_eq!(
+            set.find_val::<Date>("--day")?,
+            &Date::from_calendar_date(2024, Month::May, 2).unwrap()
+        );
+
+        let (ret, _) = parse(&["--at", "2024/05/01"], None)?;
+
+        assert!(!ret.status());
+        assert!(format!("{:?}", ret.failure()).contains("in format `RFC3339`"));
+
+        let (ret, _) = parse(&["--day", "2024-05-02"], Some("[day]/[month]/[year]"))?;
+
+        assert!(!ret.status());
+        assert!(format!("{:?}", ret.failure()).contains("in format `[day]/[month]/[year]`"));
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+/// Control how the empty value after `=` is processed, such as `--name=` or `--name= `.
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum EmptyValuePolicy {
+    /// Store the value as it is, this is the default behavior.
+    #[default]
+    Store,
+
+    /// Report a missing value failure.
+    Error,
+
+    /// Consume the argument, but leave the option unset.
+    Unset,
+}
+
+impl EmptyValuePolicy {
+    pub fn is_store(&self) -> bool {
+        matches!(self, Self::Store)
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error)
+    }
+
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Self::Unset)
+    }
+}
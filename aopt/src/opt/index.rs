@@ -107,6 +107,17 @@ pub enum Index {
     /// `@1..3` will matching `"pos1"`, `"pos2"`.
     Range(usize, Option<usize>),
 
+    /// The NOA which index is `start + n * step` with format `start/step`, floating position.
+    ///
+    /// # Example
+    ///
+    /// For `["app", "key1", "val1", "key2", "val2", "key3", "val3"]`:
+    ///
+    /// `@1/2` will matching `"key1"`, `"key2"` or `"key3"`.
+    ///
+    /// `@2/2` will matching `"val1"`, `"val2"` or `"val3"`.
+    Step(usize, usize),
+
     /// The anywhere position of NOA, floating position.
     ///
     /// # Example
@@ -114,6 +125,11 @@ pub enum Index {
     /// For `["app", "--aopt", "--bopt=42", "pos1", "--copt", "pos2", "--dopt", "value", "pos3"]`:
     ///
     /// `@*` will matching `"app"`, `"pos1"`, `"pos2"` or `"pos3"`.
+    ///
+    /// If [`skip_claimed_pos`](crate::set::Set::skip_claimed_pos) of set is enabled,
+    /// the position claimed by other fixed position [`Pos`](crate::opt::Style::Pos) will be skipped,
+    /// and a [`Pos`](crate::opt::Style::Pos) with [`Action::Set`](crate::opt::Action::Set)
+    /// only binds the first unclaimed position.
     AnyWhere,
 
     #[default]
@@ -138,6 +154,9 @@ impl Index {
             .opt()
             .sep_once("..", num.opt())
             .map(|(beg, end)| Ok(Index::range(beg, end)));
+        let step_parser = num
+            .sep_once("/", num)
+            .map(|(start, step)| Ok(Index::Step(start, step)));
         let pos_parser = sign.then(num).map(|(s, v)| {
             Ok(if s {
                 Index::forward(v)
@@ -147,7 +166,13 @@ impl Index {
         });
 
         let parser = start
-            .then(any_parser.or(seq_parser).or(range_parser).or(pos_parser))
+            .then(
+                any_parser
+                    .or(seq_parser)
+                    .or(range_parser)
+                    .or(step_parser)
+                    .or(pos_parser),
+            )
             ._1()
             .then(end)
             ._0();
@@ -156,6 +181,10 @@ impl Index {
             .ignore(char::is_ascii_whitespace.repeat_full())
             .ctor(&parser)
             .map_err(|_| Error::index_parse(dat, "failed parsing index"))
+            .and_then(|index| match index {
+                Index::Step(_, 0) => Err(Error::index_parse(dat, "step of index can't be zero")),
+                index => Ok(index),
+            })
     }
 
     pub fn is_null(&self) -> bool {
@@ -182,10 +211,23 @@ impl Index {
         matches!(self, Self::Range(_, _))
     }
 
+    pub fn is_step(&self) -> bool {
+        matches!(self, Self::Step(_, _))
+    }
+
     pub fn is_anywhere(&self) -> bool {
         matches!(self, Self::AnyWhere)
     }
 
+    /// Return true if the index is fixed position,
+    /// such as [`Forward`](Index::Forward), [`List`](Index::List) or [`Range`](Index::Range) with upper limit.
+    pub fn is_fixed(&self) -> bool {
+        matches!(
+            self,
+            Self::Forward(_) | Self::List(_) | Self::Range(_, Some(_))
+        )
+    }
+
     pub fn to_help(&self) -> String {
         match self {
             Index::Forward(offset) => {
@@ -218,6 +260,9 @@ impl Index {
             Index::Range(start, Some(end)) => {
                 format!("{}..{}", start, end)
             }
+            Index::Step(start, step) => {
+                format!("{}/{}", start, step)
+            }
             Index::AnyWhere => "*".to_string(),
             Index::Null => String::default(),
         }
@@ -302,6 +347,12 @@ impl Index {
         }
     }
 
+    /// Create a [`Step`](Index::Step) index, panic if `step` is zero.
+    pub fn step(start: usize, step: usize) -> Self {
+        assert!(step > 0, "step of Index can't be zero");
+        Self::Step(start, step)
+    }
+
     pub fn anywhere() -> Self {
         Self::AnyWhere
     }
@@ -357,6 +408,11 @@ impl Index {
                     }
                 }
             },
+            Self::Step(start, step)
+                if noa_index >= *start && (noa_index - start).checked_rem(*step) == Some(0) =>
+            {
+                return Some(noa_index);
+            }
             Self::AnyWhere => {
                 return Some(noa_index);
             }
@@ -386,6 +442,9 @@ impl Display for Index {
                 Index::Range(s, Some(e)) => {
                     format!("{}..{}", s, e)
                 }
+                Index::Step(s, v) => {
+                    format!("{}/{}", s, v)
+                }
                 Index::List(v) => {
                     let strs: Vec<String> = v.iter().map(|v| format!("{}", v)).collect();
 
@@ -516,3 +575,51 @@ impl<const N: usize> TryFrom<[usize; N]> for Index {
         Ok(Self::list(Vec::from(value)))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Index;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_step() {
+        assert!(test_step_impl().is_ok());
+    }
+
+    fn test_step_impl() -> Result<(), Error> {
+        let index = Index::parse("1/2")?;
+        let matched: Vec<usize> = (0..6)
+            .filter(|idx| index.calc_index(*idx, 6).is_some())
+            .collect();
+
+        assert_eq!(index, Index::step(1, 2));
+        assert_eq!(index.to_string(), "1/2");
+        assert_eq!(matched, [1, 3, 5]);
+        assert!(Index::parse("1/0").is_err());
+
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("key=p@1/2")?
+            .set_pos_type::<String>()
+            .set_action(Action::App)
+            .run()?;
+        set.add_opt("val=p@2/2")?
+            .set_pos_type::<String>()
+            .set_action(Action::App)
+            .run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "a", "1", "b", "2", "c", "3"]);
+
+        policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        assert_eq!(set.find_vals::<String>("key")?, &["a", "b", "c"]);
+        assert_eq!(set.find_vals::<String>("val")?, &["1", "2", "3"]);
+        Ok(())
+    }
+}
@@ -2,9 +2,13 @@ pub(crate) mod action;
 pub(crate) mod aopt;
 pub(crate) mod config;
 pub(crate) mod creator;
+#[cfg(feature = "time")]
+pub(crate) mod datetime;
+pub(crate) mod empty;
 pub(crate) mod help;
 pub(crate) mod index;
 pub(crate) mod info;
+pub(crate) mod number;
 pub(crate) mod parser;
 #[cfg(feature = "serde")]
 pub(crate) mod serialize;
@@ -12,6 +16,7 @@ pub(crate) mod style;
 pub(crate) mod value;
 
 pub use self::action::Action;
+pub use self::aopt::default_hint;
 pub use self::aopt::AOpt;
 pub use self::config::ConfigBuild;
 pub use self::config::ConfigBuildInfer;
@@ -23,10 +28,13 @@ pub use self::config::ConfigValue;
 pub use self::config::OptConfig;
 pub use self::creator::Cid;
 pub use self::creator::Creator;
+pub use self::empty::EmptyValuePolicy;
 pub use self::help::Help;
 pub use self::index::Index;
 pub use self::info::ConstrctInfo;
 pub use self::info::Information;
+pub use self::number::FromStrRadix;
+pub use self::number::NumberFormat;
 pub use self::parser::StrParser;
 #[cfg(feature = "serde")]
 pub use self::serialize::Deserialize;
@@ -38,9 +46,11 @@ pub use self::style::Style;
 pub use self::value::OptValueExt;
 
 use std::any::TypeId;
+use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::ops::RangeInclusive;
 
 use crate::value::ValAccessor;
 use crate::Error;
@@ -216,7 +226,7 @@ impl<T> DerefMut for Main<T> {
 /// # use aopt::value::raw2str;
 /// # use aopt::prelude::*;
 ///
-/// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 /// pub struct Name(String);
 ///
 /// impl RawValParser for Name {
@@ -314,6 +324,23 @@ pub trait Opt: Debug {
     /// The help message of option.
     fn help(&self) -> &str;
 
+    /// The name of environment variable associated with option.
+    fn env(&self) -> Option<&str> {
+        None
+    }
+
+    /// The help group of option, the options of same group are displayed together in help message.
+    fn group(&self) -> Option<&str> {
+        None
+    }
+
+    /// The prefix of the name disabling the boolean option, such as `+` for option `-x`,
+    /// then `-x` sets the option to `true` and `+x` sets it to `false`.
+    /// The prefix must be registered in the [`PrefixedValidator`](crate::set::PrefixedValidator).
+    fn toggle_prefix(&self) -> Option<&str> {
+        None
+    }
+
     fn valid(&self) -> bool;
 
     /// If the option matched.
@@ -322,15 +349,51 @@ pub trait Opt: Debug {
     /// If the option is force required.
     fn force(&self) -> bool;
 
+    /// The priority of option handler, the handler with higher priority is invoked first
+    /// in [`DelayPolicy`](crate::parser::DelayPolicy).
+    /// The handlers with same priority are invoked in the declaration order of options,
+    /// the default priority is 0.
+    /// The other policies invoke the handler when the option matched and ignore the priority.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// The order of option in help message, the option with lower order is displayed first,
+    /// keep the declaration order if same. The default order is `0`.
+    fn display_order(&self) -> i32 {
+        0
+    }
+
     /// The associaed action of option.
     fn action(&self) -> &Action;
 
+    /// How the empty value after `=` is processed.
+    fn empty_value(&self) -> EmptyValuePolicy {
+        EmptyValuePolicy::default()
+    }
+
+    /// The value used when the option appears without a value.
+    fn optional_value(&self) -> Option<&OsStr> {
+        None
+    }
+
+    /// The range of value count accepted by the option,
+    /// the count of values stored is checked after parsing if the option matched.
+    fn value_count(&self) -> Option<&RangeInclusive<usize>> {
+        None
+    }
+
     /// The index of option.
     fn index(&self) -> Option<&Index>;
 
     /// The alias the option.
     fn alias(&self) -> Option<&Vec<String>>;
 
+    /// The deprecated alias of the option, and the warning message of each alias.
+    fn deprecated_alias(&self) -> Option<&Vec<(String, String)>> {
+        None
+    }
+
     fn accessor(&self) -> &ValAccessor;
 
     fn accessor_mut(&mut self) -> &mut ValAccessor;
@@ -341,6 +404,49 @@ pub trait Opt: Debug {
 
     fn ignore_index(&self) -> bool;
 
+    /// If the arguments after the command are collected verbatim.
+    ///
+    /// For [`Pos`], the arguments after the option terminator, such as `--`, are parsed into its values,
+    /// it should have the [`Null`](Index::Null) index so that it is not matched by other arguments.
+    /// Only one [`Pos`] of set can collect them, adding another one is an error.
+    fn trailing_raw(&self) -> bool {
+        false
+    }
+
+    /// If the option can not be set together with positional arguments.
+    fn conflicts_with_positional(&self) -> bool {
+        false
+    }
+
+    /// If the option can not be set together with any other arguments,
+    /// such as other options or positional arguments.
+    fn standalone(&self) -> bool {
+        false
+    }
+
+    /// If the option consumes the following arguments until next option or the end,
+    /// such as `--files a b c`, the arguments are processed like `--files=a --files=b --files=c`.
+    fn greedy(&self) -> bool {
+        false
+    }
+
+    /// If the name and alias are matched case-insensitively,
+    /// the hint is still generated from the name and alias as given.
+    fn ignore_case(&self) -> bool {
+        false
+    }
+
+    /// If the value of option is masked when logging the arguments,
+    /// such as [`normalized_args`](crate::parser::Return::normalized_args).
+    fn secret(&self) -> bool {
+        false
+    }
+
+    /// The text displayed when reading the value of missing force required option from terminal.
+    fn prompt(&self) -> Option<&str> {
+        None
+    }
+
     fn set_uid(&mut self, uid: Uid);
 
     fn set_matched(&mut self, matched: bool);
@@ -353,6 +459,11 @@ pub trait Opt: Debug {
 
     fn mat_alias(&self, name: &str) -> bool;
 
+    /// Return the warning message if the `name` is a deprecated alias of the option.
+    fn mat_deprecated_alias(&self, _: &str) -> Option<&str> {
+        None
+    }
+
     fn mat_index(&self, index: Option<(usize, usize)>) -> bool;
 
     fn init(&mut self) -> Result<(), Error>;
@@ -0,0 +1,370 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::num::ParseIntError;
+
+use crate::ctx::Ctx;
+use crate::opt::Action;
+use crate::typeid;
+use crate::value::raw2str;
+use crate::value::AnyValue;
+use crate::value::ValStorer;
+use crate::Error;
+
+/// Control which base is accepted when parsing the integer value of option,
+/// the value can have a leading `+` or `-` sign, such as `-0x10` or `+0b11`.
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum NumberFormat {
+    /// Decimal value without prefix, this is the default behavior.
+    #[default]
+    Decimal,
+
+    /// Hexadecimal value with `0x` prefix, such as `0x2a`.
+    Hex,
+
+    /// Octal value with `0o` prefix, such as `0o52`.
+    Octal,
+
+    /// Binary value with `0b` prefix, such as `0b101010`.
+    Binary,
+
+    /// Any of the above, the base is detected by prefix.
+    Any,
+}
+
+impl NumberFormat {
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal)
+    }
+
+    pub fn is_hex(&self) -> bool {
+        matches!(self, Self::Hex)
+    }
+
+    pub fn is_octal(&self) -> bool {
+        matches!(self, Self::Octal)
+    }
+
+    pub fn is_binary(&self) -> bool {
+        matches!(self, Self::Binary)
+    }
+
+    pub fn is_any(&self) -> bool {
+        matches!(self, Self::Any)
+    }
+
+    /// The value name displayed in the hint of option.
+    pub fn metavar(&self) -> &'static str {
+        match self {
+            Self::Decimal | Self::Any => "<NUM>",
+            Self::Hex => "<HEX>",
+            Self::Octal => "<OCT>",
+            Self::Binary => "<BIN>",
+        }
+    }
+
+    /// Split `val` into the digits with sign and the radix,
+    /// return `None` if `val` not match current format.
+    pub fn split(&self, val: &str) -> Option<(String, u32)> {
+        let (sign, rest) = match val.as_bytes().first() {
+            Some(b'-') => ("-", &val[1..]),
+            Some(b'+') => ("", &val[1..]),
+            _ => ("", val),
+        };
+        let prefixed = [
+            ("0x", 16),
+            ("0X", 16),
+            ("0o", 8),
+            ("0O", 8),
+            ("0b", 2),
+            ("0B", 2),
+        ]
+        .into_iter()
+        .find_map(|(prefix, radix)| rest.strip_prefix(prefix).map(|v| (v, radix)));
+        let (digits, radix) = match (self, prefixed) {
+            (Self::Decimal | Self::Any, None) => (rest, 10),
+            (Self::Any, Some(prefixed)) => prefixed,
+            (Self::Hex, Some(prefixed)) if prefixed.1 == 16 => prefixed,
+            (Self::Octal, Some(prefixed)) if prefixed.1 == 8 => prefixed,
+            (Self::Binary, Some(prefixed)) if prefixed.1 == 2 => prefixed,
+            _ => return None,
+        };
+
+        if digits.is_empty() || digits.starts_with(['+', '-']) {
+            None
+        } else {
+            Some((format!("{sign}{digits}"), radix))
+        }
+    }
+
+    /// Parse the integer value from `raw` using current format.
+    pub fn parse<T: FromStrRadix>(&self, raw: Option<&OsStr>, ctx: &Ctx) -> Result<T, Error> {
+        self.parse_with(raw, ctx, None)
+    }
+
+    /// Same as [`parse`](NumberFormat::parse),
+    /// but remove the `thousands` separator from the value before parsing.
+    pub fn parse_with<T: FromStrRadix>(
+        &self,
+        raw: Option<&OsStr>,
+        ctx: &Ctx,
+        thousands: Option<char>,
+    ) -> Result<T, Error> {
+        let val = strip_thousands(raw2str(raw)?, thousands);
+        let uid = ctx.uid()?;
+        let error = || {
+            Error::sp_rawval(
+                raw,
+                format!(
+                    "not a valid {} value of type {}",
+                    self.metavar(),
+                    std::any::type_name::<T>()
+                ),
+            )
+            .with_uid(uid)
+        };
+        let (digits, radix) = self.split(&val).ok_or_else(error)?;
+
+        T::from_str_radix(&digits, radix).map_err(|e| error().cause_by(e.into()))
+    }
+
+    /// Create a [`ValStorer`] parsing the value using current format,
+    /// return `None` if `type_id` is not an integer type.
+    pub fn storer(&self, type_id: TypeId) -> Option<ValStorer> {
+        self.storer_with(type_id, None)
+    }
+
+    /// Same as [`storer`](NumberFormat::storer),
+    /// but the `thousands` separator is removed from the value before parsing.
+    pub fn storer_with(&self, type_id: TypeId, thousands: Option<char>) -> Option<ValStorer> {
+        macro_rules! storer {
+            ($($int:ty),+) => {
+                $(
+                    if type_id == typeid::<$int>() {
+                        let format = *self;
+
+                        return Some(ValStorer::new(Box::new(
+                            move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                                let val = format.parse_with::<$int>(raw, ctx, thousands)?;
+
+                                crate::trace!("in number value storer, parsing {:?} -> {:?}", raw, val);
+                                act.store1(Some(val), handler);
+                                Ok(())
+                            },
+                        )));
+                    }
+                )+
+            };
+        }
+
+        storer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+        None
+    }
+}
+
+/// Remove the `thousands` separator from `val`, such as `1,000` to `1000`.
+fn strip_thousands(val: &str, thousands: Option<char>) -> Cow<'_, str> {
+    match thousands {
+        Some(sep) if val.contains(sep) => Cow::Owned(val.replace(sep, "")),
+        _ => Cow::Borrowed(val),
+    }
+}
+
+/// Create a [`ValStorer`] parsing the float value, the `thousands` separator is removed before parsing,
+/// return `None` if `type_id` is not a float type.
+fn float_storer(type_id: TypeId, thousands: char) -> Option<ValStorer> {
+    macro_rules! storer {
+        ($($float:ty),+) => {
+            $(
+                if type_id == typeid::<$float>() {
+                    return Some(ValStorer::new(Box::new(
+                        move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                            let val = strip_thousands(raw2str(raw)?, Some(thousands));
+                            let uid = ctx.uid()?;
+                            let val = val.parse::<$float>().map_err(|e| {
+                                Error::sp_rawval(
+                                    raw,
+                                    format!(
+                                        "not a valid {} value of type {}",
+                                        NumberFormat::Decimal.metavar(),
+                                        std::any::type_name::<$float>()
+                                    ),
+                                )
+                                .with_uid(uid)
+                                .cause_by(e.into())
+                            })?;
+
+                            crate::trace!("in number value storer, parsing {:?} -> {:?}", raw, val);
+                            act.store1(Some(val), handler);
+                            Ok(())
+                        },
+                    )));
+                }
+            )+
+        };
+    }
+
+    storer!(f32, f64);
+    None
+}
+
+/// Create a [`ValStorer`] for the number option configured by
+/// [`number_format`](crate::opt::ConfigValue::number_format) or
+/// [`strip_thousands`](crate::opt::ConfigValue::strip_thousands),
+/// return `None` if neither is set or `type_id` is not a number type.
+pub(crate) fn number_storer(
+    type_id: TypeId,
+    format: Option<&NumberFormat>,
+    thousands: Option<char>,
+) -> Option<ValStorer> {
+    match (format, thousands) {
+        (None, None) => None,
+        (format, thousands) => format
+            .copied()
+            .unwrap_or_default()
+            .storer_with(type_id, thousands)
+            .or_else(|| thousands.and_then(|sep| float_storer(type_id, sep))),
+    }
+}
+
+/// Integer type can be parsed from string with given radix.
+pub trait FromStrRadix: Sized {
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($int:ty),+) => {
+        $(
+            impl FromStrRadix for $int {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$int>::from_str_radix(src, radix)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str_radix!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod test {
+    use super::NumberFormat;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_number_format() {
+        assert!(test_number_format_impl().is_ok());
+    }
+
+    fn parse(args: &[&str], format: NumberFormat) -> Result<(Option<i64>, Return, String), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--num=i")?.set_number_format(format).run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app"].into_iter().chain(args.iter().copied()));
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        let hint = set.find("--num")?.hint().to_owned();
+
+        Ok((set.find_val::<i64>("--num").ok().copied(), ret, hint))
+    }
+
+    fn test_number_format_impl() -> Result<(), Error> {
+        let (val, ret, hint) = parse(&["--num", "0x2a"], NumberFormat::Hex)?;
+
+        assert!(ret.status());
+        assert_eq!(val, Some(42));
+        assert_eq!(hint, "--num <HEX>");
+
+        let (val, ret, _) = parse(&["--num=-0x10"], NumberFormat::Hex)?;
+
+        assert!(ret.status());
+        assert_eq!(val, Some(-16));
+
+        let (val, ret, _) = parse(&["--num", "42"], NumberFormat::Hex)?;
+
+        assert!(!ret.status());
+        assert_eq!(val, None);
+
+        let (val, ret, hint) = parse(&["--num", "0b101"], NumberFormat::Binary)?;
+
+        assert!(ret.status());
+        assert_eq!(val, Some(5));
+        assert_eq!(hint, "--num <BIN>");
+
+        for (arg, expect) in [("0o17", 15), ("+0b11", 3), ("-42", -42), ("0x1F", 31)] {
+            let (val, ret, _) = parse(&["--num", arg], NumberFormat::Any)?;
+
+            assert!(ret.status());
+            assert_eq!(val, Some(expect));
+        }
+        assert_eq!(NumberFormat::Decimal.split("0x2a"), None);
+        assert_eq!(NumberFormat::Octal.split("0x2a"), None);
+        assert_eq!(NumberFormat::Hex.split("0x-2a"), None);
+        assert_eq!(NumberFormat::Hex.split("0x"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_thousands() {
+        assert!(test_strip_thousands_impl().is_ok());
+    }
+
+    fn test_strip_thousands_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], thousands: Option<char>) -> Result<(Return, ASet), Error> {
+            let mut policy = AFwdPolicy::default();
+            let mut set = policy.default_set();
+            let mut inv = policy.default_inv();
+            let mut ser = policy.default_ser();
+
+            if let Some(sep) = thousands {
+                set.add_opt("--count=i")?.set_strip_thousands(sep).run()?;
+                set.add_opt("--ratio=f")?.set_strip_thousands(sep).run()?;
+            } else {
+                set.add_opt("--count=i")?.run()?;
+                set.add_opt("--ratio=f")?.run()?;
+            }
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+
+            let args = Args::from(["app"].into_iter().chain(args.iter().copied()));
+            let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+            Ok((ret, set))
+        }
+
+        let (ret, set) = parse(&["--count", "1,000", "--ratio=1,234.5"], Some(','))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--count")?, &1000);
+        assert_eq!(set.find_val::<f64>("--ratio")?, &1234.5);
+
+        let (ret, set) = parse(&["--count", "1_000_000"], Some('_'))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--count")?, &1000000);
+
+        let (ret, _) = parse(&["--count", "1,000"], None)?;
+
+        assert!(!ret.status());
+
+        let (ret, _) = parse(&["--ratio", "1,234.5"], None)?;
+
+        assert!(!ret.status());
+
+        let (ret, set) = parse(&["--count", "1000"], Some(','))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--count")?, &1000);
+        Ok(())
+    }
+}
@@ -67,9 +67,90 @@ impl StrParser {
         Self {}
     }
 
+    /// Check the names and type of option string before parsing,
+    /// return an error with the position of first unsupported character.
+    ///
+    /// The name can not contain whitespace, control characters or quotes,
+    /// use the [`ConfigValue`](crate::opt::ConfigValue) if you need an unusual name.
+    pub fn check_creator_string(&self, dat: &str) -> Result<(), Error> {
+        let chars: Vec<_> = dat.chars().collect();
+        let error = |pos: usize, hint: &str| {
+            Error::create_str(dat, format!("{} at position {}", hint, pos))
+        };
+        let name_end = chars
+            .iter()
+            .position(|c| ['=', '!', '*', '@', ':'].contains(c))
+            .unwrap_or(chars.len());
+        let has_alias = chars[..name_end].contains(&';');
+        let mut start = 0;
+
+        for (idx, name) in chars[..name_end].split(|c| *c == ';').enumerate() {
+            let trimmed = name.iter().position(|c| !c.is_whitespace());
+
+            match trimmed {
+                Some(offset) => {
+                    let len = name.iter().rposition(|c| !c.is_whitespace()).unwrap() + 1;
+
+                    for (pos, c) in name[..len].iter().enumerate().skip(offset) {
+                        if c.is_whitespace() || c.is_control() || ['"', '\'', '`'].contains(c) {
+                            return Err(error(
+                                start + pos,
+                                &format!("unsupported character `{}` in name", c.escape_debug()),
+                            ));
+                        }
+                    }
+                }
+                None if has_alias => {
+                    return Err(error(
+                        start,
+                        if idx == 0 {
+                            "missing name before alias"
+                        } else {
+                            "empty alias"
+                        },
+                    ));
+                }
+                None => {}
+            }
+            start += name.len() + 1;
+        }
+
+        let mut pos = name_end;
+
+        if chars.get(pos) == Some(&'=') {
+            pos += 1;
+            let ctor_start = pos;
+
+            while let Some(c) = chars.get(pos).filter(|c| !['!', '*', '@', ':'].contains(c)) {
+                if !c.is_alphabetic() {
+                    return Err(error(
+                        pos,
+                        &format!("unsupported character `{}` in type", c.escape_debug()),
+                    ));
+                }
+                pos += 1;
+            }
+            if pos == ctor_start {
+                return Err(error(pos, "missing type after `=`"));
+            }
+        }
+        if matches!(chars.get(pos), Some('!' | '*')) {
+            pos += 1;
+        }
+        match chars.get(pos) {
+            None | Some('@' | ':') => Ok(()),
+            Some(c) => Err(error(
+                pos,
+                &format!("unexpected character `{}`", c.escape_debug()),
+            )),
+        }
+    }
+
     pub fn parse_creator_string(&self, dat: &str) -> Result<ConstrctInfo, Error> {
         use neure::prelude::*;
 
+        self.check_creator_string(dat)?;
+
         let start = re::start();
         let end = re::end();
         let name = ['=', '!', '*', '@', ';', ':'].not().repeat_one_more();
@@ -131,6 +212,47 @@ mod test {
 
     use crate::prelude::*;
 
+    #[test]
+    fn test_malformed_str() {
+        let parser = StrParser;
+        let malformed = [
+            (
+                "--my opt=s",
+                "unsupported character ` ` in name at position 4",
+            ),
+            (
+                "-a;--al\"ias",
+                "unsupported character `\\\"` in name at position 7",
+            ),
+            (
+                "-a;--a\tb",
+                "unsupported character `\\t` in name at position 6",
+            ),
+            ("-a;;-b", "empty alias at position 3"),
+            (";-b", "missing name before alias at position 0"),
+            ("--opt=", "missing type after `=` at position 6"),
+            (
+                "--opt=i2",
+                "unsupported character `2` in type at position 7",
+            ),
+            ("--opt=s!x", "unexpected character `x` at position 8"),
+            ("--opt!!", "unexpected character `!` at position 6"),
+        ];
+
+        for (pattern, hint) in malformed {
+            let err = parser.parse_opt(pattern).unwrap_err();
+
+            assert_eq!(err.kind(), &crate::err::ErrorKind::CreateStrParse);
+            assert_eq!(
+                err.to_string(),
+                format!("invalid option create string `{}`: {}", pattern, hint)
+            );
+        }
+        // the whitespace around name and the index is allowed
+        assert_eq!(parser.parse_opt(" --opt =s").unwrap().name(), Some("--opt"));
+        assert!(parser.parse_opt("--opt=s@[1, 2]").is_ok());
+    }
+
     #[test]
     fn test_str_parser() {
         let options = [
@@ -1,8 +1,10 @@
+use std::any::type_name;
 use std::ffi::OsString;
 
 use crate::map::Entry;
 use crate::map::ErasedTy;
 use crate::raise_error;
+use crate::typeid;
 use crate::value::ErasedValue;
 use crate::Error;
 
@@ -17,7 +19,7 @@ pub trait OptValueExt {
 
     fn vals_mut<T: ErasedTy>(&mut self) -> Result<&mut Vec<T>, Error>;
 
-    fn entry<T: ErasedTy>(&mut self) -> Entry<'_, Vec<T>>;
+    fn entry<T: ErasedTy + Clone>(&mut self) -> Entry<'_, Vec<T>>;
 
     fn rawval(&self) -> Result<&OsString, Error>;
 
@@ -30,48 +32,54 @@ pub trait OptValueExt {
     fn filter<T: ErasedTy>(&mut self, f: impl FnMut(&T) -> bool) -> Result<Vec<T>, Error>;
 }
 
+/// Create the error of value accessing, name the option and the expected type
+/// if `T` is not the value type of option.
+fn value_error<T: ErasedTy>(opt: &impl Opt, what: &str) -> Error {
+    let name = opt.name();
+    let uid = opt.uid();
+
+    if opt.r#type() != &typeid::<T>() {
+        let ty = type_name::<T>();
+
+        match opt.accessor().rawval() {
+            Ok(raw) => raise_error!(
+                "option `{name}` value `{}` is not a valid {ty}",
+                raw.to_string_lossy()
+            ),
+            Err(_) => raise_error!("option `{name}` has no value of type {ty}"),
+        }
+    } else {
+        raise_error!("can not find {what} of `{}`({})", opt.hint(), opt.action())
+    }
+    .with_uid(uid)
+}
+
 impl<O: Opt> OptValueExt for O {
     fn val<T: ErasedTy>(&self) -> Result<&T, Error> {
-        let hint = self.hint();
-        let act = self.action();
-        let uid = self.uid();
-
-        self.accessor().val().map_err(|e| {
-            e.cause(raise_error!("can not find value(ref) of `{hint}`({act})"))
-                .with_uid(uid)
-        })
+        self.accessor()
+            .val()
+            .map_err(|e| e.cause(value_error::<T>(self, "value(ref)")))
     }
 
     fn val_mut<T: ErasedTy>(&mut self) -> Result<&mut T, Error> {
-        let hint = self.hint();
-        let act = self.action();
-        let uid = self.uid();
-        let err = raise_error!("can not find value(mut) of `{}`({})", hint, act).with_uid(uid);
+        let err = value_error::<T>(self, "value(mut)");
 
         self.accessor_mut().val_mut().map_err(|e| e.cause(err))
     }
 
     fn vals<T: ErasedTy>(&self) -> Result<&Vec<T>, Error> {
-        let hint = self.hint();
-        let act = self.action();
-        let uid = self.uid();
-
-        self.accessor().vals().map_err(|e| {
-            e.cause(raise_error!("can not find values(ref) of `{hint}`({act})"))
-                .with_uid(uid)
-        })
+        self.accessor()
+            .vals()
+            .map_err(|e| e.cause(value_error::<T>(self, "values(ref)")))
     }
 
     fn vals_mut<T: ErasedTy>(&mut self) -> Result<&mut Vec<T>, Error> {
-        let hint = self.hint();
-        let act = self.action();
-        let uid = self.uid();
-        let err = raise_error!("can not find values(mut) of `{}`({})", hint, act).with_uid(uid);
+        let err = value_error::<T>(self, "values(mut)");
 
         self.accessor_mut().vals_mut().map_err(|e| e.cause(err))
     }
 
-    fn entry<T: ErasedTy>(&mut self) -> Entry<'_, Vec<T>> {
+    fn entry<T: ErasedTy + Clone>(&mut self) -> Entry<'_, Vec<T>> {
         self.accessor_mut().entry::<T>()
     }
 
@@ -125,3 +133,52 @@ impl<O: Opt> OptValueExt for O {
         Ok(removed)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_value_error() {
+        assert!(test_value_error_impl().is_ok());
+    }
+
+    fn test_value_error_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--num=s")?.run()?;
+        set.add_opt("--name=s")?.run()?;
+        set.add_opt("--count=i")?.run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "--num", "abc"]),
+        )?;
+
+        let err = set.find_val::<i64>("--num").unwrap_err().to_string();
+
+        assert!(err.starts_with("option `--num` value `abc` is not a valid i64"));
+
+        let err = set.find_vals_mut::<u8>("--num").unwrap_err().to_string();
+
+        assert!(err.starts_with("option `--num` value `abc` is not a valid u8"));
+
+        let err = set.find_val::<i64>("--name").unwrap_err().to_string();
+
+        assert!(err.starts_with("option `--name` has no value of type i64"));
+
+        let err = set.find_val::<i64>("--count").unwrap_err().to_string();
+
+        assert!(err.starts_with("can not find value(ref) of `--count`"));
+        assert!(set.get_typed::<bool>("--num").is_err());
+        Ok(())
+    }
+}
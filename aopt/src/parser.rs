@@ -7,30 +7,57 @@ pub(crate) mod policy_fwd;
 pub(crate) mod policy_pre;
 pub(crate) mod returnval;
 pub(crate) mod style;
+pub(crate) mod warning;
 
+pub use self::checker::CheckPhase;
+pub use self::checker::CustomCheck;
 pub use self::checker::DefaultSetChecker;
 pub use self::commit::ParserCommit;
 pub use self::commit::ParserCommitWithValue;
 pub use self::failure::FailManager;
 pub use self::optset::HCOptSet;
+pub use self::optset::SetSnapshot;
 pub use self::policy_delay::DelayPolicy;
 pub use self::policy_fwd::FwdPolicy;
 pub use self::policy_pre::PrePolicy;
+pub use self::returnval::GuessTrace;
+pub use self::returnval::ParseStatus;
 pub use self::returnval::Return;
+pub use self::returnval::SECRET_MASK;
+pub use self::style::CustomStyleHandler;
+pub use self::style::GuessResult;
 pub use self::style::OptStyleManager;
 pub use self::style::UserStyle;
+pub use self::warning::Warning;
+pub use self::warning::Warnings;
 
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
+use crate::args::ArgInfo;
+use crate::args::ArgSource;
 use crate::args::Args;
+use crate::ctx::Ctx;
+use crate::ctx::HandlerCollection;
 use crate::ctx::InnerCtx;
 use crate::ext::APolicyExt;
+use crate::opt::config::alias_starts_with;
+use crate::opt::Index;
+use crate::opt::Opt;
+use crate::opt::Style;
+use crate::opt::BOOL_FALSE;
 use crate::set::OptValidator;
 use crate::set::PrefixedValidator;
 use crate::set::Set;
+use crate::set::SetExt;
+use crate::set::SetOpt;
+use crate::value::ErasedValue;
 use crate::Error;
+use crate::ResultExt;
 use crate::Uid;
 
 #[derive(Debug, Clone)]
@@ -102,6 +129,59 @@ pub enum Action {
     Null,
 }
 
+/// The decision for the unmatched option-like argument, see [`PolicySettings::set_unknown_handler`].
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnknownAction {
+    /// Keep the argument as positional argument, it is the default behavior.
+    #[default]
+    Keep,
+
+    /// Drop the argument.
+    Drop,
+
+    /// Return an error like the strict mode.
+    Error,
+}
+
+#[cfg(feature = "sync")]
+pub type UnknownHandler = std::sync::Arc<dyn Fn(&OsStr) -> UnknownAction + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type UnknownHandler = std::sync::Arc<dyn Fn(&OsStr) -> UnknownAction>;
+
+/// How to handle the option argument can be interpreted in more than one way,
+/// such as `-ab` is option `-a` with value `b` or the boolean options `-a` and `-b`.
+/// See [`PolicySettings::set_ambiguity`].
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AmbiguityMode {
+    /// The first matched style wins, and a [`Warning::AmbiguousOption`] is added.
+    #[default]
+    FirstWins,
+
+    /// Return an error report the interpretations.
+    Error,
+}
+
+impl AmbiguityMode {
+    pub fn is_first_wins(&self) -> bool {
+        matches!(self, Self::FirstWins)
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error)
+    }
+}
+
+/// Read the value of missing force required option, see [`PolicySettings::set_prompt_handler`].
+///
+/// The arguments are the [`prompt`](Opt::prompt) text and whether the option is [`secret`](Opt::secret),
+/// return `None` if the value can not be read, such as the stdin is not a terminal.
+#[cfg(feature = "sync")]
+pub type PromptHandler = std::sync::Arc<dyn Fn(&str, bool) -> Option<String> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type PromptHandler = std::sync::Arc<dyn Fn(&str, bool) -> Option<String>>;
+
 pub trait PolicySettings {
     fn style_manager(&self) -> &OptStyleManager;
 
@@ -113,15 +193,856 @@ pub trait PolicySettings {
 
     fn no_delay(&self) -> Option<&[String]>;
 
+    /// Return true if the option overload is enabled, see [`set_overload`](PolicySettings::set_overload).
     fn overload(&self) -> bool;
 
+    /// The maximum number of positional arguments, including the [`Cmd`](crate::opt::Cmd).
+    fn max_positionals(&self) -> Option<usize> {
+        None
+    }
+
     fn set_strict(&mut self, strict: bool) -> &mut Self;
 
     fn set_styles(&mut self, styles: Vec<UserStyle>) -> &mut Self;
 
+    /// Remove the `style` from the guess order, the order of other styles is kept.
+    fn disable_style(&mut self, style: UserStyle) -> &mut Self {
+        self.style_manager_mut().remove(style);
+        self
+    }
+
+    /// Append the `style` to the guess order if it is not enabled.
+    fn enable_style(&mut self, style: UserStyle) -> &mut Self {
+        self.style_manager_mut().push(style);
+        self
+    }
+
+    /// The character separating the option name and value, it is `=` in default.
+    fn assign_char(&self) -> char {
+        self.style_manager().assign_char()
+    }
+
+    /// How to handle the option argument can be interpreted in more than one way.
+    fn ambiguity(&self) -> AmbiguityMode {
+        self.style_manager().ambiguity()
+    }
+
+    /// Set how to handle the option argument can be interpreted in more than one way,
+    /// such as `-ab` is the option `-ab`, option `-a` with value `b` or the boolean options `-a` and `-b`.
+    fn set_ambiguity(&mut self, ambiguity: AmbiguityMode) -> &mut Self {
+        self.style_manager_mut().set_ambiguity(ambiguity);
+        self
+    }
+
+    /// Set the character separating the option name and value, such as `:` for `--opt:42`,
+    /// then the `=` is a normal character of argument.
+    fn set_assign_char(&mut self, assign: char) -> &mut Self {
+        self.style_manager_mut().set_assign_char(assign);
+        self
+    }
+
     fn set_no_delay(&mut self, name: impl Into<String>) -> &mut Self;
 
+    /// Enable the option overload, it is false in default.
+    ///
+    /// A repeated option can not be used more than once, such as the option of scalar value,
+    /// see [`multiple`](crate::opt::ConfigValue::multiple),
+    /// keeps the value of last occurrence instead of raising a failure,
+    /// and the option with [`App`](crate::opt::Action::App) still appends the values.
+    /// The argument also tries the options with same name, such as `-flag=i` and `-flag=s`.
     fn set_overload(&mut self, overload: bool) -> &mut Self;
+
+    /// Set the maximum number of positional arguments,
+    /// it is ignored if any [`Pos`](crate::opt::Pos) can absorb the rest arguments, such as `Pos@*`.
+    fn set_max_positionals(&mut self, max: Option<usize>) -> &mut Self;
+
+    /// Accept the unique prefix of [`Cmd`](crate::opt::Cmd) name, such as `che` for `checkout`.
+    fn allow_abbrev_subcommand(&self) -> bool {
+        false
+    }
+
+    /// Set if the unique prefix of [`Cmd`](crate::opt::Cmd) name can be used as the command,
+    /// the exact match is preferred, and a failure is returned if the prefix is ambiguous.
+    fn set_allow_abbrev_subcommand(&mut self, allow: bool) -> &mut Self;
+
+    /// Return true if the options can be set after positional arguments, it is true in default.
+    fn intermixed(&self) -> bool {
+        true
+    }
+
+    /// Set if the options can be intermixed with positional arguments.
+    ///
+    /// If it is false, the first positional argument stops the option parsing,
+    /// the rest arguments are passed to positional arguments as they are.
+    /// The [`Cmd`](crate::opt::Cmd) is a positional argument too,
+    /// so the arguments after a sub command are left to the sub command,
+    /// which parses them using its own setting.
+    fn set_intermixed(&mut self, intermixed: bool) -> &mut Self;
+
+    /// The marker terminating the option parsing, default is `--`.
+    fn option_terminator(&self) -> &str {
+        STOP
+    }
+
+    /// Set the marker terminating the option parsing, such as `END`,
+    /// the arguments after it are positional arguments.
+    ///
+    /// If it is `None`, the default marker `--` is used.
+    /// The marker matched by an option, such as [`Stop`](crate::value::Stop), is processed by the option.
+    fn set_option_terminator(&mut self, terminator: Option<String>) -> &mut Self;
+
+    /// Return true if the guess decisions are recorded, see [`Return::guess_trace`].
+    fn diagnostics(&self) -> bool {
+        false
+    }
+
+    /// Set if record the styles tried for each option argument and the style matched it,
+    /// it is independent of the `tracing` feature.
+    fn set_diagnostics(&mut self, diagnostics: bool) -> &mut Self;
+
+    /// Set the handler invoked for each unmatched option-like argument in non-strict mode,
+    /// the argument is kept, dropped or raise an error according to the [`UnknownAction`] returned.
+    fn set_unknown_handler(&mut self, handler: Option<UnknownHandler>) -> &mut Self;
+
+    /// Set the handler reading the value of missing force required option which has a [`prompt`](Opt::prompt),
+    /// the [`stdin_prompt`] is used if it is `None`.
+    fn set_prompt_handler(&mut self, handler: Option<PromptHandler>) -> &mut Self;
+
+    /// Return true if the validators of options are run in the opt check phase.
+    fn parallel_checks(&self) -> bool {
+        false
+    }
+
+    /// Set if defer the validators of options to the opt check phase, all the errors are collected.
+    ///
+    /// The validators run concurrently if the `parallel` feature enabled, otherwise they run sequentially.
+    /// Only the options matched by name are deferred, the validators of positional arguments, commands
+    /// and main are run when storing, they decide whether the argument is matched.
+    /// The deferred values are checked before invoking any other handler,
+    /// so the handlers never see an invalid value of other options.
+    fn set_parallel_checks(&mut self, parallel: bool) -> &mut Self;
+}
+
+/// Read a line from stdin as the value if it is a terminal, the `prompt` is printed to stderr.
+///
+/// The [`secret`](Opt::secret) value is never read because the input can not be hidden,
+/// use [`PolicySettings::set_prompt_handler`] read it without echo, such as using [`read_prompt`]
+/// with the echo of terminal toggled by a terminal library.
+#[cfg(feature = "std")]
+pub fn stdin_prompt(prompt: &str, secret: bool) -> Option<String> {
+    use std::io::IsTerminal;
+
+    let stdin = std::io::stdin();
+
+    if !stdin.is_terminal() {
+        return None;
+    }
+    read_prompt(
+        prompt,
+        secret,
+        &mut stdin.lock(),
+        &mut std::io::stderr(),
+        |_| false,
+    )
+}
+
+/// Print the `prompt` to `output` and read a line from `input` as the value.
+///
+/// The `echo(false)` turns the echo of `input` off before reading the secret value,
+/// and `echo(true)` restores the echo after reading, even if the reading failed.
+/// The secret value is not read if `echo(false)` returns false.
+#[cfg(feature = "std")]
+pub fn read_prompt(
+    prompt: &str,
+    secret: bool,
+    input: &mut impl std::io::BufRead,
+    output: &mut impl std::io::Write,
+    mut echo: impl FnMut(bool) -> bool,
+) -> Option<String> {
+    let mut line = String::default();
+
+    if secret && !echo(false) {
+        return None;
+    }
+    let ret = write!(output, "{prompt}")
+        .and_then(|_| output.flush())
+        .and_then(|_| input.read_line(&mut line));
+
+    if secret {
+        echo(true);
+        // the newline of input is not echoed
+        writeln!(output).ok();
+    }
+    ret.ok()?;
+    Some(line.trim_end_matches(['\r', '\n']).to_owned())
+}
+
+/// Read the value of missing force required options which have a [`prompt`](Opt::prompt) by `handler`,
+/// the option is not changed if the value can not be read, so that it is reported by the checker as before.
+pub(crate) fn prompt_missing<S>(set: &mut S, handler: Option<&PromptHandler>) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let uids: Vec<_> = set
+        .iter()
+        .filter(|opt| {
+            opt.prompt().is_some()
+                && !opt.valid()
+                && (opt.mat_style(Style::Argument)
+                    || opt.mat_style(Style::Boolean)
+                    || opt.mat_style(Style::Combined)
+                    || opt.mat_style(Style::Flag))
+        })
+        .map(|opt| opt.uid())
+        .collect();
+
+    for uid in uids {
+        let opt = set.get_mut(uid).unwrap();
+        let prompt = opt.prompt().unwrap_or_default();
+        let value = match handler {
+            Some(handler) => handler(prompt, opt.secret()),
+            #[cfg(feature = "std")]
+            None => stdin_prompt(prompt, opt.secret()),
+            #[cfg(not(feature = "std"))]
+            None => None,
+        };
+
+        if let Some(value) = value {
+            let act = *opt.action();
+            let ctx = Ctx::default().with_inner_ctx(
+                InnerCtx::default()
+                    .with_uid(uid)
+                    .with_name(Some(opt.name().to_owned().into()))
+                    .with_style(Style::Argument),
+            );
+
+            crate::trace!("read value of {} from prompt", opt.name());
+            opt.accessor_mut()
+                .store_all(Some(OsStr::new(&value)), &ctx, &act)
+                .option_context(opt.name())
+                .map_err(|e| match opt.secret() {
+                    true => secret_error(e, opt.name()),
+                    false => e,
+                })?;
+            opt.set_matched(true);
+        }
+    }
+    Ok(())
+}
+
+/// Return the `args` with the value of [`secret`](Opt::secret) options replaced by [`SECRET_MASK`],
+/// both `--name value` and `--name=value` are masked.
+pub(crate) fn mask_secret_args<'a, S>(set: &S, args: &[&'a OsStr]) -> Vec<Cow<'a, OsStr>>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let secret = |name: &str| {
+        set.iter()
+            .any(|opt| opt.secret() && (opt.mat_name(Some(name)) || opt.mat_alias(name)))
+    };
+    let mut ret = Vec::with_capacity(args.len());
+    let mut mask_next = false;
+
+    for arg in args {
+        let name = arg.to_str().unwrap_or_default();
+
+        if std::mem::take(&mut mask_next) {
+            ret.push(Cow::Borrowed(OsStr::new(SECRET_MASK)));
+        } else if let Some((name, _)) = name.split_once('=').filter(|(name, _)| secret(name)) {
+            ret.push(Cow::Owned(OsString::from(format!("{name}={SECRET_MASK}"))));
+        } else {
+            mask_next = secret(name);
+            ret.push(Cow::Borrowed(*arg));
+        }
+    }
+    ret
+}
+
+/// Replace the failure of [`secret`](Opt::secret) option `name`, the description and causes may contain the value.
+pub(crate) fn secret_error(err: Error, name: &str) -> Error {
+    let desp = format!("invalid value `{SECRET_MASK}` of option `{name}`");
+    let mut masked = Error::new(*err.kind()).with_desp(desp.clone());
+
+    // keep the kind of root cause
+    if err.specific_kind() != *err.kind() {
+        masked = masked.cause_by(Error::new(err.specific_kind()).with_desp(desp));
+    }
+    match err.uid() {
+        Some(uid) => masked.with_uid(uid),
+        None => masked,
+    }
+}
+
+/// Create the [`GuessTrace`] of the option argument, the last style tried is the winner if matched.
+pub(crate) fn guess_trace(arg: &OsStr, styles: Vec<UserStyle>, matched: bool) -> GuessTrace {
+    let winner = styles.last().filter(|_| matched).cloned();
+
+    GuessTrace {
+        token: arg.to_string_lossy().to_string(),
+        styles,
+        winner,
+    }
+}
+
+/// Return a failure if the number of positional arguments exceeds `max`,
+/// the limit is skipped if any [`Pos`](crate::opt::Pos) can absorb the rest arguments.
+pub(crate) fn check_max_positionals<S>(
+    set: &S,
+    max: Option<usize>,
+    total: usize,
+) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    if let Some(max) = max {
+        // the first argument is the program name
+        let count = total.saturating_sub(1);
+        let catch_all = set.iter().any(|opt| {
+            opt.mat_style(Style::Pos)
+                && matches!(
+                    opt.index(),
+                    Some(
+                        Index::AnyWhere
+                            | Index::Except(_)
+                            | Index::Range(_, None)
+                            | Index::Step(_, _)
+                    )
+                )
+        });
+
+        if !catch_all && count > max {
+            return Err(crate::raise_failure!(
+                "too many arguments, expected at most {max}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Return a failure if any option [`conflicts with positional`](Opt::conflicts_with_positional)
+/// is set while there are positional arguments.
+pub(crate) fn check_conflicts_with_positional<S>(set: &S, total: usize) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    // the first argument is the program name
+    if total > 1 {
+        if let Some(opt) = set
+            .iter()
+            .find(|opt| opt.conflicts_with_positional() && opt.matched())
+        {
+            return Err(crate::raise_failure!(
+                "option `{}` can not be used with positional arguments",
+                opt.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Return a failure if the count of values stored by any matched option
+/// is out of its [`value count`](Opt::value_count) range.
+pub(crate) fn check_value_count<S>(set: &S) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    for opt in set.iter().filter(|opt| opt.matched()) {
+        if let Some(range) = opt.value_count() {
+            let count = opt.accessor().rawvals().map(Vec::len).unwrap_or_default();
+
+            if !range.contains(&count) {
+                let expect = if range.start() == range.end() {
+                    format!("{}", range.start())
+                } else {
+                    format!("{} to {}", range.start(), range.end())
+                };
+
+                return Err(crate::raise_failure!(
+                    "option `{}` expected {} values, but found {}",
+                    opt.name(),
+                    expect,
+                    count
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check the values of options `uids` by their validators, which are deferred by
+/// [`set_parallel_checks`](PolicySettings::set_parallel_checks).
+/// Return a failure of the first invalid option, the failures of others are chained under it.
+pub(crate) fn check_values<S>(set: &S, uids: &[Uid]) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let opts = uids
+        .iter()
+        .map(|uid| set.opt(*uid))
+        .collect::<Result<Vec<_>, Error>>()?;
+    let accessors: Vec<_> = opts.iter().map(|opt| opt.accessor()).collect();
+    let mut error: Option<Error> = None;
+
+    for (opt, ret) in opts.iter().zip(check_accessors(&accessors)).rev() {
+        if let Err(msg) = ret {
+            let fail = Error::sp_invalid_value(None, format!("option `{}`", opt.name()), msg)
+                .with_uid(opt.uid());
+
+            error = Some(match error {
+                Some(error) => fail.cause_by(error),
+                None => fail,
+            });
+        }
+    }
+    error.map_or(Ok(()), Err)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn check_accessors(accessors: &[&crate::value::ValAccessor]) -> Vec<Result<(), String>> {
+    accessors.iter().map(|accessor| accessor.check()).collect()
+}
+
+#[cfg(feature = "parallel")]
+fn check_accessors(accessors: &[&crate::value::ValAccessor]) -> Vec<Result<(), String>> {
+    let threads = std::thread::available_parallelism().map_or(1, |v| v.get());
+    let size = accessors.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|s| {
+        let handles: Vec<_> = accessors
+            .chunks(size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|accessor| accessor.check())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    })
+}
+
+/// Return true if the validator of option matched with `style` is deferred to the opt check phase.
+pub(crate) fn deferred_style(ctx: &Ctx, style: Style) -> bool {
+    ctx.deferred_check()
+        && matches!(
+            style,
+            Style::Argument | Style::Boolean | Style::Combined | Style::Flag
+        )
+}
+
+/// Check the deferred values before invoking the handler of `uid` in deferred mode,
+/// then the handler never sees the invalid value of other options.
+/// The options have no handler are stored by fallback, they are checked in batch later.
+pub(crate) fn check_before_invoke<'a, S, Ser, Inv>(
+    set: &S,
+    inv: &mut Inv,
+    ctx: &mut Ctx,
+    uid: Uid,
+) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+    Inv: HandlerCollection<'a, S, Ser>,
+{
+    if ctx.deferred_check()
+        && !ctx.pending_check.is_empty()
+        && inv.get_ctx_handler(&uid, ctx).is_some()
+    {
+        check_values(set, &ctx.take_pending_check())?;
+    }
+    Ok(())
+}
+
+/// Return a failure if any [`standalone`](Opt::standalone) option is set together with
+/// other options or positional arguments.
+pub(crate) fn check_standalone<S>(set: &S, total: usize) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    if let Some(opt) = set.iter().find(|opt| opt.standalone() && opt.matched()) {
+        let other = set.iter().any(|v| {
+            v.uid() != opt.uid()
+                && v.matched()
+                && !v.mat_style(Style::Pos)
+                && !v.mat_style(Style::Cmd)
+                && !v.mat_style(Style::Main)
+        });
+
+        // the first argument is the program name
+        if other || total > 1 {
+            return Err(crate::raise_failure!(
+                "option `{}` can not be used with other arguments",
+                opt.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Find the [`Cmd`](crate::opt::Cmd) which collect the arguments after it verbatim.
+pub(crate) fn trailing_raw_cmd<S>(set: &S, arg: &OsStr) -> Option<Uid>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let name = arg.to_str()?;
+
+    set.iter()
+        .find(|opt| {
+            opt.mat_style(Style::Cmd)
+                && opt.trailing_raw()
+                && (opt.mat_name(Some(name)) || opt.mat_alias(name))
+        })
+        .map(|opt| opt.uid())
+}
+
+/// Find the [`Pos`](crate::opt::Pos) which collect the arguments after the option terminator.
+pub(crate) fn trailing_raw_pos<S>(set: &S) -> Option<Uid>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    set.iter()
+        .find(|opt| opt.mat_style(Style::Pos) && opt.trailing_raw())
+        .map(|opt| opt.uid())
+}
+
+/// The maximum number of argument expansions in one parsing, see [`expand_args`](Ctx::expand_args).
+pub const EXPANSION_LIMIT: usize = 64;
+
+/// Splice the `expansion` into the arguments after the `lefts`, the `rest` arguments not parsed are appended.
+pub(crate) fn splice_args<'a>(
+    lefts: &[&'a OsStr],
+    expansion: Vec<OsString>,
+    rest: impl Iterator<Item = &'a OsStr>,
+) -> Args {
+    let lefts = lefts.iter().map(|v| v.to_os_string());
+
+    Args::from(lefts.chain(expansion).chain(rest.map(OsStr::to_os_string)))
+}
+
+/// Return an error if the arguments expanded too many times, the expansion may not terminate.
+pub(crate) fn check_expansion(count: usize) -> Result<(), Error> {
+    if count > EXPANSION_LIMIT {
+        Err(Error::raise_error(format!(
+            "too many argument expansions, the limit is {}",
+            EXPANSION_LIMIT
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Return an error if the arguments expanded by a handler can not be parsed, such as
+/// the expansion in the handler of NOA or the handler of option delayed by [`DelayPolicy`].
+pub(crate) fn check_unexpanded(ctx: &Ctx) -> Result<(), Error> {
+    if ctx.take_expansion().is_some() {
+        Err(Error::raise_error(
+            "the arguments can only be expanded in the handler of option matched by FwdPolicy or PrePolicy",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse the arguments into the values of [`Pos`](crate::opt::Pos) `uid`, the handlers are not invoked.
+pub(crate) fn store_trailing_pos<'a, S>(
+    set: &mut S,
+    uid: Uid,
+    args: impl Iterator<Item = &'a OsStr>,
+) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let args: Vec<_> = args.collect();
+    let total = args.len();
+
+    if let Some(opt) = set.get_mut(uid) {
+        let name = opt.name().to_owned();
+        let act = *opt.action();
+
+        for (idx, arg) in args.into_iter().enumerate() {
+            let ctx = Ctx::default().with_inner_ctx(
+                InnerCtx::default()
+                    .with_uid(uid)
+                    .with_idx(idx)
+                    .with_total(total)
+                    .with_name(Some(Cow::Borrowed(name.as_str())))
+                    .with_style(Style::Pos)
+                    .with_arg(Some(Cow::Borrowed(arg))),
+            );
+
+            opt.accessor_mut()
+                .store_all(Some(arg), &ctx, &act)
+                .option_context(&name)?;
+        }
+        opt.set_matched(true);
+    }
+    Ok(())
+}
+
+/// Expand the abbreviation `name` to the full name of [`Cmd`](crate::opt::Cmd),
+/// return a failure if more than one [`Cmd`](crate::opt::Cmd) starts with `name`.
+pub(crate) fn expand_abbrev_cmd<'a, S>(
+    set: &S,
+    name: Option<Cow<'a, str>>,
+) -> Result<Option<Cow<'a, str>>, Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let Some(abbrev) = name.as_deref() else {
+        return Ok(name);
+    };
+    let mut cmds = set.iter().filter(|opt| opt.mat_style(Style::Cmd));
+
+    if cmds
+        .clone()
+        .any(|opt| opt.mat_name(Some(abbrev)) || opt.mat_alias(abbrev))
+    {
+        return Ok(name);
+    }
+
+    let mut candidates: Vec<&str> = vec![];
+
+    for opt in cmds.by_ref() {
+        let mut names = std::iter::once(opt.name())
+            .chain(opt.alias().into_iter().flatten().map(|v| v.as_str()));
+
+        if names.any(|v| alias_starts_with(v, abbrev, opt.ignore_case())) {
+            candidates.push(opt.name());
+        }
+    }
+    match candidates.len() {
+        0 => Ok(name),
+        1 => Ok(Some(Cow::Owned(candidates[0].to_owned()))),
+        _ => Err(crate::raise_failure!(
+            "ambiguous sub command `{abbrev}`, candidates: {}",
+            candidates.join(", ")
+        )),
+    }
+}
+
+/// The default marker terminating the option parsing.
+pub(crate) const STOP: &str = "--";
+
+/// Save the arguments as [`OsString`] values of command `uid`, the leading `terminator` is skipped.
+pub(crate) fn store_trailing_raw<'a, S>(
+    set: &mut S,
+    uid: Uid,
+    terminator: &str,
+    args: impl Iterator<Item = &'a OsStr>,
+) where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let mut args = args.peekable();
+
+    if args.peek() == Some(&OsStr::new(terminator)) {
+        args.next();
+    }
+    if let Some(opt) = set.get_mut(uid) {
+        let (_, value) = opt.accessor_mut().handlers();
+
+        value.set(args.map(|v| v.to_os_string()).collect::<Vec<OsString>>());
+    }
+}
+
+/// Add a [`Warning`] to `ctx` if `name` is a deprecated alias of any option in `set`.
+pub(crate) fn check_deprecated_alias<S>(set: &S, ctx: &mut Ctx<'_>, name: &str)
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    if let Some(opt) = set
+        .iter()
+        .find(|opt| opt.mat_deprecated_alias(name).is_some())
+    {
+        ctx.add_warning(Warning::DeprecatedAlias {
+            alias: name.to_owned(),
+            name: opt.name().to_owned(),
+            message: opt
+                .mat_deprecated_alias(name)
+                .unwrap_or_default()
+                .to_owned(),
+        });
+    }
+}
+
+/// Check if the option argument `name` without value can be interpreted in more than one way
+/// by the styles enabled, add a warning or return an error according to the [`AmbiguityMode`].
+pub(crate) fn check_ambiguity<S>(
+    set: &S,
+    ctx: &mut Ctx<'_>,
+    styles: &OptStyleManager,
+    prefix: &str,
+    name: &str,
+) -> Result<(), Error>
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    let find = |name: &str, style: Style| {
+        set.iter()
+            .any(|opt| (opt.mat_name(Some(name)) || opt.mat_alias(name)) && opt.mat_style(style))
+    };
+    let enabled =
+        |style: UserStyle| styles.contains(&style) && styles.is_enabled_for(&style, prefix);
+    let chars: Vec<_> = name[prefix.len()..].chars().collect();
+    let mut candidates = vec![];
+
+    if chars.len() < 2 {
+        return Ok(());
+    }
+    if [
+        Style::Argument,
+        Style::Boolean,
+        Style::Combined,
+        Style::Flag,
+    ]
+    .into_iter()
+    .any(|style| find(name, style))
+    {
+        candidates.push(format!("option `{name}`"));
+    }
+    if enabled(UserStyle::EmbeddedValue) {
+        let (opt, value) = name.split_at(prefix.len() + chars[0].len_utf8());
+
+        if find(opt, Style::Argument) {
+            candidates.push(format!("option `{opt}` with value `{value}`"));
+        }
+    }
+    if enabled(UserStyle::CombinedOption) {
+        let opts: Vec<_> = chars.iter().map(|ch| format!("`{prefix}{ch}`")).collect();
+
+        if opts
+            .iter()
+            .all(|opt| find(opt.trim_matches('`'), Style::Boolean))
+        {
+            candidates.push(format!("options {}", opts.join(", ")));
+        }
+    }
+    if candidates.len() > 1 {
+        if styles.ambiguity().is_error() {
+            return Err(Error::arg(
+                name,
+                format!("it is ambiguous, can be {}", candidates.join(" or ")),
+            ));
+        }
+        ctx.add_warning(Warning::AmbiguousOption {
+            name: name.to_owned(),
+            candidates,
+        });
+    }
+    Ok(())
+}
+
+/// Return true if any [`greedy`](Opt::greedy) option matches `name`.
+pub(crate) fn greedy_opt<S>(set: &S, name: &str) -> bool
+where
+    S: Set,
+    SetOpt<S>: Opt,
+{
+    set.iter()
+        .any(|opt| opt.greedy() && (opt.mat_name(Some(name)) || opt.mat_alias(name)))
+}
+
+/// Map the toggled name, such as `+x` of option `-x` with [`toggle prefix`](Opt::toggle_prefix) `+`,
+/// to the name of option and the value `false`.
+pub(crate) fn toggle_arg<'a, S>(set: &S, info: ArgInfo<'a>) -> ArgInfo<'a>
+where
+    S: Set + OptValidator,
+    SetOpt<S>: Opt,
+{
+    if info.value.is_some() {
+        return info;
+    }
+    if let Ok((prefix, rest)) = set.split(&info.name) {
+        let same = |name: &str| {
+            set.split(&Cow::Borrowed(name))
+                .is_ok_and(|(prefix_, rest_)| prefix_ != prefix && rest_ == rest)
+        };
+
+        if let Some(opt) = set.iter().find(|opt| {
+            opt.toggle_prefix() == Some(&prefix)
+                && (same(opt.name()) || opt.alias().is_some_and(|v| v.iter().any(|v| same(v))))
+        }) {
+            return ArgInfo {
+                name: Cow::Owned(opt.name().to_owned()),
+                value: Some(Cow::Borrowed(OsStr::new(BOOL_FALSE))),
+            };
+        }
+    }
+    info
+}
+
+/// Raise an error if `arg` has no option name before `assign`, such as `=5`.
+pub(crate) fn check_option_name(arg: &OsStr, assign: char) -> Result<(), Error> {
+    match crate::str::split_once(arg, assign) {
+        Some((name, _)) if name.to_str().is_some_and(|v| v.trim().is_empty()) => Err(Error::arg(
+            std::path::Path::new(arg).display().to_string(),
+            format!("expected an option name before `{assign}`"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Return true if `name` is a bare prefix, such as `-`, and no option matches it.
+/// It is an operand by convention, such as the stdin marker.
+pub(crate) fn bare_prefix<S>(set: &S, name: &str) -> bool
+where
+    S: Set + OptValidator,
+    SetOpt<S>: Opt,
+{
+    set.split(&Cow::Borrowed(name))
+        .is_ok_and(|(_, rest)| rest.is_empty())
+        && !set
+            .iter()
+            .any(|opt| opt.mat_name(Some(name)) || opt.mat_alias(name))
+}
+
+/// Take `arg` as the value of greedy option `name`,
+/// return `None` if `arg` is the `terminator` or looks like an option.
+pub(crate) fn greedy_value<'a, S>(
+    set: &mut S,
+    styles: &OptStyleManager,
+    terminator: &str,
+    name: Cow<'a, str>,
+    arg: &'a OsStr,
+) -> Option<ArgInfo<'a>>
+where
+    S: OptValidator,
+{
+    if arg == OsStr::new(terminator) {
+        return None;
+    }
+    if let Ok(ArgInfo { name, .. }) = styles.parse_arg(arg) {
+        if set.check(&name).unwrap_or_default() {
+            return None;
+        }
+    }
+    Some(ArgInfo {
+        name,
+        value: Some(Cow::Borrowed(arg)),
+    })
 }
 
 pub trait PolicyParser<P>
@@ -130,6 +1051,7 @@ where
 {
     type Error: Into<Error>;
 
+    #[cfg(feature = "std")]
     fn parse_env(&mut self) -> Result<P::Ret, Self::Error>
     where
         P: Default,
@@ -145,6 +1067,7 @@ where
         self.parse_policy(args, &mut policy)
     }
 
+    #[cfg(feature = "std")]
     fn parse_env_policy(&mut self, policy: &mut P) -> Result<P::Ret, Self::Error> {
         let args = Args::from_env();
         self.parse_policy(args, policy)
@@ -294,7 +1217,7 @@ where
         Ok(self)
     }
 
-    /// Call the [`init`](crate::opt::Opt::init) of [`Opt`](crate::opt::Opt) initialize the option value.
+    /// Call the [`init`](crate::opt::Opt::init) of [`Opt`] initialize the option value.
     pub fn init(&mut self) -> Result<(), Error> {
         self.optset.init()
     }
@@ -302,6 +1225,110 @@ where
     pub fn parse(&mut self, args: Args) -> Result<<P as Policy>::Ret, Error> {
         PolicyParser::<P>::parse_policy(&mut self.optset, args, &mut self.policy)
     }
+
+    /// Parse the arguments produced by `src`, such as [`EnvArgs`](crate::args::EnvArgs) or a command line string.
+    pub fn parse_source(&mut self, src: impl ArgSource) -> Result<<P as Policy>::Ret, Error> {
+        self.parse(src.into_args()?)
+    }
+}
+
+impl<P> Parser<'_, P>
+where
+    P::Set: Set + OptValidator,
+    P: Policy,
+{
+    /// Parse the global options, stop at the first positional argument if it is not a known [`Cmd`](crate::opt::Cmd).
+    ///
+    /// Return the result of parsing and the raw arguments starting from the unknown sub command,
+    /// which can be dispatched to an external program, such as `app foo ...` to `app-foo ...`.
+    /// The arguments are parsed as [`parse`](Parser::parse) if no unknown sub command found,
+    /// and the returned arguments are empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = Parser::new_policy(AFwdPolicy::default());
+    ///
+    /// parser.add_opt("--verbose=b")?;
+    /// parser.add_opt("--dir=s")?;
+    /// parser.add_opt("build=c")?;
+    ///
+    /// let (ret, rest) = parser.parse_partial(Args::from(["app", "--dir", "src", "foo", "--bar"]))?;
+    ///
+    /// assert!(ret.status());
+    /// assert_eq!(parser.find_val::<String>("--dir")?, "src");
+    /// assert_eq!(rest.as_slice(), ["foo", "--bar"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_partial(&mut self, args: Args) -> Result<(<P as Policy>::Ret, Args), Error>
+    where
+        P: PolicySettings,
+    {
+        let Some(idx) = self.unknown_cmd_index(&args)? else {
+            return Ok((self.parse(args)?, Args::default()));
+        };
+        // the sub command is dispatched to others, don't require the Cmd when checking
+        let cmds: Vec<Uid> = self
+            .iter()
+            .filter(|opt| opt.mat_style(Style::Cmd) && !opt.matched())
+            .map(|opt| opt.uid())
+            .collect();
+
+        for opt in self.iter_mut().filter(|opt| cmds.contains(&opt.uid())) {
+            opt.set_matched(true);
+        }
+
+        let ret = self.parse(Args::from(args[..idx].iter().cloned()));
+
+        for opt in self.iter_mut().filter(|opt| cmds.contains(&opt.uid())) {
+            opt.set_matched(false);
+        }
+        Ok((ret?, Args::from(args[idx..].iter().cloned())))
+    }
+
+    /// Find the first positional argument that is not a known [`Cmd`](crate::opt::Cmd),
+    /// skip the values of options.
+    fn unknown_cmd_index(&mut self, args: &Args) -> Result<Option<usize>, Error>
+    where
+        P: PolicySettings,
+    {
+        let mut iter = args.iter().enumerate().skip(1);
+
+        while let Some((idx, arg)) = iter.next() {
+            if arg == self.option_terminator() {
+                return Ok(None);
+            }
+            if let Ok(ArgInfo { name, value }) = self.style_manager().parse_arg(arg) {
+                if self.check(&name)? {
+                    // the option with optional value never consumes the next argument
+                    let takes_value = self
+                        .iter()
+                        .find(|opt| opt.mat_name(Some(&name)) || opt.mat_alias(&name))
+                        .is_some_and(|opt| {
+                            opt.mat_style(Style::Argument) && opt.optional_value().is_none()
+                        });
+
+                    if takes_value && value.is_none() {
+                        iter.next();
+                    }
+                    continue;
+                }
+            }
+            let known = arg.to_str().is_some_and(|name| {
+                self.iter().any(|opt| {
+                    opt.mat_style(Style::Cmd) && (opt.mat_name(Some(name)) || opt.mat_alias(name))
+                })
+            });
+
+            return Ok((!known).then_some(idx));
+        }
+        Ok(None)
+    }
 }
 
 impl<P> PolicySettings for Parser<'_, P>
@@ -332,6 +1359,10 @@ where
         self.policy().overload()
     }
 
+    fn max_positionals(&self) -> Option<usize> {
+        self.policy().max_positionals()
+    }
+
     fn set_strict(&mut self, strict: bool) -> &mut Self {
         self.policy_mut().set_strict(strict);
         self
@@ -351,6 +1382,66 @@ where
         self.policy_mut().set_overload(overload);
         self
     }
+
+    fn set_max_positionals(&mut self, max: Option<usize>) -> &mut Self {
+        self.policy_mut().set_max_positionals(max);
+        self
+    }
+
+    fn allow_abbrev_subcommand(&self) -> bool {
+        self.policy().allow_abbrev_subcommand()
+    }
+
+    fn set_allow_abbrev_subcommand(&mut self, allow: bool) -> &mut Self {
+        self.policy_mut().set_allow_abbrev_subcommand(allow);
+        self
+    }
+
+    fn intermixed(&self) -> bool {
+        self.policy().intermixed()
+    }
+
+    fn set_intermixed(&mut self, intermixed: bool) -> &mut Self {
+        self.policy_mut().set_intermixed(intermixed);
+        self
+    }
+
+    fn option_terminator(&self) -> &str {
+        self.policy().option_terminator()
+    }
+
+    fn set_option_terminator(&mut self, terminator: Option<String>) -> &mut Self {
+        self.policy_mut().set_option_terminator(terminator);
+        self
+    }
+
+    fn diagnostics(&self) -> bool {
+        self.policy().diagnostics()
+    }
+
+    fn set_diagnostics(&mut self, diagnostics: bool) -> &mut Self {
+        self.policy_mut().set_diagnostics(diagnostics);
+        self
+    }
+
+    fn set_unknown_handler(&mut self, handler: Option<UnknownHandler>) -> &mut Self {
+        self.policy_mut().set_unknown_handler(handler);
+        self
+    }
+
+    fn set_prompt_handler(&mut self, handler: Option<PromptHandler>) -> &mut Self {
+        self.policy_mut().set_prompt_handler(handler);
+        self
+    }
+
+    fn parallel_checks(&self) -> bool {
+        self.policy().parallel_checks()
+    }
+
+    fn set_parallel_checks(&mut self, parallel: bool) -> &mut Self {
+        self.policy_mut().set_parallel_checks(parallel);
+        self
+    }
 }
 
 impl<P> OptValidator for Parser<'_, P>
@@ -386,6 +1477,10 @@ where
     fn unreg_prefix(&mut self, val: &str) -> Result<(), Self::Error> {
         PrefixedValidator::unreg_prefix(&mut self.optset, val).map_err(Into::into)
     }
+
+    fn prefixes(&self) -> &[String] {
+        PrefixedValidator::prefixes(&self.optset)
+    }
 }
 
 impl<P> Parser<'_, P>
@@ -395,8 +1490,12 @@ where
     /// Enable [`CombinedOption`](UserStyle::CombinedOption) option set style.
     /// This can support option style like `-abc` which set `-a`, `-b` and `-c` both.
     pub fn enable_combined(&mut self) -> &mut Self {
-        self.style_manager_mut().push(UserStyle::CombinedOption);
-        self
+        self.enable_style(UserStyle::CombinedOption)
+    }
+
+    /// Disable [`CombinedOption`](UserStyle::CombinedOption) option set style.
+    pub fn disable_combined(&mut self) -> &mut Self {
+        self.disable_style(UserStyle::CombinedOption)
     }
 
     /// Enable [`EmbeddedValuePlus`](UserStyle::EmbeddedValuePlus) option set style.
@@ -404,15 +1503,23 @@ where
     /// In default the [`EmbeddedValue`](UserStyle::EmbeddedValue) style only support
     /// one letter option such as `-i`.
     pub fn enable_embedded_plus(&mut self) -> &mut Self {
-        self.style_manager_mut().push(UserStyle::EmbeddedValuePlus);
-        self
+        self.enable_style(UserStyle::EmbeddedValuePlus)
+    }
+
+    /// Disable [`EmbeddedValuePlus`](UserStyle::EmbeddedValuePlus) option set style.
+    pub fn disable_embedded_plus(&mut self) -> &mut Self {
+        self.disable_style(UserStyle::EmbeddedValuePlus)
     }
 
     /// Enable [`Flag`](UserStyle::Flag) option set style.
     /// It will support set style like `--flag`, but the value will be set to None.
     pub fn enable_flag(&mut self) -> &mut Self {
-        self.style_manager_mut().push(UserStyle::Flag);
-        self
+        self.enable_style(UserStyle::Flag)
+    }
+
+    /// Disable [`Flag`](UserStyle::Flag) option set style.
+    pub fn disable_flag(&mut self) -> &mut Self {
+        self.disable_style(UserStyle::Flag)
     }
 }
 
@@ -437,3 +1544,140 @@ where
         PolicyParser::<P>::parse_policy(&mut self.optset, args, policy)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_parse_partial() {
+        assert!(test_parse_partial_impl().is_ok());
+    }
+
+    fn test_parse_partial_impl() -> Result<(), Error> {
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("-v;--verbose=b")?;
+        parser.add_opt("-C;--dir=s")?;
+        parser.add_opt("build=c")?;
+        parser.add_opt("target=p@2")?;
+
+        let args = ["app", "-v", "-C", "build", "foo", "--bar", "build", "-C=x"];
+        let (ret, rest) = parser.parse_partial(Args::from(args))?;
+
+        assert!(ret.status());
+        assert!(*parser.find_val::<bool>("--verbose")?);
+        assert_eq!(parser.find_val::<String>("--dir")?, "build");
+        assert!(!parser.find("build")?.matched());
+        assert_eq!(rest.as_slice(), ["foo", "--bar", "build", "-C=x"]);
+
+        parser.reset()?;
+
+        let args = ["app", "--dir=src", "build", "release"];
+        let (ret, rest) = parser.parse_partial(Args::from(args))?;
+
+        assert!(ret.status());
+        assert!(rest.is_empty());
+        assert_eq!(parser.find_val::<String>("--dir")?, "src");
+        assert!(parser.find("target")?.matched());
+
+        parser.reset()?;
+
+        let (ret, rest) = parser.parse_partial(Args::from(["app", "--", "foo"]))?;
+
+        assert!(!ret.status());
+        assert!(rest.is_empty());
+
+        let mut parser = Parser::new_policy(
+            AFwdPolicy::default().with_option_terminator(Some(String::from("END"))),
+        );
+
+        parser.add_opt("--verbose=b")?;
+        parser
+            .add_opt("--color=s")?
+            .set_optional_value("auto")
+            .run()?;
+        parser.add_opt("rest=p@1..")?;
+
+        let (ret, rest) = parser.parse_partial(Args::from(["app", "END", "--verbose"]))?;
+
+        assert!(ret.status());
+        assert!(rest.is_empty());
+
+        parser.reset()?;
+
+        let (_, rest) = parser.parse_partial(Args::from(["app", "--color", "foo", "x"]))?;
+
+        assert_eq!(parser.find_val::<String>("--color")?, "auto");
+        assert_eq!(rest.as_slice(), ["foo", "x"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_option_name() {
+        assert!(test_option_name_impl().is_ok());
+    }
+
+    fn test_option_name_impl() -> Result<(), Error> {
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("--n=i")?;
+        parser.add_opt("value=p@1")?;
+
+        let err = parser.parse(Args::from(["app", "=5"])).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid argument `=5`: expected an option name before `=`"
+        );
+
+        let mut parser = Parser::new_policy(ADelayPolicy::default());
+
+        parser.add_opt("--n=s")?;
+        assert!(parser.parse(Args::from(["app", "=5"])).is_err());
+        // the argument is the value of `--n`
+        assert!(parser.parse(Args::from(["app", "--n", "=5"]))?.status());
+        assert_eq!(parser.find_val::<String>("--n")?, "=5");
+
+        // not strict, the argument is a positional argument
+        let mut parser = Parser::new_policy(AFwdPolicy::default().with_strict(false));
+
+        parser.add_opt("value=p@1")?;
+        assert!(parser.parse(Args::from(["app", "=5"]))?.status());
+        assert!(parser.find("value")?.matched());
+        Ok(())
+    }
+
+    #[test]
+    fn test_enable_style() {
+        assert!(test_enable_style_impl().is_ok());
+    }
+
+    fn test_enable_style_impl() -> Result<(), Error> {
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("-a=b")?;
+        parser.add_opt("-b=b")?;
+
+        assert!(!parser.parse(Args::from(["app", "-ab"]))?.status());
+
+        parser.enable_combined().enable_combined();
+        assert_eq!(
+            parser
+                .styles()
+                .iter()
+                .filter(|v| v == &&UserStyle::CombinedOption)
+                .count(),
+            1
+        );
+        assert!(parser.parse(Args::from(["app", "-ab"]))?.status());
+        assert!(*parser.find_val::<bool>("-a")?);
+        assert!(*parser.find_val::<bool>("-b")?);
+
+        parser.disable_combined();
+        assert!(!parser.styles().contains(&UserStyle::CombinedOption));
+        assert!(!parser.parse(Args::from(["app", "-ab"]))?.status());
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use std::fmt::Debug;
-use std::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::opt::Index;
 use crate::opt::Opt;
@@ -11,25 +11,66 @@ use crate::Error;
 use crate::HashMap;
 use crate::Uid;
 
+/// The phase of [`SetChecker`] in which the custom check is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckPhase {
+    /// Run after [`pre_check`](SetChecker::pre_check).
+    Pre,
+
+    /// Run after [`opt_check`](SetChecker::opt_check).
+    Opt,
+
+    /// Run after [`pos_check`](SetChecker::pos_check).
+    Pos,
+
+    /// Run after [`cmd_check`](SetChecker::cmd_check).
+    Cmd,
+
+    /// Run after [`post_check`](SetChecker::post_check).
+    Post,
+}
+
+#[cfg(feature = "sync")]
+pub type CustomCheck<S> = Box<dyn Fn(&S) -> Result<(), Error> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type CustomCheck<S> = Box<dyn Fn(&S) -> Result<(), Error>>;
+
 /// Check the option base on [`Style`].
 /// The checker will used for option check of [`Policy`](crate::parser::Policy).
-pub struct DefaultSetChecker<S>(PhantomData<S>);
+///
+/// The custom checks added by [`add_check`](DefaultSetChecker::add_check) are run
+/// in order after the default check of the phase.
+pub struct DefaultSetChecker<S> {
+    checks: Vec<(CheckPhase, Arc<CustomCheck<S>>)>,
+}
 
 impl<S> Clone for DefaultSetChecker<S> {
     fn clone(&self) -> Self {
-        Self(self.0)
+        Self {
+            checks: self.checks.clone(),
+        }
     }
 }
 
 impl<S> Debug for DefaultSetChecker<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("DefaultSetChecker").finish()
+        f.debug_struct("DefaultSetChecker")
+            .field(
+                "checks",
+                &self
+                    .checks
+                    .iter()
+                    .map(|(phase, _)| phase)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
     }
 }
 
 impl<S> Default for DefaultSetChecker<S> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self { checks: vec![] }
     }
 }
 
@@ -39,7 +80,7 @@ where
     SetOpt<S>: Opt,
 {
     pub fn new() -> Self {
-        Self(PhantomData)
+        Self::default()
     }
 
     pub fn clear(&mut self) {}
@@ -47,6 +88,32 @@ where
     pub fn opt<'a>(set: &'a S, id: &Uid) -> &'a SetOpt<S> {
         set.get(*id).unwrap()
     }
+
+    /// Add a custom check run in the `phase`, the error returned is raised as the error of the phase.
+    ///
+    /// Return a [`failure`](Error::raise_failure) if the check should not stop the parsing.
+    pub fn add_check(&mut self, phase: CheckPhase, check: CustomCheck<S>) -> &mut Self {
+        self.checks.push((phase, Arc::new(check)));
+        self
+    }
+
+    pub fn with_check(mut self, phase: CheckPhase, check: CustomCheck<S>) -> Self {
+        self.add_check(phase, check);
+        self
+    }
+
+    /// Remove all the custom checks.
+    pub fn clear_checks(&mut self) {
+        self.checks.clear();
+    }
+
+    fn run_checks(&self, phase: CheckPhase, set: &S) -> Result<(), Error> {
+        for (_, check) in self.checks.iter().filter(|(p, _)| *p == phase) {
+            trace!("in {phase:?} check, run custom check ...");
+            check(set)?;
+        }
+        Ok(())
+    }
 }
 
 impl<S> SetChecker<S> for DefaultSetChecker<S>
@@ -78,6 +145,7 @@ where
                 }
             }
         }
+        self.run_checks(CheckPhase::Pre, set)?;
         Ok(true)
     }
 
@@ -97,6 +165,7 @@ where
                 return Err(Error::sp_opt_require(vec![opt.hint()]).with_uid(opt.uid()));
             }
         }
+        self.run_checks(CheckPhase::Opt, set)?;
         Ok(true)
     }
 
@@ -132,6 +201,7 @@ where
                         Index::Backward(_)
                         | Index::Except(_)
                         | Index::Range(_, _)
+                        | Index::Step(_, _)
                         | Index::AnyWhere => {
                             float_vec.push(opt.uid());
                         }
@@ -173,6 +243,7 @@ where
                 return Err(Error::sp_pos_require(names).with_uid(float_vec[0]));
             }
         }
+        self.run_checks(CheckPhase::Pos, set)?;
         Ok(true)
     }
 
@@ -198,15 +269,19 @@ where
         if !valid && !names.is_empty() {
             return Err(Error::sp_cmd_require(names).with_uid(uids[0]));
         }
+        self.run_checks(CheckPhase::Cmd, set)?;
         Ok(true)
     }
 
     /// Call [`valid`](crate::opt::Opt::valid) on options those style are [`Main`](Style::Main).
     fn post_check(&self, set: &mut S) -> Result<bool, Error> {
         trace!("in post check, call valid on Main ...");
-        Ok(set
+        let valid = set
             .iter()
             .filter(|opt| opt.mat_style(Style::Main))
-            .all(|opt| opt.valid()))
+            .all(|opt| opt.valid());
+
+        self.run_checks(CheckPhase::Post, set)?;
+        Ok(valid)
     }
 }
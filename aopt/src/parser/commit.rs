@@ -2,6 +2,8 @@ use std::any::TypeId;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::ctx::wrap_handler_action;
+use crate::ctx::wrap_handler_state;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::HandlerEntry;
@@ -14,6 +16,7 @@ use crate::opt::Main;
 use crate::opt::Opt;
 use crate::opt::Pos;
 use crate::raise_error;
+use crate::ser::ServicesValExt;
 use crate::set::Commit;
 use crate::set::Set;
 use crate::set::SetCfg;
@@ -169,7 +172,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -188,7 +191,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -198,6 +201,98 @@ where
         Ok(HandlerEntry::new(ser.unwrap(), uid).on(handler))
     }
 
+    #[cfg(not(feature = "sync"))]
+    #[allow(clippy::type_complexity)]
+    /// Register the handler which will be called when option is set,
+    /// the handler can mutate the shared state `V` kept in services, see [`wrap_handler_state`].
+    pub fn on_with_state<V, O>(
+        self,
+        handler: impl FnMut(&mut V, &mut S, &Ctx) -> Result<Option<O>, Error> + 'a,
+    ) -> Result<
+        HandlerEntryThen<
+            'a,
+            'b,
+            I,
+            S,
+            Ser,
+            impl FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
+            O,
+        >,
+        Error,
+    >
+    where
+        V: ErasedTy,
+        O: ErasedTy + Clone,
+        Ser: ServicesValExt + 'a,
+    {
+        self.on(wrap_handler_state(handler))
+    }
+
+    #[cfg(feature = "sync")]
+    #[allow(clippy::type_complexity)]
+    /// Register the handler which will be called when option is set,
+    /// the handler can mutate the shared state `V` kept in services, see [`wrap_handler_state`].
+    pub fn on_with_state<V, O>(
+        self,
+        handler: impl FnMut(&mut V, &mut S, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+    ) -> Result<
+        HandlerEntryThen<
+            'a,
+            'b,
+            I,
+            S,
+            Ser,
+            impl FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+            O,
+        >,
+        Error,
+    >
+    where
+        V: ErasedTy,
+        O: ErasedTy + Clone,
+        Ser: ServicesValExt + 'a,
+    {
+        self.on(wrap_handler_state(handler))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    /// Register the handler which will be called when option is set by name `alias`,
+    /// the handler registered by [`on`](Self::on) will handle the other names.
+    /// The function will register the option to [`Set`](Set) first,
+    /// so it should be called after all the configurations are set.
+    pub fn on_alias<H, O>(mut self, alias: &str, handler: H) -> Result<Self, Error>
+    where
+        O: ErasedTy + Clone,
+        H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+
+        self.inv_ser
+            .as_mut()
+            .unwrap()
+            .register_alias(uid, alias, wrap_handler_action(handler));
+        Ok(self)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Register the handler which will be called when option is set by name `alias`,
+    /// the handler registered by [`on`](Self::on) will handle the other names.
+    /// The function will register the option to [`Set`](Set) first,
+    /// so it should be called after all the configurations are set.
+    pub fn on_alias<H, O>(mut self, alias: &str, handler: H) -> Result<Self, Error>
+    where
+        O: ErasedTy + Clone,
+        H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+
+        self.inv_ser
+            .as_mut()
+            .unwrap()
+            .register_alias(uid, alias, wrap_handler_action(handler));
+        Ok(self)
+    }
+
     #[cfg(not(feature = "sync"))]
     /// Register the handler which will be called when option is set.
     /// And the [`fallback`](crate::ctx::Invoker::fallback) will be called if
@@ -209,7 +304,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -230,7 +325,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -340,7 +435,7 @@ where
     }
 
     /// Set the option value validator.
-    pub fn set_validator_t<T: ErasedTy + RawValParser>(
+    pub fn set_validator_t<T: ErasedTy + Clone + RawValParser>(
         self,
         validator: ValValidator<T>,
     ) -> ParserCommitWithValue<'a, 'b, I, S, Ser, U, T> {
@@ -453,7 +548,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -472,7 +567,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -482,6 +577,98 @@ where
         Ok(HandlerEntry::new(ser.unwrap(), uid).on(handler))
     }
 
+    #[cfg(not(feature = "sync"))]
+    #[allow(clippy::type_complexity)]
+    /// Register the handler which will be called when option is set,
+    /// the handler can mutate the shared state `V` kept in services, see [`wrap_handler_state`].
+    pub fn on_with_state<V, O>(
+        self,
+        handler: impl FnMut(&mut V, &mut S, &Ctx) -> Result<Option<O>, Error> + 'a,
+    ) -> Result<
+        HandlerEntryThen<
+            'a,
+            'b,
+            I,
+            S,
+            Ser,
+            impl FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
+            O,
+        >,
+        Error,
+    >
+    where
+        V: ErasedTy,
+        O: ErasedTy + Clone,
+        Ser: ServicesValExt + 'a,
+    {
+        self.on(wrap_handler_state(handler))
+    }
+
+    #[cfg(feature = "sync")]
+    #[allow(clippy::type_complexity)]
+    /// Register the handler which will be called when option is set,
+    /// the handler can mutate the shared state `V` kept in services, see [`wrap_handler_state`].
+    pub fn on_with_state<V, O>(
+        self,
+        handler: impl FnMut(&mut V, &mut S, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+    ) -> Result<
+        HandlerEntryThen<
+            'a,
+            'b,
+            I,
+            S,
+            Ser,
+            impl FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+            O,
+        >,
+        Error,
+    >
+    where
+        V: ErasedTy,
+        O: ErasedTy + Clone,
+        Ser: ServicesValExt + 'a,
+    {
+        self.on(wrap_handler_state(handler))
+    }
+
+    #[cfg(not(feature = "sync"))]
+    /// Register the handler which will be called when option is set by name `alias`,
+    /// the handler registered by [`on`](Self::on) will handle the other names.
+    /// The function will register the option to [`Set`](Set) first,
+    /// so it should be called after all the configurations are set.
+    pub fn on_alias<H, O>(mut self, alias: &str, handler: H) -> Result<Self, Error>
+    where
+        O: ErasedTy + Clone,
+        H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+
+        self.inv_ser
+            .as_mut()
+            .unwrap()
+            .register_alias(uid, alias, wrap_handler_action(handler));
+        Ok(self)
+    }
+
+    #[cfg(feature = "sync")]
+    /// Register the handler which will be called when option is set by name `alias`,
+    /// the handler registered by [`on`](Self::on) will handle the other names.
+    /// The function will register the option to [`Set`](Set) first,
+    /// so it should be called after all the configurations are set.
+    pub fn on_alias<H, O>(mut self, alias: &str, handler: H) -> Result<Self, Error>
+    where
+        O: ErasedTy + Clone,
+        H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
+    {
+        let uid = self.commit_inner_change()?;
+
+        self.inv_ser
+            .as_mut()
+            .unwrap()
+            .register_alias(uid, alias, wrap_handler_action(handler));
+        Ok(self)
+    }
+
     #[cfg(not(feature = "sync"))]
     /// Register the handler which will be called when option is set.
     /// And the [`fallback`](crate::ctx::Invoker::fallback) will be called if
@@ -493,7 +680,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -514,7 +701,7 @@ where
         handler: H,
     ) -> Result<HandlerEntryThen<'a, 'b, I, S, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut S, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
     {
         let uid = self.commit_inner_change()?;
@@ -598,7 +785,7 @@ impl<'a, I, S, Ser, U, T> ParserCommitWithValue<'a, '_, I, S, Ser, U, T>
 where
     S: Set,
     U: Infer + 'static,
-    T: ErasedTy + RawValParser,
+    T: ErasedTy + Clone + RawValParser,
     U::Val: RawValParser,
     I: HandlerCollection<'a, S, Ser>,
     SetOpt<S>: Opt,
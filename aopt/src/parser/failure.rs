@@ -1,6 +1,17 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::fmt::Display;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
+use super::mask_secret_args;
+use super::secret_error;
+use super::SECRET_MASK;
+use crate::ctx::Ctx;
+use crate::opt::Opt;
+use crate::opt::Style;
+use crate::set::Set;
+use crate::set::SetOpt;
 use crate::Error;
 
 #[derive(Debug, Default)]
@@ -14,6 +25,81 @@ impl FailManager {
         self
     }
 
+    /// Push the failure raised by the handler invoked with `ctx`,
+    /// the arguments are rendered with a caret under the offending one, see [`render`](FailManager::render).
+    /// The value of [`secret`](Opt::secret) options in `set` are masked in the snippet.
+    pub fn push_ctx<S>(&mut self, err: Error, ctx: &Ctx, set: &S) -> &mut Self
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let args = ctx.args();
+        let index = ctx.inner_ctx().ok().and_then(|inner| {
+            let idx = inner.idx();
+
+            match inner.style() {
+                Style::Main => None,
+                // the value is the next argument
+                Style::Argument
+                    if inner.arg().map(|v| v.as_ref()) == args.get(idx + 1).copied() =>
+                {
+                    Some(idx + 1)
+                }
+                _ => Some(idx),
+            }
+        });
+        let uid = ctx.uid().ok();
+        let secret = set
+            .iter()
+            .find(|opt| Some(opt.uid()) == uid && opt.secret())
+            .map(|opt| opt.name());
+        let mut args = mask_secret_args(set, args);
+        let mut err = err;
+
+        if let Some(name) = secret {
+            // the value of secret positional can not be detected by name
+            if let Some(arg @ Cow::Borrowed(_)) = index.and_then(|idx| args.get_mut(idx)) {
+                *arg = Cow::Borrowed(OsStr::new(SECRET_MASK));
+            }
+            err = secret_error(err, name);
+        }
+
+        let args: Vec<_> = args.iter().map(|v| v.as_ref()).collect();
+        let snippet = Self::render(&args, index, &err);
+
+        self.push(err.with_snippet(snippet))
+    }
+
+    /// Render the arguments in one line, and the `msg` in next line with a caret under the argument at `index`.
+    /// Only the `msg` is displayed in next line if `index` is `None` or out of range.
+    ///
+    /// ```txt
+    /// app --num abc
+    ///           ^^^ invalid value `abc` for option `--num`: not a number
+    /// ```
+    pub fn render(args: &[&OsStr], index: Option<usize>, msg: impl Display) -> String {
+        let mut line = String::default();
+        let mut caret = None;
+
+        for (idx, arg) in args.iter().enumerate() {
+            let arg = arg.to_string_lossy();
+
+            if idx > 0 {
+                line.push(' ');
+            }
+            if Some(idx) == index {
+                caret = Some((line.chars().count(), arg.chars().count().max(1)));
+            }
+            line.push_str(&arg);
+        }
+        match caret {
+            Some((offset, width)) => {
+                format!("{line}\n{}{} {msg}", " ".repeat(offset), "^".repeat(width))
+            }
+            None => format!("{line}\n{msg}"),
+        }
+    }
+
     pub fn cause(mut self, new_err: Error) -> Error {
         if self.is_empty() {
             new_err
@@ -73,3 +159,85 @@ impl DerefMut for FailManager {
         &mut self.fails
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use super::FailManager;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_render() {
+        assert!(test_render_impl().is_ok());
+    }
+
+    fn test_render_impl() -> Result<(), Error> {
+        let args = ["app", "--num", "abc", "foo"].map(OsStr::new);
+
+        assert_eq!(
+            FailManager::render(&args, Some(2), "bad number"),
+            "app --num abc foo\n          ^^^ bad number"
+        );
+        assert_eq!(
+            FailManager::render(&args, None, "bad number"),
+            "app --num abc foo\nbad number"
+        );
+        assert_eq!(
+            FailManager::render(&args, Some(4), "bad number"),
+            "app --num abc foo\nbad number"
+        );
+
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("--num=i!")?;
+        parser.add_opt("--ratio=f")?;
+
+        let err = parser
+            .parse(Args::from(["app", "--ratio=0.5", "--num", "abc"]))?
+            .ok()
+            .unwrap_err();
+        let snippet = err.caused_by().and_then(Error::snippet).unwrap();
+        let (line, caret) = snippet.split_once('\n').unwrap();
+
+        assert_eq!(line, "app --ratio=0.5 --num abc");
+        assert_eq!(line.find("abc"), caret.find('^'));
+        assert!(caret.contains("^^^ invalid value `Some(abc)`"));
+
+        let err = parser
+            .parse(Args::from(["app", "--num=x1"]))?
+            .ok()
+            .unwrap_err();
+        let snippet = err.caused_by().and_then(Error::snippet).unwrap();
+
+        assert!(snippet.starts_with("app --num=x1\n    ^^^^^^^^ "));
+        // the snippet is not displayed
+        assert!(!err.to_string().contains("app --num"));
+        assert!(!err.caused_by().unwrap().to_string().contains("app --num"));
+
+        let mut parser = Parser::new_policy(AFwdPolicy::default());
+
+        parser.add_opt("--num=i")?;
+        parser.add_opt("--token=i")?.set_secret(true);
+        parser.add_opt("--pin=s")?.set_secret(true);
+
+        let err = parser
+            .parse(Args::from([
+                "app",
+                "--pin",
+                "1234",
+                "--token=abc",
+                "--num",
+                "x",
+            ]))?
+            .ok()
+            .unwrap_err();
+        let snippet = err.caused_by().and_then(Error::snippet).unwrap();
+
+        assert!(snippet.starts_with("app --pin *** --token=*** --num x\n"));
+        assert!(!snippet.contains("1234"));
+        assert!(!snippet.contains("abc"));
+        Ok(())
+    }
+}
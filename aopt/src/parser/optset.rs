@@ -1,45 +1,162 @@
 use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::fmt::Debug;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::sync::Arc;
 
 use crate::args::Args;
 use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::ctx::HandlerEntry;
 use crate::map::ErasedTy;
+use crate::opt::Action;
+use crate::opt::Cid;
 use crate::opt::ConfigBuild;
 use crate::opt::ConfigValue;
 use crate::opt::Information;
 use crate::opt::Opt;
 use crate::opt::OptParser;
+use crate::opt::OptValueExt;
 use crate::parser::ParserCommit;
 use crate::parser::Policy;
+use crate::raise_error;
 use crate::ser::ServicesValExt;
+use crate::set::Commit;
 use crate::set::OptValidator;
 use crate::set::PrefixedValidator;
 use crate::set::SetCfg;
 use crate::set::SetCommit;
+use crate::set::SetExt;
 use crate::set::SetOpt;
 use crate::set::SetValueFindExt;
+use crate::typeid;
+use crate::value::AnyValue;
+use crate::value::ErasedValue;
 use crate::value::Infer;
 use crate::value::Placeholder;
 use crate::value::RawValParser;
+use crate::value::ValueFilter;
 use crate::Error;
 use crate::Uid;
 
 use super::Parser;
 use super::PolicyParser;
 
-#[derive(Debug, Default, Clone)]
+#[cfg(feature = "sync")]
+type PostBuildCheck<T> = Arc<dyn Fn(&T) -> Result<(), Error> + Send + Sync + 'static>;
+
+#[cfg(not(feature = "sync"))]
+type PostBuildCheck<T> = Arc<dyn Fn(&T) -> Result<(), Error> + 'static>;
+
+#[derive(Default, Clone)]
 pub struct HCOptSet<Set, Inv, Ser> {
     set: Set,
     inv: Inv,
     ser: Ser,
+    default_action: Option<Action>,
+    checks: Vec<PostBuildCheck<Self>>,
+    filter: Option<ValueFilter>,
+}
+
+impl<Set, Inv, Ser> Debug for HCOptSet<Set, Inv, Ser>
+where
+    Set: Debug,
+    Inv: Debug,
+    Ser: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HCOptSet")
+            .field("set", &self.set)
+            .field("inv", &self.inv)
+            .field("ser", &self.ser)
+            .field("default_action", &self.default_action)
+            .field("checks", &self.checks.len())
+            .field("filter", &self.filter.as_ref().map(|_| "{...}"))
+            .finish()
+    }
 }
 
 impl<Set, Inv, Ser> HCOptSet<Set, Inv, Ser> {
     pub fn new(set: Set, inv: Inv, ser: Ser) -> Self {
-        Self { set, inv, ser }
+        Self {
+            set,
+            inv,
+            ser,
+            default_action: None,
+            checks: vec![],
+            filter: None,
+        }
+    }
+
+    pub fn with_default_action(mut self, action: Action) -> Self {
+        self.default_action = Some(action);
+        self
+    }
+
+    /// The default [`Action`] of options added later.
+    pub fn default_action(&self) -> Option<Action> {
+        self.default_action
+    }
+
+    /// Set the default [`Action`] of options added later, it is used if the option has no explicit action,
+    /// instead of the one [`inferred`](crate::value::Infer::infer_act) from the value type.
+    /// The options created by [`Cmd`](Cid::Cmd) and [`Main`](Cid::Main) are not affected.
+    pub fn set_default_action(&mut self, action: Action) -> &mut Self {
+        self.default_action = Some(action);
+        self
+    }
+
+    /// Add a check of the whole set, such as ensure at least one of the output options exists.
+    /// The checks are invoked in order at the start of parsing, the first error is returned.
+    #[cfg(feature = "sync")]
+    pub fn add_post_build_check(
+        &mut self,
+        check: impl Fn(&Self) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.checks.push(Arc::new(check));
+        self
+    }
+
+    /// Add a check of the whole set, such as ensure at least one of the output options exists.
+    /// The checks are invoked in order at the start of parsing, the first error is returned.
+    #[cfg(not(feature = "sync"))]
+    pub fn add_post_build_check(
+        &mut self,
+        check: impl Fn(&Self) -> Result<(), Error> + 'static,
+    ) -> &mut Self {
+        self.checks.push(Arc::new(check));
+        self
+    }
+
+    /// Set the filter invoked on the raw value of every option before it is parsed,
+    /// such as reject any value containing a null byte.
+    /// It is passed to the [`ValStorer`](crate::value::ValStorer) of options by [`init`](HCOptSet::init).
+    #[cfg(feature = "sync")]
+    pub fn set_global_value_filter(
+        &mut self,
+        filter: impl Fn(&OsStr) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Set the filter invoked on the raw value of every option before it is parsed,
+    /// such as reject any value containing a null byte.
+    /// It is passed to the [`ValStorer`](crate::value::ValStorer) of options by [`init`](HCOptSet::init).
+    #[cfg(not(feature = "sync"))]
+    pub fn set_global_value_filter(
+        &mut self,
+        filter: impl Fn(&OsStr) -> Result<(), Error> + 'static,
+    ) -> &mut Self {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Invoke the checks added by [`add_post_build_check`](HCOptSet::add_post_build_check).
+    pub fn post_build_check(&self) -> Result<(), Error> {
+        self.checks.iter().try_for_each(|check| check(self))
     }
 
     pub fn invoker(&self) -> &Inv {
@@ -120,9 +237,181 @@ where
 
         for opt in set.iter_mut() {
             opt.init()?;
+            opt.accessor_mut()
+                .storer_mut()
+                .set_filter(self.filter.clone());
+        }
+        Ok(())
+    }
+
+    /// Return the [`Uid`]s of options matching the predicate `pred`, in the order they were inserted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+    ///
+    /// let name = set.add_opt("--name=s!")?.run()?;
+    /// set.add_opt("--debug=b")?;
+    ///
+    /// assert_eq!(set.find_by(|opt| opt.force()), vec![name]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_by(&self, pred: impl Fn(&dyn Opt) -> bool) -> Vec<Uid> {
+        self.set
+            .iter_ordered()
+            .filter(|opt| pred(*opt))
+            .map(|opt| opt.uid())
+            .collect()
+    }
+
+    /// Clone the values, raw values, occurrences and matched flags of all options into a [`SetSnapshot`].
+    ///
+    /// Call [`restore`](HCOptSet::restore) give them back, such as when the speculative parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+    ///
+    /// set.add_opt("--count=i")?;
+    /// PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--count=1"]))?;
+    ///
+    /// let snapshot = set.snapshot();
+    ///
+    /// assert_eq!(set.find_val::<i64>("--count")?, &1);
+    /// PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--count=2"]))?;
+    /// assert_eq!(set.find_val::<i64>("--count")?, &2);
+    ///
+    /// set.restore(snapshot);
+    /// assert_eq!(set.find_val::<i64>("--count")?, &1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> SetSnapshot {
+        let values = self
+            .set
+            .iter()
+            .map(|opt| {
+                let accessor = opt.accessor();
+
+                (
+                    opt.uid(),
+                    opt.matched(),
+                    accessor.occurrences(),
+                    accessor.indices().to_vec(),
+                    accessor.rawvals().cloned().unwrap_or_default(),
+                    AnyValue::clone(accessor),
+                )
+            })
+            .collect();
+
+        SetSnapshot { values }
+    }
+
+    /// Restore the values, raw values, occurrences and matched flags of options from the [`SetSnapshot`].
+    /// The options not exist in current set are ignored.
+    pub fn restore(&mut self, snapshot: SetSnapshot) {
+        for (uid, matched, occurrences, indices, rawvals, values) in snapshot.values {
+            if let Some(opt) = self.set.iter_mut().find(|opt| opt.uid() == uid) {
+                let handlers = opt.accessor_mut().handlers();
+
+                *handlers.0 = rawvals;
+                *handlers.1 = values;
+                opt.accessor_mut()
+                    .set_occurrences(occurrences)
+                    .set_indices(indices);
+                opt.set_matched(matched);
+            }
+        }
+    }
+
+    /// Validate the current values of options without parsing any argument,
+    /// such as the defaults loaded from config file.
+    ///
+    /// The values are treated as the final state, they are checked by the
+    /// [`validator`](crate::value::ValStorer::new_validator) of options and the checks added by
+    /// [`add_post_build_check`](HCOptSet::add_post_build_check).
+    /// Call [`init`](HCOptSet::init) before it if the values are set by initializers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+    ///
+    /// set.add_opt("--jobs=i")?
+    ///     .set_value_t(64i64)
+    ///     .set_validator_t(ValValidator::range_full(1i64, 32));
+    /// set.init()?;
+    ///
+    /// assert!(set.validate_config().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_config(&self) -> Result<(), Error> {
+        self.post_build_check()?;
+        for opt in self.set.iter() {
+            opt.accessor().check().map_err(|msg| {
+                Error::sp_invalid_value(None, format!("option `{}`", opt.name()), msg)
+                    .with_uid(opt.uid())
+            })?;
         }
         Ok(())
     }
+
+    /// Unset the option `name`, clear its values, raw values, occurrences and matched flag.
+    ///
+    /// Unlike [`reset`](HCOptSet::reset) which only clears the matched flag, the option is left
+    /// as it was never set, and the initializer is not applied.
+    /// Call [`init`](HCOptSet::init) later to apply the initializer again, such as the
+    /// environment variable fallback of [`ValInitializer::from_env`](crate::value::ValInitializer::from_env).
+    pub fn unset(&mut self, name: impl ConfigBuild<SetCfg<Set>>) -> Result<&mut Self, Error>
+    where
+        Set: SetValueFindExt,
+        SetCfg<Set>: ConfigValue + Default,
+    {
+        let uid = self.set.find_uid(name)?;
+        let opt = self.set.opt_mut(uid)?;
+        let (rawvals, values) = opt.accessor_mut().handlers();
+
+        rawvals.clear();
+        *values = AnyValue::default();
+        opt.accessor_mut().set_occurrences(0).set_indices(vec![]);
+        opt.set_matched(false);
+        Ok(self)
+    }
+}
+
+/// The uid, matched flag, occurrences, indices, raw values and values of an option.
+type SnapshotValue = (Uid, bool, usize, Vec<usize>, Vec<OsString>, AnyValue);
+
+/// The values, raw values, occurrences and matched flags of options taken by [`HCOptSet::snapshot`].
+#[derive(Debug, Default)]
+pub struct SetSnapshot {
+    values: Vec<SnapshotValue>,
+}
+
+impl SetSnapshot {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
 }
 
 impl<Set, Inv, Ser> HCOptSet<Set, Inv, Ser>
@@ -306,13 +595,90 @@ where
         B: ConfigBuild<SetCfg<Set>>,
         <B::Val as Infer>::Val: RawValParser,
     {
-        let cfg = cb.build(&self.set)?;
+        let mut cfg = cb.build(&self.set)?;
+
+        self.fill_default_action(&mut cfg);
         let set = &mut self.set;
         let inv = &mut self.inv;
 
         Ok(ParserCommit::new(SetCommit::new(set, cfg), inv))
     }
 
+    /// Add an option to the [`Set`](Policy::Set), the type of option value is given by `U`,
+    /// return a typed [`ParserCommit`] without calling [`set_infer`](ParserCommit::set_infer).
+    ///
+    ///```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser
+    ///     .add_opt_typed::<i64>("--count")?
+    ///     .set_value(1)
+    ///     .set_validator(ValValidator::range_from(1));
+    /// parser.parse(Args::from(["app", "--count", "42"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<i64>("--count")?, &42);
+    /// #
+    /// # Ok(())
+    /// # }
+    ///```
+    pub fn add_opt_typed<U>(
+        &mut self,
+        cb: impl ConfigBuild<SetCfg<Set>>,
+    ) -> Result<ParserCommit<'a, '_, Inv, Set, Ser, U>, Error>
+    where
+        U: Infer + 'static,
+        U::Val: RawValParser,
+    {
+        let mut cfg = cb.build(&self.set)?;
+
+        self.fill_default_action(&mut cfg);
+        Ok(ParserCommit::new(
+            SetCommit::new(&mut self.set, cfg),
+            &mut self.inv,
+        ))
+    }
+
+    /// Add an option to the [`Set`](Policy::Set), modify the configuration with `func`
+    /// and commit the option, return the [`Uid`] of the option.
+    ///
+    ///```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut parser = AFwdParser::default();
+    ///
+    /// parser.add_opt_with("--count=i", |cfg| {
+    ///     cfg.add_alias("-c");
+    ///     cfg.set_initializer(ValInitializer::new_value(1i64));
+    /// })?;
+    /// parser.parse(Args::from(["app", "-c", "42"]))?;
+    ///
+    /// assert_eq!(parser.find_val::<i64>("--count")?, &42);
+    /// #
+    /// # Ok(())
+    /// # }
+    ///```
+    pub fn add_opt_with<B>(
+        &mut self,
+        cb: B,
+        func: impl FnOnce(&mut SetCfg<Set>),
+    ) -> Result<Uid, Error>
+    where
+        B::Val: Infer + 'static,
+        B: ConfigBuild<SetCfg<Set>>,
+        <B::Val as Infer>::Val: RawValParser,
+    {
+        let mut commit = self.add_opt(cb)?;
+
+        func(commit.cfg_mut());
+        commit.run()
+    }
+
     /// Add an option to the [`Set`](Policy::Set), return a [`ParserCommit`].
     ///
     /// ```rust
@@ -368,8 +734,11 @@ where
         &mut self,
         config: impl Into<SetCfg<Set>>,
     ) -> Result<ParserCommit<'a, '_, Inv, Set, Ser, Placeholder>, Error> {
+        let mut config = config.into();
+
+        self.fill_default_action(&mut config);
         Ok(ParserCommit::new(
-            SetCommit::new_placeholder(&mut self.set, config.into()),
+            SetCommit::new_placeholder(&mut self.set, config),
             &mut self.inv,
         ))
     }
@@ -382,11 +751,44 @@ where
         U: Infer + 'static,
         U::Val: RawValParser,
     {
+        let mut config = config.into();
+
+        self.fill_default_action(&mut config);
         Ok(ParserCommit::new(
-            SetCommit::new(&mut self.set, config.into()),
+            SetCommit::new(&mut self.set, config),
             &mut self.inv,
         ))
     }
+
+    /// Commit an option from a fully specified config, such as the [`OptConfig`](crate::opt::OptConfig)
+    /// deserialized from JSON or TOML, return the [`Uid`] of new option.
+    ///
+    /// The config must have the `ctor` and `name`,
+    /// the value type, storer and initializer are inferred from the `ctor` if not set,
+    /// the default value is not serialized and need be set by the initializer.
+    pub fn add_opt_from_config(&mut self, config: impl Into<SetCfg<Set>>) -> Result<Uid, Error> {
+        let config = config.into();
+
+        if config.ctor().is_none() {
+            return Err(raise_error!("incomplete configuration: missing `ctor`"));
+        }
+        if config.name().is_none() {
+            return Err(raise_error!("incomplete configuration: missing `name`"));
+        }
+        self.add_opt_cfg(config)?.run()
+    }
+
+    fn fill_default_action(&self, cfg: &mut SetCfg<Set>) {
+        if let Some(action) = self.default_action {
+            let structural = cfg
+                .ctor()
+                .is_some_and(|v| matches!(Cid::from(v), Cid::Cmd | Cid::Main));
+
+            if !structural && !cfg.has_action() {
+                cfg.set_action(action);
+            }
+        }
+    }
 }
 
 impl<'a, Set, Inv, Ser> HCOptSet<Set, Inv, Ser>
@@ -401,7 +803,7 @@ where
         uid: Uid,
     ) -> Result<HandlerEntry<'a, '_, Inv, Set, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
     {
         Ok(HandlerEntry::new(&mut self.inv, uid))
@@ -414,7 +816,7 @@ where
         uid: Uid,
     ) -> Result<HandlerEntry<'a, '_, Inv, Set, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut Set, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         Ok(HandlerEntry::new(&mut self.inv, uid))
@@ -455,9 +857,94 @@ where
         Set::iter_mut(&mut self.set)
     }
 
+    fn iter_ordered(&self) -> impl Iterator<Item = &SetOpt<Self>> {
+        Set::iter_ordered(&self.set)
+    }
+
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid {
         Set::insert(&mut self.set, opt)
     }
+
+    fn remove(&mut self, uid: Uid) -> Option<SetOpt<Self>> {
+        Set::remove(&mut self.set, uid)
+    }
+
+    fn check_duplicate(&self) -> bool {
+        Set::check_duplicate(&self.set)
+    }
+
+    fn skip_claimed_pos(&self) -> bool {
+        Set::skip_claimed_pos(&self.set)
+    }
+
+    fn help_option(&self) -> Option<&str> {
+        Set::help_option(&self.set)
+    }
+}
+
+impl<'a, Set, Inv, Ser> HCOptSet<Set, Inv, Ser>
+where
+    SetOpt<Set>: Opt,
+    Inv: HandlerCollection<'a, Set, Ser>,
+    Set: crate::set::Set + PrefixedValidator + 'a,
+    Ser: 'a,
+{
+    /// Move the options, handlers, prefixes and checks of `other` into current set,
+    /// the uid of options are reassigned.
+    ///
+    /// Return an error if any name or alias of `other` already exists in current set,
+    /// current set is not modified in that case.
+    /// The services of `other` is dropped.
+    pub fn merge(&mut self, mut other: Self) -> Result<(), Error> {
+        fn names<O: Opt>(opt: &O) -> impl Iterator<Item = &str> {
+            std::iter::once(opt.name())
+                .chain(opt.alias().into_iter().flatten().map(|v| v.as_str()))
+                .chain(
+                    opt.deprecated_alias()
+                        .into_iter()
+                        .flatten()
+                        .map(|(alias, _)| alias.as_str()),
+                )
+        }
+
+        for opt in other.set.iter() {
+            for name in names(opt) {
+                if let Some(exist) = self.set.iter().find(|v| names(*v).any(|v| v == name)) {
+                    return Err(raise_error!(
+                        "can not merge option `{}`: name `{name}` is used by option `{}`",
+                        opt.name(),
+                        exist.name()
+                    ));
+                }
+            }
+        }
+        for prefix in other.set.prefixes().to_vec() {
+            if !self.set.prefixes().contains(&prefix) {
+                self.set.reg_prefix(&prefix).map_err(Into::into)?;
+            }
+        }
+
+        let uids: Vec<_> = other.set.iter_ordered().map(|v| v.uid()).collect();
+
+        for uid in uids {
+            if let Some(opt) = other.set.remove(uid) {
+                let new_uid = self.set.insert(opt);
+
+                crate::trace!("merge option {uid} -> {new_uid}");
+                if let Some(handler) = other.inv.take_handler(&uid) {
+                    #[cfg(not(feature = "sync"))]
+                    self.inv.register(new_uid, handler);
+                    #[cfg(feature = "sync")]
+                    self.inv.register_handler(new_uid, handler);
+                }
+                for (alias, handler) in other.inv.take_alias_handlers(&uid) {
+                    self.inv.register_alias(new_uid, &alias, handler);
+                }
+            }
+        }
+        self.checks.append(&mut other.checks);
+        Ok(())
+    }
 }
 
 impl<Set, Inv, Ser> OptValidator for HCOptSet<Set, Inv, Ser>
@@ -488,6 +975,10 @@ where
     fn unreg_prefix(&mut self, val: &str) -> Result<(), Self::Error> {
         PrefixedValidator::unreg_prefix(&mut self.set, val)
     }
+
+    fn prefixes(&self) -> &[String] {
+        PrefixedValidator::prefixes(&self.set)
+    }
 }
 
 impl<P: Policy> PolicyParser<P> for HCOptSet<P::Set, P::Inv<'_>, P::Ser>
@@ -501,6 +992,7 @@ where
         args: Args,
         policy: &mut P,
     ) -> Result<<P as Policy>::Ret, Self::Error> {
+        self.post_build_check()?;
         self.init()?;
 
         let set = &mut self.set;
@@ -524,6 +1016,58 @@ where
     }
 }
 
+impl<Set, Inv, Ser> HCOptSet<Set, Inv, Ser>
+where
+    Set: SetValueFindExt,
+    SetCfg<Set>: ConfigValue + Default,
+{
+    /// Return how many times the option appeared in the last parsing,
+    /// it works for any option type, and return `0` if the option not exist.
+    pub fn occurrences(&self, cb: impl ConfigBuild<SetCfg<Self>>) -> usize {
+        self.find_opt(cb)
+            .map(|opt| opt.accessor().occurrences())
+            .unwrap_or_default()
+    }
+
+    /// Return the argument index of each occurrence of the option in the last parsing,
+    /// in the order of occurrences, and return an empty vector if the option not exist.
+    ///
+    /// For option it is the index in the arguments, for NOA it is the index in the NOA arguments.
+    pub fn value_indices(&self, cb: impl ConfigBuild<SetCfg<Self>>) -> Vec<usize> {
+        self.find_opt(cb)
+            .map(|opt| opt.accessor().indices().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Borrow the last value of option without cloning it, return `None` if the option has no value.
+    ///
+    /// The reference points into the value stored in the option, so the set can not be
+    /// modified or parsed again while the reference is alive, take the value if you need keep it.
+    /// Return an error if option not found, or the value type of option is not `T`.
+    pub fn borrow_value<T: ErasedTy>(
+        &self,
+        cb: impl ConfigBuild<SetCfg<Self>>,
+    ) -> Result<Option<&T>, Error> {
+        let opt = self.find_opt(cb)?;
+
+        match opt.vals::<T>() {
+            Ok(vals) => Ok(vals.last()),
+            Err(_) if opt.r#type() == &typeid::<T>() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Return the last raw value of option exactly as typed in the command line,
+    /// the raw values are kept beside the typed values, see [`rawvals`](crate::value::ErasedValue::rawvals).
+    /// Return `None` if the option not exist or has no raw value.
+    pub fn raw_value(&self, cb: impl ConfigBuild<SetCfg<Self>>) -> Option<&OsString> {
+        self.find_opt(cb)
+            .ok()
+            .and_then(|opt| opt.rawvals().ok())
+            .and_then(|rawvals| rawvals.last())
+    }
+}
+
 impl<Set, Inv, Ser> SetValueFindExt for HCOptSet<Set, Inv, Ser>
 where
     Set: SetValueFindExt,
@@ -578,4 +1122,721 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_check_duplicate() {
+        assert!(test_check_duplicate_impl().is_ok());
+    }
+
+    fn test_check_duplicate_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::new(ASet::default(), AInvoker::default(), ASer::default());
+
+        assert!(crate::set::Set::check_duplicate(&set));
+        set.add_opt("-c;--count=i")?;
+        assert!(set.add_opt("--count=s")?.run().is_err());
+        assert!(set.add_opt("-c=b")?.run().is_err());
+        assert_eq!(set.len(), 1);
+
+        let mut set = HCOptSet::new(
+            ASet::default().with_check_duplicate(false),
+            AInvoker::default(),
+            ASer::default(),
+        );
+
+        assert!(!crate::set::Set::check_duplicate(&set));
+        set.add_opt("-c;--count=i")?;
+        set.add_opt("--count=s")?.run()?;
+        assert_eq!(set.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_opt_with() {
+        assert!(test_add_opt_with_impl().is_ok());
+    }
+
+    fn test_add_opt_with_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        let uid = set.add_opt_with("--count=i", |cfg| {
+            cfg.add_alias("-c");
+            cfg.set_help("Set the count");
+            cfg.set_initializer(ValInitializer::new_value(7i64));
+        })?;
+
+        set.init()?;
+
+        let opt = set.opt(uid)?;
+
+        assert_eq!(opt.name(), "--count");
+        assert_eq!(opt.alias(), Some(&vec!["-c".to_owned()]));
+        assert_eq!(opt.help(), "Set the count");
+        assert_eq!(set.find_val::<i64>("--count")?, &7);
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "-c", "42"]))?;
+        assert_eq!(set.find_val::<i64>("--count")?, &42);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_opt_typed() {
+        assert!(test_add_opt_typed_impl().is_ok());
+    }
+
+    fn test_add_opt_typed_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt_typed::<i64>("--level;-l")?
+            .set_value(3)
+            .set_validator(ValValidator::range_full(1, 5));
+        set.add_opt_typed::<Pos<String>>("file@1")?;
+
+        set.init()?;
+        assert_eq!(set.find_val::<i64>("--level")?, &3);
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "-l", "4", "a.txt"]))?;
+        assert_eq!(set.find_val::<i64>("--level")?, &4);
+        assert_eq!(set.find_val::<String>("file")?, "a.txt");
+
+        set.init()?;
+        let ret = PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "-l", "8"]))?;
+
+        assert!(!ret.status());
+        assert_eq!(set.find_val::<i64>("--level")?, &3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_by() {
+        assert!(test_find_by_impl().is_ok());
+    }
+
+    fn test_find_by_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        let output = set.add_opt("--output=s!")?.run()?;
+        let level = set.add_opt("--level=i!")?.set_value_t(1i64).run()?;
+        let file = set.add_opt("file=p!@1")?.run()?;
+
+        set.add_opt("--debug=b")?;
+        set.add_opt("--tag=s")?;
+        set.add_opt("list=c")?.set_force(false).run()?;
+
+        assert_eq!(
+            set.find_by(|opt| opt.force() && !opt.mat_style(Style::Cmd)),
+            vec![output, level, file]
+        );
+        assert_eq!(
+            set.find_by(|opt| opt.force() && opt.mat_style(Style::Argument)),
+            vec![output, level]
+        );
+        assert!(set.find_by(|opt| opt.name() == "--none").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_ordered() {
+        assert!(test_iter_ordered_impl().is_ok());
+    }
+
+    fn test_iter_ordered_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--aopt=b")?;
+        let bopt = set.add_opt("--bopt=i")?.run()?;
+        set.add_opt("--copt=s")?;
+        set.add_opt("--dopt=f")?;
+
+        assert!(set.remove(bopt).is_some());
+        assert!(set.remove(bopt).is_none());
+        set.add_opt("--eopt=u")?;
+
+        let names: Vec<_> = set.iter_ordered().map(|v| v.name()).collect();
+
+        assert_eq!(names, ["--aopt", "--copt", "--dopt", "--eopt"]);
+        assert!(set.find("--bopt").is_err());
+        assert_eq!(set.find_uid("--eopt")?, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() {
+        assert!(test_snapshot_impl().is_ok());
+    }
+
+    fn test_snapshot_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--flag=b")?;
+        set.add_opt("--name=s")?;
+        set.add_opt("--count=i")?.set_action(Action::App);
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from(["app", "--flag", "--name=foo", "--count=1", "--count=2"]),
+        )?;
+
+        let snapshot = set.snapshot();
+
+        assert_eq!(snapshot.len(), 3);
+        assert!(set.find("--flag")?.matched());
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--name=bar"]))?;
+        *set.find_val_mut::<String>("--name")? += "baz";
+        assert_eq!(set.find_val::<String>("--name")?, "barbaz");
+        assert!(!*set.find_val::<bool>("--flag")?);
+
+        set.restore(snapshot);
+        assert!(set.find("--flag")?.matched());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert_eq!(set.find_vals::<i64>("--count")?, &[1, 2]);
+        assert_eq!(
+            set.find("--name")?.accessor().rawvals()?,
+            &[std::ffi::OsString::from("foo")]
+        );
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Point(i64, i64);
+
+        // the values of any type are cloned
+        set.add_opt("--point=i")?.set_value_t(Point(1, 2));
+        set.add_opt("--input=s")?.set_stdin_marker("-".to_owned());
+        set.init()?;
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--input=-"]))?;
+
+        let snapshot = set.snapshot();
+
+        set.find_val_mut::<Point>("--point")?.0 = 3;
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--input=foo"]))?;
+        assert!(!set.value_is_stdin("--input")?);
+        set.restore(snapshot);
+        assert_eq!(set.find_val::<Point>("--point")?, &Point(1, 2));
+        assert!(set.value_is_stdin("--input")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(test_validate_config_impl().is_ok());
+    }
+
+    fn test_validate_config_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--jobs=i")?.set_value_t(64i64).set_validator_t(
+            ValValidator::range_full(1i64, 32).with_desc("jobs must be in range 1..=32"),
+        );
+        set.add_opt("--name=s")?
+            .set_validator_t(ValValidator::<String>::contains2(vec!["foo", "bar"]));
+        set.init()?;
+
+        let err = set.validate_config().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid value for option `--jobs`: jobs must be in range 1..=32 (uid = 0)"
+        );
+
+        // the default from config file is valid now
+        *set.find_val_mut::<i64>("--jobs")? = 8;
+        assert!(set.validate_config().is_ok());
+
+        set.add_post_build_check(|set| {
+            set.find("--output")
+                .map(|_| ())
+                .map_err(|_| crate::raise_error!("need an output option"))
+        });
+        assert!(set.validate_config().is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_unset() {
+        assert!(test_unset_impl().is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    fn test_unset_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        std::env::set_var("AOPT_TEST_UNSET_NAME", "env");
+        set.add_opt("--name=s")?
+            .set_action(Action::Set)
+            .set_initializer(ValInitializer::from_env(
+                "AOPT_TEST_UNSET_NAME",
+                String::from("default"),
+            ));
+        set.add_opt("--count=i")?;
+        set.init()?;
+        assert_eq!(set.find_val::<String>("--name")?, "env");
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from(["app", "--name=cli", "--count=1"]),
+        )?;
+        assert_eq!(set.find_val::<String>("--name")?, "cli");
+
+        // reset only clears the matched flag
+        set.reset()?;
+        assert!(!set.find("--name")?.matched());
+        assert_eq!(set.find_val::<String>("--name")?, "cli");
+
+        set.unset("--name")?;
+        let opt = set.find("--name")?;
+
+        assert!(!opt.matched());
+        assert_eq!(opt.accessor().occurrences(), 0);
+        assert!(opt.accessor().rawvals()?.is_empty());
+        assert!(set.find_val::<String>("--name").is_err());
+        assert_eq!(set.find_val::<i64>("--count")?, &1);
+
+        // the environment variable fallback is applied again
+        set.init()?;
+        assert_eq!(set.find_val::<String>("--name")?, "env");
+        assert!(set.unset("--missing").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_with_state() {
+        assert!(test_on_with_state_impl().is_ok());
+    }
+
+    fn test_on_with_state_impl() -> Result<(), crate::Error> {
+        #[derive(Debug, Default, PartialEq)]
+        struct Counter(i64);
+
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.set_app_data(Counter::default())?;
+        set.add_opt("-a=b")?
+            .on_with_state(|cnt: &mut Counter, _: &mut ASet, _: &Ctx| {
+                cnt.0 += 1;
+                Ok(Some(true))
+            })?;
+        set.add_opt("-b=i")?
+            .on_with_state(|cnt: &mut Counter, _: &mut ASet, ctx: &Ctx| {
+                let val = ctx.value::<i64>()?;
+
+                cnt.0 += val;
+                Ok(Some(val))
+            })?;
+        // register to invoker directly
+        let uid = set.add_opt("-c=b")?.run()?;
+
+        set.entry(uid)?.on(wrap_handler_state(
+            |cnt: &mut Counter, _: &mut ASet, _: &Ctx| {
+                cnt.0 *= 10;
+                Ok(Some(true))
+            },
+        ));
+        set.init()?;
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "-a", "-b=3", "-a", "-c"]))?;
+        assert_eq!(set.app_data::<Counter>()?, &Counter(50));
+        assert_eq!(set.find_val::<i64>("-b")?, &3);
+        assert!(*set.find_val::<bool>("-c")?);
+
+        // the state is missing
+        set.service_mut().sve_take_val::<Counter>()?;
+        assert!(PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "-a"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_action() {
+        assert!(test_default_action_impl().is_ok());
+    }
+
+    fn test_default_action_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        assert_eq!(set.default_action(), None);
+        set.set_default_action(Action::App);
+        set.add_opt("--count=i")?;
+        set.add_opt("--name=s")?.set_action(Action::Set);
+        set.add_opt("ls=c")?;
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from([
+                "app",
+                "ls",
+                "--count=1",
+                "--name=foo",
+                "--count=2",
+                "--name=bar",
+            ]),
+        )?;
+
+        assert_eq!(set.find("--count")?.action(), &Action::App);
+        assert_eq!(set.find_vals::<i64>("--count")?, &[1, 2]);
+        assert_eq!(set.find_vals::<String>("--name")?, &["bar"]);
+        assert_eq!(set.find("ls")?.action(), &Action::Set);
+        assert!(*set.find_val::<bool>("ls")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_post_build_check() {
+        assert!(test_post_build_check_impl().is_ok());
+    }
+
+    fn test_post_build_check_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--verbose=b")?;
+        set.add_post_build_check(|set| {
+            if ["--output", "--stdout"]
+                .iter()
+                .any(|name| set.find(*name).is_ok())
+            {
+                Ok(())
+            } else {
+                Err(crate::raise_error!(
+                    "at least one of `--output` or `--stdout` is required"
+                ))
+            }
+        });
+
+        let err = PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--verbose"]))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "at least one of `--output` or `--stdout` is required"
+        );
+        assert!(set.find_val::<bool>("--verbose").is_err());
+
+        set.add_opt("--stdout=b")?;
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--verbose", "--stdout"]))?;
+        assert!(*set.find_val::<bool>("--verbose")?);
+        assert!(*set.find_val::<bool>("--stdout")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_occurrences() {
+        assert!(test_occurrences_impl().is_ok());
+    }
+
+    fn test_occurrences_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--flag=b")?;
+        set.add_opt("--name=s")?.set_action(Action::App);
+        set.add_opt("--count=i")?;
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from(["app", "--flag", "--name=a", "--flag", "--name", "b"]),
+        )?;
+
+        assert_eq!(set.occurrences("--flag"), 2);
+        assert_eq!(set.find_val::<bool>("--flag")?, &true);
+        assert_eq!(set.find_vals::<bool>("--flag")?.len(), 1);
+        assert_eq!(set.occurrences("--name"), 2);
+        assert_eq!(set.occurrences("--count"), 0);
+        assert_eq!(set.occurrences("--unknown"), 0);
+
+        let snapshot = set.snapshot();
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app"]))?;
+        assert_eq!(set.occurrences("--flag"), 0);
+        set.restore(snapshot);
+        assert_eq!(set.occurrences("--flag"), 2);
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--flag"]))?;
+        assert_eq!(set.occurrences("--flag"), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_value_filter() {
+        assert!(test_global_value_filter_impl().is_ok());
+    }
+
+    fn test_global_value_filter_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--name=s")?;
+        set.add_opt("--path=s")?;
+        set.add_opt("file=p@1")?.set_pos_type::<String>();
+        set.set_global_value_filter(|raw| {
+            if raw.to_string_lossy().contains('\0') {
+                Err(crate::raise_error!("value {:?} contains null byte", raw))
+            } else {
+                Ok(())
+            }
+        });
+
+        let mut parse = |args: &[&str]| {
+            PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(args.iter().copied()))
+                .map(|ret| ret.status())
+        };
+
+        assert!(parse(&["app", "--name=a\0b"]).is_err());
+        assert!(parse(&["app", "--path", "\0"]).is_err());
+        assert!(parse(&["app", "in\0.txt"]).is_err());
+        assert!(parse(&["app", "--name=a", "--path", "b", "in.txt"])?);
+        assert_eq!(set.find_val::<String>("--name")?, "a");
+        assert_eq!(set.find_val::<String>("file")?, "in.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_indices() {
+        assert!(test_value_indices_impl().is_ok());
+    }
+
+    fn test_value_indices_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--include=s")?.set_action(Action::App);
+        set.add_opt("--debug=b")?;
+        set.add_opt("file=p@1..")?;
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from([
+                "app",
+                "--include",
+                "a",
+                "--debug",
+                "foo",
+                "--include=b",
+                "bar",
+                "--include",
+                "c",
+            ]),
+        )?;
+
+        assert_eq!(set.find_vals::<String>("--include")?, &["a", "b", "c"]);
+        assert_eq!(set.value_indices("--include"), vec![1, 5, 7]);
+        assert_eq!(set.value_indices("--debug"), vec![3]);
+        assert_eq!(set.value_indices("--unknown"), Vec::<usize>::new());
+
+        let snapshot = set.snapshot();
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app"]))?;
+        assert!(set.value_indices("--include").is_empty());
+        set.restore(snapshot);
+        assert_eq!(set.value_indices("--include"), vec![1, 5, 7]);
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--include=d"]))?;
+        assert_eq!(set.value_indices("--include"), vec![1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_borrow_value() {
+        assert!(test_borrow_value_impl().is_ok());
+    }
+
+    fn test_borrow_value_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+        let name = "a".repeat(1024);
+
+        set.add_opt("--name=s")?;
+        set.add_opt("--other=s")?;
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--name", &name]))?;
+
+        let borrowed = set.borrow_value::<String>("--name")?.unwrap();
+        let stored = set.find_val::<String>("--name")?;
+
+        // the value is borrowed from the set, not a copy of it
+        assert_eq!(borrowed, &name);
+        assert!(std::ptr::eq(borrowed, stored));
+        assert_eq!(borrowed.as_ptr(), stored.as_ptr());
+        assert_eq!(set.borrow_value::<String>("--other")?, None);
+        assert!(set.borrow_value::<i64>("--name").is_err());
+        assert!(set.borrow_value::<String>("--unknown").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_value() {
+        assert!(test_raw_value_impl().is_ok());
+    }
+
+    fn test_raw_value_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--num=i")?
+            .set_number_format(NumberFormat::Hex)
+            .run()?;
+        set.add_opt("--other=s")?;
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--num", "0xFF"]))?;
+
+        assert_eq!(set.find_val::<i64>("--num")?, &255);
+        assert_eq!(
+            set.raw_value("--num"),
+            Some(&std::ffi::OsString::from("0xFF"))
+        );
+        assert_eq!(set.raw_value("--other"), None);
+        assert_eq!(set.raw_value("--unknown"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_alias() {
+        assert!(test_on_alias_impl().is_ok());
+    }
+
+    fn test_on_alias_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--verbose=i")?
+            .add_alias("-v")
+            .set_action(Action::App)
+            .on_alias("-v", |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                Ok(Some(ctx.value::<i64>()? * 10))
+            })?
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| Ok(Some(ctx.value::<i64>()?)))?;
+        set.add_opt("--name=s")?
+            .add_alias("-n")
+            .on_alias("-n", |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                Ok(Some(ctx.value::<String>()?.to_uppercase()))
+            })?
+            .run()?;
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from(["app", "-v", "1", "--verbose", "2", "-v=3", "-n", "foo"]),
+        )?;
+
+        assert_eq!(set.find_vals::<i64>("--verbose")?, &[10, 2, 30]);
+        assert_eq!(set.find_val::<String>("--name")?, "FOO");
+
+        PolicyParser::<AFwdPolicy>::parse(&mut set, Args::from(["app", "--name", "bar"]))?;
+        assert_eq!(set.find_val::<String>("--name")?, "bar");
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge() {
+        assert!(test_merge_impl().is_ok());
+    }
+
+    fn test_merge_impl() -> Result<(), crate::Error> {
+        let mut set = HCOptSet::<ASet, AInvoker, ASer>::default();
+        let mut plugin = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        set.add_opt("--debug=b")?.add_alias("-d");
+        set.add_opt("--name=s")?;
+        plugin
+            .add_opt("--count=i")?
+            .add_alias("-c")
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| Ok(Some(ctx.value::<i64>()? * 2)))?;
+        plugin.reg_prefix("+")?;
+        plugin
+            .add_opt("--size=u")?
+            .add_alias("+s")
+            .on_alias("+s", |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                Ok(Some(ctx.value::<u64>()? + 1))
+            })?
+            .run()?;
+        set.merge(plugin)?;
+
+        assert_eq!(set.len(), 4);
+        assert!(set.prefixes().iter().any(|v| v == "+"));
+
+        PolicyParser::<AFwdPolicy>::parse(
+            &mut set,
+            Args::from(["app", "-d", "--name=foo", "-c", "21", "+s", "41"]),
+        )?;
+
+        assert!(*set.find_val::<bool>("--debug")?);
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert_eq!(set.find_val::<i64>("--count")?, &42);
+        assert_eq!(set.find_val::<u64>("--size")?, &42);
+
+        let mut plugin = HCOptSet::<ASet, AInvoker, ASer>::default();
+
+        plugin.add_opt("--verbose=b")?;
+        plugin.add_opt("--dry-run=b")?.add_alias("-d");
+
+        let err = set.merge(plugin).unwrap_err();
+
+        assert!(err.to_string().contains("`-d`"));
+        assert_eq!(set.len(), 4);
+        assert!(set.find_opt("--verbose").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_add_opt_from_config() {
+        assert!(test_add_opt_from_config_impl().is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    fn test_add_opt_from_config_impl() -> Result<(), crate::Error> {
+        let mut parser = AFwdParser::default();
+        let commit = parser
+            .add_opt("-c;--count=i")?
+            .set_force(true)
+            .set_help("Set the count")
+            .set_value_t(1i64);
+        // serialize the config of option committed to parser
+        let json = serde_json::to_string(commit.cfg()).map_err(|e| crate::raise_error!("{e}"))?;
+
+        commit.run()?;
+        assert!(parser.parse(Args::from(["app", "-c", "42"]))?.status());
+        assert_eq!(parser.find_val::<i64>("--count")?, &42);
+
+        let from_json = || -> Result<OptConfig, crate::Error> {
+            serde_json::from_str(&json).map_err(|e| crate::raise_error!("{e}"))
+        };
+        let config = from_json()?;
+
+        assert_eq!(config.ctor(), Some("i"));
+        assert_eq!(config.name(), Some("-c"));
+        assert_eq!(config.alias(), Some(&vec!["--count".to_owned()]));
+        assert_eq!(config.help(), Some("Set the count"));
+        assert_eq!(config.force(), Some(true));
+        // the default value is not serialized
+        assert!(config.initializer().is_none());
+
+        let parse =
+            |config: OptConfig, args: &[&str]| -> Result<(bool, AFwdParser), crate::Error> {
+                let mut parser = AFwdParser::default();
+
+                parser.add_opt_from_config(config)?;
+                let ret = parser.parse(Args::from(args))?;
+
+                Ok((ret.status(), parser))
+            };
+        let (status, parser) = parse(config, &["app", "-c", "42"])?;
+
+        assert!(status);
+        assert_eq!(parser.find_val::<i64>("--count")?, &42);
+        assert_eq!(parser.find_opt("--count")?.help(), "Set the count");
+        // the force is kept
+        assert!(!parse(from_json()?, &["app"])?.0);
+
+        // the default value need be set again after deserialized
+        let config = from_json()?
+            .with_force(false)
+            .with_initializer(ValInitializer::new_value(1i64));
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt_from_config(config)?;
+        assert!(parser.parse(Args::from(["app"]))?.status());
+        assert_eq!(parser.find_val::<i64>("--count")?, &1);
+
+        let mut parser = AFwdParser::default();
+
+        assert!(parser
+            .add_opt_from_config(OptConfig::default().with_ctor("i"))
+            .is_err());
+        assert!(parser
+            .add_opt_from_config(OptConfig::default().with_name("--count"))
+            .is_err());
+        Ok(())
+    }
 }
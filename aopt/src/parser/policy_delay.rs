@@ -1,12 +1,41 @@
 use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use super::bare_prefix;
+use super::check_ambiguity;
+use super::check_before_invoke;
+use super::check_conflicts_with_positional;
+use super::check_deprecated_alias;
+use super::check_max_positionals;
+use super::check_option_name;
+use super::check_standalone;
+use super::check_unexpanded;
+use super::check_value_count;
+use super::check_values;
+use super::deferred_style;
+use super::expand_abbrev_cmd;
+use super::greedy_opt;
+use super::greedy_value;
+use super::guess_trace;
+use super::prompt_missing;
+use super::store_trailing_pos;
+use super::store_trailing_raw;
+use super::toggle_arg;
+use super::trailing_raw_cmd;
+use super::trailing_raw_pos;
 use super::OptStyleManager;
+use super::ParseStatus;
 use super::Policy;
 use super::PolicySettings;
+use super::PromptHandler;
 use super::Return;
+use super::UnknownAction;
+use super::UnknownHandler;
 use super::UserStyle;
+use super::Warning;
+use super::STOP;
 use crate::args;
 use crate::args::ArgInfo;
 use crate::args::Args;
@@ -48,11 +77,37 @@ pub struct DelayCtxSaver<'a> {
     pub delay_ctx: Vec<DelayCtx<'a>>,
 }
 
+impl DelayCtxSaver<'_> {
+    /// Return the highest [`priority`](Opt::priority) of options in the context.
+    pub fn priority<S>(&self, set: &S) -> i32
+    where
+        S: crate::set::Set,
+        SetOpt<S>: Opt,
+    {
+        self.delay_ctx
+            .iter()
+            .flat_map(|delay_ctx| delay_ctx.uids.iter())
+            .filter_map(|uid| set.get(*uid).map(|opt| opt.priority()))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Return the smallest [`Uid`] of options in the context, which is the declaration order.
+    pub fn uid(&self) -> Uid {
+        self.delay_ctx
+            .iter()
+            .flat_map(|delay_ctx| delay_ctx.uids.iter().copied())
+            .min()
+            .unwrap_or(Uid::MAX)
+    }
+}
+
 /// [`DelayPolicy`] matching the command line arguments with [`Opt`] in the [`Set`](crate::set::Set).
 /// The option would match failed if any special [`Error`] raised during option processing.
 /// [`DelayPolicy`] will return Some([`Return`]) if match successful.
 /// [`DelayPolicy`] processes the option first but does not invoke the handler of option.
-/// The handler will be called after [`Cmd`](crate::opt::Style::Cmd) NOA and [`Pos`](crate::opt::Style::Pos) NOA processed.
+/// The handler will be called after [`Cmd`](crate::opt::Style::Cmd) NOA and [`Pos`](crate::opt::Style::Pos) NOA processed,
+/// in the descending order of [`priority`](Opt::priority), and the declaration order of options for the same priority.
 /// In last, [`DelayPolicy`] will process [`Main`](crate::opt::Style::Main) NOA.
 /// During parsing, you can't get the value of any option in the handler of NOA.
 ///
@@ -117,7 +172,7 @@ pub struct DelayCtxSaver<'a> {
 ///         Ok(Some(true))
 ///     })?;
 ///
-/// getopt!(Args::from_env(), &mut parser)?;
+/// getopt!(Args::from(["app", "--dir", "."]), &mut parser)?;
 /// #
 /// # Ok(())
 /// # }
@@ -127,6 +182,22 @@ pub struct DelayPolicy<Set, Ser, Chk> {
 
     overload: bool,
 
+    max_positionals: Option<usize>,
+
+    abbrev_subcommand: bool,
+
+    intermixed: bool,
+
+    option_terminator: Option<String>,
+
+    diagnostics: bool,
+
+    parallel_checks: bool,
+
+    prompt_handler: Option<PromptHandler>,
+
+    unknown_handler: Option<UnknownHandler>,
+
     checker: Chk,
 
     style_manager: OptStyleManager,
@@ -144,6 +215,14 @@ where
         Self {
             strict: self.strict,
             overload: self.overload,
+            max_positionals: self.max_positionals,
+            abbrev_subcommand: self.abbrev_subcommand,
+            intermixed: self.intermixed,
+            option_terminator: self.option_terminator.clone(),
+            diagnostics: self.diagnostics,
+            parallel_checks: self.parallel_checks,
+            prompt_handler: self.prompt_handler.clone(),
+            unknown_handler: self.unknown_handler.clone(),
             checker: self.checker.clone(),
             style_manager: self.style_manager.clone(),
             no_delay_opt: self.no_delay_opt.clone(),
@@ -160,6 +239,14 @@ where
         f.debug_struct("DelayPolicy")
             .field("strict", &self.strict)
             .field("overload", &self.overload)
+            .field("max_positionals", &self.max_positionals)
+            .field("abbrev_subcommand", &self.abbrev_subcommand)
+            .field("intermixed", &self.intermixed)
+            .field("option_terminator", &self.option_terminator)
+            .field("diagnostics", &self.diagnostics)
+            .field("parallel_checks", &self.parallel_checks)
+            .field("prompt_handler", &self.prompt_handler.is_some())
+            .field("unknown_handler", &self.unknown_handler.is_some())
             .field("checker", &self.checker)
             .field("style_manager", &self.style_manager)
             .field("no_delay_opt", &self.no_delay_opt)
@@ -175,6 +262,14 @@ where
         Self {
             strict: true,
             overload: false,
+            max_positionals: None,
+            abbrev_subcommand: false,
+            intermixed: true,
+            option_terminator: None,
+            diagnostics: false,
+            parallel_checks: false,
+            prompt_handler: None,
+            unknown_handler: None,
             checker: Chk::default(),
             style_manager: OptStyleManager::default(),
             no_delay_opt: vec![],
@@ -223,6 +318,46 @@ impl<Set, Ser, Chk> DelayPolicy<Set, Ser, Chk> {
         self
     }
 
+    pub fn with_max_positionals(mut self, max: Option<usize>) -> Self {
+        self.max_positionals = max;
+        self
+    }
+
+    pub fn with_abbrev_subcommand(mut self, allow: bool) -> Self {
+        self.abbrev_subcommand = allow;
+        self
+    }
+
+    pub fn with_intermixed(mut self, intermixed: bool) -> Self {
+        self.intermixed = intermixed;
+        self
+    }
+
+    pub fn with_option_terminator(mut self, terminator: Option<String>) -> Self {
+        self.option_terminator = terminator;
+        self
+    }
+
+    pub fn with_diagnostics(mut self, diagnostics: bool) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    pub fn with_parallel_checks(mut self, parallel: bool) -> Self {
+        self.parallel_checks = parallel;
+        self
+    }
+
+    pub fn with_prompt_handler(mut self, handler: Option<PromptHandler>) -> Self {
+        self.prompt_handler = handler;
+        self
+    }
+
+    pub fn with_unknown_handler(mut self, handler: Option<UnknownHandler>) -> Self {
+        self.unknown_handler = handler;
+        self
+    }
+
     pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
         self.checker = checker;
         self
@@ -274,6 +409,22 @@ impl<Set, Ser, Chk> PolicySettings for DelayPolicy<Set, Ser, Chk> {
         self.overload
     }
 
+    fn max_positionals(&self) -> Option<usize> {
+        self.max_positionals
+    }
+
+    fn allow_abbrev_subcommand(&self) -> bool {
+        self.abbrev_subcommand
+    }
+
+    fn intermixed(&self) -> bool {
+        self.intermixed
+    }
+
+    fn option_terminator(&self) -> &str {
+        self.option_terminator.as_deref().unwrap_or(STOP)
+    }
+
     fn set_strict(&mut self, strict: bool) -> &mut Self {
         self.strict = strict;
         self
@@ -293,6 +444,54 @@ impl<Set, Ser, Chk> PolicySettings for DelayPolicy<Set, Ser, Chk> {
         self.overload = overload;
         self
     }
+
+    fn set_max_positionals(&mut self, max: Option<usize>) -> &mut Self {
+        self.max_positionals = max;
+        self
+    }
+
+    fn set_allow_abbrev_subcommand(&mut self, allow: bool) -> &mut Self {
+        self.abbrev_subcommand = allow;
+        self
+    }
+
+    fn set_intermixed(&mut self, intermixed: bool) -> &mut Self {
+        self.intermixed = intermixed;
+        self
+    }
+
+    fn set_option_terminator(&mut self, terminator: Option<String>) -> &mut Self {
+        self.option_terminator = terminator;
+        self
+    }
+
+    fn diagnostics(&self) -> bool {
+        self.diagnostics
+    }
+
+    fn set_diagnostics(&mut self, diagnostics: bool) -> &mut Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    fn parallel_checks(&self) -> bool {
+        self.parallel_checks
+    }
+
+    fn set_parallel_checks(&mut self, parallel: bool) -> &mut Self {
+        self.parallel_checks = parallel;
+        self
+    }
+
+    fn set_prompt_handler(&mut self, handler: Option<PromptHandler>) -> &mut Self {
+        self.prompt_handler = handler;
+        self
+    }
+
+    fn set_unknown_handler(&mut self, handler: Option<UnknownHandler>) -> &mut Self {
+        self.unknown_handler = handler;
+        self
+    }
 }
 
 impl<Set, Ser, Chk> DelayPolicy<Set, Ser, Chk>
@@ -317,14 +516,22 @@ where
     where
         Inv: HandlerCollection<'b, Set, Ser>,
     {
+        let deferred = deferred_style(ctx, inner_ctx.style());
+
+        ctx.set_inner_ctx(Some(inner_ctx.with_uid(uid)));
+        check_before_invoke(set, inv, ctx, uid)?;
+        let ret = inv.invoke_fb(&uid, set, ser, ctx);
+
+        check_unexpanded(ctx)?;
         let fail = |e: Error| {
-            fail.push(e);
+            fail.push_ctx(e, ctx, set);
             Ok(())
         };
+        let ret = process_handler_ret(ret, |_| Ok(()), fail)?;
 
-        ctx.set_inner_ctx(Some(inner_ctx.with_uid(uid)));
-        let ret = process_handler_ret(inv.invoke_fb(&uid, set, ser, ctx), |_| Ok(()), fail)?;
-
+        if ret && deferred {
+            ctx.add_pending_check(uid);
+        }
         set.opt_mut(uid)?.set_matched(ret);
         Ok(ret)
     }
@@ -464,6 +671,7 @@ where
         self.checker().pre_check(set).map_err(|e| e.into())?;
 
         let overload = self.overload();
+        let diagnostics = self.diagnostics();
         let opt_styles = self.style_manager.clone();
         let args: Vec<_> = orig.iter().map(|v| v.as_os_str()).collect();
         let total = args.len();
@@ -471,6 +679,7 @@ where
         let mut lefts = vec![];
         let mut opt_fail = FailManager::default();
         let mut iter2 = args::iter2(&args).enumerate();
+        let mut greedy = None;
 
         trace!("parsing {ctx:?} using delay policy");
         // set option args, and args length
@@ -479,16 +688,63 @@ where
             let mut matched = false;
             let mut consume = false;
             let mut stopped = false;
+            let mut like_opt = false;
+            let mut dropped = false;
+
+            // collect the rest arguments verbatim if the command has trailing raw
+            if lefts.len() == Self::noa_cmd() {
+                if let Some(uid) = trailing_raw_cmd(set, opt) {
+                    lefts.push(*opt);
+                    store_trailing_raw(
+                        set,
+                        uid,
+                        self.option_terminator(),
+                        iter2.map(|(_, (a, _))| *a),
+                    );
+                    break;
+                }
+            }
+            // collect the rest arguments if any positional argument has trailing raw
+            if OsStr::new(self.option_terminator()) == *opt {
+                if let Some(uid) = trailing_raw_pos(set) {
+                    store_trailing_pos(set, uid, iter2.map(|(_, (a, _))| *a))?;
+                    break;
+                }
+            }
+            // the custom option terminator stops the option parsing
+            if self.option_terminator() != STOP && OsStr::new(self.option_terminator()) == *opt {
+                // skip current, put left argument to noa args
+                lefts.extend(iter2.map(|(_, (a, _))| *a));
+                break;
+            }
 
             // parsing current argument
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
+            // the argument is a value of previous greedy option
+            let info = match greedy.take().and_then(|name| {
+                let terminator = self.option_terminator();
+
+                greedy_value(set, &opt_styles, terminator, name, opt)
+            }) {
+                Some(info) => Ok(info),
+                None => {
+                    if self.strict() {
+                        check_option_name(opt, opt_styles.assign_char())?;
+                    }
+                    opt_styles.parse_arg(opt).map(|info| toggle_arg(set, info))
+                }
+            };
+
+            if let Ok(ArgInfo { name, value }) = info {
                 trace!(
                     "guess name: {:?} value: {:?} & next: {:?}",
                     name,
                     value,
                     next
                 );
-                if set.check(&name).map_err(Into::into)? {
+                let valid = set.check(&name).map_err(Into::into)? && !bare_prefix(set, &name);
+
+                if valid || opt_styles.has_custom() {
+                    like_opt = valid;
                     let arg = value.clone();
                     let next = next.map(|v| Cow::Borrowed(*v));
                     let mut guess = InvokeGuess {
@@ -504,8 +760,35 @@ where
                         name: Some(name.clone()),
                     };
 
+                    let mut tried = vec![];
+
+                    let prefix = match valid {
+                        true => guess.set.split(&name).map_err(Into::into)?.0,
+                        false => Cow::Borrowed(""),
+                    };
+
+                    if valid && guess.arg.is_none() {
+                        check_ambiguity(guess.set, guess.ctx, &opt_styles, &prefix, &name)?;
+                    }
+
                     for style in opt_styles.iter() {
-                        if let Some(ret) = guess.guess_and_collect(style, overload)? {
+                        // only the custom styles are guessed if it not like an option
+                        if !opt_styles.is_enabled_for(style, &prefix)
+                            || !(valid || matches!(style, UserStyle::Custom(_)))
+                        {
+                            continue;
+                        }
+                        if diagnostics {
+                            tried.push(style.clone());
+                        }
+                        let ret = match opt_styles.guess_custom(style, opt, guess.set)? {
+                            Some(custom) => guess.with_custom(custom, |guess, style| {
+                                guess.guess_and_collect(style, overload)
+                            })?,
+                            None => guess.guess_and_collect(style, overload)?,
+                        };
+
+                        if let Some(ret) = ret {
                             // pretend we are matched, cause it is delay
                             matched = true;
                             consume = ret.consume;
@@ -527,14 +810,37 @@ where
                             Action::Null => {}
                         }
                     }
-                    if !stopped && !matched && self.strict() {
-                        return Err(opt_fail.cause(Error::sp_not_found(name)));
+                    if diagnostics && (valid || matched) {
+                        guess.ctx.add_guess_trace(guess_trace(opt, tried, matched));
+                    }
+                    if matched {
+                        check_deprecated_alias(guess.set, guess.ctx, &name);
+                        if greedy_opt(guess.set, &name) {
+                            greedy = Some(name.clone());
+                        }
+                    } else if !stopped && valid {
+                        if self.strict() {
+                            return Err(opt_fail.cause(Error::sp_not_found(name)));
+                        }
+                        match self.unknown_handler.as_ref().map(|handler| handler(opt)) {
+                            Some(UnknownAction::Drop) => dropped = true,
+                            Some(UnknownAction::Error) => {
+                                return Err(opt_fail.cause(Error::sp_not_found(name)));
+                            }
+                            _ => {
+                                guess.ctx.add_warning(Warning::UnknownOption {
+                                    name: name.to_string(),
+                                });
+                            }
+                        }
                     }
                 } else {
                     trace!("`{:?}` not like option", opt);
                 }
             }
-            if stopped {
+            // the default terminator `--` not matched by Stop option stops the option parsing
+            if stopped || (!matched && OsStr::new(STOP) == *opt && self.option_terminator() == STOP)
+            {
                 // skip current, put left argument to noa args
                 lefts.extend(iter2.map(|(_, (a, _))| *a));
                 break;
@@ -542,9 +848,14 @@ where
             // if consume the argument, skip it
             if matched && consume {
                 iter2.next();
-            } else if !matched {
+            } else if !matched && !dropped {
                 // add it to NOA if current argument not matched
                 lefts.push(*opt);
+                if !like_opt && !self.intermixed() && lefts.len() > Self::noa_cmd() {
+                    // the first positional argument stops the option parsing
+                    lefts.extend(iter2.map(|(_, (a, _))| *a));
+                    break;
+                }
             }
         }
 
@@ -557,7 +868,11 @@ where
         ctx.set_args(args.clone());
         // when style is pos, noa index is [1..=len]
         if total > 0 {
-            let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+            let mut name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+
+            if self.allow_abbrev_subcommand() {
+                name = expand_abbrev_cmd(set, name)?;
+            }
             let mut guess = InvokeGuess {
                 set,
                 inv,
@@ -572,7 +887,15 @@ where
             };
 
             trace!("guess Cmd = {:?}", guess.name);
-            guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
+            let ret = guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
+
+            if let Some(name) = guess
+                .name
+                .as_deref()
+                .filter(|_| ret.is_some_and(|v| v.matched))
+            {
+                check_deprecated_alias(guess.set, guess.ctx, name);
+            }
             if let Action::Quit = ctx.policy_act() {
                 return Ok(());
             }
@@ -610,6 +933,8 @@ where
         }
 
         trace!("in delay policy, invoke the handler of option");
+        // the handler with higher priority invoked first, keep the declaration order if same
+        contexts.sort_by_cached_key(|saver| (std::cmp::Reverse(saver.priority(set)), saver.uid()));
         // after cmd and pos callback invoked, invoke the callback of option
         for saver in contexts {
             let ret = self.process_delay_ctx(&mut prev_ctx, set, inv, ser, &mut opt_fail, saver)?;
@@ -630,8 +955,16 @@ where
             }
         }
 
+        prompt_missing(set, self.prompt_handler.as_ref())?;
         opt_fail.process_check(self.checker().opt_check(set))?;
+        if self.parallel_checks() {
+            check_values(set, &prev_ctx.take_pending_check())?;
+        }
         pos_fail.process_check(self.checker().pos_check(set))?;
+        check_max_positionals(set, self.max_positionals(), total)?;
+        check_conflicts_with_positional(set, total)?;
+        check_standalone(set, total)?;
+        check_value_count(set)?;
 
         let name = crate::str::osstr_to_str_i(&ctx.args, Self::noa_main());
         let mut main_fail = FailManager::default();
@@ -679,10 +1012,16 @@ where
         ser: &mut Self::Ser,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
+        let mut ctx = Ctx::default()
+            .with_orig(orig.clone())
+            .with_deferred_check(self.parallel_checks())
+            .with_overload(self.overload());
 
         match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
-            Ok(_) => Ok(Return::new(ctx)),
+            Ok(_) => {
+                check_unexpanded(&ctx)?;
+                Ok(Return::new(ctx).with_parse_status(ParseStatus::of(set)))
+            }
             Err(e) => {
                 if e.is_failure() {
                     Ok(Return::new(ctx).with_failure(e))
@@ -787,7 +1126,8 @@ mod test {
         let mut policy = ADelayPolicy::default();
         let mut ser = policy.default_ser();
         let mut inv = policy.default_inv();
-        let mut set = policy.default_set();
+        // `+>` is the alias of both `--positive` and `--bigger-than`
+        let mut set = policy.default_set().with_check_duplicate(false);
 
         let args = Args::from([
             "app",
@@ -882,4 +1222,99 @@ mod test {
         assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
         Ok(())
     }
+
+    #[test]
+    fn test_priority() {
+        assert!(test_priority_impl().is_ok());
+    }
+
+    fn test_priority_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<Vec<String>, Error> {
+            let mut policy = ADelayPolicy::default();
+            let mut set = policy.default_set();
+            let mut inv = policy.default_inv();
+            let mut ser = policy.default_ser();
+            let record = |set: &mut ASet, ser: &mut ASer, ctx: &Ctx| {
+                let uid = ctx.uid()?;
+
+                ser.sve_val_mut::<Vec<String>>()?
+                    .push(set.opt(uid)?.name().to_owned());
+                Ok(Some(true))
+            };
+
+            ser.sve_insert(Vec::<String>::new());
+            inv.entry(set.add_opt("--verbose=b")?.run()?).on(record);
+            inv.entry(set.add_opt("--debug=b")?.run()?).on(record);
+            inv.entry(set.add_opt("--output=s")?.set_priority(-1).run()?)
+                .on(record);
+            inv.entry(set.add_opt("--config=s")?.set_priority(10).run()?)
+                .on(record);
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+            assert!(policy
+                .parse(&mut set, &mut inv, &mut ser, Args::from(args))?
+                .status());
+            ser.sve_take_val::<Vec<String>>()
+        }
+
+        assert_eq!(
+            parse(&["app", "--output=a", "--verbose", "--config=b"])?,
+            ["--config", "--verbose", "--output"]
+        );
+        assert_eq!(
+            parse(&["app", "--config=b", "--output=a", "--verbose"])?,
+            ["--config", "--verbose", "--output"]
+        );
+        // the options with same priority keep the declaration order
+        assert_eq!(
+            parse(&["app", "--debug", "--output=a", "--verbose", "--config=b"])?,
+            ["--config", "--verbose", "--debug", "--output"]
+        );
+        assert_eq!(
+            parse(&[
+                "app",
+                "--verbose",
+                "--config=b",
+                "--debug",
+                "--output=a",
+                "--verbose"
+            ])?,
+            ["--config", "--verbose", "--verbose", "--debug", "--output"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand_args() {
+        assert!(test_expand_args_impl().is_ok());
+    }
+
+    fn test_expand_args_impl() -> Result<(), Error> {
+        let mut policy = ADelayPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        inv.entry(set.add_opt("--preset=s")?.run()?)
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                ctx.expand_args(["--threads", "8"]);
+                Ok(Some(ctx.value::<String>()?))
+            });
+        set.add_opt("--threads=i")?.run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        // the expansion is not supported by delay policy
+        let ret = policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "--preset", "fast"]),
+        );
+
+        assert!(ret.is_err_and(|e| e.to_string().contains("can only be expanded")));
+        Ok(())
+    }
 }
@@ -1,13 +1,43 @@
 use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use super::bare_prefix;
+use super::check_ambiguity;
+use super::check_conflicts_with_positional;
+use super::check_deprecated_alias;
+use super::check_expansion;
+use super::check_max_positionals;
+use super::check_option_name;
+use super::check_standalone;
+use super::check_unexpanded;
+use super::check_value_count;
+use super::check_values;
+use super::expand_abbrev_cmd;
+use super::greedy_opt;
+use super::greedy_value;
+use super::guess_trace;
+use super::prompt_missing;
+use super::splice_args;
+use super::store_trailing_pos;
+use super::store_trailing_raw;
+use super::toggle_arg;
+use super::trailing_raw_cmd;
+use super::trailing_raw_pos;
 use super::FailManager;
 use super::OptStyleManager;
+use super::ParseStatus;
 use super::Policy;
 use super::PolicySettings;
+use super::PromptHandler;
 use super::Return;
+use super::UnknownAction;
+use super::UnknownHandler;
 use super::UserStyle;
+use super::Warning;
+use super::Warnings;
+use super::STOP;
 use crate::args;
 use crate::args::ArgInfo;
 use crate::args::Args;
@@ -96,6 +126,22 @@ pub struct FwdPolicy<Set, Ser, Chk> {
 
     overload: bool,
 
+    max_positionals: Option<usize>,
+
+    abbrev_subcommand: bool,
+
+    intermixed: bool,
+
+    option_terminator: Option<String>,
+
+    diagnostics: bool,
+
+    parallel_checks: bool,
+
+    prompt_handler: Option<PromptHandler>,
+
+    unknown_handler: Option<UnknownHandler>,
+
     checker: Chk,
 
     style_manager: OptStyleManager,
@@ -111,6 +157,14 @@ where
         Self {
             strict: self.strict,
             overload: self.overload,
+            max_positionals: self.max_positionals,
+            abbrev_subcommand: self.abbrev_subcommand,
+            intermixed: self.intermixed,
+            option_terminator: self.option_terminator.clone(),
+            diagnostics: self.diagnostics,
+            parallel_checks: self.parallel_checks,
+            prompt_handler: self.prompt_handler.clone(),
+            unknown_handler: self.unknown_handler.clone(),
             checker: self.checker.clone(),
             style_manager: self.style_manager.clone(),
             marker_s: self.marker_s,
@@ -126,6 +180,14 @@ where
         f.debug_struct("FwdPolicy")
             .field("strict", &self.strict)
             .field("overload", &self.overload)
+            .field("max_positionals", &self.max_positionals)
+            .field("abbrev_subcommand", &self.abbrev_subcommand)
+            .field("intermixed", &self.intermixed)
+            .field("option_terminator", &self.option_terminator)
+            .field("diagnostics", &self.diagnostics)
+            .field("parallel_checks", &self.parallel_checks)
+            .field("prompt_handler", &self.prompt_handler.is_some())
+            .field("unknown_handler", &self.unknown_handler.is_some())
             .field("checker", &self.checker)
             .field("style_manager", &self.style_manager)
             .finish()
@@ -140,6 +202,14 @@ where
         Self {
             strict: true,
             overload: false,
+            max_positionals: None,
+            abbrev_subcommand: false,
+            intermixed: true,
+            option_terminator: None,
+            diagnostics: false,
+            parallel_checks: false,
+            prompt_handler: None,
+            unknown_handler: None,
             style_manager: OptStyleManager::default(),
             checker: Chk::default(),
             marker_s: PhantomData,
@@ -183,6 +253,46 @@ impl<Set, Ser, Chk> FwdPolicy<Set, Ser, Chk> {
         self
     }
 
+    pub fn with_max_positionals(mut self, max: Option<usize>) -> Self {
+        self.max_positionals = max;
+        self
+    }
+
+    pub fn with_abbrev_subcommand(mut self, allow: bool) -> Self {
+        self.abbrev_subcommand = allow;
+        self
+    }
+
+    pub fn with_intermixed(mut self, intermixed: bool) -> Self {
+        self.intermixed = intermixed;
+        self
+    }
+
+    pub fn with_option_terminator(mut self, terminator: Option<String>) -> Self {
+        self.option_terminator = terminator;
+        self
+    }
+
+    pub fn with_diagnostics(mut self, diagnostics: bool) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    pub fn with_parallel_checks(mut self, parallel: bool) -> Self {
+        self.parallel_checks = parallel;
+        self
+    }
+
+    pub fn with_prompt_handler(mut self, handler: Option<PromptHandler>) -> Self {
+        self.prompt_handler = handler;
+        self
+    }
+
+    pub fn with_unknown_handler(mut self, handler: Option<UnknownHandler>) -> Self {
+        self.unknown_handler = handler;
+        self
+    }
+
     pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
         self.checker = checker;
         self
@@ -234,6 +344,22 @@ impl<Set, Ser, Chk> PolicySettings for FwdPolicy<Set, Ser, Chk> {
         self.overload
     }
 
+    fn max_positionals(&self) -> Option<usize> {
+        self.max_positionals
+    }
+
+    fn allow_abbrev_subcommand(&self) -> bool {
+        self.abbrev_subcommand
+    }
+
+    fn intermixed(&self) -> bool {
+        self.intermixed
+    }
+
+    fn option_terminator(&self) -> &str {
+        self.option_terminator.as_deref().unwrap_or(STOP)
+    }
+
     fn set_strict(&mut self, strict: bool) -> &mut Self {
         self.strict = strict;
         self
@@ -252,6 +378,54 @@ impl<Set, Ser, Chk> PolicySettings for FwdPolicy<Set, Ser, Chk> {
         self.overload = overload;
         self
     }
+
+    fn set_max_positionals(&mut self, max: Option<usize>) -> &mut Self {
+        self.max_positionals = max;
+        self
+    }
+
+    fn set_allow_abbrev_subcommand(&mut self, allow: bool) -> &mut Self {
+        self.abbrev_subcommand = allow;
+        self
+    }
+
+    fn set_intermixed(&mut self, intermixed: bool) -> &mut Self {
+        self.intermixed = intermixed;
+        self
+    }
+
+    fn set_option_terminator(&mut self, terminator: Option<String>) -> &mut Self {
+        self.option_terminator = terminator;
+        self
+    }
+
+    fn diagnostics(&self) -> bool {
+        self.diagnostics
+    }
+
+    fn set_diagnostics(&mut self, diagnostics: bool) -> &mut Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    fn parallel_checks(&self) -> bool {
+        self.parallel_checks
+    }
+
+    fn set_parallel_checks(&mut self, parallel: bool) -> &mut Self {
+        self.parallel_checks = parallel;
+        self
+    }
+
+    fn set_prompt_handler(&mut self, handler: Option<PromptHandler>) -> &mut Self {
+        self.prompt_handler = handler;
+        self
+    }
+
+    fn set_unknown_handler(&mut self, handler: Option<UnknownHandler>) -> &mut Self {
+        self.unknown_handler = handler;
+        self
+    }
 }
 
 impl<Set, Ser, Chk> FwdPolicy<Set, Ser, Chk>
@@ -267,16 +441,18 @@ where
         ser: &mut <Self as Policy>::Ser,
         orig: &'a Args,
         ctx: &mut Ctx<'a>,
-    ) -> Result<(), <Self as Policy>::Error> {
+    ) -> Result<Option<Args>, <Self as Policy>::Error> {
         self.checker().pre_check(set).map_err(|e| e.into())?;
 
         let overload = self.overload();
+        let diagnostics = self.diagnostics();
         let opt_styles = &self.style_manager;
         let args: Vec<_> = orig.iter().map(|v| v.as_os_str()).collect();
         let total = args.len();
         let mut lefts = vec![];
         let mut opt_fail = FailManager::default();
         let mut iter2 = args::iter2(&args).enumerate();
+        let mut greedy = None;
 
         trace!("parsing {ctx:?} using fwd policy");
         ctx.set_args(args.clone());
@@ -284,15 +460,63 @@ where
             let mut matched = false;
             let mut consume = false;
             let mut stopped = false;
+            let mut like_opt = false;
+            let mut dropped = false;
+            let mut expansion = None;
+
+            // collect the rest arguments verbatim if the command has trailing raw
+            if lefts.len() == Self::noa_cmd() {
+                if let Some(uid) = trailing_raw_cmd(set, opt) {
+                    lefts.push(*opt);
+                    store_trailing_raw(
+                        set,
+                        uid,
+                        self.option_terminator(),
+                        iter2.map(|(_, (a, _))| *a),
+                    );
+                    break;
+                }
+            }
+            // collect the rest arguments if any positional argument has trailing raw
+            if OsStr::new(self.option_terminator()) == *opt {
+                if let Some(uid) = trailing_raw_pos(set) {
+                    store_trailing_pos(set, uid, iter2.map(|(_, (a, _))| *a))?;
+                    break;
+                }
+            }
+            // the custom option terminator stops the option parsing
+            if self.option_terminator() != STOP && OsStr::new(self.option_terminator()) == *opt {
+                // skip current, put left argument to noa args
+                lefts.extend(iter2.map(|(_, (a, _))| *a));
+                break;
+            }
+
+            // the argument is a value of previous greedy option
+            let info = match greedy.take().and_then(|name| {
+                let terminator = self.option_terminator();
+
+                greedy_value(set, opt_styles, terminator, name, opt)
+            }) {
+                Some(info) => Ok(info),
+                None => {
+                    if self.strict() {
+                        check_option_name(opt, opt_styles.assign_char())?;
+                    }
+                    opt_styles.parse_arg(opt).map(|info| toggle_arg(set, info))
+                }
+            };
 
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
+            if let Ok(ArgInfo { name, value }) = info {
                 trace!(
                     "guess name: {:?} value: {:?} & next: {:?}",
                     name,
                     value,
                     next
                 );
-                if set.check(&name).map_err(Into::into)? {
+                let valid = set.check(&name).map_err(Into::into)? && !bare_prefix(set, &name);
+
+                if valid || opt_styles.has_custom() {
+                    like_opt = valid;
                     let arg = value.clone();
                     let next = next.map(|v| Cow::Borrowed(*v));
                     let mut guess = InvokeGuess {
@@ -308,8 +532,35 @@ where
                         name: Some(name.clone()),
                     };
 
+                    let mut tried = vec![];
+
+                    let prefix = match valid {
+                        true => guess.set.split(&name).map_err(Into::into)?.0,
+                        false => Cow::Borrowed(""),
+                    };
+
+                    if valid && guess.arg.is_none() {
+                        check_ambiguity(guess.set, guess.ctx, opt_styles, &prefix, &name)?;
+                    }
+
                     for style in opt_styles.iter() {
-                        if let Some(ret) = guess.guess_and_invoke(style, overload)? {
+                        // only the custom styles are guessed if it not like an option
+                        if !opt_styles.is_enabled_for(style, &prefix)
+                            || !(valid || matches!(style, UserStyle::Custom(_)))
+                        {
+                            continue;
+                        }
+                        if diagnostics {
+                            tried.push(style.clone());
+                        }
+                        let ret = match opt_styles.guess_custom(style, opt, guess.set)? {
+                            Some(custom) => guess.with_custom(custom, |guess, style| {
+                                guess.guess_and_invoke(style, overload)
+                            })?,
+                            None => guess.guess_and_invoke(style, overload)?,
+                        };
+
+                        if let Some(ret) = ret {
                             (matched, consume) = (ret.matched, ret.consume);
                         }
                         match guess.ctx.policy_act() {
@@ -318,21 +569,46 @@ where
                                 guess.ctx.reset_policy_act();
                                 break;
                             }
-                            Action::Quit => return Ok(()),
+                            Action::Quit => return Ok(None),
                             Action::Null => {}
                         }
                         if matched {
                             break;
                         }
                     }
-                    if !stopped && !matched && self.strict() {
-                        return Err(opt_fail.cause(Error::sp_not_found(name)));
+                    if diagnostics && (valid || matched) {
+                        guess.ctx.add_guess_trace(guess_trace(opt, tried, matched));
+                    }
+                    // the expansion of unmatched handler is dropped
+                    expansion = guess.ctx.take_expansion().filter(|_| matched);
+                    if matched {
+                        check_deprecated_alias(guess.set, guess.ctx, &name);
+                        if greedy_opt(guess.set, &name) {
+                            greedy = Some(name.clone());
+                        }
+                    } else if !stopped && valid {
+                        if self.strict() {
+                            return Err(opt_fail.cause(Error::sp_not_found(name)));
+                        }
+                        match self.unknown_handler.as_ref().map(|handler| handler(opt)) {
+                            Some(UnknownAction::Drop) => dropped = true,
+                            Some(UnknownAction::Error) => {
+                                return Err(opt_fail.cause(Error::sp_not_found(name)));
+                            }
+                            _ => {
+                                guess.ctx.add_warning(Warning::UnknownOption {
+                                    name: name.to_string(),
+                                });
+                            }
+                        }
                     }
                 } else {
                     trace!("`{:?}` not like option", opt);
                 }
             }
-            if stopped {
+            // the default terminator `--` not matched by Stop option stops the option parsing
+            if stopped || (!matched && OsStr::new(STOP) == *opt && self.option_terminator() == STOP)
+            {
                 // skip current, put left argument to noa args
                 lefts.extend(iter2.map(|(_, (a, _))| *a));
                 break;
@@ -340,13 +616,30 @@ where
             // if consume the argument, skip it
             if matched && consume {
                 iter2.next();
-            } else if !matched {
+            } else if !matched && !dropped {
                 // add it to NOA if current argument not matched
                 lefts.push(*opt);
+                if !like_opt && !self.intermixed() && lefts.len() > Self::noa_cmd() {
+                    // the first positional argument stops the option parsing
+                    lefts.extend(iter2.map(|(_, (a, _))| *a));
+                    break;
+                }
+            }
+            if let Some(expansion) = expansion {
+                // parse again with the arguments of matched option replaced by expansion
+                return Ok(Some(splice_args(
+                    &lefts,
+                    expansion,
+                    iter2.map(|(_, (a, _))| *a),
+                )));
             }
         }
 
+        prompt_missing(set, self.prompt_handler.as_ref())?;
         opt_fail.process_check(self.checker().opt_check(set))?;
+        if self.parallel_checks() {
+            check_values(set, &ctx.take_pending_check())?;
+        }
 
         let args = lefts;
         let total = args.len();
@@ -356,7 +649,11 @@ where
         ctx.set_args(args.clone());
         // when style is pos, noa index is [1..=len]
         if total > 0 {
-            let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+            let mut name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+
+            if self.allow_abbrev_subcommand() {
+                name = expand_abbrev_cmd(set, name)?;
+            }
             let mut guess = InvokeGuess {
                 set,
                 inv,
@@ -371,9 +668,17 @@ where
             };
 
             trace!("guess Cmd = {:?}", guess.name);
-            guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
+            let ret = guess.guess_and_invoke(&UserStyle::Cmd, overload)?;
+
+            if let Some(name) = guess
+                .name
+                .as_deref()
+                .filter(|_| ret.is_some_and(|v| v.matched))
+            {
+                check_deprecated_alias(guess.set, guess.ctx, name);
+            }
             if let Action::Quit = ctx.policy_act() {
-                return Ok(());
+                return Ok(None);
             }
             cmd_fail.process_check(self.checker().cmd_check(set))?;
 
@@ -400,7 +705,7 @@ where
                         guess.ctx.reset_policy_act();
                         break;
                     }
-                    Action::Quit => return Ok(()),
+                    Action::Quit => return Ok(None),
                     Action::Null => {}
                 }
             }
@@ -409,6 +714,10 @@ where
         }
 
         pos_fail.process_check(self.checker().pos_check(set))?;
+        check_max_positionals(set, self.max_positionals(), total)?;
+        check_conflicts_with_positional(set, total)?;
+        check_standalone(set, total)?;
+        check_value_count(set)?;
 
         let name = crate::str::osstr_to_str_i(&ctx.args, Self::noa_main());
         let mut main_fail = FailManager::default();
@@ -428,7 +737,7 @@ where
         trace!("guess Main {:?}", guess.name);
         guess.guess_and_invoke(&UserStyle::Main, overload)?;
         main_fail.process_check(self.checker().post_check(set))?;
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -455,15 +764,36 @@ where
         ser: &mut Self::Ser,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
-
-        match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
-            Ok(_) => Ok(Return::new(ctx)),
-            Err(e) => {
-                if e.is_failure() {
-                    Ok(Return::new(ctx).with_failure(e))
-                } else {
-                    Err(e)
+        let mut expanded: Option<Args> = None;
+        let mut warnings = Warnings::default();
+        let mut count = 0;
+
+        loop {
+            let args = expanded.clone().unwrap_or_else(|| orig.clone());
+            let mut ctx = Ctx::default()
+                .with_orig(orig.clone())
+                .with_expanded(expanded.clone())
+                .with_deferred_check(self.parallel_checks())
+                .with_overload(self.overload());
+
+            ctx.warnings = std::mem::take(&mut warnings);
+            match self.parse_impl(set, inv, ser, &args, &mut ctx) {
+                Ok(Some(args)) => {
+                    count += 1;
+                    check_expansion(count)?;
+                    warnings = std::mem::take(&mut ctx.warnings);
+                    expanded = Some(args);
+                }
+                Ok(None) => {
+                    check_unexpanded(&ctx)?;
+                    return Ok(Return::new(ctx).with_parse_status(ParseStatus::of(set)));
+                }
+                Err(e) => {
+                    return if e.is_failure() {
+                        Ok(Return::new(ctx).with_failure(e))
+                    } else {
+                        Err(e)
+                    };
                 }
             }
         }
@@ -475,6 +805,9 @@ mod test {
 
     use std::any::TypeId;
     use std::ffi::OsStr;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::sync::Mutex;
 
     use crate::opt::Cmd;
     use crate::opt::ConfigBuildInfer;
@@ -482,6 +815,25 @@ mod test {
     use crate::prelude::*;
     use crate::Error;
 
+    /// Add the options by `setup`, then parse the `args` with `policy`.
+    fn parse_with(
+        policy: &mut AFwdPolicy,
+        args: &[&str],
+        setup: impl FnOnce(&mut ASet, &mut AInvoker<'static>) -> Result<(), Error>,
+    ) -> Result<(Return, ASet), Error> {
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        setup(&mut set, &mut inv)?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(args))?;
+
+        Ok((ret, set))
+    }
+
     #[test]
     fn testing_1() {
         assert!(testing_1_main().is_ok());
@@ -1053,4 +1405,1830 @@ mod test {
         policy.parse(&mut set, &mut inv, &mut ser, args)?;
         Ok(())
     }
+
+    #[test]
+    fn test_max_positionals() {
+        assert!(test_max_positionals_impl().is_ok());
+    }
+
+    fn test_max_positionals_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], catch_all: Option<&str>) -> Result<Return, Error> {
+            let mut policy = AFwdPolicy::default().with_max_positionals(Some(2));
+
+            parse_with(&mut policy, args, |set, _| {
+                set.add_opt("--flag=b")?;
+                set.add_opt("name=p@1")?.set_pos_type::<String>().run()?;
+                set.add_opt("value=p@2")?.set_pos_type::<String>().run()?;
+                if let Some(catch_all) = catch_all {
+                    set.add_opt(catch_all)?.set_pos_type::<String>().run()?;
+                }
+                Ok(())
+            })
+            .map(|(ret, _)| ret)
+        }
+
+        assert!(parse(&["app", "--flag", "foo"], None)?.status());
+        assert!(parse(&["app", "foo", "--flag", "bar"], None)?.status());
+
+        let ret = parse(&["app", "foo", "bar", "--flag", "baz"], None)?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.to_string()).as_deref(),
+            Some("too many arguments, expected at most 2")
+        );
+        assert!(parse(&["app", "foo", "bar", "--flag", "baz"], Some("rest=p@*"))?.status());
+        assert!(parse(
+            &["app", "foo", "bar", "--flag", "baz", "qux"],
+            Some("rest=p@3/2")
+        )?
+        .status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing_raw() {
+        assert!(test_trailing_raw_impl().is_ok());
+    }
+
+    fn test_trailing_raw_impl() -> Result<(), Error> {
+        let (ret, set) = parse_with(
+            &mut AFwdPolicy::default(),
+            &["app", "--flag", "run", "--", "--not-a-flag", "x"],
+            |set, _| {
+                set.add_opt("--flag=b")?;
+                set.add_opt("run=c")?.set_trailing_raw(true).run()?;
+                Ok(())
+            },
+        )?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert!(*set.find_val::<bool>("run")?);
+        assert_eq!(
+            set.find_vals::<std::ffi::OsString>("run")?,
+            &[OsStr::new("--not-a-flag"), OsStr::new("x")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing_pos() {
+        assert!(test_trailing_pos_impl().is_ok());
+    }
+
+    fn test_trailing_pos_impl() -> Result<(), Error> {
+        let (ret, set) = parse_with(
+            &mut AFwdPolicy::default(),
+            &["app", "--flag", "a.txt", "--", "--flag", "b.txt"],
+            |set, _| {
+                set.add_opt("--flag=b")?.run()?;
+                set.add_opt("file=p@1")?.run()?;
+                set.add_opt("rest=p@0")?
+                    .set_pos_type::<String>()
+                    .set_trailing_raw(true)
+                    .run()?;
+
+                // only one positional can collect the trailing arguments
+                let err = set
+                    .add_opt("other=p@0")?
+                    .set_pos_type::<String>()
+                    .set_trailing_raw(true)
+                    .run()
+                    .unwrap_err();
+
+                assert!(err
+                    .to_string()
+                    .contains("the trailing arguments are already collected by `rest`"));
+                Ok(())
+            },
+        )?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_val::<bool>("file")?, &true);
+        assert_eq!(set.find_vals::<String>("rest")?, &["--flag", "b.txt"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_value() {
+        assert!(test_empty_value_impl().is_ok());
+    }
+
+    fn test_empty_value_impl() -> Result<(), Error> {
+        fn parse(empty_value: EmptyValuePolicy, args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--name=s")?
+                    .set_empty_value(empty_value)
+                    .run()?;
+                set.add_opt("--flag=b")?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(EmptyValuePolicy::Store, &["app", "--name=", "--flag"])?;
+
+        assert!(ret.status());
+        assert!(set.find("--name")?.matched());
+        assert_eq!(set.find_val::<String>("--name")?, "");
+
+        let (ret, set) = parse(EmptyValuePolicy::Store, &["app", "--name= "])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--name")?, " ");
+
+        let (ret, set) = parse(EmptyValuePolicy::Error, &["app", "--name=", "--flag"])?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.kind()),
+            Some(&crate::ErrorKind::MissingValue)
+        );
+        assert!(!set.find("--name")?.matched());
+
+        let (ret, set) = parse(EmptyValuePolicy::Error, &["app", "--name=foo"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+
+        let (ret, set) = parse(EmptyValuePolicy::Unset, &["app", "--name=", "--flag"])?;
+
+        assert!(ret.status());
+        assert_eq!(ret.args().len(), 1);
+        assert!(!set.find("--name")?.matched());
+        assert!(set.find_val::<String>("--name").is_err());
+        assert!(*set.find_val::<bool>("--flag")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_anywhere_pos() {
+        assert!(test_anywhere_pos_impl().is_ok());
+    }
+
+    fn test_anywhere_pos_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], skip: bool) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.set_skip_claimed_pos(skip);
+                set.add_opt("--flag=b")?;
+                set.add_opt("cmd=p@1")?.set_pos_type::<String>().run()?;
+                set.add_opt("dest=p@3")?.set_pos_type::<String>().run()?;
+                set.add_opt("file=p@*")?
+                    .set_pos_type::<String>()
+                    .set_action(Action::Set)
+                    .run()?;
+                set.add_opt("rest=p@*")?.set_pos_type::<String>().run()?;
+                Ok(())
+            })
+        }
+
+        // default, the `AnyWhere` position argument matches all the NOA
+        let (ret, set) = parse(
+            &["app", "copy", "--flag", "a.txt", "dir", "b.txt", "c.txt"],
+            false,
+        )?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("cmd")?, "copy");
+        assert_eq!(set.find_val::<String>("dest")?, "dir");
+        assert_eq!(set.find_val::<String>("file")?, "c.txt");
+        assert_eq!(
+            set.find_vals::<String>("rest")?,
+            &["copy", "a.txt", "dir", "b.txt", "c.txt"]
+        );
+
+        let (ret, set) = parse(
+            &["app", "copy", "--flag", "a.txt", "dir", "b.txt", "c.txt"],
+            true,
+        )?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("cmd")?, "copy");
+        assert_eq!(set.find_val::<String>("dest")?, "dir");
+        assert_eq!(set.find_val::<String>("file")?, "a.txt");
+        assert_eq!(
+            set.find_vals::<String>("rest")?,
+            &["a.txt", "b.txt", "c.txt"]
+        );
+
+        let (ret, set) = parse(&["app", "move", "x.txt", "--flag"], true)?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("cmd")?, "move");
+        assert_eq!(set.find_val::<String>("file")?, "x.txt");
+        assert_eq!(set.find_vals::<String>("rest")?, &["x.txt"]);
+        assert!(set.find_val::<String>("dest").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ctx_index() {
+        assert!(test_ctx_index_impl().is_ok());
+    }
+
+    fn test_ctx_index_impl() -> Result<(), Error> {
+        type Record = Vec<(String, usize, usize, bool)>;
+
+        fn record(set: &ASet, ser: &mut ASer, ctx: &Ctx) -> Result<(), Error> {
+            let name = set.opt(ctx.uid()?)?.name().to_owned();
+
+            assert_eq!(ctx.index()?, ctx.idx()?);
+            ser.sve_val_mut::<Record>()?
+                .push((name, ctx.index()?, ctx.total()?, ctx.is_last()?));
+            Ok(())
+        }
+
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        for opt in ["--flag=b", "--last=b"] {
+            let uid = set.add_opt(opt)?.run()?;
+
+            inv.entry(uid)
+                .on(|set: &mut ASet, ser: &mut ASer, ctx: &Ctx| {
+                    record(set, ser, ctx)?;
+                    Ok(Some(true))
+                });
+        }
+        for opt in ["--name=s", "file=p@1"] {
+            let uid = set.add_opt(opt)?.run()?;
+
+            inv.entry(uid)
+                .on(|set: &mut ASet, ser: &mut ASer, ctx: &Ctx| {
+                    record(set, ser, ctx)?;
+                    ctx.value::<String>().map(Some)
+                });
+        }
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        ser.sve_insert(Record::new());
+
+        let args = Args::from(["app", "--flag", "--name", "foo", "bar", "--last"]);
+
+        assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
+        assert_eq!(
+            ser.sve_val::<Record>()?,
+            &vec![
+                (String::from("--flag"), 1, 6, false),
+                (String::from("--name"), 2, 6, false),
+                (String::from("--last"), 5, 6, true),
+                (String::from("file"), 1, 2, true),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_style() {
+        assert!(test_custom_style_impl().is_ok());
+    }
+
+    fn test_custom_style_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        let style = policy.style_manager_mut().register_custom(|raw, _| {
+            let (key, value) = raw.to_str()?.split_once(':')?;
+
+            Some(GuessResult::new(format!("--{key}")).with_value(value))
+        });
+
+        assert_eq!(style, UserStyle::Custom(0));
+        assert_eq!(policy.style_manager().last(), Some(&style));
+        set.add_opt("--color=s")?.run()?;
+        set.add_opt("--debug=b")?.run()?;
+        set.add_opt("file=p@1")?.set_pos_type::<PathBuf>().run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "color:red", "--debug", "foo"]);
+
+        assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
+        assert_eq!(set.find_val::<String>("--color")?, "red");
+        assert!(*set.find_val::<bool>("--debug")?);
+        assert_eq!(set.find_val::<PathBuf>("file")?, &PathBuf::from("foo"));
+
+        // the argument not matched by custom style is a positional argument
+        let args = Args::from(["app", "path:foo"]);
+
+        assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
+        assert_eq!(set.find_val::<PathBuf>("file")?, &PathBuf::from("path:foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_style_order() {
+        assert!(test_custom_style_order_impl().is_ok());
+    }
+
+    fn test_custom_style_order_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], first: bool) -> Result<ASet, Error> {
+            let mut policy = AFwdPolicy::default();
+
+            // `--debug` is same as `--level=debug`, the name is split by validator
+            let style = policy
+                .style_manager_mut()
+                .register_custom(|raw, validator| {
+                    let raw = raw.to_str()?;
+                    let (prefix, name) = validator.split(&std::borrow::Cow::Borrowed(raw)).ok()?;
+
+                    Some(GuessResult::new(format!("{prefix}level")).with_value(name.as_ref()))
+                });
+
+            if first {
+                policy
+                    .style_manager_mut()
+                    .insert_before(UserStyle::EqualWithValue, style);
+            }
+            parse_with(&mut policy, args, |set, _| {
+                set.add_opt("--level=s")?.run()?;
+                set.add_opt("--debug=b")?.run()?;
+                Ok(())
+            })
+            .map(|(_, set)| set)
+        }
+
+        // the custom style is guessed after the default styles
+        let set = parse(&["app", "--debug"], false)?;
+
+        assert!(*set.find_val::<bool>("--debug")?);
+        assert!(set.find_val::<String>("--level").is_err());
+
+        let set = parse(&["app", "--info"], false)?;
+
+        assert_eq!(set.find_val::<String>("--level")?, "info");
+
+        // the custom style is guessed first
+        let set = parse(&["app", "--debug"], true)?;
+
+        assert!(!*set.find_val::<bool>("--debug")?);
+        assert_eq!(set.find_val::<String>("--level")?, "debug");
+        Ok(())
+    }
+
+    #[test]
+    fn test_toggle_prefix() {
+        assert!(test_toggle_prefix_impl().is_ok());
+    }
+
+    fn test_toggle_prefix_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.validator_mut().add_prefix("+");
+                set.add_opt("-x=b")?
+                    .add_alias("--extract")
+                    .set_toggle_prefix("+")
+                    .run()?;
+                set.add_opt("-l=b")?.set_toggle_prefix("+").run()?;
+                set.add_opt("-v=b")?.run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "-x", "+l"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("-x")?);
+        assert!(!*set.find_val::<bool>("-l")?);
+
+        let (ret, set) = parse(&["app", "-x", "+x"])?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("-x")?);
+
+        let (ret, set) = parse(&["app", "+x", "-x", "+l", "-l"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("-x")?);
+        assert!(*set.find_val::<bool>("-l")?);
+
+        // the alias is toggled too
+        let (ret, set) = parse(&["app", "--extract", "+extract"])?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("-x")?);
+
+        // the option without toggle prefix is not matched
+        let (ret, _) = parse(&["app", "+v"])?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_handler() {
+        assert!(test_unknown_handler_impl().is_ok());
+    }
+
+    fn test_unknown_handler_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default().with_strict(false);
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("-a=b")?.run()?;
+        policy.set_unknown_handler(Some(std::sync::Arc::new(|arg: &OsStr| {
+            match arg.to_str() {
+                Some("--drop") => UnknownAction::Drop,
+                Some("--bad") => UnknownAction::Error,
+                _ => UnknownAction::Keep,
+            }
+        })));
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "--drop", "--keep", "-a", "foo"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("-a")?);
+        assert_eq!(ret.args(), &["app", "--keep", "foo"]);
+        assert_eq!(ret.warnings().len(), 1);
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let args = Args::from(["app", "--bad", "-a"]);
+
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(!ret.status());
+        assert!(ret
+            .failure()
+            .is_some_and(|e| e.to_string().contains("can not find option `--bad`")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_main_orig_args() {
+        assert!(test_main_orig_args_impl().is_ok());
+    }
+
+    fn test_main_orig_args_impl() -> Result<(), Error> {
+        let (ret, set) = parse_with(
+            &mut AFwdPolicy::default(),
+            &["app", "--debug", "--name", "bar", "foo"],
+            |set, inv| {
+                set.add_opt("--debug=b")?.run()?;
+                set.add_opt("--name=s")?.run()?;
+                inv.entry(set.add_opt("main=m")?.run()?).on(
+                    |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                        let orig: Vec<_> = ctx.orig_args().iter().map(|v| v.to_str()).collect();
+
+                        assert_eq!(ctx.args(), &["app", "foo"]);
+                        assert_eq!(
+                            orig,
+                            [
+                                Some("app"),
+                                Some("--debug"),
+                                Some("--name"),
+                                Some("bar"),
+                                Some("foo")
+                            ]
+                        );
+                        Ok(Some(true))
+                    },
+                );
+                Ok(())
+            },
+        )?;
+
+        assert!(ret.status());
+        assert!(set.find("main")?.matched());
+        Ok(())
+    }
+
+    #[test]
+    fn test_guess_trace() {
+        assert!(test_guess_trace_impl().is_ok());
+    }
+
+    fn test_guess_trace_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default().with_styles(vec![
+            UserStyle::EqualWithValue,
+            UserStyle::Argument,
+            UserStyle::Boolean,
+            UserStyle::CombinedOption,
+        ]);
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("-a=b")?.run()?;
+        set.add_opt("-b=b")?.run()?;
+        set.add_opt("-c=b")?.run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        // nothing recorded in default
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "-abc"]))?;
+
+        assert!(ret.status());
+        assert!(ret.guess_trace().is_empty());
+
+        policy.set_diagnostics(true);
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let args = Args::from(["app", "-a", "-bc"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        let trace = ret.guess_trace();
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("-c")?);
+        assert_eq!(trace.len(), 2);
+        assert_eq!(trace[0].token, "-a");
+        assert_eq!(trace[0].winner, Some(UserStyle::Boolean));
+        assert_eq!(trace[1].token, "-bc");
+        assert_eq!(
+            trace[1].styles,
+            vec![
+                UserStyle::EqualWithValue,
+                UserStyle::Argument,
+                UserStyle::Boolean,
+                UserStyle::CombinedOption
+            ]
+        );
+        assert_eq!(trace[1].winner, Some(UserStyle::CombinedOption));
+        Ok(())
+    }
+
+    #[test]
+    fn test_combined_prefixes() {
+        assert!(test_combined_prefixes_impl().is_ok());
+    }
+
+    fn test_combined_prefixes_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        policy
+            .style_manager_mut()
+            .set_combined_prefixes(vec!["+".to_owned()])
+            .push(UserStyle::CombinedOption);
+        set.validator_mut().add_prefix("+");
+        for name in ["+a", "+b", "--x", "--y"] {
+            set.add_opt(format!("{name}=b"))?.run()?;
+        }
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "+ab"]))?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("+a")?);
+        assert!(*set.find_val::<bool>("+b")?);
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "--xy"]))?;
+
+        assert!(!ret.status());
+        assert!(!*set.find_val::<bool>("--x")?);
+        assert!(!*set.find_val::<bool>("--y")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_disable_style() {
+        assert!(test_disable_style_impl().is_ok());
+    }
+
+    fn test_disable_style_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("-n".infer::<i64>())?.run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "-n5"]))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("-n")?, &5);
+
+        policy.disable_style(UserStyle::EmbeddedValue);
+        assert!(policy
+            .style_manager()
+            .position(&UserStyle::EmbeddedValue)
+            .is_none());
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "-n5"]))?;
+
+        assert!(!ret.status());
+
+        policy.enable_style(UserStyle::EmbeddedValue);
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "-n5"]))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("-n")?, &5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_assign_char() {
+        assert!(test_assign_char_impl().is_ok());
+    }
+
+    fn test_assign_char_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--opt=i")?.run()?;
+        set.add_opt("--expr=s")?.run()?;
+        policy.set_assign_char(':');
+        assert_eq!(policy.assign_char(), ':');
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "--opt:42", "--expr:a=b"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--opt")?, &42);
+        assert_eq!(set.find_val::<String>("--expr")?, "a=b");
+
+        // the `=` is not special anymore
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let args = Args::from(["app", "--opt=42"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(!ret.status());
+        assert!(ret
+            .failure()
+            .is_some_and(|e| e.to_string().contains("can not find option `--opt=42`")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ambiguity() {
+        assert!(test_ambiguity_impl().is_ok());
+    }
+
+    fn test_ambiguity_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("-a=b")?.run()?;
+        set.add_opt("-b=b")?.run()?;
+        set.add_opt("-ab=b")?.run()?;
+        set.add_opt("-c=s")?.run()?;
+        set.add_opt("-cx=b")?.run()?;
+        policy.enable_style(UserStyle::CombinedOption);
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "-ab", "-cx"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("-ab")?);
+        assert!(!*set.find_val::<bool>("-a")?);
+        assert!(*set.find_val::<bool>("-cx")?);
+        assert_eq!(
+            ret.warnings(),
+            &[
+                crate::parser::Warning::AmbiguousOption {
+                    name: "-ab".to_owned(),
+                    candidates: vec!["option `-ab`".to_owned(), "options `-a`, `-b`".to_owned()],
+                },
+                crate::parser::Warning::AmbiguousOption {
+                    name: "-cx".to_owned(),
+                    candidates: vec![
+                        "option `-cx`".to_owned(),
+                        "option `-c` with value `x`".to_owned()
+                    ],
+                }
+            ]
+        );
+
+        // the argument has value is not ambiguous
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "-c", "x"]))?;
+
+        assert!(ret.status());
+        assert!(ret.warnings().is_empty());
+
+        policy.set_ambiguity(AmbiguityMode::Error);
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let err = policy
+            .parse(&mut set, &mut inv, &mut ser, Args::from(["app", "-ab"]))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid argument `-ab`: it is ambiguous, can be option `-ab` or options `-a`, `-b`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prompt_handler() {
+        assert!(test_prompt_handler_impl().is_ok());
+    }
+
+    fn test_prompt_handler_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--name=s!")?
+            .set_prompt("Name: ".to_owned())
+            .run()?;
+        set.add_opt("--token=s!")?
+            .set_prompt("Token: ".to_owned())
+            .set_secret(true)
+            .run()?;
+        policy.set_prompt_handler(Some(std::sync::Arc::new(|prompt: &str, secret| {
+            match (prompt, secret) {
+                ("Name: ", false) => Some("lily".to_owned()),
+                ("Token: ", true) => Some("t0ken".to_owned()),
+                _ => None,
+            }
+        })));
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app"]))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--name")?, "lily");
+        assert_eq!(set.find_val::<String>("--token")?, "t0ken");
+
+        // the option set in command line is not prompted
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let args = Args::from(["app", "--name", "tom"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--name")?, "tom");
+        assert_eq!(set.find_val::<String>("--token")?, "t0ken");
+
+        // the handler can not read the value, such as stdin is not a terminal
+        let mut set = policy.default_set();
+
+        set.add_opt("--name=s!")?
+            .set_prompt("Name: ".to_owned())
+            .run()?;
+        policy.set_prompt_handler(Some(std::sync::Arc::new(|_: &str, _| None)));
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app"]))?;
+
+        assert!(!ret.status());
+        // the stdin is not a terminal
+        #[cfg(feature = "std")]
+        assert_eq!(stdin_prompt("Token: ", true), None);
+        #[cfg(feature = "std")]
+        {
+            let mut echoes = vec![];
+            let mut output = vec![];
+            let value = read_prompt(
+                "Token: ",
+                true,
+                &mut "t0ken\n".as_bytes(),
+                &mut output,
+                |echo| {
+                    echoes.push(echo);
+                    true
+                },
+            );
+
+            assert_eq!(value.as_deref(), Some("t0ken"));
+            assert_eq!(echoes, [false, true]);
+            assert_eq!(output, b"Token: \n");
+
+            // the secret value is not read if the echo can not be turned off
+            let value = read_prompt(
+                "Token: ",
+                true,
+                &mut "t0ken\n".as_bytes(),
+                &mut vec![],
+                |_| false,
+            );
+
+            assert_eq!(value, None);
+
+            // the echo is restored even if the reading failed
+            let mut echoes = vec![];
+            let value = read_prompt(
+                "Token: ",
+                true,
+                &mut [0xff, b'\n'].as_slice(),
+                &mut vec![],
+                |echo| {
+                    echoes.push(echo);
+                    true
+                },
+            );
+
+            assert_eq!(value, None);
+            assert_eq!(echoes, [false, true]);
+
+            let mut output = vec![];
+            let value = read_prompt(
+                "Name: ",
+                false,
+                &mut "foo\r\n".as_bytes(),
+                &mut output,
+                |_| false,
+            );
+
+            assert_eq!(value.as_deref(), Some("foo"));
+            assert_eq!(output, b"Name: ");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_check() {
+        assert!(test_custom_check_impl().is_ok());
+    }
+
+    fn test_custom_check_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--min=i")?.run()?;
+        set.add_opt("--max=i")?.run()?;
+        policy.checker_mut().add_check(
+            CheckPhase::Post,
+            Box::new(|set: &ASet| {
+                let min = set.find_val::<i64>("--min").ok();
+                let max = set.find_val::<i64>("--max").ok();
+
+                match (min, max) {
+                    (Some(min), Some(max)) if min > max => Err(crate::raise_failure!(
+                        "`--min` {} is greater than `--max` {}",
+                        min,
+                        max
+                    )),
+                    _ => Ok(()),
+                }
+            }),
+        );
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "--min=1", "--max=5"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+        let args = Args::from(["app", "--min=6", "--max=5"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(!ret.status());
+        assert!(ret.failure().is_some_and(|e| e
+            .to_string()
+            .contains("`--min` 6 is greater than `--max` 5")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_checks() {
+        assert!(test_parallel_checks_impl().is_ok());
+    }
+
+    fn test_parallel_checks_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], parallel: bool) -> Result<(Return, ASet), Error> {
+            parse_with(
+                &mut AFwdPolicy::default().with_parallel_checks(parallel),
+                args,
+                |set, _| {
+                    set.add_opt("--jobs".infer::<i64>())?
+                        .set_validator(ValValidator::range_full(1, 32))
+                        .run()?;
+                    set.add_opt("--mode".infer::<String>())?
+                        .set_validator(ValValidator::contains2(vec!["fast", "slow"]))
+                        .run()?;
+                    set.add_opt("file".infer::<Pos<String>>())?
+                        .set_index(Index::forward(1))
+                        .set_validator(ValValidator::contains2(vec!["a.txt", "b.txt"]))
+                        .run()?;
+                    Ok(())
+                },
+            )
+        }
+
+        for args in [
+            &["app", "--jobs=4", "--mode=fast", "a.txt"][..],
+            &["app", "--jobs=64", "--mode=fast"],
+            &["app", "--jobs=4", "--mode=none"],
+            &["app", "--mode=slow", "c.txt"],
+        ] {
+            let (seq_ret, seq_set) = parse(args, false)?;
+            let (par_ret, par_set) = parse(args, true)?;
+
+            assert_eq!(seq_ret.status(), par_ret.status(), "{args:?}");
+            assert_eq!(
+                seq_ret.failure().map(Error::specific_kind),
+                par_ret.failure().map(Error::specific_kind),
+                "{args:?}"
+            );
+            if seq_ret.status() {
+                assert_eq!(
+                    seq_set.find_val::<i64>("--jobs").ok(),
+                    par_set.find_val::<i64>("--jobs").ok()
+                );
+                assert_eq!(
+                    seq_set.find_val::<String>("--mode").ok(),
+                    par_set.find_val::<String>("--mode").ok()
+                );
+            }
+        }
+
+        // all the invalid options are collected
+        let (ret, _) = parse(&["app", "--jobs=64", "--mode=none"], true)?;
+        let error = ret.failure().unwrap();
+
+        assert!(error.to_string().contains("option `--jobs`"));
+        assert!(error
+            .caused_by()
+            .is_some_and(|e| e.to_string().contains("option `--mode`")));
+
+        // the handlers never see the invalid values
+        fn parse_seen(args: &[&str], seen: &Arc<Mutex<Vec<i64>>>) -> Result<Return, Error> {
+            let mut policy = AFwdPolicy::default().with_parallel_checks(true);
+
+            parse_with(&mut policy, args, |set, inv| {
+                set.add_opt("--jobs".infer::<i64>())?
+                    .set_validator(ValValidator::range_full(1, 32))
+                    .run()?;
+                let seen_c = seen.clone();
+                inv.entry(set.add_opt("--name".infer::<String>())?.run()?)
+                    .on(move |set: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                        seen_c.lock().unwrap().push(*set["--jobs"].val::<i64>()?);
+                        Ok(Some(ctx.value::<String>()?))
+                    });
+                let seen_c = seen.clone();
+                let file = set
+                    .add_opt("file".infer::<Pos<String>>())?
+                    .set_index(Index::forward(1))
+                    .run()?;
+                inv.entry(file)
+                    .on(move |set: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                        seen_c.lock().unwrap().push(*set["--jobs"].val::<i64>()?);
+                        Ok(Some(ctx.value::<String>()?))
+                    });
+                Ok(())
+            })
+            .map(|(ret, _)| ret)
+        }
+
+        let seen = Arc::new(Mutex::new(vec![]));
+        let ret = parse_seen(&["app", "--jobs=64", "--name=x", "a.txt"], &seen)?;
+
+        assert!(!ret.status());
+        assert!(seen.lock().unwrap().is_empty());
+        let ret = parse_seen(&["app", "--jobs=8", "--name=x", "a.txt"], &seen)?;
+
+        assert!(ret.status());
+        assert_eq!(seen.lock().unwrap().as_slice(), &[8, 8]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reorder_style() {
+        assert!(test_reorder_style_impl().is_ok());
+    }
+
+    fn test_reorder_style_impl() -> Result<(), Error> {
+        fn parse(policy: &mut AFwdPolicy) -> Result<ASet, Error> {
+            let (ret, set) = parse_with(policy, &["app", "-a42"], |set, _| {
+                set.add_opt("-a=i")?.run()?;
+                set.add_opt("-a42=b")?.run()?;
+                Ok(())
+            })?;
+
+            assert!(ret.status());
+            Ok(set)
+        }
+
+        let mut policy = AFwdPolicy::default();
+
+        assert!(
+            policy.style_manager().position(&UserStyle::Boolean)
+                < policy.style_manager().position(&UserStyle::EmbeddedValue)
+        );
+
+        // `-a42` is the boolean option in default
+        let set = parse(&mut policy)?;
+
+        assert!(*set.find_val::<bool>("-a42")?);
+        assert!(set.find_val::<i64>("-a").is_err());
+
+        // prioritize the embedded value over the boolean
+        policy
+            .style_manager_mut()
+            .insert_before(UserStyle::Boolean, UserStyle::EmbeddedValue);
+        assert_eq!(
+            policy.style_manager().as_slice(),
+            &[
+                UserStyle::EqualWithValue,
+                UserStyle::Argument,
+                UserStyle::EmbeddedValue,
+                UserStyle::Boolean,
+            ]
+        );
+
+        let set = parse(&mut policy)?;
+
+        assert_eq!(set.find_val::<i64>("-a")?, &42);
+        assert!(!*set.find_val::<bool>("-a42")?);
+
+        policy.style_manager_mut().set(vec![UserStyle::Boolean]);
+        assert_eq!(policy.style_manager().as_slice(), &[UserStyle::Boolean]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_intermixed() {
+        assert!(test_intermixed_impl().is_ok());
+    }
+
+    fn test_intermixed_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], intermixed: bool) -> Result<(Return, ASet), Error> {
+            parse_with(
+                &mut AFwdPolicy::default().with_intermixed(intermixed),
+                args,
+                |set, _| {
+                    set.add_opt("--flag=b")?;
+                    set.add_opt("--name=s")?;
+                    set.add_opt("args=p@1..")?
+                        .set_pos_type::<String>()
+                        .set_action(Action::App)
+                        .run()?;
+                    Ok(())
+                },
+            )
+        }
+
+        let args = ["app", "--name", "foo", "bar", "--flag"];
+        let (ret, set) = parse(&args, true)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert_eq!(set.find_vals::<String>("args")?, &["bar"]);
+
+        // the trailing `--flag` is a positional argument
+        let (ret, set) = parse(&args, false)?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert_eq!(set.find_vals::<String>("args")?, &["bar", "--flag"]);
+        assert_eq!(ret.args(), &["app", "bar", "--flag"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_conflicts_with_positional() {
+        assert!(test_conflicts_with_positional_impl().is_ok());
+    }
+
+    fn test_conflicts_with_positional_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--version=b")?
+                    .set_conflicts_with_positional(true)
+                    .run()?;
+                set.add_opt("--debug=b")?.run()?;
+                set.add_opt("file=p@1")?.set_pos_type::<String>().run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--version", "--debug"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--version")?);
+
+        let (ret, set) = parse(&["app", "foo"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("file")?, "foo");
+
+        let (ret, _) = parse(&["app", "--version", "foo"])?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.to_string()).as_deref(),
+            Some("option `--version` can not be used with positional arguments")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_standalone() {
+        assert!(test_standalone_impl().is_ok());
+    }
+
+    fn test_standalone_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--version=b")?.set_standalone(true).run()?;
+                set.add_opt("--debug=b")?.run()?;
+                set.add_opt("file=p@1")?.set_pos_type::<String>().run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--version"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--version")?);
+
+        let (ret, set) = parse(&["app", "--debug", "foo"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("file")?, "foo");
+
+        for args in [
+            &["app", "--version", "--debug"][..],
+            &["app", "--version", "foo"],
+        ] {
+            let (ret, _) = parse(args)?;
+
+            assert!(!ret.status());
+            assert_eq!(
+                ret.failure().map(|v| v.to_string()).as_deref(),
+                Some("option `--version` can not be used with other arguments")
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_count() {
+        assert!(test_value_count_impl().is_ok());
+    }
+
+    fn test_value_count_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--coords=i")?
+                    .set_action(Action::App)
+                    .set_value_count(2..=3)
+                    .run()?;
+                set.add_opt("--debug=b")?.run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, _) = parse(&["app", "--coords=1", "--debug"])?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.to_string()).as_deref(),
+            Some("option `--coords` expected 2 to 3 values, but found 1")
+        );
+
+        let (ret, set) = parse(&["app", "--coords=1", "--coords=2", "--coords=3"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_vals::<i64>("--coords")?, &[1, 2, 3]);
+
+        let (ret, set) = parse(&["app", "--debug"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--debug")?);
+
+        let (ret, _) = parse(&[
+            "app",
+            "--coords=1",
+            "--coords=2",
+            "--coords=3",
+            "--coords=4",
+        ])?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.to_string()).as_deref(),
+            Some("option `--coords` expected 2 to 3 values, but found 4")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple() {
+        assert!(test_multiple_impl().is_ok());
+    }
+
+    fn test_multiple_impl() -> Result<(), Error> {
+        pub struct Name;
+
+        impl Infer for Name {
+            type Val = String;
+
+            fn infer_act() -> Action {
+                Action::Set
+            }
+
+            fn infer_map(_: Self::Val) -> Self {
+                Name
+            }
+        }
+
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--output;-o=s")?.set_multiple(false).run()?;
+                set.add_opt("--include=s")?
+                    .set_action(Action::App)
+                    .set_multiple(true)
+                    .run()?;
+                set.add_opt("--level=i")?.run()?;
+                set.add_opt("--name".infer::<Name>())?.run()?;
+                set.add_opt("--last=s")?.set_action(Action::Set).run()?;
+                set.add_opt("--flag=b")?.run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--output", "a.txt", "--level=1", "--level=2"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--output")?, "a.txt");
+        assert_eq!(set.find_val::<i64>("--level")?, &2);
+
+        let (ret, _) = parse(&["app", "--output", "a.txt", "-o", "b.txt"])?;
+        let error = ret.failure().unwrap();
+
+        assert!(!ret.status());
+        assert_eq!(error.specific_kind(), crate::ErrorKind::OptDuplicated);
+        assert_eq!(error.exit_code(), 2);
+
+        let mut cause = Some(error);
+        let mut found = false;
+
+        while let Some(error) = cause {
+            found = found
+                || error
+                    .to_string()
+                    .contains("option `-o` used more than once");
+            cause = error.caused_by();
+        }
+        assert!(found);
+
+        let (ret, set) = parse(&["app", "--include=a", "--include=b", "--include=c"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_vals::<String>("--include")?, &["a", "b", "c"]);
+
+        // the scalar value type can not be used more than once by default
+        let (ret, _) = parse(&["app", "--name=a", "--name=b"])?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.specific_kind()),
+            Some(crate::ErrorKind::OptDuplicated)
+        );
+
+        // the explicit Action::Set and flags can be used more than once
+        let (ret, set) = parse(&["app", "--last=a", "--last=b", "--flag", "--flag"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--last")?, "b");
+        assert!(*set.find_val::<bool>("--flag")?);
+
+        // occurrences are reset before parsing
+        let (ret, _) = parse(&["app", "-o", "b.txt"])?;
+
+        assert!(ret.status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_occurrences() {
+        assert!(test_max_occurrences_impl().is_ok());
+    }
+
+    fn test_max_occurrences_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("-v=b")?
+                    .set_action(Action::Cnt)
+                    .set_max_occurrences(3)
+                    .run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "-v", "-v", "-v"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<u64>("-v")?, &3);
+
+        let (ret, _) = parse(&["app", "-v", "-v", "-v", "-v"])?;
+        let error = ret.failure().unwrap();
+
+        assert!(!ret.status());
+        assert_eq!(error.specific_kind(), crate::ErrorKind::OptDuplicated);
+
+        let mut cause = Some(error);
+        let mut found = false;
+
+        while let Some(error) = cause {
+            found = found
+                || error
+                    .to_string()
+                    .contains("option `-v` used more than 3 times");
+            cause = error.caused_by();
+        }
+        assert!(found);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bool_with_value() {
+        assert!(test_bool_with_value_impl().is_ok());
+    }
+
+    fn test_bool_with_value_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--flag=b")?.set_value_t(true).run()?;
+                set.add_opt("--debug=b")?.run()?;
+                set.add_opt("--color=b")?
+                    .set_bool_literals(["yes", "on"], ["no", "off"])
+                    .run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--flag=false", "--debug=true"])?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("--flag")?);
+        assert!(*set.find_val::<bool>("--debug")?);
+
+        let (ret, set) = parse(&["app", "--debug"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert!(*set.find_val::<bool>("--debug")?);
+
+        let (ret, _) = parse(&["app", "--flag=maybe"])?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.specific_kind()),
+            Some(crate::ErrorKind::RawValParse)
+        );
+
+        // the configured literals
+        let (ret, set) = parse(&["app", "--color=off"])?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("--color")?);
+
+        let (ret, set) = parse(&["app", "--color=on", "--color=true"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--color")?);
+
+        let (ret, set) = parse(&["app", "--color"])?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--color")?);
+
+        // the literals only for the option configured
+        let (ret, _) = parse(&["app", "--debug=yes"])?;
+
+        assert!(!ret.status());
+
+        let (ret, _) = parse(&["app", "--color=YES"])?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_typed() {
+        assert!(test_get_typed_impl().is_ok());
+    }
+
+    fn test_get_typed_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, inv| {
+                set.add_opt("-c=b")?.run()?;
+                set.add_opt("--cpp=b")?.run()?;
+                set.add_opt("--output=s")?.run()?;
+                inv.entry(set.add_opt("--std=s")?.run()?).on(
+                    |set: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                        let std = ctx.value::<String>()?;
+                        let output = set.get_typed::<String>("--output")?;
+
+                        assert_eq!(output, None);
+                        if set.get_typed::<bool>("-c")? == Some(true) && !std.starts_with('c') {
+                            Err(crate::raise_failure!("`{std}` is not a valid c standard"))
+                        } else if set.get_typed::<bool>("--cpp")? == Some(true)
+                            && !std.starts_with("c++")
+                        {
+                            Err(crate::raise_failure!("`{std}` is not a valid c++ standard"))
+                        } else {
+                            Ok(Some(std))
+                        }
+                    },
+                );
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--cpp", "--std=c++17"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.get_typed::<String>("--std")?.as_deref(), Some("c++17"));
+
+        let (ret, set) = parse(&["app", "-c", "--std=c11"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.get_typed::<String>("--std")?.as_deref(), Some("c11"));
+        assert_eq!(set.get_typed::<String>("--output")?, None);
+        assert!(set.get_typed::<i64>("--std").is_err());
+        assert!(set.get_typed::<String>("--input").is_err());
+
+        let (ret, set) = parse(&["app", "--cpp", "--std=c11"])?;
+
+        assert!(!ret.status());
+        assert_eq!(set.get_typed::<String>("--std")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_val_or() {
+        assert!(test_val_or_impl().is_ok());
+    }
+
+    fn test_val_or_impl() -> Result<(), Error> {
+        let (ret, mut set) = parse_with(
+            &mut AFwdPolicy::default(),
+            &["app", "--jobs=4"],
+            |set, _| {
+                set.add_opt("--jobs=i")?.run()?;
+                set.add_opt("--output=s")?.run()?;
+                Ok(())
+            },
+        )?;
+        let output = String::from("a.out");
+
+        assert!(ret.status());
+        assert_eq!(set.find_val_or::<i64>("--jobs", &1), &4);
+        assert_eq!(set.find_val_or("--output", &output), "a.out");
+        assert_eq!(set.find_val_or::<i64>("--unknown", &1), &1);
+        assert_eq!(set.take_val_or::<i64>("--jobs", 1), 4);
+        // the value is taken
+        assert_eq!(set.take_val_or::<i64>("--jobs", 1), 1);
+        assert_eq!(set.take_val_or("--output", output), "a.out");
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_such_option() {
+        assert!(test_no_such_option_impl().is_ok());
+    }
+
+    fn test_no_such_option_impl() -> Result<(), Error> {
+        let (ret, mut set) =
+            parse_with(&mut AFwdPolicy::default(), &["app", "-j", "4"], |set, _| {
+                set.add_opt("--jobs;-j=i")?.run()?;
+                set.add_opt("--output=s")?.run()?;
+                Ok(())
+            })?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("-j")?, &4);
+
+        // the option is not registered
+        let err = set.find_val::<i64>("--typo").unwrap_err();
+
+        assert_eq!(err.kind(), &crate::ErrorKind::NoSuchOption);
+        assert_eq!(err.to_string(), "no option named `--typo`");
+        assert_eq!(
+            set.find_opt_mut("--typo").unwrap_err().kind(),
+            &crate::ErrorKind::NoSuchOption
+        );
+
+        // the option exists but has no value
+        let err = set.find_val::<String>("--output").unwrap_err();
+
+        assert_ne!(err.kind(), &crate::ErrorKind::NoSuchOption);
+        // the option exists but type is not matched
+        let err = set.find_val::<bool>("--output=b").unwrap_err();
+
+        assert_ne!(err.kind(), &crate::ErrorKind::NoSuchOption);
+        Ok(())
+    }
+
+    #[test]
+    fn test_option_terminator() {
+        assert!(test_option_terminator_impl().is_ok());
+    }
+
+    fn test_option_terminator_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], terminator: Option<&str>) -> Result<(Return, ASet), Error> {
+            let mut policy =
+                AFwdPolicy::default().with_option_terminator(terminator.map(String::from));
+
+            parse_with(&mut policy, args, |set, _| {
+                set.add_opt("--flag=b")?.run()?;
+                set.add_opt("--name=s")?.run()?;
+                set.add_opt("stop".infer::<crate::value::Stop>())?.run()?;
+                set.add_opt("args=p@1..")?
+                    .set_pos_type::<String>()
+                    .set_action(Action::App)
+                    .run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--flag", "END", "--name=foo", "bar"], Some("END"))?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert!(set.find_val::<String>("--name").is_err());
+        assert_eq!(set.find_vals::<String>("args")?, &["--name=foo", "bar"]);
+
+        let (ret, set) = parse(&["app", "----", "--flag"], Some("----"))?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_vals::<String>("args")?, &["--flag"]);
+
+        // the `--` is handled by Stop option if terminator not set
+        let (ret, set) = parse(&["app", "--flag", "--", "--name=foo"], None)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert!(set.find_val::<String>("--name").is_err());
+        assert_eq!(set.find_vals::<String>("args")?, &["--name=foo"]);
+
+        let (ret, set) = parse(&["app", "END", "--name=foo"], None)?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert_eq!(set.find_vals::<String>("args")?, &["END"]);
+
+        // the default terminator `--` works without Stop option
+        let mut policy = AFwdPolicy::default();
+
+        assert_eq!(policy.option_terminator(), "--");
+        let (ret, set) = parse_with(&mut policy, &["app", "--", "--flag", "foo"], |set, _| {
+            set.add_opt("--flag=b")?.run()?;
+            set.add_opt("args=p@1..")?
+                .set_pos_type::<String>()
+                .set_action(Action::App)
+                .run()?;
+            Ok(())
+        })?;
+
+        assert!(ret.status());
+        assert!(!*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_vals::<String>("args")?, &["--flag", "foo"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overload() {
+        assert!(test_overload_impl().is_ok());
+    }
+
+    fn test_overload_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], overload: bool) -> Result<(Return, ASet), Error> {
+            parse_with(
+                &mut AFwdPolicy::default().with_overload(overload),
+                args,
+                |set, _| {
+                    set.set_check_duplicate(false);
+                    set.add_opt("--n=i")?
+                        .set_action(Action::Set)
+                        .set_multiple(false)
+                        .run()?;
+                    set.add_opt("--list=i")?.set_action(Action::App).run()?;
+                    set.add_opt("--flag".infer::<i64>())?.run()?;
+                    set.add_opt("--flag".infer::<String>())?.run()?;
+                    Ok(())
+                },
+            )
+        }
+
+        // the repeated scalar option keeps the last value
+        let (ret, set) = parse(
+            &["app", "--n", "1", "--n", "2", "--list=1", "--list=2"],
+            true,
+        )?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_vals::<i64>("--n")?, &[2]);
+        assert_eq!(set.find_vals::<i64>("--list")?, &[1, 2]);
+
+        // the non multiple option can not be used more than once without overload
+        let (ret, _) = parse(&["app", "--n", "1", "--n", "2"], false)?;
+
+        assert!(!ret.status());
+
+        let (ret, set) = parse(&["app", "--n", "1", "--list=1", "--list=2"], false)?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--n")?, &1);
+        assert_eq!(set.find_vals::<i64>("--list")?, &[1, 2]);
+
+        let (ret, set) = parse(&["app", "--flag=foo", "--flag=42"], true)?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--flag".infer::<i64>())?, &42);
+        assert_eq!(set.find_val::<String>("--flag".infer::<String>())?, "foo");
+
+        let (ret, _) = parse(&["app", "--flag=foo"], false)?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_optional_value() {
+        assert!(test_optional_value_impl().is_ok());
+    }
+
+    fn test_optional_value_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--color=s")?.set_optional_value("auto").run()?;
+                set.add_opt("--name=s")?.run()?;
+                set.add_opt("args=p@1..")?
+                    .set_pos_type::<String>()
+                    .set_action(Action::App)
+                    .run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--color"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--color")?, "auto");
+
+        let (ret, set) = parse(&["app", "--color=never", "foo"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--color")?, "never");
+        assert_eq!(set.find_vals::<String>("args")?, &["foo"]);
+
+        let (ret, set) = parse(&["app", "--color", "always", "--name", "bar"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--color")?, "auto");
+        assert_eq!(set.find_val::<String>("--name")?, "bar");
+        assert_eq!(set.find_vals::<String>("args")?, &["always"]);
+
+        let (ret, _) = parse(&["app", "--name"])?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_greedy() {
+        assert!(test_greedy_impl().is_ok());
+    }
+
+    fn test_greedy_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, _| {
+                set.add_opt("--files=s")?
+                    .set_action(Action::App)
+                    .set_greedy(true)
+                    .run()?;
+                set.add_opt("--other=b")?.run()?;
+                set.add_opt("stop".infer::<crate::value::Stop>())?.run()?;
+                set.add_opt("args=p@1..")?
+                    .set_pos_type::<String>()
+                    .set_action(Action::App)
+                    .run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--files", "a", "b", "c", "--other"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_vals::<String>("--files")?, &["a", "b", "c"]);
+        assert!(*set.find_val::<bool>("--other")?);
+        assert!(set.find_vals::<String>("args").is_err());
+
+        let (ret, set) = parse(&["app", "--files=a", "b", "--", "c", "d"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_vals::<String>("--files")?, &["a", "b"]);
+        assert_eq!(set.find_vals::<String>("args")?, &["c", "d"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand_args() {
+        assert!(test_expand_args_impl().is_ok());
+    }
+
+    fn test_expand_args_impl() -> Result<(), Error> {
+        fn parse(args: &[&str]) -> Result<(Return, ASet), Error> {
+            parse_with(&mut AFwdPolicy::default(), args, |set, inv| {
+                inv.entry(set.add_opt("--preset=s")?.run()?).on(
+                    |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                        let val = ctx.value::<String>()?;
+
+                        if val == "fast" {
+                            ctx.expand_args(["--threads", "8", "--opt-level", "3"]);
+                        }
+                        Ok(Some(val))
+                    },
+                );
+                inv.entry(set.add_opt("--loop=b")?.run()?).on(
+                    |_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                        ctx.expand_args(["--loop"]);
+                        Ok(Some(true))
+                    },
+                );
+                set.add_opt("--threads=i")?.run()?;
+                set.add_opt("--opt-level=i")?.run()?;
+                set.add_opt("--debug=b")?.run()?;
+                set.add_opt("args=p@1..")?
+                    .set_pos_type::<String>()
+                    .set_action(Action::App)
+                    .run()?;
+                Ok(())
+            })
+        }
+
+        let (ret, set) = parse(&["app", "--debug", "--preset", "fast", "foo"])?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--preset")?, "fast");
+        assert_eq!(set.find_val::<i64>("--threads")?, &8);
+        assert_eq!(set.find_val::<i64>("--opt-level")?, &3);
+        assert!(*set.find_val::<bool>("--debug")?);
+        assert_eq!(set.find_vals::<String>("args")?, &["foo"]);
+
+        // the arguments after expansion can override the values
+        let (ret, set) = parse(&["app", "--preset=fast", "--threads", "2"])?;
+
+        assert!(ret.status());
+        assert_eq!(ret.orig_args().len(), 4);
+        assert_eq!(ret.expanded_args().map(|v| v.len()), Some(7));
+        assert_eq!(set.find_val::<i64>("--threads")?, &2);
+        assert_eq!(set.find_val::<i64>("--opt-level")?, &3);
+
+        let (ret, set) = parse(&["app", "--preset", "slow"])?;
+
+        assert!(ret.status());
+        assert!(set.find_val::<i64>("--threads").is_err());
+
+        // the expansion never terminates
+        assert!(parse(&["app", "--loop"]).is_err_and(|e| e.to_string().contains("too many")));
+        Ok(())
+    }
 }
@@ -1,13 +1,41 @@
 use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use super::bare_prefix;
+use super::check_ambiguity;
+use super::check_conflicts_with_positional;
+use super::check_deprecated_alias;
+use super::check_expansion;
+use super::check_max_positionals;
+use super::check_option_name;
+use super::check_standalone;
+use super::check_unexpanded;
+use super::check_value_count;
+use super::check_values;
+use super::expand_abbrev_cmd;
+use super::greedy_opt;
+use super::greedy_value;
+use super::guess_trace;
+use super::prompt_missing;
+use super::splice_args;
+use super::store_trailing_pos;
+use super::store_trailing_raw;
+use super::toggle_arg;
+use super::trailing_raw_cmd;
+use super::trailing_raw_pos;
 use super::FailManager;
 use super::OptStyleManager;
+use super::ParseStatus;
 use super::Policy;
 use super::PolicySettings;
+use super::PromptHandler;
 use super::Return;
+use super::UnknownHandler;
 use super::UserStyle;
+use super::Warnings;
+use super::STOP;
 use crate::args;
 use crate::args::ArgInfo;
 use crate::args::Args;
@@ -116,6 +144,20 @@ pub struct PrePolicy<Set, Ser, Chk> {
 
     overload: bool,
 
+    max_positionals: Option<usize>,
+
+    abbrev_subcommand: bool,
+
+    intermixed: bool,
+
+    option_terminator: Option<String>,
+
+    diagnostics: bool,
+
+    parallel_checks: bool,
+
+    prompt_handler: Option<PromptHandler>,
+
     style_manager: OptStyleManager,
 
     checker: Chk,
@@ -131,6 +173,13 @@ where
         Self {
             strict: self.strict,
             overload: self.overload,
+            max_positionals: self.max_positionals,
+            abbrev_subcommand: self.abbrev_subcommand,
+            intermixed: self.intermixed,
+            option_terminator: self.option_terminator.clone(),
+            diagnostics: self.diagnostics,
+            parallel_checks: self.parallel_checks,
+            prompt_handler: self.prompt_handler.clone(),
             style_manager: self.style_manager.clone(),
             checker: self.checker.clone(),
             marker_s: self.marker_s,
@@ -146,6 +195,13 @@ where
         f.debug_struct("PrePolicy")
             .field("strict", &self.strict)
             .field("overload", &self.overload)
+            .field("max_positionals", &self.max_positionals)
+            .field("abbrev_subcommand", &self.abbrev_subcommand)
+            .field("intermixed", &self.intermixed)
+            .field("option_terminator", &self.option_terminator)
+            .field("diagnostics", &self.diagnostics)
+            .field("parallel_checks", &self.parallel_checks)
+            .field("prompt_handler", &self.prompt_handler.is_some())
             .field("style_manager", &self.style_manager)
             .field("checker", &self.checker)
             .finish()
@@ -160,6 +216,13 @@ where
         Self {
             strict: false,
             overload: false,
+            max_positionals: None,
+            abbrev_subcommand: false,
+            intermixed: true,
+            option_terminator: None,
+            diagnostics: false,
+            parallel_checks: false,
+            prompt_handler: None,
             style_manager: OptStyleManager::default(),
             checker: Chk::default(),
             marker_s: PhantomData,
@@ -203,6 +266,41 @@ impl<Set, Ser, Chk> PrePolicy<Set, Ser, Chk> {
         self
     }
 
+    pub fn with_max_positionals(mut self, max: Option<usize>) -> Self {
+        self.max_positionals = max;
+        self
+    }
+
+    pub fn with_abbrev_subcommand(mut self, allow: bool) -> Self {
+        self.abbrev_subcommand = allow;
+        self
+    }
+
+    pub fn with_intermixed(mut self, intermixed: bool) -> Self {
+        self.intermixed = intermixed;
+        self
+    }
+
+    pub fn with_option_terminator(mut self, terminator: Option<String>) -> Self {
+        self.option_terminator = terminator;
+        self
+    }
+
+    pub fn with_diagnostics(mut self, diagnostics: bool) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    pub fn with_parallel_checks(mut self, parallel: bool) -> Self {
+        self.parallel_checks = parallel;
+        self
+    }
+
+    pub fn with_prompt_handler(mut self, handler: Option<PromptHandler>) -> Self {
+        self.prompt_handler = handler;
+        self
+    }
+
     pub fn set_checker(&mut self, checker: Chk) -> &mut Self {
         self.checker = checker;
         self
@@ -268,6 +366,22 @@ impl<Set, Ser, Chk> PolicySettings for PrePolicy<Set, Ser, Chk> {
         self.overload
     }
 
+    fn max_positionals(&self) -> Option<usize> {
+        self.max_positionals
+    }
+
+    fn allow_abbrev_subcommand(&self) -> bool {
+        self.abbrev_subcommand
+    }
+
+    fn intermixed(&self) -> bool {
+        self.intermixed
+    }
+
+    fn option_terminator(&self) -> &str {
+        self.option_terminator.as_deref().unwrap_or(STOP)
+    }
+
     fn set_strict(&mut self, strict: bool) -> &mut Self {
         self.strict = strict;
         self
@@ -286,6 +400,53 @@ impl<Set, Ser, Chk> PolicySettings for PrePolicy<Set, Ser, Chk> {
         self.overload = overload;
         self
     }
+
+    fn set_max_positionals(&mut self, max: Option<usize>) -> &mut Self {
+        self.max_positionals = max;
+        self
+    }
+
+    fn set_allow_abbrev_subcommand(&mut self, allow: bool) -> &mut Self {
+        self.abbrev_subcommand = allow;
+        self
+    }
+
+    fn set_intermixed(&mut self, intermixed: bool) -> &mut Self {
+        self.intermixed = intermixed;
+        self
+    }
+
+    fn set_option_terminator(&mut self, terminator: Option<String>) -> &mut Self {
+        self.option_terminator = terminator;
+        self
+    }
+
+    fn diagnostics(&self) -> bool {
+        self.diagnostics
+    }
+
+    fn set_diagnostics(&mut self, diagnostics: bool) -> &mut Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    fn parallel_checks(&self) -> bool {
+        self.parallel_checks
+    }
+
+    fn set_parallel_checks(&mut self, parallel: bool) -> &mut Self {
+        self.parallel_checks = parallel;
+        self
+    }
+
+    fn set_prompt_handler(&mut self, handler: Option<PromptHandler>) -> &mut Self {
+        self.prompt_handler = handler;
+        self
+    }
+
+    fn set_unknown_handler(&mut self, _: Option<UnknownHandler>) -> &mut Self {
+        self
+    }
 }
 
 impl<Set, Ser, Chk> PrePolicy<Set, Ser, Chk>
@@ -301,16 +462,18 @@ where
         ser: &mut <Self as Policy>::Ser,
         orig: &'a Args,
         ctx: &mut Ctx<'a>,
-    ) -> Result<(), <Self as Policy>::Error> {
+    ) -> Result<Option<Args>, <Self as Policy>::Error> {
         self.checker().pre_check(set).map_err(|e| e.into())?;
 
         let overload = self.overload();
+        let diagnostics = self.diagnostics();
         let opt_styles = &self.style_manager;
         let args: Vec<_> = orig.iter().map(|v| v.as_os_str()).collect();
         let total = args.len();
         let mut lefts = vec![];
         let mut opt_fail = FailManager::default();
         let mut iter2 = args::iter2(&args).enumerate();
+        let mut greedy = None;
 
         trace!("parsing {ctx:?} using pre policy");
         ctx.set_args(args.clone());
@@ -319,8 +482,51 @@ where
             let mut consume = false;
             let mut stopped = false;
             let mut like_opt = false;
+            let mut expansion = None;
+
+            // collect the rest arguments verbatim if the command has trailing raw
+            if lefts.len() == Self::noa_cmd() {
+                if let Some(uid) = trailing_raw_cmd(set, opt) {
+                    lefts.push(*opt);
+                    store_trailing_raw(
+                        set,
+                        uid,
+                        self.option_terminator(),
+                        iter2.map(|(_, (a, _))| *a),
+                    );
+                    break;
+                }
+            }
+            // collect the rest arguments if any positional argument has trailing raw
+            if OsStr::new(self.option_terminator()) == *opt {
+                if let Some(uid) = trailing_raw_pos(set) {
+                    store_trailing_pos(set, uid, iter2.map(|(_, (a, _))| *a))?;
+                    break;
+                }
+            }
+            // the custom option terminator stops the option parsing
+            if self.option_terminator() != STOP && OsStr::new(self.option_terminator()) == *opt {
+                // skip current, put left argument to noa args
+                lefts.extend(iter2.map(|(_, (a, _))| *a));
+                break;
+            }
+
+            // the argument is a value of previous greedy option
+            let info = match greedy.take().and_then(|name| {
+                let terminator = self.option_terminator();
 
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
+                greedy_value(set, opt_styles, terminator, name, opt)
+            }) {
+                Some(info) => Ok(info),
+                None => {
+                    if self.strict() {
+                        check_option_name(opt, opt_styles.assign_char())?;
+                    }
+                    opt_styles.parse_arg(opt).map(|info| toggle_arg(set, info))
+                }
+            };
+
+            if let Ok(ArgInfo { name, value }) = info {
                 trace!(
                     "guess name: {:?} value: {:?} & next: {:?}",
                     name,
@@ -328,8 +534,10 @@ where
                     next
                 );
                 if let Some(valid) = Self::ig_failure(set.check(&name).map_err(Into::into))? {
-                    if valid {
-                        like_opt = true;
+                    let valid = valid && !bare_prefix(set, &name);
+
+                    if valid || opt_styles.has_custom() {
+                        like_opt = valid;
                         let arg = value.clone();
                         let next = next.map(|v| Cow::Borrowed(*v));
                         let mut guess = InvokeGuess {
@@ -345,10 +553,35 @@ where
                             name: Some(name.clone()),
                         };
 
+                        let mut tried = vec![];
+
+                        let prefix = match valid {
+                            true => guess.set.split(&name).map_err(Into::into)?.0,
+                            false => Cow::Borrowed(""),
+                        };
+
+                        if valid && guess.arg.is_none() {
+                            check_ambiguity(guess.set, guess.ctx, opt_styles, &prefix, &name)?;
+                        }
+
                         for style in opt_styles.iter() {
-                            if let Some(Some(ret)) =
-                                Self::ig_failure(guess.guess_and_invoke(style, overload))?
+                            // only the custom styles are guessed if it not like an option
+                            if !opt_styles.is_enabled_for(style, &prefix)
+                                || !(valid || matches!(style, UserStyle::Custom(_)))
                             {
+                                continue;
+                            }
+                            if diagnostics {
+                                tried.push(style.clone());
+                            }
+                            let ret = match opt_styles.guess_custom(style, opt, guess.set)? {
+                                Some(custom) => guess.with_custom(custom, |guess, style| {
+                                    guess.guess_and_invoke(style, overload)
+                                }),
+                                None => guess.guess_and_invoke(style, overload),
+                            };
+
+                            if let Some(Some(ret)) = Self::ig_failure(ret)? {
                                 (matched, consume) = (ret.matched, ret.consume);
                             }
                             match guess.ctx.policy_act() {
@@ -357,19 +590,32 @@ where
                                     guess.ctx.reset_policy_act();
                                     break;
                                 }
-                                Action::Quit => return Ok(()),
+                                Action::Quit => return Ok(None),
                                 Action::Null => {}
                             }
                             if matched {
                                 break;
                             }
                         }
+                        if diagnostics && (valid || matched) {
+                            guess.ctx.add_guess_trace(guess_trace(opt, tried, matched));
+                        }
+                        // the expansion of unmatched handler is dropped
+                        expansion = guess.ctx.take_expansion().filter(|_| matched);
+                        if matched {
+                            check_deprecated_alias(guess.set, guess.ctx, &name);
+                            if greedy_opt(guess.set, &name) {
+                                greedy = Some(name.clone());
+                            }
+                        }
                     }
                 } else {
                     trace!("`{:?}` not like option", opt);
                 }
             }
-            if stopped {
+            // the default terminator `--` not matched by Stop option stops the option parsing
+            if stopped || (!matched && OsStr::new(STOP) == *opt && self.option_terminator() == STOP)
+            {
                 // skip current, put left argument to noa args
                 lefts.extend(iter2.map(|(_, (a, _))| *a));
                 break;
@@ -381,9 +627,26 @@ where
                 // add it to NOA if current argument not matched
                 // and not in strict mode or the argument not like an option
                 lefts.push(*opt);
+                if !like_opt && !self.intermixed() && lefts.len() > Self::noa_cmd() {
+                    // the first positional argument stops the option parsing
+                    lefts.extend(iter2.map(|(_, (a, _))| *a));
+                    break;
+                }
+            }
+            if let Some(expansion) = expansion {
+                // parse again with the arguments of matched option replaced by expansion
+                return Ok(Some(splice_args(
+                    &lefts,
+                    expansion,
+                    iter2.map(|(_, (a, _))| *a),
+                )));
             }
         }
+        prompt_missing(set, self.prompt_handler.as_ref())?;
         opt_fail.process_check(self.checker().opt_check(set))?;
+        if self.parallel_checks() {
+            check_values(set, &ctx.take_pending_check())?;
+        }
 
         let args = lefts;
         let total = args.len();
@@ -392,7 +655,11 @@ where
 
         ctx.set_args(args.clone());
         if total > 0 {
-            let name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+            let mut name = crate::str::osstr_to_str_i(&args, Self::noa_cmd());
+
+            if self.allow_abbrev_subcommand() {
+                name = expand_abbrev_cmd(set, name)?;
+            }
             let mut guess = InvokeGuess {
                 set,
                 inv,
@@ -407,9 +674,17 @@ where
             };
 
             trace!("guess Cmd = {:?}", guess.name);
-            Self::ig_failure(guess.guess_and_invoke(&UserStyle::Cmd, overload))?;
+            let ret = Self::ig_failure(guess.guess_and_invoke(&UserStyle::Cmd, overload))?;
+
+            if let Some(name) = guess
+                .name
+                .as_deref()
+                .filter(|_| ret.flatten().is_some_and(|v| v.matched))
+            {
+                check_deprecated_alias(guess.set, guess.ctx, name);
+            }
             if let Action::Quit = ctx.policy_act() {
-                return Ok(());
+                return Ok(None);
             }
             cmd_fail.process_check(self.checker().cmd_check(set))?;
 
@@ -436,7 +711,7 @@ where
                         guess.ctx.reset_policy_act();
                         break;
                     }
-                    Action::Quit => return Ok(()),
+                    Action::Quit => return Ok(None),
                     Action::Null => {}
                 }
             }
@@ -444,6 +719,10 @@ where
             cmd_fail.process_check(self.checker().cmd_check(set))?;
         }
         pos_fail.process_check(self.checker().pos_check(set))?;
+        check_max_positionals(set, self.max_positionals(), total)?;
+        check_conflicts_with_positional(set, total)?;
+        check_standalone(set, total)?;
+        check_value_count(set)?;
 
         let name = crate::str::osstr_to_str_i(&ctx.args, Self::noa_main());
         let mut main_fail = FailManager::default();
@@ -463,7 +742,7 @@ where
         trace!("guess Main {:?}", guess.name);
         Self::ig_failure(guess.guess_and_invoke(&UserStyle::Main, overload))?;
         main_fail.process_check(self.checker().post_check(set))?;
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -490,15 +769,36 @@ where
         ser: &mut Self::Ser,
         orig: Args,
     ) -> Result<Self::Ret, Self::Error> {
-        let mut ctx = Ctx::default().with_orig(orig.clone());
-
-        match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
-            Ok(_) => Ok(Return::new(ctx)),
-            Err(e) => {
-                if e.is_failure() {
-                    Ok(Return::new(ctx).with_failure(e))
-                } else {
-                    Err(e)
+        let mut expanded: Option<Args> = None;
+        let mut warnings = Warnings::default();
+        let mut count = 0;
+
+        loop {
+            let args = expanded.clone().unwrap_or_else(|| orig.clone());
+            let mut ctx = Ctx::default()
+                .with_orig(orig.clone())
+                .with_expanded(expanded.clone())
+                .with_deferred_check(self.parallel_checks())
+                .with_overload(self.overload());
+
+            ctx.warnings = std::mem::take(&mut warnings);
+            match self.parse_impl(set, inv, ser, &args, &mut ctx) {
+                Ok(Some(args)) => {
+                    count += 1;
+                    check_expansion(count)?;
+                    warnings = std::mem::take(&mut ctx.warnings);
+                    expanded = Some(args);
+                }
+                Ok(None) => {
+                    check_unexpanded(&ctx)?;
+                    return Ok(Return::new(ctx).with_parse_status(ParseStatus::of(set)));
+                }
+                Err(e) => {
+                    return if e.is_failure() {
+                        Ok(Return::new(ctx).with_failure(e))
+                    } else {
+                        Err(e)
+                    };
                 }
             }
         }
@@ -1120,4 +1420,121 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_abbrev_subcommand() {
+        assert!(test_abbrev_subcommand_impl().is_ok());
+    }
+
+    fn test_abbrev_subcommand_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], abbrev: bool) -> Result<(Return, ASet), Error> {
+            let mut policy = APrePolicy::default().with_abbrev_subcommand(abbrev);
+            let mut set = policy.default_set();
+            let mut inv = policy.default_inv();
+            let mut ser = policy.default_ser();
+
+            set.add_opt("--flag=b")?;
+            set.add_opt("checkout=c")?;
+            set.add_opt("cherry-pick=c")?;
+            set.add_opt("che=c")?;
+            set.add_opt("commit=c")?.add_alias("ci").run()?;
+            set.add_opt("Status=c")?.set_ignore_case(true).run()?;
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+            let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(args))?;
+
+            Ok((ret, set))
+        }
+
+        // unique prefix
+        let (ret, set) = parse(&["app", "chec", "--flag"], true)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("checkout")?);
+        assert!(!*set.find_val::<bool>("cherry-pick")?);
+
+        let (ret, set) = parse(&["app", "com"], true)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("commit")?);
+
+        // disabled by default
+        let (ret, set) = parse(&["app", "chec"], false)?;
+
+        assert!(!ret.status());
+        assert!(!*set.find_val::<bool>("checkout")?);
+
+        // exact match is preferred
+        let (ret, set) = parse(&["app", "che"], true)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("che")?);
+        assert!(!*set.find_val::<bool>("checkout")?);
+
+        // ambiguous prefix
+        let (ret, _) = parse(&["app", "ch"], true)?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.to_string()).as_deref(),
+            Some("ambiguous sub command `ch`, candidates: checkout, cherry-pick, che")
+        );
+
+        // prefix matched case-insensitively
+        let (ret, set) = parse(&["app", "sTA"], true)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("Status")?);
+
+        let (ret, _) = parse(&["app", "Che"], true)?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+
+    #[test]
+    fn test_intermixed() {
+        assert!(test_intermixed_impl().is_ok());
+    }
+
+    fn test_intermixed_impl() -> Result<(), Error> {
+        fn parse(args: &[&str], intermixed: bool) -> Result<(Return, ASet), Error> {
+            let mut policy = APrePolicy::default().with_intermixed(intermixed);
+            let mut set = policy.default_set();
+            let mut inv = policy.default_inv();
+            let mut ser = policy.default_ser();
+
+            set.add_opt("--flag=b")?;
+            set.add_opt("sub=c")?;
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+            let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(args))?;
+
+            Ok((ret, set))
+        }
+
+        let (ret, set) = parse(&["app", "sub", "--flag"], true)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("sub")?);
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert_eq!(ret.args(), &["app", "sub"]);
+
+        // the sub command stops the option parsing, `--flag` is left to it
+        let (ret, set) = parse(&["app", "sub", "--flag"], false)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("sub")?);
+        assert!(!*set.find_val::<bool>("--flag")?);
+        assert_eq!(ret.args(), &["app", "sub", "--flag"]);
+
+        // options before the sub command still work
+        let (ret, set) = parse(&["app", "--flag", "sub"], false)?;
+
+        assert!(ret.status());
+        assert!(*set.find_val::<bool>("--flag")?);
+        Ok(())
+    }
 }
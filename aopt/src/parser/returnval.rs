@@ -1,10 +1,22 @@
+use std::collections::BTreeMap;
 use std::ffi::OsString;
+use std::ops::Deref;
 
 use crate::args::Args;
 use crate::ctx::Ctx;
+use crate::opt::Opt;
 use crate::opt::Style;
+use crate::parser::UserStyle;
+use crate::parser::Warning;
+use crate::set::Set;
+use crate::set::SetOpt;
+use crate::value::AnyValue;
+use crate::value::ErasedValue;
 use crate::{Error, Uid};
 
+/// The text replacing the value of [`secret`](Opt::secret) option when logging the arguments.
+pub const SECRET_MASK: &str = "***";
+
 #[derive(Debug, Clone, Default)]
 pub struct Guess {
     pub uid: Uid,
@@ -24,17 +36,96 @@ pub struct Guess {
 pub struct Context {
     pub orig: Args,
 
+    /// The arguments expanded by [`expand_args`](Ctx::expand_args), `None` if nothing expanded.
+    pub expanded: Option<Args>,
+
     pub args: Vec<OsString>,
 
     pub guess: Option<Guess>,
 }
 
+/// The guess decision of an option argument, recorded if the
+/// [`diagnostics`](crate::parser::PolicySettings::diagnostics) is enabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuessTrace {
+    /// The argument from command line.
+    pub token: String,
+
+    /// The styles tried in order.
+    pub styles: Vec<UserStyle>,
+
+    /// The style matched the argument, it is `None` if nothing matched.
+    pub winner: Option<UserStyle>,
+}
+
+/// The status of parsing set by the policies, see [`Return::parse_status`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ParseStatus {
+    /// The parsing successes and some options matched.
+    #[default]
+    Completed,
+
+    /// The parsing successes but no option matched, such as an empty input.
+    NothingMatched,
+
+    /// The parsing successes and the help option matched,
+    /// see [`help_option`](crate::set::Set::help_option).
+    HelpRequested,
+
+    /// The parsing failed, the failure is kept in [`Return::failure`].
+    Failed,
+}
+
+impl ParseStatus {
+    /// Get the status of successful parsing from the options of `set`.
+    pub fn of<S>(set: &S) -> Self
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let help = set.help_option();
+        let is_help = |opt: &SetOpt<S>| help.is_some_and(|name| opt.name() == name);
+        // the main options are always invoked
+        let mut matched = set
+            .iter()
+            .filter(|opt| opt.matched() && !opt.mat_style(Style::Main));
+
+        match matched.next() {
+            None => Self::NothingMatched,
+            Some(opt) if is_help(opt) || matched.any(is_help) => Self::HelpRequested,
+            Some(_) => Self::Completed,
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        matches!(self, Self::Completed)
+    }
+
+    pub fn is_nothing_matched(&self) -> bool {
+        matches!(self, Self::NothingMatched)
+    }
+
+    pub fn is_help_requested(&self) -> bool {
+        matches!(self, Self::HelpRequested)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed)
+    }
+}
+
 /// Return value for [`Policy`](crate::parser::Policy).
 #[derive(Debug, Clone, Default)]
 pub struct Return {
     ctx: Context,
 
     failure: Option<Error>,
+
+    parse_status: ParseStatus,
+
+    warnings: Vec<Warning>,
+
+    guess_trace: Vec<GuessTrace>,
 }
 
 impl Return {
@@ -44,6 +135,7 @@ impl Return {
         Self {
             ctx: Context {
                 orig: ctx.orig,
+                expanded: ctx.expanded,
                 args,
                 guess: ctx.inner_ctx.map(|v| Guess {
                     uid: v.uid(),
@@ -55,23 +147,63 @@ impl Return {
                 }),
             },
             failure: None,
+            parse_status: ParseStatus::default(),
+            warnings: ctx.warnings.into_inner(),
+            guess_trace: ctx.guess_trace,
         }
     }
 
+    /// Set the failure, the [`parse_status`](Return::parse_status) is set to [`ParseStatus::Failed`].
     pub fn with_failure(mut self, failure: Error) -> Self {
         self.failure = Some(failure);
+        self.parse_status = ParseStatus::Failed;
         self
     }
 
+    pub fn with_parse_status(mut self, parse_status: ParseStatus) -> Self {
+        self.parse_status = parse_status;
+        self
+    }
+
+    /// Set the failure, the [`parse_status`](Return::parse_status) is set to [`ParseStatus::Failed`].
     pub fn set_failure(&mut self, failure: Error) -> &mut Self {
         self.failure = Some(failure);
+        self.parse_status = ParseStatus::Failed;
         self
     }
 
+    pub fn set_parse_status(&mut self, parse_status: ParseStatus) -> &mut Self {
+        self.parse_status = parse_status;
+        self
+    }
+
+    /// The [`ParseStatus`] of parsing, it is [`Failed`](ParseStatus::Failed) if any failure raised.
+    pub fn parse_status(&self) -> ParseStatus {
+        self.parse_status
+    }
+
     pub fn failure(&self) -> Option<&Error> {
         self.failure.as_ref()
     }
 
+    /// The warnings raised during parsing, such as the deprecated alias is used.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// The guess decisions recorded during parsing, it is empty if the
+    /// [`diagnostics`](crate::parser::PolicySettings::diagnostics) is disabled.
+    pub fn guess_trace(&self) -> &[GuessTrace] {
+        &self.guess_trace
+    }
+
+    /// Print the warnings to stderr.
+    pub fn print_warnings(&self) {
+        for warning in self.warnings.iter() {
+            eprintln!("warning: {warning}");
+        }
+    }
+
     pub fn ctx(&self) -> &Context {
         &self.ctx
     }
@@ -85,8 +217,15 @@ impl Return {
         &self.ctx.orig
     }
 
+    /// The arguments parsed last after expanded by [`expand_args`](Ctx::expand_args),
+    /// `None` if nothing expanded.
+    pub fn expanded_args(&self) -> Option<&Args> {
+        self.ctx.expanded.as_ref()
+    }
+
     /// The [`status`](Return::status) is true if parsing successes
     /// otherwise it will be false if any [`failure`](Error::is_failure) raised.
+    /// See [`parse_status`](Return::parse_status) for more details of parsing.
     pub fn status(&self) -> bool {
         self.failure.is_none()
     }
@@ -123,6 +262,143 @@ impl Return {
     pub fn clone_args(&self) -> Vec<OsString> {
         self.ctx.args.clone()
     }
+
+    /// Iterate the name and values of options of `set` matched in the last parsing,
+    /// in the order of options added. The [`Main`](Style::Main) is not included.
+    pub fn set_options<'a, S>(&self, set: &'a S) -> impl Iterator<Item = (&'a str, &'a AnyValue)>
+    where
+        S: Set,
+        SetOpt<S>: Opt + 'a,
+    {
+        set.iter_ordered()
+            .filter(|opt| opt.matched() && !opt.mat_style(Style::Main))
+            .map(|opt| (opt.name(), opt.accessor().deref()))
+    }
+
+    /// Reassemble a re-runnable command line from the matched options of `set`
+    /// and the arguments left, such as positionals and the arguments not matched.
+    ///
+    /// The options are generated in `--name value` form, and the options
+    /// have multiple values will expand to repeated options.
+    /// The value of [`secret`](Opt::secret) option is replaced by [`SECRET_MASK`].
+    pub fn rebuild_command_line<S>(&self, set: &S) -> Vec<OsString>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let mut ret = vec![];
+        let mut args = self.ctx.args.iter();
+
+        // the first argument is the program name
+        if let Some(first) = args.next() {
+            ret.push(first.clone());
+        }
+        for opt in set.iter_ordered().filter(|opt| opt.matched()) {
+            let rawvals = opt.accessor().rawvals().ok();
+
+            if opt.mat_style(Style::Argument) {
+                for raw in rawvals.into_iter().flatten() {
+                    ret.push(OsString::from(opt.name()));
+                    ret.push(if opt.secret() {
+                        OsString::from(SECRET_MASK)
+                    } else {
+                        raw.clone()
+                    });
+                }
+            } else if opt.mat_style(Style::Boolean)
+                || opt.mat_style(Style::Combined)
+                || opt.mat_style(Style::Flag)
+            {
+                let count = rawvals.map(|v| v.len()).unwrap_or_default().max(1);
+
+                for _ in 0..count {
+                    ret.push(OsString::from(opt.name()));
+                }
+            }
+        }
+        ret.extend(args.cloned());
+        ret
+    }
+
+    /// Return the canonical textual form of the matched options of `set`
+    /// and the arguments left, it is stable and suitable for logging.
+    ///
+    /// The options are generated in `--name=value` or `--flag` form using the name of option,
+    /// the alias is resolved to the name. The program name is not included.
+    /// The value of [`secret`](Opt::secret) option is replaced by [`SECRET_MASK`].
+    pub fn normalized_args<S>(&self, set: &S) -> Vec<String>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let mut ret = vec![];
+
+        for opt in set.iter_ordered().filter(|opt| opt.matched()) {
+            let rawvals = opt.accessor().rawvals().ok();
+
+            if opt.mat_style(Style::Argument) {
+                for raw in rawvals.into_iter().flatten() {
+                    if opt.secret() {
+                        ret.push(format!("{}={}", opt.name(), SECRET_MASK));
+                    } else {
+                        ret.push(format!("{}={}", opt.name(), raw.to_string_lossy()));
+                    }
+                }
+            } else if opt.mat_style(Style::Boolean)
+                || opt.mat_style(Style::Combined)
+                || opt.mat_style(Style::Flag)
+            {
+                let count = rawvals.map(|v| v.len()).unwrap_or_default().max(1);
+
+                for _ in 0..count {
+                    ret.push(opt.name().to_owned());
+                }
+            }
+        }
+        ret.extend(
+            self.ctx
+                .args
+                .iter()
+                .skip(1)
+                .map(|v| v.to_string_lossy().into_owned()),
+        );
+        ret
+    }
+
+    /// Return the raw values of matched options of `set` keyed by the name of option,
+    /// it is suitable for JSON or other structured dumps.
+    ///
+    /// The options have no value, such as boolean options, map to an empty list.
+    /// The value of [`secret`](Opt::secret) option is replaced by [`SECRET_MASK`].
+    pub fn dump_args<S>(&self, set: &S) -> BTreeMap<String, Vec<String>>
+    where
+        S: Set,
+        SetOpt<S>: Opt,
+    {
+        let mut ret = BTreeMap::new();
+
+        for opt in set
+            .iter()
+            .filter(|opt| opt.matched() && !opt.mat_style(Style::Main))
+        {
+            let rawvals = opt.accessor().rawvals().ok();
+            let values = if opt.mat_style(Style::Argument) || opt.mat_style(Style::Pos) {
+                rawvals
+                    .into_iter()
+                    .flatten()
+                    .map(|raw| match opt.secret() {
+                        true => SECRET_MASK.to_owned(),
+                        false => raw.to_string_lossy().into_owned(),
+                    })
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            ret.insert(opt.name().to_owned(), values);
+        }
+        ret
+    }
 }
 
 impl From<Return> for bool {
@@ -142,3 +418,242 @@ impl From<&mut Return> for bool {
         value.status()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_rebuild_command_line() {
+        assert!(test_rebuild_command_line_impl().is_ok());
+    }
+
+    fn test_rebuild_command_line_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+        let new_set = |policy: &AFwdPolicy| -> Result<ASet, Error> {
+            let mut set = policy.default_set();
+
+            set.add_opt("--name=s")?;
+            set.add_opt("--flag=b")?;
+            set.add_opt("--vals=i")?;
+            set.add_opt("file=p@1")?.set_pos_type::<String>().run()?;
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+            Ok(set)
+        };
+
+        let mut set = new_set(&policy)?;
+        let args = Args::from([
+            "app", "--name", "foo", "bar.txt", "--vals", "1", "--flag", "--vals=2", "extra",
+        ]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        let cmdline = ret.rebuild_command_line(&set);
+
+        assert_eq!(
+            cmdline,
+            ["app", "--name", "foo", "--flag", "--vals", "1", "--vals", "2", "bar.txt", "extra"]
+        );
+
+        let mut set = new_set(&policy)?;
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(cmdline))?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<String>("--name")?, "foo");
+        assert!(*set.find_val::<bool>("--flag")?);
+        assert_eq!(set.find_vals::<i64>("--vals")?, &[1, 2]);
+        assert_eq!(set.find_val::<String>("file")?, "bar.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalized_args() {
+        assert!(test_normalized_args_impl().is_ok());
+    }
+
+    fn test_normalized_args_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--output=i")?.add_alias("-o").run()?;
+        set.add_opt("--canonical=s")?.add_alias("--alias").run()?;
+        set.add_opt("--verbose=b")?.add_alias("-v").run()?;
+        set.add_opt("--unused=s")?.run()?;
+        set.add_opt("file=p@1")?.set_pos_type::<String>().run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "-o1", "foo.txt", "--alias", "v", "-v", "bar"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+        assert_eq!(
+            ret.normalized_args(&set),
+            ["--output=1", "--canonical=v", "--verbose", "foo.txt", "bar"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_secret_args() {
+        assert!(test_secret_args_impl().is_ok());
+    }
+
+    fn test_secret_args_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--user=s")?.run()?;
+        set.add_opt("--token=s")?.set_secret(true).run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "--user", "lily", "--token=s3cr3t", "data"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+
+        assert!(ret.status());
+        assert_eq!(
+            ret.normalized_args(&set),
+            ["--user=lily", "--token=***", "data"]
+        );
+        assert_eq!(
+            ret.rebuild_command_line(&set),
+            ["app", "--user", "lily", "--token", "***", "data"]
+        );
+        let json =
+            serde_json::to_string(&ret.dump_args(&set)).map_err(|e| crate::raise_error!("{e}"))?;
+
+        assert_eq!(json, r#"{"--token":["***"],"--user":["lily"]}"#);
+        // the value stored is not masked
+        assert_eq!(set.find_val::<String>("--token")?, "s3cr3t");
+
+        // the value is masked in the failure
+        let mut parse = |args: &[&str]| -> Result<String, Error> {
+            let mut set = policy.default_set();
+
+            set.add_opt("--pin=i")?.set_secret(true).run()?;
+            set.add_opt("--code=i!")?
+                .set_secret(true)
+                .set_prompt(String::from("code: "))
+                .run()?;
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+            policy.set_prompt_handler(Some(Arc::new(|_, _| Some("c0de".to_owned()))));
+
+            let ret = policy.parse(&mut set, &mut inv, &mut ser, Args::from(args));
+            let mut message = String::default();
+            let mut error = Some(ret.map_or_else(|e| e, |ret| ret.failure().unwrap().clone()));
+
+            while let Some(err) = error {
+                message.push_str(&format!("{err}\n{}\n", err.snippet().unwrap_or_default()));
+                error = err.caused_by().cloned();
+            }
+            Ok(message)
+        };
+        let message = parse(&["app", "--pin", "s3cr3t", "--code=1"])?;
+
+        assert!(message.contains("--pin"));
+        assert!(!message.contains("s3cr3t"));
+
+        let message = parse(&["app"])?;
+
+        assert!(message.contains("--code"));
+        assert!(!message.contains("c0de"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_options() {
+        assert!(test_set_options_impl().is_ok());
+    }
+
+    fn test_set_options_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--user=s")?.run()?;
+        set.add_opt("--count=i")?.run()?;
+        set.add_opt("--debug=b")?.run()?;
+        set.add_opt("--quiet=b")?.run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from(["app", "--count=1", "--debug", "--count=2", "--user", "lily"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        let options: Vec<_> = ret.set_options(&set).collect();
+
+        assert!(ret.status());
+        assert_eq!(
+            options.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ["--user", "--count", "--debug"]
+        );
+        assert_eq!(options[0].1.val::<String>()?, "lily");
+        assert_eq!(options[1].1.vals::<i64>()?, &[1, 2]);
+        assert!(*options[2].1.val::<bool>()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert!(test_parse_status_impl().is_ok());
+    }
+
+    fn test_parse_status_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+        let new_set = |policy: &AFwdPolicy| -> Result<ASet, Error> {
+            let mut set = policy.default_set();
+
+            set.set_help_option(Some("--help".to_owned()));
+            set.add_opt("--help=b")?.add_alias("-h").run()?;
+            set.add_opt("--name=s")?;
+            set.add_opt("--count=i")?;
+            set.add_opt("main=m")?;
+            for opt in set.iter_mut() {
+                opt.init()?;
+            }
+            Ok(set)
+        };
+        let mut parse = |args: &[&str]| -> Result<Return, Error> {
+            let mut set = new_set(&policy)?;
+
+            policy.parse(&mut set, &mut inv, &mut ser, Args::from(args))
+        };
+
+        let ret = parse(&["app", "--name", "foo", "--count=1"])?;
+
+        assert!(ret.status());
+        assert_eq!(ret.parse_status(), ParseStatus::Completed);
+
+        let ret = parse(&["app"])?;
+
+        assert!(ret.status());
+        assert_eq!(ret.parse_status(), ParseStatus::NothingMatched);
+
+        let ret = parse(&["app", "-h"])?;
+
+        assert!(ret.parse_status().is_help_requested());
+
+        let ret = parse(&["app", "--count=abc"])?;
+
+        assert!(!ret.status());
+        assert_eq!(ret.parse_status(), ParseStatus::Failed);
+        Ok(())
+    }
+}
@@ -1,4 +1,26 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::fmt::Debug;
 use std::ops::Deref;
+use std::sync::Arc;
+
+use super::AmbiguityMode;
+use crate::args::ArgInfo;
+use crate::set::OptValidator;
+use crate::Error;
+
+#[cfg(feature = "sync")]
+pub type CustomStyleHandler = Arc<
+    dyn Fn(&OsStr, &mut dyn OptValidator<Error = Error>) -> Option<GuessResult>
+        + Send
+        + Sync
+        + 'static,
+>;
+
+#[cfg(not(feature = "sync"))]
+pub type CustomStyleHandler =
+    Arc<dyn Fn(&OsStr, &mut dyn OptValidator<Error = Error>) -> Option<GuessResult> + 'static>;
 
 /// User set option style used for generate [`InvokeGuess`](crate::guess::InvokeGuess).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -27,17 +49,62 @@ pub enum UserStyle {
     /// Option set style like `-abc`, thus set both boolean options `a`, `b` and `c`.
     CombinedOption,
 
-    /// Option set style like `--bool`, only support boolean option.
+    /// Option set style like `--bool` or `--bool=false`, only support boolean option.
     Boolean,
 
     /// Option set style like `--flag`, but the value will be set to None.
     Flag,
+
+    /// User defined option set style, registered by [`register_custom`](OptStyleManager::register_custom).
+    Custom(usize),
+}
+
+/// The option name and value resolved from command line item by custom style handler.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuessResult {
+    pub name: String,
+
+    pub value: Option<OsString>,
+}
+
+impl GuessResult {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<OsString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
 }
 
 /// Manage the support option set style[`UserStyle`].
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct OptStyleManager {
     styles: Vec<UserStyle>,
+
+    customs: Vec<CustomStyleHandler>,
+
+    combined_prefixes: Option<Vec<String>>,
+
+    assign_char: char,
+
+    ambiguity: AmbiguityMode,
+}
+
+impl Debug for OptStyleManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OptStyleManager")
+            .field("styles", &self.styles)
+            .field("customs", &self.customs.len())
+            .field("combined_prefixes", &self.combined_prefixes)
+            .field("assign_char", &self.assign_char)
+            .field("ambiguity", &self.ambiguity)
+            .finish()
+    }
 }
 
 impl Default for OptStyleManager {
@@ -49,6 +116,10 @@ impl Default for OptStyleManager {
                 UserStyle::Boolean,
                 UserStyle::EmbeddedValue,
             ],
+            customs: vec![],
+            combined_prefixes: None,
+            assign_char: '=',
+            ambiguity: AmbiguityMode::default(),
         }
     }
 }
@@ -64,6 +135,97 @@ impl OptStyleManager {
         self
     }
 
+    /// Set the prefixes participate in [`UserStyle::CombinedOption`],
+    /// such as `+` for `+abc`, the options with other prefixes are not clustered.
+    pub fn with_combined_prefixes(mut self, prefixes: Vec<String>) -> Self {
+        self.combined_prefixes = Some(prefixes);
+        self
+    }
+
+    /// Set the prefixes participate in [`UserStyle::CombinedOption`],
+    /// such as `+` for `+abc`, the options with other prefixes are not clustered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut policy = AFwdPolicy::default();
+    /// let mut set = policy.default_set();
+    /// let mut inv = policy.default_inv();
+    /// let mut ser = policy.default_ser();
+    ///
+    /// policy
+    ///     .style_manager_mut()
+    ///     .set_combined_prefixes(vec!["+".to_owned()])
+    ///     .push(UserStyle::CombinedOption);
+    /// set.validator_mut().add_prefix("+");
+    /// set.add_opt("+a=b")?.run()?;
+    /// set.add_opt("+b=b")?.run()?;
+    /// policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "+ab"]))?;
+    ///
+    /// assert!(*set.find_val::<bool>("+a")?);
+    /// assert!(*set.find_val::<bool>("+b")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_combined_prefixes(&mut self, prefixes: Vec<String>) -> &mut Self {
+        self.combined_prefixes = Some(prefixes);
+        self
+    }
+
+    /// The prefixes participate in [`UserStyle::CombinedOption`], `None` means all the prefixes.
+    pub fn combined_prefixes(&self) -> Option<&[String]> {
+        self.combined_prefixes.as_deref()
+    }
+
+    /// Return true if the `style` can be guessed for the option has `prefix`.
+    pub fn is_enabled_for(&self, style: &UserStyle, prefix: &str) -> bool {
+        match (style, &self.combined_prefixes) {
+            (UserStyle::CombinedOption, Some(prefixes)) => prefixes.iter().any(|v| v == prefix),
+            _ => true,
+        }
+    }
+
+    /// Return the position of `style` in the guess order.
+    pub fn position(&self, style: &UserStyle) -> Option<usize> {
+        self.styles.iter().position(|v| v == style)
+    }
+
+    /// Move or insert `style` before `anchor`, so it is guessed before `anchor`.
+    /// The `style` is appended if `anchor` not exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// #
+    /// let mut manager = OptStyleManager::default();
+    ///
+    /// manager.insert_before(UserStyle::Boolean, UserStyle::EmbeddedValue);
+    /// assert_eq!(
+    ///     manager.as_slice(),
+    ///     &[
+    ///         UserStyle::EqualWithValue,
+    ///         UserStyle::Argument,
+    ///         UserStyle::EmbeddedValue,
+    ///         UserStyle::Boolean,
+    ///     ]
+    /// );
+    /// ```
+    pub fn insert_before(&mut self, anchor: UserStyle, style: UserStyle) -> &mut Self {
+        if let Some(index) = self.position(&style) {
+            self.styles.remove(index);
+        }
+        match self.position(&anchor) {
+            Some(index) => self.styles.insert(index, style),
+            None => self.styles.push(style),
+        }
+        self
+    }
+
     pub fn remove(&mut self, style: UserStyle) -> &mut Self {
         if let Some((index, _)) = self.styles.iter().enumerate().find(|v| v.1 == &style) {
             self.styles.remove(index);
@@ -82,6 +244,187 @@ impl OptStyleManager {
         }
         self
     }
+
+    /// Register a custom style `handler`, return the [`UserStyle::Custom`] appended to the styles.
+    ///
+    /// The handler resolves a command line item to the option name and value,
+    /// the [`OptValidator`] of set is passed to it for checking or splitting the option name.
+    /// The custom style is guessed at its position of the styles like other styles,
+    /// move it by [`insert_before`](OptStyleManager::insert_before) if needed.
+    /// The name resolved must be a valid option name, it is matched as
+    /// [`EqualWithValue`](UserStyle::EqualWithValue) if the value exists,
+    /// otherwise as [`Boolean`](UserStyle::Boolean).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut policy = AFwdPolicy::default();
+    /// let mut set = policy.default_set();
+    /// let mut inv = policy.default_inv();
+    /// let mut ser = policy.default_ser();
+    ///
+    /// policy.style_manager_mut().register_custom(|raw, _| {
+    ///     let (key, value) = raw.to_str()?.split_once(':')?;
+    ///
+    ///     Some(GuessResult::new(format!("--{key}")).with_value(value))
+    /// });
+    /// set.add_opt("--color=s")?.run()?;
+    /// policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "color:red"]))?;
+    ///
+    /// assert_eq!(set.find_val::<String>("--color")?, "red");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sync")]
+    pub fn register_custom(
+        &mut self,
+        handler: impl Fn(&OsStr, &mut dyn OptValidator<Error = Error>) -> Option<GuessResult>
+            + Send
+            + Sync
+            + 'static,
+    ) -> UserStyle {
+        self.insert_custom(Arc::new(handler))
+    }
+
+    /// Register a custom style `handler`, return the [`UserStyle::Custom`] appended to the styles.
+    ///
+    /// The handler resolves a command line item to the option name and value,
+    /// the [`OptValidator`] of set is passed to it for checking or splitting the option name.
+    /// The custom style is guessed at its position of the styles like other styles,
+    /// move it by [`insert_before`](OptStyleManager::insert_before) if needed.
+    /// The name resolved must be a valid option name, it is matched as
+    /// [`EqualWithValue`](UserStyle::EqualWithValue) if the value exists,
+    /// otherwise as [`Boolean`](UserStyle::Boolean).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aopt::prelude::*;
+    /// # use aopt::Error;
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// let mut policy = AFwdPolicy::default();
+    /// let mut set = policy.default_set();
+    /// let mut inv = policy.default_inv();
+    /// let mut ser = policy.default_ser();
+    ///
+    /// policy.style_manager_mut().register_custom(|raw, _| {
+    ///     let (key, value) = raw.to_str()?.split_once(':')?;
+    ///
+    ///     Some(GuessResult::new(format!("--{key}")).with_value(value))
+    /// });
+    /// set.add_opt("--color=s")?.run()?;
+    /// policy.parse(&mut set, &mut inv, &mut ser, Args::from(["app", "color:red"]))?;
+    ///
+    /// assert_eq!(set.find_val::<String>("--color")?, "red");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "sync"))]
+    pub fn register_custom(
+        &mut self,
+        handler: impl Fn(&OsStr, &mut dyn OptValidator<Error = Error>) -> Option<GuessResult> + 'static,
+    ) -> UserStyle {
+        self.insert_custom(Arc::new(handler))
+    }
+
+    fn insert_custom(&mut self, handler: CustomStyleHandler) -> UserStyle {
+        let style = UserStyle::Custom(self.customs.len());
+
+        self.customs.push(handler);
+        self.styles.push(style.clone());
+        style
+    }
+
+    /// Set the character separating the option name and value, such as `:` for `--opt:value`.
+    pub fn with_assign_char(mut self, assign: char) -> Self {
+        self.assign_char = assign;
+        self
+    }
+
+    /// Set the character separating the option name and value, such as `:` for `--opt:value`.
+    pub fn set_assign_char(&mut self, assign: char) -> &mut Self {
+        self.assign_char = assign;
+        self
+    }
+
+    /// The character separating the option name and value, it is `=` in default.
+    pub fn assign_char(&self) -> char {
+        self.assign_char
+    }
+
+    /// Set how to handle the option argument can be interpreted in more than one way.
+    pub fn with_ambiguity(mut self, ambiguity: AmbiguityMode) -> Self {
+        self.ambiguity = ambiguity;
+        self
+    }
+
+    /// Set how to handle the option argument can be interpreted in more than one way.
+    pub fn set_ambiguity(&mut self, ambiguity: AmbiguityMode) -> &mut Self {
+        self.ambiguity = ambiguity;
+        self
+    }
+
+    pub fn ambiguity(&self) -> AmbiguityMode {
+        self.ambiguity
+    }
+
+    /// Parse the command line item `arg` into option name and value.
+    pub(crate) fn parse_arg<'a>(&self, arg: &'a OsStr) -> Result<ArgInfo<'a>, Error> {
+        ArgInfo::parse_with(arg, self.assign_char)
+    }
+
+    /// Return true if any custom style is in the styles.
+    pub(crate) fn has_custom(&self) -> bool {
+        self.styles
+            .iter()
+            .any(|style| matches!(style, UserStyle::Custom(_)))
+    }
+
+    /// Resolve the command line item `arg` using the custom `style`,
+    /// return None if the style is not custom or the name resolved is not a valid option name.
+    pub(crate) fn guess_custom<V>(
+        &self,
+        style: &UserStyle,
+        arg: &OsStr,
+        validator: &mut V,
+    ) -> Result<Option<GuessResult>, Error>
+    where
+        V: OptValidator,
+    {
+        let UserStyle::Custom(id) = style else {
+            return Ok(None);
+        };
+        let mut validator = ValidatorRef(validator);
+
+        match self
+            .customs
+            .get(*id)
+            .and_then(|handler| (handler)(arg, &mut validator))
+        {
+            Some(ret) if validator.check(&ret.name)? => Ok(Some(ret)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Map the error of [`OptValidator`] to [`Error`].
+struct ValidatorRef<'a, V>(&'a mut V);
+
+impl<V: OptValidator> OptValidator for ValidatorRef<'_, V> {
+    type Error = Error;
+
+    fn check(&mut self, name: &str) -> Result<bool, Self::Error> {
+        self.0.check(name).map_err(Into::into)
+    }
+
+    fn split<'a>(&self, name: &Cow<'a, str>) -> Result<(Cow<'a, str>, Cow<'a, str>), Self::Error> {
+        self.0.split(name).map_err(Into::into)
+    }
 }
 
 impl Deref for OptStyleManager {
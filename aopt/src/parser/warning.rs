@@ -0,0 +1,165 @@
+use std::fmt::Display;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+/// The warning raised during parsing, it does not stop the parsing.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Warning {
+    /// The option is matched by its deprecated alias.
+    DeprecatedAlias {
+        alias: String,
+
+        name: String,
+
+        message: String,
+    },
+
+    /// The argument looks like an option but no option matched, it is ignored in non-strict mode.
+    UnknownOption { name: String },
+
+    /// The argument can be interpreted in more than one way, the first matched one is used.
+    AmbiguousOption {
+        name: String,
+
+        candidates: Vec<String>,
+    },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::DeprecatedAlias {
+                alias,
+                name,
+                message,
+            } => write!(f, "`{alias}` is a deprecated alias of `{name}`, {message}"),
+            Warning::UnknownOption { name } => write!(f, "unknown option `{name}` is ignored"),
+            Warning::AmbiguousOption { name, candidates } => write!(
+                f,
+                "`{name}` is ambiguous, it can be {}",
+                candidates.join(" or ")
+            ),
+        }
+    }
+}
+
+/// Collect the [`Warning`]s during parsing,
+/// they are saved in [`Return`](crate::parser::Return) after parsing.
+#[derive(Debug, Clone, Default)]
+pub struct Warnings {
+    warnings: Vec<Warning>,
+}
+
+impl Warnings {
+    pub fn push(&mut self, warning: Warning) -> &mut Self {
+        crate::trace!("add warning: {}", warning);
+        self.warnings.push(warning);
+        self
+    }
+
+    pub fn into_inner(self) -> Vec<Warning> {
+        self.warnings
+    }
+}
+
+impl Deref for Warnings {
+    type Target = Vec<Warning>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.warnings
+    }
+}
+
+impl DerefMut for Warnings {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.warnings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Warning;
+    use crate::prelude::*;
+    use crate::Error;
+
+    #[test]
+    fn test_warnings() {
+        assert!(test_warnings_impl().is_ok());
+    }
+
+    fn test_warnings_impl() -> Result<(), Error> {
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--new=i")?
+            .add_deprecated_alias("--old", "use --new instead")
+            .run()?;
+        set.add_opt("--debug=b")?.run()?;
+        set.add_opt("list=c")?
+            .add_deprecated_alias("ls", "use list instead")
+            .run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let ret = policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "ls", "--old", "42"]),
+        )?;
+
+        assert!(ret.status());
+        assert_eq!(set.find_val::<i64>("--new")?, &42);
+        assert_eq!(
+            ret.warnings(),
+            [
+                Warning::DeprecatedAlias {
+                    alias: "--old".to_owned(),
+                    name: "--new".to_owned(),
+                    message: "use --new instead".to_owned(),
+                },
+                Warning::DeprecatedAlias {
+                    alias: "ls".to_owned(),
+                    name: "list".to_owned(),
+                    message: "use list instead".to_owned(),
+                }
+            ]
+        );
+        assert_eq!(
+            ret.warnings()[0].to_string(),
+            "`--old` is a deprecated alias of `--new`, use --new instead"
+        );
+
+        let ret = policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "list", "--new", "1"]),
+        )?;
+
+        assert!(ret.status());
+        assert!(ret.warnings().is_empty());
+
+        policy.set_strict(false);
+
+        let ret = policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "list", "--debug", "--verbose"]),
+        )?;
+
+        assert!(ret.status());
+        assert_eq!(
+            ret.warnings(),
+            [Warning::UnknownOption {
+                name: "--verbose".to_owned()
+            }]
+        );
+        Ok(())
+    }
+}
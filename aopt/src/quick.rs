@@ -0,0 +1,90 @@
+//! Extract a single option from [`Args`] without building a parser.
+//!
+//! The helpers are useful for scripts and tests, they are not aware of the option type or style.
+//! The first argument is skipped as the program name, and the searching stops at `--`.
+//! The default prefixes of [`PrefixOptValidator`] are used to tell options from values.
+//!
+//! ```rust
+//! # use aopt::prelude::*;
+//! # use aopt::quick::*;
+//! #
+//! let args = Args::from(["app", "--verbose", "--name=foo", "-n", "42"]);
+//!
+//! assert!(flag_present(&args, "--verbose"));
+//! assert_eq!(value_of(&args, "--name").as_deref(), Some("foo"));
+//! assert_eq!(value_of(&args, "-n").as_deref(), Some("42"));
+//! ```
+use std::ffi::OsStr;
+
+use crate::args::ArgInfo;
+use crate::args::Args;
+use crate::set::OptValidator;
+use crate::set::PrefixOptValidator;
+
+const STOP: &str = "--";
+
+fn options(args: &Args) -> impl Iterator<Item = (usize, ArgInfo<'_>)> {
+    args.iter()
+        .enumerate()
+        .skip(1)
+        .take_while(|(_, arg)| *arg != STOP)
+        .filter_map(|(idx, arg)| ArgInfo::parse(arg).ok().map(|info| (idx, info)))
+}
+
+/// Return true if the option `name` is present, such as `--verbose` or `--verbose=true`.
+pub fn flag_present(args: &Args, name: &str) -> bool {
+    options(args).any(|(_, info)| info.name == name)
+}
+
+/// Return the value of first option `name`, the value can be set by `--name=value` or `--name value`.
+///
+/// The next argument is not taken as the value if it looks like an option.
+pub fn value_of(args: &Args, name: &str) -> Option<String> {
+    let mut validator = PrefixOptValidator::default();
+
+    options(args)
+        .find(|(_, info)| info.name == name)
+        .and_then(|(idx, info)| match info.value {
+            Some(value) => Some(value.to_string_lossy().to_string()),
+            None => args
+                .get(idx + 1)
+                .map(|v| v.as_os_str())
+                .filter(|v| *v != OsStr::new(STOP))
+                .map(|v| v.to_string_lossy().to_string())
+                .filter(|v| !validator.check(v).unwrap_or_default()),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::flag_present;
+    use super::value_of;
+    use crate::args::Args;
+
+    #[test]
+    fn test_quick() {
+        let args = Args::from([
+            "app",
+            "--verbose",
+            "--name=foo",
+            "-n",
+            "42",
+            "--empty",
+            "-v",
+            "--",
+            "--later",
+        ]);
+
+        assert!(flag_present(&args, "--verbose"));
+        assert!(flag_present(&args, "--name"));
+        assert!(!flag_present(&args, "--debug"));
+        assert!(!flag_present(&args, "--later"));
+        assert!(!flag_present(&args, "app"));
+        assert_eq!(value_of(&args, "--name").as_deref(), Some("foo"));
+        assert_eq!(value_of(&args, "-n").as_deref(), Some("42"));
+        assert_eq!(value_of(&args, "--empty"), None);
+        assert_eq!(value_of(&args, "-v"), None);
+        assert_eq!(value_of(&args, "--later"), None);
+        assert_eq!(value_of(&args, "--missing"), None);
+    }
+}
@@ -16,7 +16,9 @@ pub use self::optvalid::PrefixOptValidator;
 pub use self::optvalid::PrefixedValidator;
 
 use std::any::type_name;
+use std::ffi::OsString;
 use std::fmt::Debug;
+use std::ops::RangeInclusive;
 use std::slice::Iter;
 use std::slice::IterMut;
 
@@ -25,10 +27,14 @@ use crate::opt::Action;
 use crate::opt::Cid;
 use crate::opt::ConfigBuild;
 use crate::opt::ConfigValue;
+use crate::opt::EmptyValuePolicy;
 use crate::opt::Index;
+use crate::opt::NumberFormat;
 use crate::opt::Opt;
 use crate::opt::OptValueExt;
 use crate::raise_error;
+use crate::typeid;
+use crate::value::FromStdin;
 use crate::value::ValInitializer;
 use crate::value::ValStorer;
 use crate::Error;
@@ -147,12 +153,47 @@ pub trait Set {
 
     fn iter_mut(&mut self) -> IterMut<'_, SetOpt<Self>>;
 
+    /// Return an iterator of options in the order they were inserted.
+    ///
+    /// The order of [`iter`](Set::iter) is not guaranteed after [`remove`](Set::remove).
+    fn iter_ordered(&self) -> impl Iterator<Item = &SetOpt<Self>> {
+        self.iter()
+    }
+
     fn contain(&self, uid: Uid) -> bool {
         self.iter().any(|v| v.uid() == uid)
     }
 
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid;
 
+    /// Return true if an option can not use the name or alias of existing options,
+    /// it is checked when committing the option, default is true.
+    /// Disable it if the set contains overloaded options, such as `--flag=i` and `--flag=s`.
+    fn check_duplicate(&self) -> bool {
+        true
+    }
+
+    /// Return true if the [`Pos`](crate::opt::Style::Pos) with index [`AnyWhere`](crate::opt::Index::AnyWhere)
+    /// skips the positions claimed by fixed position [`Pos`](crate::opt::Style::Pos), default is false.
+    fn skip_claimed_pos(&self) -> bool {
+        false
+    }
+
+    /// Return the name of help option, the [`HelpRequested`](crate::parser::ParseStatus::HelpRequested)
+    /// is set if it matched, default is None.
+    fn help_option(&self) -> Option<&str> {
+        None
+    }
+
+    /// Remove the option with given uid, return it if found.
+    ///
+    /// The uid of removed option will not be reused by later [`insert`](Set::insert).
+    /// The default implementation does not support removal and always return None.
+    fn remove(&mut self, uid: Uid) -> Option<SetOpt<Self>> {
+        let _ = uid;
+        None
+    }
+
     fn get(&self, uid: Uid) -> Option<&SetOpt<Self>> {
         self.iter().find(|v| v.uid() == uid)
     }
@@ -234,6 +275,40 @@ where
         self.opt_mut(self.find_uid(cb)?)?.vals_mut()
     }
 
+    /// Get a copy of the last value of option, return `None` if the option has no value.
+    ///
+    /// It is useful for accessing the value of other option in the handler.
+    /// Return an error if option not found, or the value type of option is not `T`.
+    fn get_typed<T: ErasedTy + Clone>(
+        &self,
+        cb: impl ConfigBuild<SetCfg<Self>>,
+    ) -> Result<Option<T>, Error> {
+        let opt = self.opt(self.find_uid(cb)?)?;
+
+        match opt.vals::<T>() {
+            Ok(vals) => Ok(vals.last().cloned()),
+            Err(_) if opt.r#type() == &typeid::<T>() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the last value of option, return `default` if the option not found or has no value.
+    fn find_val_or<'a, T: ErasedTy>(
+        &'a self,
+        cb: impl ConfigBuild<SetCfg<Self>>,
+        default: &'a T,
+    ) -> &'a T {
+        self.find_val(cb).unwrap_or(default)
+    }
+
+    /// Return true if the last value of option is the stdin marker,
+    /// see [`ConfigValue::set_stdin_marker`].
+    fn value_is_stdin(&self, cb: impl ConfigBuild<SetCfg<Self>>) -> Result<bool, Error> {
+        let opt = self.opt(self.find_uid(cb)?)?;
+
+        Ok(opt.vals::<FromStdin>().is_ok_and(|v| !v.is_empty()))
+    }
+
     fn take_val<T: ErasedTy>(&mut self, cb: impl ConfigBuild<SetCfg<Self>>) -> Result<T, Error> {
         let opt = self.opt_mut(self.find_uid(cb)?)?;
         let (name, uid) = (opt.name(), opt.uid());
@@ -245,6 +320,11 @@ where
         opt.vals_mut::<T>()?.pop().ok_or_else(|| err.with_uid(uid))
     }
 
+    /// Take the last value of option, return `default` if the option not found or has no value.
+    fn take_val_or<T: ErasedTy>(&mut self, cb: impl ConfigBuild<SetCfg<Self>>, default: T) -> T {
+        self.take_val(cb).unwrap_or(default)
+    }
+
     fn take_vals<T: ErasedTy>(
         &mut self,
         cb: impl ConfigBuild<SetCfg<Self>>,
@@ -282,6 +362,100 @@ where
         self
     }
 
+    fn set_empty_value(mut self, empty_value: EmptyValuePolicy) -> Self {
+        self.cfg_mut().set_empty_value(empty_value);
+        self
+    }
+
+    fn set_number_format(mut self, number_format: NumberFormat) -> Self {
+        self.cfg_mut().set_number_format(number_format);
+        self
+    }
+
+    /// Remove the thousands `separator` from the number value before parsing,
+    /// see [`ConfigValue::strip_thousands`].
+    fn set_strip_thousands(mut self, separator: char) -> Self {
+        self.cfg_mut().set_strip_thousands(separator);
+        self
+    }
+
+    /// Parse the date and time value using the format description,
+    /// see [`ConfigValue::time_format`].
+    fn set_time_format(mut self, format: impl Into<String>) -> Self {
+        self.cfg_mut().set_time_format(format);
+        self
+    }
+
+    /// Read the value from terminal with `prompt` if the force required option is missing,
+    /// see [`ConfigValue::set_prompt`].
+    fn set_prompt(mut self, prompt: impl Into<Option<String>>) -> Self {
+        self.cfg_mut().set_prompt(prompt);
+        self
+    }
+
+    /// Read the value from file if it begins with the `marker`,
+    /// see [`ConfigValue::set_file_indirection`].
+    #[cfg(feature = "std")]
+    fn set_file_indirection(mut self, marker: char) -> Self {
+        self.cfg_mut().set_file_indirection(marker);
+        self
+    }
+
+    /// Split the value by `delimiter`, see [`ConfigValue::set_value_delimiter`].
+    fn set_value_delimiter(mut self, delimiter: char) -> Self {
+        self.cfg_mut().set_value_delimiter(delimiter);
+        self
+    }
+
+    /// Set the escape character of value delimiter, see [`ConfigValue::set_delimiter_escape`].
+    fn set_delimiter_escape(mut self, escape: Option<char>) -> Self {
+        self.cfg_mut().set_delimiter_escape(escape);
+        self
+    }
+
+    /// Recognize the value equal to `marker` as stdin, see [`ConfigValue::set_stdin_marker`].
+    fn set_stdin_marker(mut self, marker: impl Into<Option<String>>) -> Self {
+        self.cfg_mut().set_stdin_marker(marker);
+        self
+    }
+
+    /// Accept the extra literals of `true` and `false`, see [`ConfigValue::set_bool_literals`].
+    fn set_bool_literals<T: Into<String>, F: Into<String>>(
+        mut self,
+        trues: impl IntoIterator<Item = T>,
+        falses: impl IntoIterator<Item = F>,
+    ) -> Self {
+        self.cfg_mut().set_bool_literals((
+            trues.into_iter().map(Into::into).collect(),
+            falses.into_iter().map(Into::into).collect(),
+        ));
+        self
+    }
+
+    /// Set if the option can be used more than once, see [`ConfigValue::set_multiple`].
+    fn set_multiple(mut self, multiple: bool) -> Self {
+        self.cfg_mut().set_multiple(multiple);
+        self
+    }
+
+    /// Set the maximum times the option can be used, see [`ConfigValue::set_max_occurrences`].
+    fn set_max_occurrences(mut self, max: usize) -> Self {
+        self.cfg_mut().set_max_occurrences(max);
+        self
+    }
+
+    fn set_optional_value(mut self, optional_value: impl Into<OsString>) -> Self {
+        self.cfg_mut().set_optional_value(optional_value);
+        self
+    }
+
+    /// Raise a failure if the count of values stored is out of the range,
+    /// see [`Opt::value_count`].
+    fn set_value_count(mut self, value_count: RangeInclusive<usize>) -> Self {
+        self.cfg_mut().set_value_count(value_count);
+        self
+    }
+
     fn set_name(mut self, name: impl Into<String>) -> Self {
         self.cfg_mut().set_name(name);
         self
@@ -307,11 +481,88 @@ where
         self
     }
 
+    fn add_deprecated_alias(
+        mut self,
+        alias: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.cfg_mut().add_deprecated_alias(alias, message);
+        self
+    }
+
     fn set_force(mut self, force: bool) -> Self {
         self.cfg_mut().set_force(force);
         self
     }
 
+    /// Set the priority of option handler, see [`Opt::priority`].
+    /// It only has an effect under [`DelayPolicy`](crate::parser::DelayPolicy),
+    /// [`FwdPolicy`](crate::parser::FwdPolicy) and [`PrePolicy`](crate::parser::PrePolicy) ignore it.
+    fn set_priority(mut self, priority: i32) -> Self {
+        self.cfg_mut().set_priority(priority);
+        self
+    }
+
+    /// Set the order of option in help message, see [`Opt::display_order`].
+    fn set_display_order(mut self, order: i32) -> Self {
+        self.cfg_mut().set_display_order(order);
+        self
+    }
+
+    /// Collect the arguments after the command verbatim, see [`Opt::trailing_raw`].
+    fn set_trailing_raw(mut self, trailing_raw: bool) -> Self {
+        self.cfg_mut().set_trailing_raw(trailing_raw);
+        self
+    }
+
+    /// Raise a failure if the option is set together with positional arguments,
+    /// see [`Opt::conflicts_with_positional`].
+    fn set_conflicts_with_positional(mut self, conflicts: bool) -> Self {
+        self.cfg_mut().set_conflicts_with_positional(conflicts);
+        self
+    }
+
+    /// Raise a failure if the option is set together with any other arguments,
+    /// see [`Opt::standalone`].
+    fn set_standalone(mut self, standalone: bool) -> Self {
+        self.cfg_mut().set_standalone(standalone);
+        self
+    }
+
+    /// Consume the following arguments until next option,
+    /// see [`Opt::greedy`].
+    fn set_greedy(mut self, greedy: bool) -> Self {
+        self.cfg_mut().set_greedy(greedy);
+        self
+    }
+
+    /// Disable the boolean option by the name with `prefix`, such as `+x` for `-x`,
+    /// see [`Opt::toggle_prefix`].
+    fn set_toggle_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.cfg_mut().set_toggle_prefix(prefix);
+        self
+    }
+
+    /// Match the name and alias case-insensitively, they are still displayed as given.
+    fn set_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.cfg_mut().set_ignore_case(ignore_case);
+        self
+    }
+
+    /// Trim the leading and trailing whitespace of value before parsing,
+    /// see [`ConfigValue::set_trim`].
+    fn set_trim(mut self, trim: bool) -> Self {
+        self.cfg_mut().set_trim(trim);
+        self
+    }
+
+    /// Mask the value of option when logging the arguments,
+    /// see [`ConfigValue::set_secret`].
+    fn set_secret(mut self, secret: bool) -> Self {
+        self.cfg_mut().set_secret(secret);
+        self
+    }
+
     fn set_hint(mut self, hint: impl Into<String>) -> Self {
         self.cfg_mut().set_hint(hint);
         self
@@ -322,6 +573,17 @@ where
         self
     }
 
+    fn set_env(mut self, env: impl Into<String>) -> Self {
+        self.cfg_mut().set_env(env);
+        self
+    }
+
+    /// Set the help group of option, see [`Opt::group`].
+    fn set_group(mut self, group: impl Into<String>) -> Self {
+        self.cfg_mut().set_group(group);
+        self
+    }
+
     fn set_storer(mut self, storer: ValStorer) -> Self {
         self.cfg_mut().set_storer(storer);
         self
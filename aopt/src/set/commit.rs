@@ -2,16 +2,20 @@ use std::any::TypeId;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::opt::config::alias_eq;
 use crate::opt::AnyOpt;
 use crate::opt::Cmd;
 use crate::opt::ConfigValue;
 use crate::opt::Main;
+use crate::opt::Opt;
 use crate::opt::Pos;
+use crate::opt::Style;
 use crate::prelude::ErasedTy;
 use crate::set::Ctor;
 use crate::set::Set;
 use crate::set::SetCfg;
 use crate::set::SetExt;
+use crate::set::SetOpt;
 use crate::trace;
 use crate::value::Infer;
 use crate::value::Placeholder;
@@ -24,6 +28,66 @@ use crate::Uid;
 
 use super::Commit;
 
+/// Return an error if the name or alias in `info` is used by any option of `set`,
+/// the names are compared case-insensitively if either option [`ignore_case`](Opt::ignore_case).
+fn check_duplicate_name<S>(set: &S, info: &SetCfg<S>) -> Result<(), Error>
+where
+    S: Set,
+    SetCfg<S>: ConfigValue,
+{
+    let names = info
+        .name()
+        .into_iter()
+        .chain(info.alias().into_iter().flatten().map(|v| v.as_str()))
+        .chain(
+            info.deprecated_alias()
+                .into_iter()
+                .flatten()
+                .map(|(alias, _)| alias.as_str()),
+        );
+
+    for name in names {
+        if let Some(opt) = set.iter().find(|opt| {
+            let ignore_case = info.ignore_case() || opt.ignore_case();
+            let mat = |v: &str| alias_eq(v, name, ignore_case);
+
+            mat(opt.name())
+                || opt.alias().is_some_and(|v| v.iter().any(|v| mat(v)))
+                || opt
+                    .deprecated_alias()
+                    .is_some_and(|v| v.iter().any(|(v, _)| mat(v)))
+        }) {
+            return Err(crate::raise_error!(
+                "can not add option `{}`: `{name}` is already used by option `{}`",
+                info.name().unwrap_or_default(),
+                opt.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Return an error if `opt` is a [`Pos`] collecting the trailing arguments,
+/// and any [`Pos`] of `set` already does.
+fn check_trailing_pos<S>(set: &S, opt: &SetOpt<S>) -> Result<(), Error>
+where
+    S: Set,
+{
+    if opt.mat_style(Style::Pos) && opt.trailing_raw() {
+        if let Some(exist) = set
+            .iter()
+            .find(|v| v.mat_style(Style::Pos) && v.trailing_raw())
+        {
+            return Err(crate::raise_error!(
+                "can not add option `{}`: the trailing arguments are already collected by `{}`",
+                opt.name(),
+                exist.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Create option using given configurations.
 pub struct SetCommit<'a, S, U>
 where
@@ -157,6 +221,11 @@ where
             <U as Infer>::infer_fill_info(&mut info)?;
 
             let set = self.set.as_mut().unwrap();
+
+            if set.check_duplicate() {
+                check_duplicate_name(&**set, &info)?;
+            }
+
             let ctor = info.ctor().ok_or_else(|| {
                 crate::raise_error!("invalid configuration: missing creator name!")
             })?;
@@ -164,6 +233,9 @@ where
             trace!("register a opt {:?} with creator({})", info.name(), ctor);
 
             let opt = set.ctor_mut(ctor)?.new_with(info).map_err(|e| e.into())?;
+
+            check_trailing_pos(&**set, &opt)?;
+
             let uid = set.insert(opt);
 
             trace!("--> register option okay: {uid}");
@@ -210,7 +282,7 @@ where
     }
 
     /// Set the option value validator.
-    pub fn set_validator_t<T: ErasedTy + RawValParser>(
+    pub fn set_validator_t<T: ErasedTy + Clone + RawValParser>(
         self,
         validator: ValValidator<T>,
     ) -> SetCommitWithValue<'a, S, U, T> {
@@ -386,7 +458,7 @@ where
     S: Set,
     U: Infer + 'static,
     U::Val: RawValParser,
-    T: ErasedTy + RawValParser,
+    T: ErasedTy + Clone + RawValParser,
     SetCfg<S>: ConfigValue + Default,
 {
     /// Set the option value validator.
@@ -1,6 +1,7 @@
 use std::any::TypeId;
 use std::fmt::Debug;
 
+use crate::opt::config::alias_eq;
 use crate::opt::ConfigValue;
 use crate::opt::Index;
 use crate::opt::Opt;
@@ -35,12 +36,13 @@ where
         if ret && self.has_name() {
             // don't call match name
             let name = self.name().unwrap();
-            let mut matched = opt.name() == name;
+            let ignore_case = self.ignore_case() || opt.ignore_case();
+            let mut matched = alias_eq(opt.name(), name, ignore_case);
 
             if !matched {
                 if let Some(alias) = opt.alias().as_ref() {
                     for item in alias.iter() {
-                        if item == name {
+                        if alias_eq(item, name, ignore_case) {
                             matched = true;
                             break;
                         }
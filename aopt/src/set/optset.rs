@@ -63,6 +63,12 @@ where
     parser: P,
     validator: V,
     opts: Vec<C::Opt>,
+    order: Vec<Uid>,
+    next_uid: Uid,
+    check_duplicate: bool,
+    skip_claimed_pos: bool,
+
+    help_option: Option<String>,
     creators: HashMap<Cid, C>,
 }
 
@@ -77,6 +83,11 @@ where
             parser,
             validator,
             opts: vec![],
+            order: vec![],
+            next_uid: 0,
+            check_duplicate: true,
+            skip_claimed_pos: false,
+            help_option: None,
             creators: HashMap::new(),
         }
     }
@@ -95,6 +106,11 @@ where
             .field("parser", &self.parser)
             .field("validator", &self.validator)
             .field("opts", &self.opts)
+            .field("order", &self.order)
+            .field("next_uid", &self.next_uid)
+            .field("check_duplicate", &self.check_duplicate)
+            .field("skip_claimed_pos", &self.skip_claimed_pos)
+            .field("help_option", &self.help_option)
             .field("creators", &self.creators)
             .finish()
     }
@@ -111,6 +127,11 @@ where
             parser: P::default(),
             validator: V::default(),
             opts: vec![],
+            order: vec![],
+            next_uid: 0,
+            check_duplicate: true,
+            skip_claimed_pos: false,
+            help_option: None,
             creators: crate::ctors!(C),
         }
     }
@@ -127,6 +148,46 @@ where
         self.register(creator);
         self
     }
+
+    /// Enable or disable rejecting the option using the name or alias of existing options, it is enabled by default,
+    /// disable it for overloaded options, see [`check_duplicate`](Set::check_duplicate).
+    pub fn with_check_duplicate(mut self, check: bool) -> Self {
+        self.check_duplicate = check;
+        self
+    }
+
+    /// Enable or disable rejecting the option using the name or alias of existing options, it is enabled by default,
+    /// disable it for overloaded options, see [`check_duplicate`](Set::check_duplicate).
+    pub fn set_check_duplicate(&mut self, check: bool) -> &mut Self {
+        self.check_duplicate = check;
+        self
+    }
+
+    /// Bind the `AnyWhere` position argument to the position not claimed by others,
+    /// see [`skip_claimed_pos`](Set::skip_claimed_pos).
+    pub fn with_skip_claimed_pos(mut self, skip: bool) -> Self {
+        self.skip_claimed_pos = skip;
+        self
+    }
+
+    /// Bind the `AnyWhere` position argument to the position not claimed by others,
+    /// see [`skip_claimed_pos`](Set::skip_claimed_pos).
+    pub fn set_skip_claimed_pos(&mut self, skip: bool) -> &mut Self {
+        self.skip_claimed_pos = skip;
+        self
+    }
+
+    /// Set the name of help option, see [`help_option`](Set::help_option).
+    pub fn with_help_option(mut self, name: impl Into<String>) -> Self {
+        self.help_option = Some(name.into());
+        self
+    }
+
+    /// Set the name of help option, see [`help_option`](Set::help_option).
+    pub fn set_help_option(&mut self, name: Option<String>) -> &mut Self {
+        self.help_option = name;
+        self
+    }
 }
 
 impl<P, C, V> OptSet<P, C, V>
@@ -168,11 +229,13 @@ where
         &mut self.validator
     }
 
-    /// Take all the options
+    /// Take all the options, the uid of next inserted option will start from 0 again.
     pub fn take_options(&mut self) -> Option<Vec<C::Opt>> {
         let mut ret = None;
 
         if !self.opts.is_empty() {
+            self.order.clear();
+            self.next_uid = 0;
             ret = Some(std::mem::take(&mut self.opts));
         }
         ret
@@ -201,6 +264,18 @@ where
         Ok(SetCommit::new(self, cb.build(self.parser())?))
     }
 
+    /// Add an option into current [`OptSet`], the type of option value is given by `U`.
+    pub fn add_opt_typed<U>(
+        &mut self,
+        cb: impl ConfigBuild<C::Config>,
+    ) -> Result<SetCommit<'_, Self, U>, Error>
+    where
+        U: Infer + 'static,
+        U::Val: RawValParser,
+    {
+        Ok(SetCommit::new(self, cb.build(self.parser())?))
+    }
+
     /// Filter the option by configuration.
     ///
     /// It parsing the given option string `S` using inner [`OptParser`], return an [`Filter`].
@@ -217,13 +292,9 @@ where
         let mut info = cb.build(self.parser())?;
 
         info.infer_builtin_ty();
-        self.iter().find(|opt| info.mat_opt(*opt)).ok_or_else(|| {
-            raise_error!(
-                "can not find option with: {:?}={:?}",
-                info.name(),
-                info.ctor()
-            )
-        })
+        self.iter()
+            .find(|opt| info.mat_opt(*opt))
+            .ok_or_else(|| self.not_found(&info))
     }
 
     /// Filter the option, return an iterator of reference of [`Opt`]s.
@@ -256,15 +327,10 @@ where
         let mut info = cb.build(self.parser())?;
 
         info.infer_builtin_ty();
-        self.iter_mut()
-            .find(|opt| info.mat_opt(*opt))
-            .ok_or_else(|| {
-                raise_error!(
-                    "can not find option with: {:?}={:?}",
-                    info.name(),
-                    info.ctor()
-                )
-            })
+        match self.iter().position(|opt| info.mat_opt(opt)) {
+            Some(idx) => Ok(&mut self.opts[idx]),
+            None => Err(self.not_found(&info)),
+        }
     }
 
     /// Filter the option, return an iterator of mutable reference of [`Opt`]s.
@@ -277,6 +343,25 @@ where
         info.infer_builtin_ty();
         Ok(self.iter_mut().filter(move |opt| info.mat_opt(*opt)))
     }
+
+    /// Return [`no_such_option`](Error::no_such_option) if no option has the name of `info`,
+    /// otherwise the option exists but other configurations not matched.
+    fn not_found(&self, info: &C::Config) -> Error {
+        match info.name() {
+            Some(name)
+                if !self
+                    .iter()
+                    .any(|opt| opt.mat_name(Some(name)) || opt.mat_alias(name)) =>
+            {
+                Error::no_such_option(name.to_string())
+            }
+            _ => raise_error!(
+                "can not find option with: {:?}={:?}",
+                info.name(),
+                info.ctor()
+            ),
+        }
+    }
 }
 
 impl<P, C, V> SetValueFindExt for OptSet<P, C, V>
@@ -386,6 +471,18 @@ where
         self.opts.len()
     }
 
+    fn check_duplicate(&self) -> bool {
+        self.check_duplicate
+    }
+
+    fn skip_claimed_pos(&self) -> bool {
+        self.skip_claimed_pos
+    }
+
+    fn help_option(&self) -> Option<&str> {
+        self.help_option.as_deref()
+    }
+
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
@@ -398,20 +495,40 @@ where
         self.opts.iter_mut()
     }
 
+    fn iter_ordered(&self) -> impl Iterator<Item = &SetOpt<Self>> {
+        self.order.iter().filter_map(|uid| self.get(*uid))
+    }
+
     fn insert(&mut self, mut opt: SetOpt<Self>) -> Uid {
-        let uid = self.len() as Uid;
+        let uid = self.next_uid;
 
+        self.next_uid += 1;
         opt.set_uid(uid);
         self.opts.push(opt);
+        self.order.push(uid);
         uid
     }
 
+    fn remove(&mut self, uid: Uid) -> Option<SetOpt<Self>> {
+        let index = self.opts.iter().position(|v| v.uid() == uid)?;
+
+        self.order.retain(|v| *v != uid);
+        Some(self.opts.swap_remove(index))
+    }
+
     fn get(&self, id: Uid) -> Option<&SetOpt<Self>> {
-        self.opts.get(id as usize)
+        // uid equal to index if no option removed
+        match self.opts.get(id as usize) {
+            Some(opt) if opt.uid() == id => Some(opt),
+            _ => self.opts.iter().find(|v| v.uid() == id),
+        }
     }
 
     fn get_mut(&mut self, id: Uid) -> Option<&mut SetOpt<Self>> {
-        self.opts.get_mut(id as usize)
+        match self.opts.get(id as usize) {
+            Some(opt) if opt.uid() == id => self.opts.get_mut(id as usize),
+            _ => self.opts.iter_mut().find(|v| v.uid() == id),
+        }
     }
 }
 
@@ -465,6 +582,10 @@ where
     fn unreg_prefix(&mut self, val: &str) -> Result<(), Self::Error> {
         PrefixedValidator::unreg_prefix(&mut self.validator, val).map_err(Into::into)
     }
+
+    fn prefixes(&self) -> &[String] {
+        PrefixedValidator::prefixes(&self.validator)
+    }
 }
 
 #[cfg(test)]
@@ -652,4 +773,123 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_duplicate_option() {
+        assert!(test_duplicate_option_impl().is_ok());
+    }
+
+    fn test_duplicate_option_impl() -> Result<(), Error> {
+        let mut set = ASet::default().with_check_duplicate(false);
+
+        // the overloaded options are allowed if the check is disabled
+        set.add_opt("--count=i")?.run()?;
+        set.add_opt("--count=s")?.run()?;
+        assert_eq!(set.len(), 2);
+
+        let mut set = ASet::default();
+
+        set.add_opt("-c;--count=i")?.run()?;
+        set.add_opt("file=p@1")?.run()?;
+        set.add_opt("--debug=b")?.add_alias("-d").run()?;
+
+        let err = set.add_opt("--count=s")?.run().unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("`--count` is already used by option `-c`"));
+        assert!(set.add_opt("-c=b")?.run().is_err());
+        assert!(set.add_opt("file=c")?.run().is_err());
+        assert!(set.add_opt("--file=s")?.add_alias("-d").run().is_err());
+        assert!(set.add_opt("-d=b")?.run().is_err());
+        assert_eq!(set.len(), 3);
+
+        // the names are compared case-insensitively if the option ignore case
+        set.add_opt("--Debug=b")?.run()?;
+        assert!(set
+            .add_opt("--COUNT=i")?
+            .set_ignore_case(true)
+            .run()
+            .is_err());
+        assert_eq!(set.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dedup_alias() {
+        assert!(test_dedup_alias_impl().is_ok());
+    }
+
+    fn test_dedup_alias_impl() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        let verbose = parser
+            .add_opt("--verbose=b")?
+            .add_alias("-v")
+            .add_alias("-v")
+            .add_alias("-V")
+            .add_alias("--loud")
+            .set_ignore_case(true)
+            .add_alias("--LOUD")
+            .run()?;
+        let quiet = parser
+            .add_opt("--quiet=b")?
+            .add_alias("-q")
+            .add_alias("--silent")
+            .add_alias("-q")
+            .rem_alias("--silent")
+            .run()?;
+        let debug = parser
+            .add_opt("--debug=b")?
+            .add_alias("-d")
+            .clr_alias()
+            .run()?;
+
+        let opt = parser.opt(verbose)?;
+
+        assert_eq!(
+            opt.alias(),
+            Some(&vec!["-v".to_owned(), "--loud".to_owned()])
+        );
+        assert_eq!(opt.hint(), "-v, --loud, --verbose");
+        assert_eq!(parser.opt(quiet)?.alias(), Some(&vec!["-q".to_owned()]));
+        assert_eq!(parser.opt(debug)?.alias(), None);
+
+        parser.parse(Args::from(["app", "-V", "--quiet"]))?.ok()?;
+        assert!(*parser.find_val::<bool>("--verbose")?);
+        assert!(*parser.find_val::<bool>("--quiet")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_uid() {
+        assert!(test_remove_uid_impl().is_ok());
+    }
+
+    fn test_remove_uid_impl() -> Result<(), Error> {
+        let mut set = ASet::default();
+
+        let aopt = set.add_opt("--aopt=b")?.run()?;
+        let bopt = set.add_opt("--bopt=i")?.run()?;
+        let copt = set.add_opt("--copt=s")?.run()?;
+
+        assert_eq!((aopt, bopt, copt), (0, 1, 2));
+        assert_eq!(set.remove(copt).map(|v| v.uid()), Some(copt));
+        assert_eq!(set.remove(aopt).map(|v| v.uid()), Some(aopt));
+
+        // uid of removed option not reused
+        let dopt = set.add_opt("--dopt=f")?.run()?;
+
+        assert_eq!(dopt, 3);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get(bopt).map(|v| v.name()), Some("--bopt"));
+        assert_eq!(set.get(dopt).map(|v| v.name()), Some("--dopt"));
+        assert!(set.get(aopt).is_none());
+        assert!(set.get(copt).is_none());
+
+        // uid start from 0 after all the options taken
+        assert_eq!(set.take_options().map(|v| v.len()), Some(2));
+        assert_eq!(set.add_opt("--eopt=u")?.run()?, 0);
+        Ok(())
+    }
 }
@@ -21,6 +21,9 @@ pub trait PrefixedValidator {
 
     /// Unregister the prefix to current validator.
     fn unreg_prefix(&mut self, val: &str) -> Result<(), Self::Error>;
+
+    /// Return all the registered prefixes.
+    fn prefixes(&self) -> &[String];
 }
 
 /// A prefixed validator used in [`Policy`](crate::parser::Policy) and [`InvokeGuess`](crate::guess::InvokeGuess).
@@ -112,4 +115,8 @@ impl PrefixedValidator for PrefixOptValidator {
             Err(raise_error!("the prefix not exist"))
         }
     }
+
+    fn prefixes(&self) -> &[String] {
+        &self.0
+    }
 }
@@ -15,7 +15,6 @@ use crate::ext::AFwdParser;
 use crate::ext::APolicyExt;
 use crate::ext::ASer;
 use crate::ext::ASet;
-use crate::opt::Action;
 use crate::opt::ConfigBuildInfer;
 use crate::opt::Opt;
 use crate::opt::OptParser;
@@ -268,7 +267,7 @@ where
         let arg = std::borrow::Cow::Borrowed(arg);
 
         if set.split(&arg).is_ok() {
-            for opt in set.iter() {
+            for opt in set.iter_ordered() {
                 if opt.mat_style(Style::Argument) && opt.name() == arg {
                     self.incomplete_opt = Some(opt.uid());
                     break;
@@ -312,7 +311,7 @@ where
         )?;
         let mut need_cmd = true;
 
-        for opt in set.iter() {
+        for opt in set.iter_ordered() {
             if opt.mat_style(Style::Cmd) {
                 if opt.matched() {
                     need_cmd = false;
@@ -328,7 +327,7 @@ where
             self.display_cmd = need_cmd;
         }
         if !self.display_cmd {
-            for opt in set.iter() {
+            for opt in set.iter_ordered() {
                 if opt.mat_style(Style::Argument)
                     || opt.mat_style(Style::Boolean)
                     || opt.mat_style(Style::Combined)
@@ -338,15 +337,8 @@ where
                     let uid = opt.uid();
                     let matched = opt.matched();
 
-                    match action {
-                        Action::Set => {
-                            if !matched {
-                                self.avail_opt.push(uid);
-                            }
-                        }
-                        _ => {
-                            self.avail_opt.push(uid);
-                        }
+                    if !action.is_single() || !matched {
+                        self.avail_opt.push(uid);
                     }
                 }
             }
@@ -355,7 +347,7 @@ where
             self.process_last_arg(set, &last)?;
         }
         if self.incomplete_opt.is_none() && !self.display_cmd {
-            for opt in set.iter() {
+            for opt in set.iter_ordered() {
                 if opt.mat_style(Style::Pos)
                     && !opt.matched()
                     && opt.mat_index(Some((tot, tot + 1)))
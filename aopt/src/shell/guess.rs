@@ -5,6 +5,7 @@ use crate::ctx::Ctx;
 use crate::ctx::HandlerCollection;
 use crate::opt::Style;
 use crate::opt::BOOL_TRUE;
+use crate::parser::GuessResult;
 use crate::parser::UserStyle;
 use crate::set::OptValidator;
 use crate::str::CowStrUtils;
@@ -66,6 +67,29 @@ impl<'a, 'b, Set, Inv, Ser> CompleteGuess<'a, 'b, Set, Inv, Ser> {
         }
     }
 
+    /// Replace the name and argument with `ret` resolved by custom style,
+    /// call `f` with the style matching them, then restore the name and argument.
+    pub fn with_custom<R>(
+        &mut self,
+        ret: GuessResult,
+        f: impl FnOnce(&mut Self, &UserStyle) -> R,
+    ) -> R {
+        let style = if ret.value.is_some() {
+            UserStyle::EqualWithValue
+        } else {
+            UserStyle::Boolean
+        };
+        let name = self.name.replace(Cow::Owned(ret.name));
+        let arg = std::mem::replace(&mut self.arg, ret.value.map(Cow::Owned));
+        let next = self.next.take();
+        let ret = f(self, &style);
+
+        self.name = name;
+        self.arg = arg;
+        self.next = next;
+        ret
+    }
+
     pub fn set_ctx(&mut self, ctx: &'a mut Ctx<'b>) -> &mut Self {
         self.ctx = ctx;
         self
@@ -262,6 +286,8 @@ where
                     }
                 }
             }
+            // custom styles only resolve the name and value of option
+            UserStyle::Custom(_) => {}
         }
         Ok(Some(SimpleMatRet::new(matched, consume)))
     }
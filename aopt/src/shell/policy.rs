@@ -10,9 +10,12 @@ use crate::ctx::Invoker;
 use crate::opt::Opt;
 use crate::opt::OptParser;
 use crate::parser::OptStyleManager;
+use crate::parser::ParseStatus;
 use crate::parser::Policy;
 use crate::parser::PolicySettings;
+use crate::parser::PromptHandler;
 use crate::parser::Return;
+use crate::parser::UnknownHandler;
 use crate::parser::UserStyle;
 use crate::set::OptValidator;
 use crate::set::SetOpt;
@@ -132,6 +135,10 @@ impl<Set, Ser> PolicySettings for CompletePolicy<Set, Ser> {
         false
     }
 
+    fn max_positionals(&self) -> Option<usize> {
+        None
+    }
+
     fn set_strict(&mut self, strict: bool) -> &mut Self {
         self.strict = strict;
         self
@@ -149,6 +156,58 @@ impl<Set, Ser> PolicySettings for CompletePolicy<Set, Ser> {
     fn set_overload(&mut self, _: bool) -> &mut Self {
         self
     }
+
+    fn set_max_positionals(&mut self, _: Option<usize>) -> &mut Self {
+        self
+    }
+
+    fn allow_abbrev_subcommand(&self) -> bool {
+        false
+    }
+
+    fn set_allow_abbrev_subcommand(&mut self, _: bool) -> &mut Self {
+        self
+    }
+
+    fn intermixed(&self) -> bool {
+        true
+    }
+
+    fn set_intermixed(&mut self, _: bool) -> &mut Self {
+        self
+    }
+
+    fn option_terminator(&self) -> &str {
+        crate::parser::STOP
+    }
+
+    fn set_option_terminator(&mut self, _: Option<String>) -> &mut Self {
+        self
+    }
+
+    fn diagnostics(&self) -> bool {
+        false
+    }
+
+    fn set_diagnostics(&mut self, _: bool) -> &mut Self {
+        self
+    }
+
+    fn set_unknown_handler(&mut self, _: Option<UnknownHandler>) -> &mut Self {
+        self
+    }
+
+    fn set_prompt_handler(&mut self, _: Option<PromptHandler>) -> &mut Self {
+        self
+    }
+
+    fn parallel_checks(&self) -> bool {
+        false
+    }
+
+    fn set_parallel_checks(&mut self, _: bool) -> &mut Self {
+        self
+    }
 }
 
 impl<Set, Ser> CompletePolicy<Set, Ser>
@@ -176,14 +235,16 @@ where
             let mut matched = false;
             let mut consume = false;
 
-            if let Ok(ArgInfo { name, value }) = ArgInfo::parse(opt) {
+            if let Ok(ArgInfo { name, value }) = opt_styles.parse_arg(opt) {
                 trace!(
                     "guess name: {:?} value: {:?} & next: {:?}",
                     name,
                     value,
                     next
                 );
-                if set.check(&name).map_err(Into::into)? {
+                let valid = set.check(&name).map_err(Into::into)?;
+
+                if valid || opt_styles.has_custom() {
                     let arg = value.clone();
                     let next = next.map(|v| Cow::Borrowed(*v));
                     let mut guess = CompleteGuess {
@@ -198,8 +259,25 @@ where
                         name: Some(name.clone()),
                     };
 
+                    let prefix = match valid {
+                        true => guess.set.split(&name).map_err(Into::into)?.0,
+                        false => Cow::Borrowed(""),
+                    };
+
                     for style in opt_styles.iter() {
-                        if let Some(ret) = guess.guess_complete(style)? {
+                        // only the custom styles are guessed if it not like an option
+                        if !opt_styles.is_enabled_for(style, &prefix)
+                            || !(valid || matches!(style, UserStyle::Custom(_)))
+                        {
+                            continue;
+                        }
+                        let ret = match opt_styles.guess_custom(style, opt, guess.set)? {
+                            Some(custom) => guess
+                                .with_custom(custom, |guess, style| guess.guess_complete(style))?,
+                            None => guess.guess_complete(style)?,
+                        };
+
+                        if let Some(ret) = ret {
                             (matched, consume) = (ret.matched, ret.consume);
                         }
                         if matched {
@@ -302,7 +380,7 @@ where
         let mut ctx = Ctx::default().with_orig(orig.clone());
 
         match self.parse_impl(set, inv, ser, &orig, &mut ctx) {
-            Ok(_) => Ok(Return::new(ctx)),
+            Ok(_) => Ok(Return::new(ctx).with_parse_status(ParseStatus::of(set))),
             Err(e) => {
                 if e.is_failure() {
                     Ok(Return::new(ctx).with_failure(e))
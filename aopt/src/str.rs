@@ -45,6 +45,26 @@ pub fn split_once(str: &'_ OsStr, ch: char) -> Option<(Cow<'_, OsStr>, Cow<'_, O
         })
 }
 
+/// Convert the raw bytes to [`OsString`], the invalid UTF-8 bytes are kept as is.
+#[cfg(any(target_family = "wasm", target_family = "unix"))]
+pub fn bytes_to_os(bytes: Vec<u8>) -> OsString {
+    #[cfg(target_family = "unix")]
+    use std::os::unix::ffi::OsStringExt;
+    #[cfg(target_family = "wasm")]
+    use std::os::wasi::ffi::OsStringExt;
+
+    OsString::from_vec(bytes)
+}
+
+/// Convert the raw bytes to [`OsString`], the invalid UTF-8 bytes are replaced with `U+FFFD`.
+#[cfg(target_family = "windows")]
+pub fn bytes_to_os(bytes: Vec<u8>) -> OsString {
+    match String::from_utf8(bytes) {
+        Ok(val) => OsString::from(val),
+        Err(e) => OsString::from(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+    }
+}
+
 /// Convert a [`OsStr`] to [`Cow<'_, str>`].
 pub fn osstr_to_str_i<'a>(val: &[&'a OsStr], i: usize) -> Option<Cow<'a, str>> {
     val.get(i).and_then(|v| v.to_str().map(Cow::Borrowed))
@@ -2,10 +2,12 @@ pub(crate) mod accessor;
 pub(crate) mod infer;
 pub(crate) mod initializer;
 pub(crate) mod parser;
+pub(crate) mod registry;
 pub(crate) mod storer;
 pub(crate) mod validator;
 
 use std::any::type_name;
+use std::any::TypeId;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::fmt::Debug;
@@ -16,20 +18,29 @@ pub use self::infer::Placeholder;
 pub use self::initializer::InitHandler;
 pub use self::initializer::InitializeValue;
 pub use self::initializer::ValInitializer;
+pub use self::parser::raw2os;
+pub use self::parser::raw2path;
 pub use self::parser::raw2str;
 pub use self::parser::RawValParser;
+pub use self::registry::ParserHandler;
+pub use self::registry::ParserRegistry;
 pub use self::storer::StoreHandler;
 pub use self::storer::ValStorer;
+pub use self::storer::ValueFilter;
+pub use self::validator::ReasonHandler;
 pub use self::validator::ValValidator;
 pub use self::validator::ValidatorHandler;
 
 use crate::ctx::Ctx;
 use crate::map::AnyMap;
+use crate::map::BoxedAny;
 use crate::map::Entry;
 use crate::map::ErasedTy;
 use crate::opt::Action;
 use crate::raise_error;
+use crate::typeid;
 use crate::Error;
+use crate::HashMap;
 
 /// A special option value, can stop the policy, using for implement `--`.
 ///
@@ -75,12 +86,26 @@ use crate::Error;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Stop;
 
+/// The default marker of value read from stdin, see [`set_stdin_marker`](crate::opt::ConfigValue::set_stdin_marker).
+pub const STDIN_MARKER: &str = "-";
+
+/// A sentinel value stored along with the value equal to the stdin marker,
+/// see [`set_stdin_marker`](crate::opt::ConfigValue::set_stdin_marker).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FromStdin;
+
 pub trait ErasedValue {
     fn initialize(&mut self) -> Result<(), Error>;
 
     fn store(&mut self, raw: Option<&OsStr>, ctx: &Ctx, act: &Action) -> Result<(), Error>;
 
-    fn store_act<U: ErasedTy>(&mut self, val: U, ctx: &Ctx, act: &Action) -> Result<(), Error>;
+    fn store_act<U: ErasedTy + Clone>(
+        &mut self,
+        val: U,
+        ctx: &Ctx,
+        act: &Action,
+    ) -> Result<(), Error>;
 
     fn val<U: ErasedTy>(&self) -> Result<&U, Error>;
 
@@ -136,8 +161,10 @@ pub trait ErasedValue {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// The values are [`Clone`], it records how to clone the values when they are stored.
 #[derive(Default)]
-pub struct AnyValue(AnyMap);
+pub struct AnyValue(AnyMap, HashMap<TypeId, fn(&BoxedAny) -> BoxedAny>);
 
 impl Debug for AnyValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -151,7 +178,8 @@ impl AnyValue {
     }
 
     pub fn clear(&mut self) {
-        self.0.clear()
+        self.0.clear();
+        self.1.clear();
     }
 
     pub fn contain_type<T: ErasedTy>(&self) -> bool {
@@ -170,18 +198,19 @@ impl AnyValue {
         self.inner_mut().and_then(|v| v.pop())
     }
 
-    pub fn entry<T: ErasedTy>(&mut self) -> Entry<'_, Vec<T>> {
+    pub fn entry<T: ErasedTy + Clone>(&mut self) -> Entry<'_, Vec<T>> {
+        self.1.insert(typeid::<Vec<T>>(), clone_vals::<T>);
         self.0.entry::<Vec<T>>()
     }
 
     /// Push a value to the values of type T.
-    pub fn push<T: ErasedTy>(&mut self, val: T) -> &mut Self {
+    pub fn push<T: ErasedTy + Clone>(&mut self, val: T) -> &mut Self {
         self.entry::<T>().or_default().push(val);
         self
     }
 
     /// Set the values of type T.
-    pub fn set<T: ErasedTy>(&mut self, vals: Vec<T>) -> Option<Vec<T>> {
+    pub fn set<T: ErasedTy + Clone>(&mut self, vals: Vec<T>) -> Option<Vec<T>> {
         let ret = self.remove();
         self.entry().or_insert(vals);
         ret
@@ -232,3 +261,20 @@ impl AnyValue {
         })
     }
 }
+
+impl Clone for AnyValue {
+    fn clone(&self) -> Self {
+        let mut map = AnyMap::new();
+
+        for (id, vals) in self.0 .0.iter() {
+            if let Some(cloner) = self.1.get(id) {
+                map.0.insert(*id, cloner(vals));
+            }
+        }
+        Self(map, self.1.clone())
+    }
+}
+
+fn clone_vals<T: ErasedTy + Clone>(vals: &BoxedAny) -> BoxedAny {
+    Box::new(vals.downcast_ref::<Vec<T>>().cloned().unwrap_or_default())
+}
@@ -1,8 +1,10 @@
 use std::ffi::{OsStr, OsString};
 use std::ops::{Deref, DerefMut};
 
+use super::storer::validate_target;
 use super::AnyValue;
 use super::ErasedValue;
+use super::FromStdin;
 use super::RawValParser;
 use super::ValInitializer;
 use super::ValStorer;
@@ -97,6 +99,14 @@ pub struct ValAccessor {
     storer: ValStorer,
 
     initializer: ValInitializer,
+
+    occurrences: usize,
+
+    indices: Vec<usize>,
+
+    multiple: bool,
+
+    max_occurrences: Option<usize>,
 }
 
 impl Default for ValAccessor {
@@ -112,10 +122,14 @@ impl ValAccessor {
             rawval: vec![],
             storer,
             initializer,
+            occurrences: 0,
+            indices: vec![],
+            multiple: true,
+            max_occurrences: None,
         }
     }
 
-    pub fn new_validator<U: ErasedTy + RawValParser>(
+    pub fn new_validator<U: ErasedTy + Clone + RawValParser>(
         validator: ValValidator<U>,
         initializer: ValInitializer,
     ) -> Self {
@@ -124,15 +138,23 @@ impl ValAccessor {
             rawval: vec![],
             storer: ValStorer::new_validator(validator),
             initializer,
+            occurrences: 0,
+            indices: vec![],
+            multiple: true,
+            max_occurrences: None,
         }
     }
 
-    pub fn fallback<U: ErasedTy + RawValParser>() -> Self {
+    pub fn fallback<U: ErasedTy + Clone + RawValParser>() -> Self {
         Self {
             any_value: AnyValue::default(),
             rawval: vec![],
             storer: ValStorer::fallback::<U>(),
             initializer: ValInitializer::fallback(),
+            occurrences: 0,
+            indices: vec![],
+            multiple: true,
+            max_occurrences: None,
         }
     }
 
@@ -146,6 +168,18 @@ impl ValAccessor {
         self
     }
 
+    /// Set if the value can be stored more than once since last initialization.
+    pub fn with_multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the maximum times the value can be stored since last initialization.
+    pub fn with_max_occurrences(mut self, max: Option<usize>) -> Self {
+        self.max_occurrences = max;
+        self
+    }
+
     pub fn set_storer(&mut self, storer: ValStorer) -> &mut Self {
         self.storer = storer;
         self
@@ -156,6 +190,16 @@ impl ValAccessor {
         self
     }
 
+    pub fn set_multiple(&mut self, multiple: bool) -> &mut Self {
+        self.multiple = multiple;
+        self
+    }
+
+    pub fn set_max_occurrences(&mut self, max: Option<usize>) -> &mut Self {
+        self.max_occurrences = max;
+        self
+    }
+
     pub fn storer(&self) -> &ValStorer {
         &self.storer
     }
@@ -164,6 +208,14 @@ impl ValAccessor {
         &self.initializer
     }
 
+    pub fn multiple(&self) -> bool {
+        self.multiple
+    }
+
+    pub fn max_occurrences(&self) -> Option<usize> {
+        self.max_occurrences
+    }
+
     pub fn storer_mut(&mut self) -> &mut ValStorer {
         &mut self.storer
     }
@@ -172,6 +224,32 @@ impl ValAccessor {
         &mut self.initializer
     }
 
+    /// Return how many times the value stored successfully since last initialization.
+    pub fn occurrences(&self) -> usize {
+        self.occurrences
+    }
+
+    pub fn set_occurrences(&mut self, occurrences: usize) -> &mut Self {
+        self.occurrences = occurrences;
+        self
+    }
+
+    /// Return the argument index of each occurrence since last initialization,
+    /// in the order the values were stored.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn set_indices(&mut self, indices: Vec<usize>) -> &mut Self {
+        self.indices = indices;
+        self
+    }
+
+    /// Check the current values by the validator of storer, see [`ValStorer::check`].
+    pub fn check(&self) -> Result<(), String> {
+        self.storer.check(&self.any_value)
+    }
+
     pub fn handlers(&mut self) -> (&mut Vec<OsString>, &mut AnyValue) {
         (&mut self.rawval, &mut self.any_value)
     }
@@ -184,11 +262,23 @@ impl ValAccessor {
         ctx: &Ctx,
         act: &Action,
     ) -> Result<bool, Error> {
+        if !self.multiple && self.occurrences > 0 && !ctx.overload() {
+            return Err(Error::sp_duplicate(validate_target(ctx)?).with_uid(ctx.uid()?));
+        }
+        if let Some(max) = self.max_occurrences.filter(|max| self.occurrences >= *max) {
+            return Err(Error::sp_max_occurrences(validate_target(ctx)?, max).with_uid(ctx.uid()?));
+        }
+        if act.is_set_first() && self.occurrences > 0 {
+            // keep the value of first occurrence
+            return Ok(true);
+        }
         match self.store(arg, ctx, act) {
             Ok(_) => {
                 if let Some(raw) = arg {
                     self.rawval.push(raw.to_os_string());
                 }
+                self.occurrences += 1;
+                self.indices.push(ctx.idx()?);
                 Ok(true)
             }
             Err(e) => Err(e),
@@ -200,6 +290,9 @@ impl ErasedValue for ValAccessor {
     fn initialize(&mut self) -> Result<(), Error> {
         let handler = &mut self.any_value;
 
+        self.occurrences = 0;
+        self.indices.clear();
+        handler.remove::<FromStdin>();
         self.initializer.invoke(handler)
     }
 
@@ -209,12 +302,17 @@ impl ErasedValue for ValAccessor {
         self.storer.invoke(arg, ctx, act, handler)
     }
 
-    fn store_act<U: ErasedTy>(&mut self, val: U, _: &Ctx, act: &Action) -> Result<(), Error> {
+    fn store_act<U: ErasedTy + Clone>(
+        &mut self,
+        val: U,
+        _: &Ctx,
+        act: &Action,
+    ) -> Result<(), Error> {
         let handler = &mut self.any_value;
         let value = val;
 
         match act {
-            Action::Set => {
+            Action::Set | Action::SetLast | Action::SetFirst => {
                 handler.set(vec![value]);
             }
             Action::App => {
@@ -1,11 +1,18 @@
 use std::any::TypeId;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::ffi::OsStr;
 use std::ffi::OsString;
+use std::hash::Hash;
+#[cfg(feature = "std")]
 use std::io::Stdin;
 use std::path::PathBuf;
 
 use crate::ctx::Ctx;
 use crate::map::ErasedTy;
+use crate::opt::aopt::gen_hint;
+use crate::opt::number::number_storer;
 use crate::opt::Action;
 use crate::opt::AnyOpt;
 use crate::opt::Cid;
@@ -18,6 +25,7 @@ use crate::opt::Pos;
 use crate::opt::Style;
 use crate::trace;
 use crate::typeid;
+use crate::value::FromStdin;
 use crate::value::ValInitializer;
 use crate::value::ValValidator;
 use crate::Error;
@@ -28,8 +36,10 @@ use super::Stop;
 use super::ValStorer;
 
 /// Implement this if you want the type can used for create option.
+///
+/// The [`Val`](Infer::Val) is stored in [`AnyValue`], it must be [`Clone`].
 pub trait Infer {
-    type Val: ErasedTy;
+    type Val: ErasedTy + Clone;
 
     fn infer_act() -> Action {
         Action::App
@@ -75,6 +85,14 @@ pub trait Infer {
         typeid::<Self::Val>()
     }
 
+    /// The template of option hint displayed in usage and help, such as `{names}=<PATH>`.
+    /// The `{names}` is replaced by the default hint, such as `--input, -i`.
+    ///
+    /// It is not used if the hint is set by user, return `None` keeps the default hint.
+    fn infer_hint_template() -> Option<String> {
+        None
+    }
+
     fn infer_map(val: Self::Val) -> Self;
 
     fn infer_mutable(&mut self, val: Self::Val)
@@ -109,13 +127,23 @@ pub trait Infer {
         let ctor = Self::infer_ctor();
         let type_id = Self::infer_type_id();
         let initializer = Self::infer_initializer();
+        let hint_template = Self::infer_hint_template();
         let storer = if let Some(validator) = Self::infer_validator() {
             Some(ValStorer::from(validator))
+        } else if let Some(storer) =
+            number_storer(type_id, cfg.number_format(), cfg.strip_thousands())
+        {
+            Some(storer)
         } else {
             Some(ValStorer::fallback::<Self::Val>())
         };
+        #[cfg(feature = "time")]
+        let storer = crate::opt::datetime::time_storer(type_id, cfg.time_format()).or(storer);
 
         Self::infer_tweak_info(cfg)?;
+        // the value type inferred Action::Set is a scalar
+        let scalar = !cfg.has_action() && act.is_set();
+
         (!cfg.has_ctor()).then(|| cfg.set_ctor(ctor));
         (!cfg.has_index()).then(|| index.map(|idx| cfg.set_index(idx)));
         (!cfg.has_type()).then(|| cfg.set_type_id(type_id));
@@ -123,12 +151,38 @@ pub trait Infer {
         (!cfg.has_style()).then(|| cfg.set_style(style));
         (!cfg.has_force()).then(|| cfg.set_force(force));
         (!cfg.has_action()).then(|| cfg.set_action(act));
+        // the scalar option can not be used more than once, except the flags
+        if scalar
+            && !cfg.has_multiple()
+            && cfg.toggle_prefix().is_none()
+            && cfg
+                .style()
+                .is_some_and(|styles| styles.iter().all(|style| style == &Style::Argument))
+        {
+            cfg.set_multiple(false);
+        }
+        if let Some(template) = hint_template {
+            if !cfg.has_hint() {
+                let name = cfg.name().unwrap_or_default();
+                let names = gen_hint(None::<&str>, name, cfg.index(), cfg.alias());
+
+                cfg.set_hint(template.replace("{names}", &names));
+            }
+        }
         if let Some(storer) = storer {
             (!cfg.has_storer()).then(|| cfg.set_storer(storer));
         }
         if let Some(initializer) = initializer {
             (!cfg.has_initializer()).then(|| cfg.set_initializer(initializer));
         }
+        #[cfg(feature = "std")]
+        if let Some(env) = cfg.env().map(String::from) {
+            let initializer = cfg
+                .take_initializer()
+                .unwrap_or_else(ValInitializer::fallback);
+
+            cfg.set_initializer(initializer.with_env::<Self::Val>(env));
+        }
         cfg.set_ignore_name(ignore_name);
         cfg.set_ignore_alias(ignore_alias);
         cfg.set_ignore_index(ignore_index);
@@ -320,7 +374,7 @@ where
     }
 }
 
-impl<T: ErasedTy + RawValParser> Infer for MutOpt<T> {
+impl<T: ErasedTy + Clone + RawValParser> Infer for MutOpt<T> {
     type Val = T;
 
     fn infer_map(val: Self::Val) -> Self {
@@ -370,8 +424,9 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl Infer for Stdin {
-    type Val = Stdin;
+    type Val = FromStdin;
 
     fn infer_act() -> Action {
         Action::Set
@@ -385,8 +440,8 @@ impl Infer for Stdin {
         true
     }
 
-    fn infer_map(val: Self::Val) -> Self {
-        val
+    fn infer_map(_: Self::Val) -> Self {
+        std::io::stdin()
     }
 
     /// For type Stdin, swap the name and default alias(`-`) when build configuration.
@@ -490,6 +545,10 @@ impl_infer_for!(usize);
 impl_infer_for!(String);
 impl_infer_for!(PathBuf);
 impl_infer_for!(OsString);
+#[cfg(feature = "time")]
+impl_infer_for!(time::OffsetDateTime);
+#[cfg(feature = "time")]
+impl_infer_for!(time::Date);
 
 #[derive(Debug, Clone, Copy)]
 pub struct Placeholder;
@@ -591,6 +650,10 @@ impl<T: Infer> Infer for Option<T> {
         <T as Infer>::infer_type_id()
     }
 
+    fn infer_hint_template() -> Option<String> {
+        <T as Infer>::infer_hint_template()
+    }
+
     fn infer_map(val: Self::Val) -> Self {
         Some(<T as Infer>::infer_map(val))
     }
@@ -669,6 +732,10 @@ impl<Err, T: Infer> Infer for Result<T, Err> {
         <T as Infer>::infer_type_id()
     }
 
+    fn infer_hint_template() -> Option<String> {
+        <T as Infer>::infer_hint_template()
+    }
+
     fn infer_map(val: Self::Val) -> Self {
         Ok(<T as Infer>::infer_map(val))
     }
@@ -700,76 +767,90 @@ impl<Err, T: Infer> Infer for Result<T, Err> {
     }
 }
 
-impl<T: Infer> Infer for Vec<T> {
-    type Val = <T as Infer>::Val;
+/// Implement [`Infer`] for the container of `T`, the values are collected into it in order.
+macro_rules! impl_infer_for_collection {
+    ($coll:ident, $insert:ident $(, $bound:path)*) => {
+        impl<T: Infer $(+ $bound)*> Infer for $coll<T> {
+            type Val = <T as Infer>::Val;
 
-    fn infer_act() -> Action {
-        Action::App
-    }
+            fn infer_act() -> Action {
+                Action::App
+            }
 
-    fn infer_force() -> bool {
-        true
-    }
+            fn infer_force() -> bool {
+                true
+            }
 
-    fn infer_ctor() -> String {
-        <T as Infer>::infer_ctor()
-    }
+            fn infer_ctor() -> String {
+                <T as Infer>::infer_ctor()
+            }
 
-    fn infer_index() -> Option<Index> {
-        <T as Infer>::infer_index()
-    }
+            fn infer_index() -> Option<Index> {
+                <T as Infer>::infer_index()
+            }
 
-    fn infer_style() -> Vec<Style> {
-        <T as Infer>::infer_style()
-    }
+            fn infer_style() -> Vec<Style> {
+                <T as Infer>::infer_style()
+            }
 
-    fn infer_ignore_name() -> bool {
-        <T as Infer>::infer_ignore_name()
-    }
+            fn infer_ignore_name() -> bool {
+                <T as Infer>::infer_ignore_name()
+            }
 
-    fn infer_ignore_alias() -> bool {
-        <T as Infer>::infer_ignore_alias()
-    }
+            fn infer_ignore_alias() -> bool {
+                <T as Infer>::infer_ignore_alias()
+            }
 
-    fn infer_ignore_index() -> bool {
-        <T as Infer>::infer_ignore_index()
-    }
+            fn infer_ignore_index() -> bool {
+                <T as Infer>::infer_ignore_index()
+            }
 
-    fn infer_validator() -> Option<ValValidator<Self::Val>> {
-        <T as Infer>::infer_validator()
-    }
+            fn infer_validator() -> Option<ValValidator<Self::Val>> {
+                <T as Infer>::infer_validator()
+            }
 
-    fn infer_initializer() -> Option<ValInitializer> {
-        <T as Infer>::infer_initializer()
-    }
+            fn infer_initializer() -> Option<ValInitializer> {
+                <T as Infer>::infer_initializer()
+            }
 
-    fn infer_type_id() -> TypeId {
-        <T as Infer>::infer_type_id()
-    }
+            fn infer_type_id() -> TypeId {
+                <T as Infer>::infer_type_id()
+            }
 
-    fn infer_map(val: Self::Val) -> Self {
-        vec![<T as Infer>::infer_map(val)]
-    }
+            fn infer_hint_template() -> Option<String> {
+                <T as Infer>::infer_hint_template()
+            }
 
-    fn infer_mutable(&mut self, val: Self::Val) {
-        self.push(<T as Infer>::infer_map(val));
-    }
+            fn infer_map(val: Self::Val) -> Self {
+                std::iter::once(<T as Infer>::infer_map(val)).collect()
+            }
 
-    fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
-    where
-        Self: Sized + 'static,
-        Self::Val: RawValParser,
-        C: ConfigValue + Default,
-    {
-        <T as Infer>::infer_tweak_info(cfg)
-    }
+            fn infer_mutable(&mut self, val: Self::Val) {
+                self.$insert(<T as Infer>::infer_map(val));
+            }
 
-    fn infer_fill_info<C>(cfg: &mut C) -> Result<(), Error>
-    where
-        Self: Sized + 'static,
-        Self::Val: RawValParser,
-        C: ConfigValue + Default,
-    {
-        <T as Infer>::infer_fill_info(cfg)
-    }
+            fn infer_tweak_info<C>(cfg: &mut C) -> Result<(), Error>
+            where
+                Self: Sized + 'static,
+                Self::Val: RawValParser,
+                C: ConfigValue + Default,
+            {
+                <T as Infer>::infer_tweak_info(cfg)
+            }
+
+            fn infer_fill_info<C>(cfg: &mut C) -> Result<(), Error>
+            where
+                Self: Sized + 'static,
+                Self::Val: RawValParser,
+                C: ConfigValue + Default,
+            {
+                <T as Infer>::infer_fill_info(cfg)
+            }
+        }
+    };
 }
+
+impl_infer_for_collection!(Vec, push);
+impl_infer_for_collection!(VecDeque, push_back);
+impl_infer_for_collection!(HashSet, insert, Eq, Hash);
+impl_infer_for_collection!(BTreeSet, insert, Ord);
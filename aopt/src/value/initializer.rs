@@ -1,9 +1,19 @@
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 use std::fmt::Debug;
 
+#[cfg(feature = "std")]
+use crate::ctx::Ctx;
+#[cfg(feature = "std")]
+use crate::ctx::InnerCtx;
 use crate::map::ErasedTy;
+#[cfg(feature = "std")]
+use crate::raise_error;
 use crate::Error;
 
 use super::AnyValue;
+#[cfg(feature = "std")]
+use super::RawValParser;
 
 #[cfg(feature = "sync")]
 mod __initializer {
@@ -70,7 +80,7 @@ impl Debug for ValInitializer {
 }
 
 impl ValInitializer {
-    pub fn new<U: ErasedTy>(mut init: impl InitializeValue<Vec<U>> + 'static) -> Self {
+    pub fn new<U: ErasedTy + Clone>(mut init: impl InitializeValue<Vec<U>> + 'static) -> Self {
         Self(Box::new(move |erased_val| {
             erased_val.set(init.prepare_value().map_err(Into::into)?);
             Ok(())
@@ -91,6 +101,67 @@ impl ValInitializer {
         }))
     }
 
+    /// Create an initializer which computes the value by calling `init` every time
+    /// the option is initialized, such as current directory or timestamp.
+    pub fn with_fn(mut init: impl InitializeValue<AnyValue> + 'static) -> Self {
+        Self(Box::new(move |erased_val| {
+            *erased_val = init.prepare_value().map_err(Into::into)?;
+            Ok(())
+        }))
+    }
+
+    /// Create an initializer which parses the value from environment variable `var`
+    /// every time the option is initialized, the `fallback` is used if the variable is not set.
+    #[cfg(feature = "std")]
+    pub fn from_env<U>(var: impl Into<String>, fallback: U) -> Self
+    where
+        U: RawValParser + Clone + ErasedTy,
+    {
+        Self::new_value(fallback).with_env::<U>(var)
+    }
+
+    /// Parse the value from environment variable `var` if it is set,
+    /// otherwise prepare the values with current initializer.
+    #[cfg(feature = "std")]
+    pub fn with_env<U>(mut self, var: impl Into<String>) -> Self
+    where
+        U: RawValParser + Clone + ErasedTy,
+    {
+        let var = var.into();
+
+        Self(Box::new(move |erased_val| match std::env::var_os(&var) {
+            Some(raw) => {
+                let ctx = Ctx::default()
+                    .with_inner_ctx(InnerCtx::default().with_arg(Some(Cow::Borrowed(&raw))));
+                let val = U::parse(Some(&raw), &ctx).map_err(|e| {
+                    raise_error!("can not parse the value of environment variable `{var}`")
+                        .cause_by(e.into())
+                })?;
+
+                erased_val.set(vec![val]);
+                Ok(())
+            }
+            None => (self.0)(erased_val),
+        }))
+    }
+
+    /// Extend the values prepared by current initializer with `vals`, instead of replacing them.
+    /// It is useful for layering the defaults, such as the compile-time defaults and the defaults
+    /// loaded from config file.
+    ///
+    /// The values from command line are appended to the defaults if the option has
+    /// [`App`](crate::opt::Action::App) action, or replace them with [`Set`](crate::opt::Action::Set) action.
+    pub fn with_vec_extend<U: Clone + ErasedTy>(mut self, vals: Vec<U>) -> Self {
+        Self(Box::new(move |erased_val| {
+            (self.0)(erased_val)?;
+            erased_val
+                .entry::<U>()
+                .or_default()
+                .extend(vals.iter().cloned());
+            Ok(())
+        }))
+    }
+
     /// Default value initializer, do nothing.
     pub fn fallback() -> Self {
         Self(Box::new(|_| Ok(())))
@@ -123,3 +194,102 @@ impl<T: FnMut(&mut AnyValue) -> Result<(), Error> + Send + Sync + 'static> From<
         Self(Box::new(value))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_with_fn() {
+        assert!(test_with_fn_impl().is_ok());
+    }
+
+    fn test_with_fn_impl() -> Result<(), crate::Error> {
+        let mut count = 0i64;
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("--seq=i")?
+            .set_initializer(ValInitializer::with_fn(move || {
+                let mut value = AnyValue::new();
+
+                count += 1;
+                value.set(vec![count]);
+                Ok::<_, crate::Error>(value)
+            }));
+
+        parser.init()?;
+        assert_eq!(parser.find_val::<i64>("--seq")?, &1);
+        parser.init()?;
+        assert_eq!(parser.find_val::<i64>("--seq")?, &2);
+        parser.parse(Args::from(["app"]))?;
+        assert_eq!(parser.find_val::<i64>("--seq")?, &3);
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_env() {
+        assert!(test_from_env_impl().is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    fn test_from_env_impl() -> Result<(), crate::Error> {
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("--jobs=i")?
+            .set_initializer(ValInitializer::from_env("AOPT_TEST_FROM_ENV_JOBS", 1i64));
+        parser
+            .add_opt("--level=i")?
+            .set_initializer(ValInitializer::from_env("AOPT_TEST_FROM_ENV_LEVEL", 3i64));
+
+        std::env::set_var("AOPT_TEST_FROM_ENV_JOBS", "8");
+        std::env::remove_var("AOPT_TEST_FROM_ENV_LEVEL");
+        parser.parse(Args::from(["app"]))?;
+        assert_eq!(parser.find_val::<i64>("--jobs")?, &8);
+        assert_eq!(parser.find_val::<i64>("--level")?, &3);
+
+        parser.parse(Args::from(["app", "--jobs=2"]))?;
+        assert_eq!(parser.find_val::<i64>("--jobs")?, &2);
+
+        std::env::set_var("AOPT_TEST_FROM_ENV_JOBS", "eight");
+        assert!(parser.parse(Args::from(["app"])).is_err());
+        std::env::remove_var("AOPT_TEST_FROM_ENV_JOBS");
+        parser.parse(Args::from(["app"]))?;
+        assert_eq!(parser.find_val::<i64>("--jobs")?, &1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_vec_extend() {
+        assert!(test_with_vec_extend_impl().is_ok());
+    }
+
+    fn test_with_vec_extend_impl() -> Result<(), crate::Error> {
+        let base = || ValInitializer::new_values(vec![String::from("a")]);
+        let extend = || vec![String::from("b"), String::from("c")];
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("--set=s")?
+            .set_action(Action::Set)
+            .set_initializer(base().with_vec_extend(extend()));
+        parser
+            .add_opt("--app=s")?
+            .set_action(Action::App)
+            .set_initializer(base().with_vec_extend(extend()));
+        parser
+            .add_opt("--empty=s")?
+            .set_initializer(ValInitializer::fallback().with_vec_extend(extend()));
+
+        parser.init()?;
+        assert_eq!(parser.find_vals::<String>("--set")?, &["a", "b", "c"]);
+        assert_eq!(parser.find_vals::<String>("--empty")?, &["b", "c"]);
+
+        parser.parse(Args::from(["app", "--set=d", "--app=d"]))?;
+        assert_eq!(parser.find_vals::<String>("--set")?, &["d"]);
+        assert_eq!(parser.find_vals::<String>("--app")?, &["a", "b", "c", "d"]);
+        Ok(())
+    }
+}
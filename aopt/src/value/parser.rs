@@ -1,9 +1,12 @@
 use std::ffi::OsStr;
 use std::ffi::OsString;
+#[cfg(feature = "std")]
 use std::io::Stdin;
+use std::path::Path;
 use std::path::PathBuf;
 
 use crate::ctx::Ctx;
+use crate::value::FromStdin;
 use crate::value::Stop;
 use crate::Error;
 
@@ -28,6 +31,16 @@ pub fn raw2str(raw: Option<&OsStr>) -> Result<&str, Error> {
         .ok_or_else(|| Error::sp_rawval(raw, "can not convert OsStr to str"))
 }
 
+/// Convert raw value to &[`OsStr`], the value is kept as it is.
+pub fn raw2os(raw: Option<&OsStr>) -> Result<&OsStr, Error> {
+    ok_or_else(raw)
+}
+
+/// Convert raw value to &[`Path`], the value is kept as it is even it is not valid UTF-8.
+pub fn raw2path(raw: Option<&OsStr>) -> Result<&Path, Error> {
+    ok_or_else(raw).map(Path::new)
+}
+
 impl RawValParser for () {
     type Error = Error;
 
@@ -109,7 +122,7 @@ impl RawValParser for PathBuf {
     type Error = Error;
 
     fn parse(raw: Option<&OsStr>, _ctx: &Ctx) -> Result<Self, Self::Error> {
-        Ok(PathBuf::from(ok_or_else(raw)?))
+        Ok(raw2path(raw)?.to_path_buf())
     }
 }
 
@@ -140,19 +153,29 @@ impl RawValParser for PathBuf {
 ///     ))?;
 ///
 ///     assert_eq!(parser.find_val::<i64>("-w")?, &42);
-///     assert!(parser.find_val::<std::io::Stdin>("-").is_ok());
+///     assert!(parser.find_val::<FromStdin>("-").is_ok());
 ///     assert_eq!(parser.find_val::<bool>("foo")?, &true);
 ///     Ok(())
 /// }
 /// ```
+#[cfg(feature = "std")]
 impl RawValParser for Stdin {
     type Error = Error;
 
+    fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
+        FromStdin::parse(raw, ctx).map(|_| std::io::stdin())
+    }
+}
+
+/// The value of [`Stdin`](std::io::Stdin) option, it is stored instead of the handle of stdin.
+impl RawValParser for FromStdin {
+    type Error = Error;
+
     fn parse(raw: Option<&OsStr>, ctx: &Ctx) -> Result<Self, Self::Error> {
         const STDIN: &str = "-";
 
         if ctx.name()?.map(|v| v.as_ref()) == Some(STDIN) {
-            Ok(std::io::stdin())
+            Ok(FromStdin)
         } else {
             Err(Error::sp_rawval(raw, "except `-` for Stdin").with_uid(ctx.uid()?))
         }
@@ -173,3 +196,71 @@ impl RawValParser for Stop {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::raw2os;
+    use super::raw2path;
+    use super::raw2str;
+
+    #[test]
+    fn test_raw2path() {
+        assert!(test_raw2path_impl().is_ok());
+    }
+
+    #[cfg(not(unix))]
+    fn test_raw2path_impl() -> Result<(), crate::Error> {
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn test_raw2path_impl() -> Result<(), crate::Error> {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Path;
+        use std::path::PathBuf;
+
+        use crate::prelude::*;
+
+        let raw = OsStr::from_bytes(b"/tmp/aopt_\xff\xfe");
+
+        assert!(raw2str(Some(raw)).is_err());
+        assert!(raw2os(None).is_err());
+        assert_eq!(raw2os(Some(raw))?.as_bytes(), b"/tmp/aopt_\xff\xfe");
+        assert_eq!(raw2path(Some(raw))?, Path::new(raw));
+        assert_eq!(
+            raw2path(Some(raw))?.file_name(),
+            Some(OsStr::from_bytes(b"aopt_\xff\xfe"))
+        );
+
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("--path=s")?.set_value_type::<PathBuf>().run()?;
+        inv.entry(set.add_opt("--dir=s")?.run()?)
+            .on(|_: &mut ASet, _: &mut ASer, ctx: &Ctx| {
+                Ok(ctx
+                    .arg_path()?
+                    .and_then(|v| v.parent())
+                    .map(|v| v.to_string_lossy().to_string()))
+            });
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let args = Args::from([
+            OsStr::new("app"),
+            OsStr::new("--path"),
+            raw,
+            OsStr::new("--dir"),
+            raw,
+        ]);
+
+        assert!(policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
+        assert_eq!(set.find_val::<PathBuf>("--path")?, Path::new(raw));
+        assert_eq!(set.find_val::<String>("--dir")?, "/tmp");
+        Ok(())
+    }
+}
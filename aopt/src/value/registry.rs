@@ -0,0 +1,323 @@
+use std::any::TypeId;
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use ahash::HashMapExt;
+
+use crate::ctx::Ctx;
+use crate::map::ErasedTy;
+use crate::opt::Action;
+use crate::opt::Cid;
+use crate::opt::ConfigValue;
+use crate::opt::Creator;
+use crate::opt::Opt;
+use crate::opt::Style;
+use crate::raise_error;
+use crate::trace;
+use crate::typeid;
+use crate::Error;
+use crate::HashMap;
+
+use super::AnyValue;
+use super::ValInitializer;
+use super::ValStorer;
+
+#[cfg(feature = "sync")]
+pub type ParserHandler<T> =
+    Arc<dyn Fn(Option<&OsStr>, &Ctx) -> Result<T, Error> + Send + Sync + 'static>;
+
+#[cfg(not(feature = "sync"))]
+pub type ParserHandler<T> = Arc<dyn Fn(Option<&OsStr>, &Ctx) -> Result<T, Error> + 'static>;
+
+#[cfg(feature = "sync")]
+type StorerFactory = Arc<dyn Fn() -> ValStorer + Send + Sync + 'static>;
+
+#[cfg(not(feature = "sync"))]
+type StorerFactory = Arc<dyn Fn() -> ValStorer + 'static>;
+
+#[derive(Clone)]
+struct RegisteredParser {
+    type_id: TypeId,
+
+    type_name: &'static str,
+
+    factory: StorerFactory,
+}
+
+/// [`ParserRegistry`] map the type name to a value parser,
+/// it can create [`Creator`] using for option types only known at runtime.
+///
+/// # Example
+/// ```rust
+/// # use aopt::prelude::*;
+/// # use aopt::Error;
+/// # use aopt::value::raw2str;
+/// # use aopt::value::ParserRegistry;
+/// #
+/// # fn main() -> Result<(), Error> {
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// pub struct Port(u16);
+///
+/// let mut registry = ParserRegistry::default();
+///
+/// registry.register("port", |raw, _: &Ctx| {
+///     raw2str(raw)?
+///         .parse::<u16>()
+///         .map(Port)
+///         .map_err(|e| Error::sp_rawval(raw, format!("invalid port: {e}")))
+/// });
+///
+/// let mut policy = AFwdPolicy::default();
+/// let mut set = policy.default_set().with_creator(registry.creator("port")?);
+/// let mut inv = policy.default_inv();
+/// let mut ser = policy.default_ser();
+///
+/// set.add_opt("--port=port")?.run()?;
+/// for opt in set.iter_mut() {
+///     opt.init()?;
+/// }
+///
+/// let args = Args::from(["app", "--port=8080"]);
+///
+/// policy.parse(&mut set, &mut inv, &mut ser, args)?;
+///
+/// assert_eq!(set.find_val::<Port>("--port")?, &Port(8080));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct ParserRegistry {
+    parsers: HashMap<String, RegisteredParser>,
+}
+
+impl Debug for ParserRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+
+        for (name, parser) in self.parsers.iter() {
+            map.entry(name, &parser.type_name);
+        }
+        map.finish()
+    }
+}
+
+impl ParserRegistry {
+    pub fn new() -> Self {
+        Self {
+            parsers: HashMap::new(),
+        }
+    }
+
+    /// Register the `parser` with type name `name`, replace the previous one if exists.
+    #[cfg(feature = "sync")]
+    pub fn register<T: ErasedTy + Clone>(
+        &mut self,
+        name: impl Into<String>,
+        parser: impl Fn(Option<&OsStr>, &Ctx) -> Result<T, Error> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.insert(name.into(), Arc::new(parser));
+        self
+    }
+
+    /// Register the `parser` with type name `name`, replace the previous one if exists.
+    #[cfg(not(feature = "sync"))]
+    pub fn register<T: ErasedTy + Clone>(
+        &mut self,
+        name: impl Into<String>,
+        parser: impl Fn(Option<&OsStr>, &Ctx) -> Result<T, Error> + 'static,
+    ) -> &mut Self {
+        self.insert(name.into(), Arc::new(parser));
+        self
+    }
+
+    #[cfg(feature = "sync")]
+    pub fn with_parser<T: ErasedTy + Clone>(
+        mut self,
+        name: impl Into<String>,
+        parser: impl Fn(Option<&OsStr>, &Ctx) -> Result<T, Error> + Send + Sync + 'static,
+    ) -> Self {
+        self.register(name, parser);
+        self
+    }
+
+    #[cfg(not(feature = "sync"))]
+    pub fn with_parser<T: ErasedTy + Clone>(
+        mut self,
+        name: impl Into<String>,
+        parser: impl Fn(Option<&OsStr>, &Ctx) -> Result<T, Error> + 'static,
+    ) -> Self {
+        self.register(name, parser);
+        self
+    }
+
+    fn insert<T: ErasedTy + Clone>(&mut self, name: String, parser: ParserHandler<T>) {
+        let factory: StorerFactory = Arc::new(move || {
+            let parser = parser.clone();
+
+            ValStorer::new(Box::new(
+                move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
+                    let val = (parser)(raw, ctx);
+
+                    trace!("in registry value storer, parsing {:?} -> {:?}", raw, val);
+                    act.store1(Some(val?), handler);
+                    Ok(())
+                },
+            ))
+        });
+
+        self.parsers.insert(
+            name,
+            RegisteredParser {
+                type_id: typeid::<T>(),
+                type_name: std::any::type_name::<T>(),
+                factory,
+            },
+        );
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.parsers.contains_key(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.parsers.remove(name).is_some()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.parsers.keys().map(|v| v.as_str())
+    }
+
+    /// Return the value type of parser registered with `name`.
+    pub fn type_id(&self, name: &str) -> Option<TypeId> {
+        self.parsers.get(name).map(|v| v.type_id)
+    }
+
+    /// Create a new [`ValStorer`] using the parser registered with `name`.
+    pub fn new_storer(&self, name: &str) -> Option<ValStorer> {
+        self.parsers.get(name).map(|v| (v.factory)())
+    }
+
+    /// Fill the value type, [`ValStorer`] and other missing configurations of `cfg`
+    /// using the parser registered with `name`.
+    pub fn fill_cfg<C: ConfigValue>(&self, name: &str, cfg: &mut C) -> Result<(), Error> {
+        let parser = self
+            .parsers
+            .get(name)
+            .ok_or_else(|| raise_error!("can not find parser `{name}` in registry"))?;
+
+        (!cfg.has_type()).then(|| cfg.set_type_id(parser.type_id));
+        (!cfg.has_storer()).then(|| cfg.set_storer((parser.factory)()));
+        (!cfg.has_initializer()).then(|| cfg.set_initializer(ValInitializer::fallback()));
+        (!cfg.has_action()).then(|| cfg.set_action(Action::App));
+        (!cfg.has_style()).then(|| cfg.set_style(vec![Style::Argument]));
+        (!cfg.has_force()).then(|| cfg.set_force(false));
+        cfg.set_ignore_index(true);
+        Ok(())
+    }
+
+    /// Create a [`Creator`] with name `name`, the option created by it using
+    /// the parser registered with `name`.
+    pub fn creator<O, C>(&self, name: &str) -> Result<Creator<O, C, Error>, Error>
+    where
+        O: Opt + TryFrom<C, Error: Into<Error>>,
+        C: ConfigValue + Debug,
+    {
+        if !self.contains(name) {
+            return Err(raise_error!("can not find parser `{name}` in registry"));
+        }
+        let registry = self.clone();
+        let name = name.to_owned();
+
+        Ok(Creator::new(
+            Cid::from(name.as_str()),
+            move |mut config: C| {
+                trace!(
+                    "construct option with config {:?} using parser `{name}`",
+                    &config
+                );
+
+                registry.fill_cfg(&name, &mut config)?;
+                O::try_from(config).map_err(Into::into)
+            },
+        ))
+    }
+
+    /// Create [`Creator`]s for every registered parser.
+    pub fn creators<O, C>(&self) -> Vec<Creator<O, C, Error>>
+    where
+        O: Opt + TryFrom<C, Error: Into<Error>>,
+        C: ConfigValue + Debug,
+    {
+        self.names()
+            .filter_map(|name| self.creator(name).ok())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use super::ParserRegistry;
+    use crate::prelude::*;
+    use crate::value::raw2str;
+    use crate::Error;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    fn parse_color(raw: Option<&OsStr>, _: &Ctx) -> Result<Color, Error> {
+        match raw2str(raw)? {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            color => Err(Error::sp_rawval(raw, format!("unknown color `{color}`"))),
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        assert!(test_registry_impl().is_ok());
+    }
+
+    fn test_registry_impl() -> Result<(), Error> {
+        let registry = ParserRegistry::default().with_parser("color", parse_color);
+
+        assert!(registry.contains("color"));
+        assert!(registry.creator::<AOpt, OptConfig>("colour").is_err());
+
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        for creator in registry.creators() {
+            set.register(creator);
+        }
+        set.add_opt("--fg=color")?
+            .set_help("Foreground color")
+            .run()?;
+        set.add_opt("--bg=color")?.add_alias("-b").run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        assert_eq!(set.find("--fg")?.r#type(), &std::any::TypeId::of::<Color>());
+
+        let args = Args::from(["app", "--fg=red", "-b", "blue"]);
+
+        policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        assert_eq!(set.find_val::<Color>("--fg")?, &Color::Red);
+        assert_eq!(set.find_val::<Color>("--bg")?, &Color::Blue);
+
+        let args = Args::from(["app", "--fg=pink"]);
+
+        assert!(!policy.parse(&mut set, &mut inv, &mut ser, args)?.status());
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 use std::ffi::OsStr;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use crate::ctx::Ctx;
 use crate::map::ErasedTy;
 use crate::opt::Action;
+use crate::opt::Style;
+use crate::opt::BOOL_FALSE;
+use crate::opt::BOOL_TRUE;
 use crate::trace;
 use crate::Error;
 
 use super::AnyValue;
+use super::FromStdin;
 use super::RawValParser;
 use super::ValValidator;
 
@@ -19,29 +24,89 @@ pub type StoreHandler<T> =
 pub type StoreHandler<T> =
     Box<dyn FnMut(Option<&OsStr>, &Ctx, &Action, &mut T) -> Result<(), Error>>;
 
+#[cfg(feature = "sync")]
+pub type CheckHandler<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type CheckHandler<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+#[cfg(feature = "sync")]
+pub type ValueFilter = Arc<dyn Fn(&OsStr) -> Result<(), Error> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type ValueFilter = Arc<dyn Fn(&OsStr) -> Result<(), Error>>;
+
 /// [`ValStorer`] perform the value storing action.
-pub struct ValStorer(StoreHandler<AnyValue>);
+pub struct ValStorer {
+    handler: StoreHandler<AnyValue>,
+
+    checker: Option<CheckHandler<AnyValue>>,
+
+    filter: Option<ValueFilter>,
+}
 
 impl Debug for ValStorer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("WriterHandler").field(&"{...}").finish()
+        f.debug_struct("ValStorer")
+            .field("handler", &"{...}")
+            .field("checker", &self.checker.as_ref().map(|_| "{...}"))
+            .field("filter", &self.filter.as_ref().map(|_| "{...}"))
+            .finish()
     }
 }
 
 impl ValStorer {
     pub fn new(handler: StoreHandler<AnyValue>) -> Self {
-        Self(handler)
+        Self {
+            handler,
+            checker: None,
+            filter: None,
+        }
     }
 
-    pub fn fallback<U: ErasedTy + RawValParser>() -> Self {
-        Self(Self::fallback_handler::<U>())
+    pub fn fallback<U: ErasedTy + Clone + RawValParser>() -> Self {
+        Self::new(Self::fallback_handler::<U>())
     }
 
     /// Create a [`ValStorer`] with a value validator.
     /// The [`invoke`](ValStorer::invoke) will return a [`failure`](Error::is_failure)
     /// if value check failed.
-    pub fn new_validator<U: ErasedTy + RawValParser>(validator: ValValidator<U>) -> Self {
-        Self(Self::validator(validator))
+    /// The validator is also used by [`check`](ValStorer::check) on the values already stored.
+    pub fn new_validator<U: ErasedTy + Clone + RawValParser>(validator: ValValidator<U>) -> Self {
+        let validator = Arc::new(validator);
+        let checker = validator.clone();
+
+        Self {
+            handler: Self::validator_handler(validator),
+            checker: Some(Box::new(move |values: &AnyValue| {
+                values
+                    .vals::<U>()
+                    .map(|vals| vals.iter().try_for_each(|val| checker.check(val)))
+                    .unwrap_or(Ok(()))
+            })),
+            filter: None,
+        }
+    }
+
+    /// Check the values of type `U` with the validator of [`new_validator`](ValStorer::new_validator),
+    /// such as the values set by [`ValInitializer`](crate::value::ValInitializer).
+    /// Return the error message if check failed, it always passed if the storer has no validator.
+    pub fn check(&self, values: &AnyValue) -> Result<(), String> {
+        self.checker
+            .as_ref()
+            .map(|checker| checker(values))
+            .unwrap_or(Ok(()))
+    }
+
+    /// Set the filter invoked on every raw value before it is parsed,
+    /// the value is rejected if the filter returns an error.
+    pub fn set_filter(&mut self, filter: Option<ValueFilter>) -> &mut Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn filter(&self) -> Option<&ValueFilter> {
+        self.filter.as_ref()
     }
 
     /// Invoke the inner value store handler on [`AnyValue`].
@@ -53,17 +118,187 @@ impl ValStorer {
         arg: &mut AnyValue,
     ) -> Result<(), Error> {
         crate::trace!("saving raw value({:?}) for {}", raw, ctx.uid()?);
-        (self.0)(raw, ctx, act, arg)
+        if let (Some(filter), Some(raw)) = (&self.filter, raw) {
+            filter(raw)?;
+        }
+        (self.handler)(raw, ctx, act, arg)
+    }
+
+    /// Replace the value begins with `marker` by the trimmed content of the file,
+    /// the path of file is the rest of value.
+    /// The [`invoke`](ValStorer::invoke) will return a [`failure`](Error::is_failure)
+    /// if the file can not be read.
+    #[cfg(feature = "std")]
+    pub fn with_file_indirection(self, marker: char) -> Self {
+        let Self {
+            mut handler,
+            checker,
+            filter,
+        } = self;
+
+        Self {
+            checker,
+            filter,
+            handler: Box::new(
+                move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, values: &mut AnyValue| match raw
+                    .and_then(|v| v.to_str()?.strip_prefix(marker))
+                {
+                    Some(path) => {
+                        let uid = ctx.uid()?;
+                        let target = validate_target(ctx)?;
+                        let content = std::fs::read_to_string(path).map_err(|e| {
+                            Error::sp_invalid_value(
+                                raw,
+                                target,
+                                format!("can not read file `{path}`: {e}"),
+                            )
+                            .with_uid(uid)
+                        })?;
+
+                        trace!("read value from file {:?} for {}", path, uid);
+                        handler(Some(OsStr::new(content.trim())), ctx, act, values)
+                    }
+                    None => handler(raw, ctx, act, values),
+                },
+            ),
+        }
     }
 
-    pub fn validator<U: ErasedTy + RawValParser>(
+    /// Trim the leading and trailing whitespace of value, and invoke the inner handler on it.
+    /// The value is passed to inner handler as is if it is not valid UTF-8.
+    pub fn with_trim(self) -> Self {
+        let Self {
+            mut handler,
+            checker,
+            filter,
+        } = self;
+
+        Self {
+            checker,
+            filter,
+            handler: Box::new(
+                move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, values: &mut AnyValue| match raw
+                    .and_then(|v| v.to_str())
+                {
+                    Some(value) => handler(Some(OsStr::new(value.trim())), ctx, act, values),
+                    None => handler(raw, ctx, act, values),
+                },
+            ),
+        }
+    }
+
+    /// Replace the value in `trues` with `true` and the value in `falses` with `false`,
+    /// and invoke the inner handler on it.
+    pub fn with_bool_literals(self, trues: Vec<String>, falses: Vec<String>) -> Self {
+        let Self {
+            mut handler,
+            checker,
+            filter,
+        } = self;
+
+        Self {
+            checker,
+            filter,
+            handler: Box::new(
+                move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, values: &mut AnyValue| {
+                    let literal = raw.and_then(|v| v.to_str()).and_then(|v| {
+                        if trues.iter().any(|t| t == v) {
+                            Some(BOOL_TRUE)
+                        } else if falses.iter().any(|f| f == v) {
+                            Some(BOOL_FALSE)
+                        } else {
+                            None
+                        }
+                    });
+
+                    match literal {
+                        Some(value) => handler(Some(OsStr::new(value)), ctx, act, values),
+                        None => handler(raw, ctx, act, values),
+                    }
+                },
+            ),
+        }
+    }
+
+    /// Split the value by `delimiter`, and invoke the inner handler on each part.
+    /// The `delimiter` or `escape` following the `escape` is taken literally,
+    /// such as `a\,b,c` is split into `a,b` and `c`.
+    /// The value is passed to inner handler as is if it is not valid UTF-8.
+    pub fn with_value_delimiter(self, delimiter: char, escape: Option<char>) -> Self {
+        let Self {
+            mut handler,
+            checker,
+            filter,
+        } = self;
+
+        Self {
+            checker,
+            filter,
+            handler: Box::new(
+                move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, values: &mut AnyValue| match raw
+                    .and_then(|v| v.to_str())
+                {
+                    Some(value) => {
+                        for part in split_delimited(value, delimiter, escape) {
+                            handler(Some(OsStr::new(&part)), ctx, act, values)?;
+                        }
+                        Ok(())
+                    }
+                    None => handler(raw, ctx, act, values),
+                },
+            ),
+        }
+    }
+
+    /// Store a [`FromStdin`] if the value is equal to the `marker`, the value is passed to the inner handler as is.
+    /// The [`FromStdin`] reflects the last value stored, it is removed if the value is not the `marker`.
+    pub fn with_stdin_marker(self, marker: String) -> Self {
+        let Self {
+            mut handler,
+            checker,
+            filter,
+        } = self;
+
+        Self {
+            checker,
+            filter,
+            handler: Box::new(
+                move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, values: &mut AnyValue| {
+                    handler(raw, ctx, act, values)?;
+                    if raw.is_some_and(|v| v == marker.as_str()) {
+                        trace!("value of {} is read from stdin", ctx.uid()?);
+                        values.set(vec![FromStdin]);
+                    } else {
+                        values.remove::<FromStdin>();
+                    }
+                    Ok(())
+                },
+            ),
+        }
+    }
+
+    pub fn validator<U: ErasedTy + Clone + RawValParser>(
         validator: ValValidator<U>,
+    ) -> StoreHandler<AnyValue> {
+        Self::validator_handler(Arc::new(validator))
+    }
+
+    fn validator_handler<U: ErasedTy + Clone + RawValParser>(
+        validator: Arc<ValValidator<U>>,
     ) -> StoreHandler<AnyValue> {
         Box::new(
             move |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
                 let val = U::parse(raw, ctx).map_err(Into::into)?;
 
-                if !validator.invoke(&val) {
+                if deferred_check(ctx)? {
+                    trace!(
+                        "validator value storer deferred, parsing {:?} -> {:?}",
+                        raw,
+                        val
+                    );
+                    act.store1(Some(val), handler);
+                    Ok(())
+                } else if let Err(msg) = validator.check(&val) {
                     let uid = ctx.uid()?;
 
                     trace!(
@@ -71,10 +306,7 @@ impl ValStorer {
                         raw,
                         val
                     );
-                    Err(
-                        crate::raise_failure!("value check failed: `{:?}`", ctx.inner_ctx().ok(),)
-                            .with_uid(uid),
-                    )
+                    Err(Error::sp_invalid_value(raw, validate_target(ctx)?, msg).with_uid(uid))
                 } else {
                     trace!(
                         "validator value storer okay, parsing {:?} -> {:?}",
@@ -88,7 +320,7 @@ impl ValStorer {
         )
     }
 
-    pub fn fallback_handler<U: ErasedTy + RawValParser>() -> StoreHandler<AnyValue> {
+    pub fn fallback_handler<U: ErasedTy + Clone + RawValParser>() -> StoreHandler<AnyValue> {
         Box::new(
             |raw: Option<&OsStr>, ctx: &Ctx, act: &Action, handler: &mut AnyValue| {
                 let val = U::parse(raw, ctx).map_err(Into::into);
@@ -101,13 +333,57 @@ impl ValStorer {
     }
 }
 
-impl<U: ErasedTy + RawValParser> From<ValValidator<U>> for ValStorer {
+/// Split the `value` by `delimiter`, the `delimiter` or `escape` after `escape` is kept as is.
+fn split_delimited(value: &str, delimiter: char, escape: Option<char>) -> Vec<String> {
+    let mut parts = vec![];
+    let mut part = String::default();
+    let mut chars = value.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if Some(ch) == escape {
+            match chars.peek() {
+                Some(&next) if next == delimiter || Some(next) == escape => {
+                    part.push(next);
+                    chars.next();
+                }
+                _ => part.push(ch),
+            }
+        } else if ch == delimiter {
+            parts.push(std::mem::take(&mut part));
+        } else {
+            part.push(ch);
+        }
+    }
+    parts.push(part);
+    parts
+}
+
+/// The validator is deferred to the opt check phase for the options matched by name.
+fn deferred_check(ctx: &Ctx) -> Result<bool, Error> {
+    Ok(crate::parser::deferred_style(ctx, ctx.style()?))
+}
+
+/// Describe the option being validated, the name of positional argument is the argument itself,
+/// so using the index instead.
+pub(crate) fn validate_target(ctx: &Ctx) -> Result<String, Error> {
+    let inner = ctx.inner_ctx()?;
+
+    Ok(match (inner.style(), inner.name()) {
+        (Style::Pos | Style::Cmd | Style::Main, _) => {
+            format!("positional argument at index {}", inner.idx())
+        }
+        (_, Some(name)) => format!("option `{name}`"),
+        (_, None) => format!("option {}", inner.uid()),
+    })
+}
+
+impl<U: ErasedTy + Clone + RawValParser> From<ValValidator<U>> for ValStorer {
     fn from(validator: ValValidator<U>) -> Self {
         Self::new_validator(validator)
     }
 }
 
-impl<U: ErasedTy + RawValParser> From<Option<ValValidator<U>>> for ValStorer {
+impl<U: ErasedTy + Clone + RawValParser> From<Option<ValValidator<U>>> for ValStorer {
     fn from(validator: Option<ValValidator<U>>) -> Self {
         if let Some(validator) = validator {
             Self::new_validator(validator)
@@ -116,3 +392,189 @@ impl<U: ErasedTy + RawValParser> From<Option<ValValidator<U>>> for ValStorer {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::split_delimited;
+    use crate::prelude::*;
+    use crate::Error;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_split_delimited() {
+        let split = |value, escape| split_delimited(value, ',', escape);
+
+        assert_eq!(split("a,b,c", Some('\\')), ["a", "b", "c"]);
+        assert_eq!(split("a\\,b,c", Some('\\')), ["a,b", "c"]);
+        assert_eq!(split("a\\\\,b", Some('\\')), ["a\\", "b"]);
+        assert_eq!(split("a\\b,,c", Some('\\')), ["a\\b", "", "c"]);
+        assert_eq!(split("a,b\\", Some('\\')), ["a", "b\\"]);
+        assert_eq!(split("a\\,b", None), ["a\\", "b"]);
+        assert_eq!(split("a^,b", Some('^')), ["a,b"]);
+        assert_eq!(split("", Some('\\')), [""]);
+    }
+
+    #[test]
+    fn test_value_delimiter() {
+        assert!(test_value_delimiter_impl().is_ok());
+    }
+
+    fn test_value_delimiter_impl() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("--tag=s")?
+            .set_action(Action::App)
+            .set_value_delimiter(',');
+        parser
+            .add_opt("--raw=s")?
+            .set_action(Action::App)
+            .set_value_delimiter(',')
+            .set_delimiter_escape(None);
+        parser
+            .add_opt("--port=i")?
+            .set_action(Action::App)
+            .set_value_delimiter(':');
+
+        parser
+            .parse(Args::from([
+                "app",
+                "--tag",
+                "a\\,b,c",
+                "--tag=d",
+                "--raw=x\\,y",
+                "--port=80:8080",
+            ]))?
+            .ok()?;
+        assert_eq!(parser.find_vals::<String>("--tag")?, &["a,b", "c", "d"]);
+        assert_eq!(parser.find_vals::<String>("--raw")?, &["x\\", "y"]);
+        assert_eq!(parser.find_vals::<i64>("--port")?, &[80, 8080]);
+
+        let ret = parser.parse(Args::from(["app", "--port=80:http"]))?;
+
+        assert!(!ret.status());
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_file_indirection() {
+        assert!(test_file_indirection_impl().is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    fn test_file_indirection_impl() -> Result<(), Error> {
+        let dir = std::env::temp_dir().join(format!("aopt_test_indirect_{}", std::process::id()));
+        let file = dir.join("token");
+        let missing = dir.join("missing");
+
+        std::fs::create_dir_all(&dir).map_err(Error::from)?;
+        std::fs::write(&file, "s3cr3t\n").map_err(Error::from)?;
+
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--token=s")?.set_file_indirection('@');
+        parser.add_opt("--count=i")?.set_file_indirection('@');
+
+        // the literal value
+        parser
+            .parse(Args::from(["app", "--token", "s3cr3t"]))?
+            .ok()?;
+        assert_eq!(parser.find_val::<String>("--token")?, "s3cr3t");
+
+        let token = format!("@{}", file.display());
+
+        parser.parse(Args::from(["app", "--token", &token]))?.ok()?;
+        assert_eq!(parser.find_val::<String>("--token")?, "s3cr3t");
+
+        std::fs::write(&file, " 42 ").map_err(Error::from)?;
+        parser.parse(Args::from(["app", "--count", &token]))?.ok()?;
+        assert_eq!(parser.find_val::<i64>("--count")?, &42);
+
+        let missing = format!("@{}", missing.display());
+        let ret = parser.parse(Args::from(["app", "--token", &missing]))?;
+
+        assert!(!ret.status());
+        assert!(ret
+            .failure()
+            .and_then(|e| e.caused_by())
+            .is_some_and(|e| e.to_string().contains("can not read file")));
+        std::fs::remove_dir_all(&dir).map_err(Error::from)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_stdin_marker() {
+        assert!(test_stdin_marker_impl().is_ok());
+    }
+
+    fn test_stdin_marker_impl() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser
+            .add_opt("file=p@1")?
+            .set_pos_type::<PathBuf>()
+            .set_stdin_marker(None);
+        parser
+            .add_opt("--input=s")?
+            .set_stdin_marker("@stdin".to_owned());
+        parser.add_opt("--output=s")?;
+
+        parser.parse(Args::from(["app", "-", "--output=-"]))?.ok()?;
+        assert!(parser.value_is_stdin("file")?);
+        assert_eq!(parser.find_val::<PathBuf>("file")?, &PathBuf::from("-"));
+        assert!(!parser.value_is_stdin("--input")?);
+        assert!(!parser.value_is_stdin("--output")?);
+        assert_eq!(parser.find_val::<String>("--output")?, "-");
+
+        parser
+            .parse(Args::from(["app", "data.txt", "--input=@stdin"]))?
+            .ok()?;
+        assert!(!parser.value_is_stdin("file")?);
+        assert!(parser.value_is_stdin("--input")?);
+
+        parser.parse(Args::from(["app", "data.txt"]))?.ok()?;
+        assert!(!parser.value_is_stdin("--input")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim() {
+        assert!(test_trim_impl().is_ok());
+    }
+
+    fn test_trim_impl() -> Result<(), Error> {
+        let mut parser = AFwdParser::default();
+
+        parser.add_opt("--name=s")?.set_trim(true);
+        parser.add_opt("--raw=s")?;
+        parser.add_opt("--level=i")?.set_trim(true);
+        parser
+            .add_opt("--tag=s")?
+            .set_trim(true)
+            .set_value_delimiter(',');
+        parser.add_opt("--dir".infer::<PathBuf>())?.set_trim(true);
+
+        parser
+            .parse(Args::from([
+                "app",
+                "--name",
+                "  lily \t",
+                "--raw= lucy ",
+                "--level= 42\n",
+                "--tag=a , b",
+                "--dir= data ",
+            ]))?
+            .ok()?;
+        assert_eq!(parser.find_val::<String>("--name")?, "lily");
+        assert_eq!(parser.find_val::<String>("--raw")?, " lucy ");
+        assert_eq!(parser.find_val::<i64>("--level")?, &42);
+        assert_eq!(parser.find_vals::<String>("--tag")?, &["a", "b"]);
+        // the path value is untouched
+        assert_eq!(
+            parser.find_val::<PathBuf>("--dir")?,
+            &PathBuf::from(" data ")
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "std")]
+use std::path::Path;
+
 use crate::map::ErasedTy;
 
 #[cfg(feature = "sync")]
@@ -6,41 +9,149 @@ pub type ValidatorHandler<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
 #[cfg(not(feature = "sync"))]
 pub type ValidatorHandler<T> = Box<dyn Fn(&T) -> bool>;
 
-pub struct ValValidator<T>(ValidatorHandler<T>);
+#[cfg(feature = "sync")]
+pub type ReasonHandler<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+#[cfg(not(feature = "sync"))]
+pub type ReasonHandler<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+enum Inner<T> {
+    Handler(ValidatorHandler<T>),
+
+    Reason(ReasonHandler<T>),
+
+    All(Vec<ValValidator<T>>),
+
+    Any(Vec<ValValidator<T>>),
+}
+
+pub struct ValValidator<T> {
+    inner: Inner<T>,
+
+    desc: Option<String>,
+}
 
 impl<T> std::fmt::Debug for ValValidator<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("ValValidator").field(&"{...}").finish()
+        f.debug_struct("ValValidator")
+            .field("inner", &"{...}")
+            .field("desc", &self.desc)
+            .finish()
     }
 }
 
 impl<T: ErasedTy> ValValidator<T> {
     pub fn new(handler: ValidatorHandler<T>) -> Self {
-        Self(handler)
+        Self {
+            inner: Inner::Handler(handler),
+            desc: None,
+        }
+    }
+
+    /// Set the description of validator, it is used as error message when value check failed.
+    pub fn with_desc(mut self, desc: impl Into<String>) -> Self {
+        self.desc = Some(desc.into());
+        self
+    }
+
+    pub fn desc(&self) -> Option<&str> {
+        self.desc.as_deref()
     }
 
     pub fn invoke(&self, val: &T) -> bool {
-        (self.0)(val)
+        match &self.inner {
+            Inner::Handler(handler) => (handler)(val),
+            Inner::Reason(handler) => (handler)(val).is_ok(),
+            Inner::All(validators) => validators.iter().all(|v| v.invoke(val)),
+            Inner::Any(validators) => validators.iter().any(|v| v.invoke(val)),
+        }
+    }
+
+    /// Check the value, return the error message if check failed.
+    ///
+    /// The error message is the description of validator,
+    /// or composed by the messages of inner validators if it has no description.
+    pub fn check(&self, val: &T) -> Result<(), String> {
+        let ret = match &self.inner {
+            Inner::Handler(handler) => {
+                if (handler)(val) {
+                    Ok(())
+                } else {
+                    Err(String::from("value is invalid"))
+                }
+            }
+            Inner::Reason(handler) => (handler)(val),
+            Inner::All(validators) => validators.iter().try_for_each(|v| v.check(val)),
+            Inner::Any(validators) => {
+                let mut msgs = Vec::with_capacity(validators.len());
+
+                for validator in validators {
+                    match validator.check(val) {
+                        Ok(_) => return Ok(()),
+                        Err(msg) => msgs.push(msg),
+                    }
+                }
+                Err(format!("none of the checks passed: {}", msgs.join("; ")))
+            }
+        };
+
+        ret.map_err(|msg| self.desc.clone().unwrap_or(msg))
+    }
+
+    /// Create a validator that passed if all the `validators` passed, the check
+    /// stopped at the first failed validator.
+    pub fn all(validators: Vec<ValValidator<T>>) -> Self {
+        Self {
+            inner: Inner::All(validators),
+            desc: None,
+        }
+    }
+
+    /// Create a validator that passed if any of the `validators` passed, the check
+    /// stopped at the first passed validator.
+    pub fn any(validators: Vec<ValValidator<T>>) -> Self {
+        Self {
+            inner: Inner::Any(validators),
+            desc: None,
+        }
     }
 
     #[cfg(feature = "sync")]
     pub fn from_fn(func: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
-        Self(Box::new(move |val| func(val)))
+        Self::new(Box::new(move |val| func(val)))
     }
 
     #[cfg(not(feature = "sync"))]
     pub fn from_fn(func: impl Fn(&T) -> bool + 'static) -> Self {
-        Self(Box::new(move |val| func(val)))
+        Self::new(Box::new(move |val| func(val)))
+    }
+
+    /// Create a validator from `func`, the returned reason is used as error message when check failed.
+    #[cfg(feature = "sync")]
+    pub fn from_reason_fn(func: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self {
+            inner: Inner::Reason(Box::new(func)),
+            desc: None,
+        }
+    }
+
+    /// Create a validator from `func`, the returned reason is used as error message when check failed.
+    #[cfg(not(feature = "sync"))]
+    pub fn from_reason_fn(func: impl Fn(&T) -> Result<(), String> + 'static) -> Self {
+        Self {
+            inner: Inner::Reason(Box::new(func)),
+            desc: None,
+        }
     }
 }
 
 impl<T: ErasedTy + PartialEq> ValValidator<T> {
     pub fn equal(val: T) -> Self {
-        Self(Box::new(move |inner_val| inner_val == &val))
+        Self::new(Box::new(move |inner_val| inner_val == &val))
     }
 
     pub fn contains(vals: Vec<T>) -> Self {
-        Self(Box::new(move |inner_val| vals.contains(inner_val)))
+        Self::new(Box::new(move |inner_val| vals.contains(inner_val)))
     }
 }
 
@@ -49,14 +160,14 @@ impl<T: ErasedTy> ValValidator<T> {
     where
         K: ErasedTy + PartialEq<T>,
     {
-        Self(Box::new(move |inner_val| &val == inner_val))
+        Self::new(Box::new(move |inner_val| &val == inner_val))
     }
 
     pub fn contains2<K>(vals: Vec<K>) -> Self
     where
         K: ErasedTy + PartialEq<T>,
     {
-        Self(Box::new(move |inner_val| {
+        Self::new(Box::new(move |inner_val| {
             vals.iter().any(|v| PartialEq::eq(v, inner_val))
         }))
     }
@@ -64,16 +175,195 @@ impl<T: ErasedTy> ValValidator<T> {
 
 impl<T: ErasedTy + PartialOrd> ValValidator<T> {
     pub fn range_full(start: T, end: T) -> Self {
-        Self(Box::new(move |inner_val| {
+        Self::new(Box::new(move |inner_val| {
             inner_val >= &start && inner_val <= &end
         }))
     }
 
     pub fn range_from(start: T) -> Self {
-        Self(Box::new(move |inner_val| inner_val >= &start))
+        Self::new(Box::new(move |inner_val| inner_val >= &start))
     }
 
     pub fn range_to(end: T) -> Self {
-        Self(Box::new(move |inner_val| inner_val <= &end))
+        Self::new(Box::new(move |inner_val| inner_val <= &end))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ErasedTy + AsRef<Path>> ValValidator<T> {
+    /// Create a validator check if the path exists.
+    pub fn path_exists() -> Self {
+        Self::new(Box::new(|val: &T| val.as_ref().exists())).with_desc("path does not exist")
+    }
+
+    /// Create a validator check if the path exists and is a regular file.
+    pub fn is_file() -> Self {
+        Self::new(Box::new(|val: &T| val.as_ref().is_file()))
+            .with_desc("path does not exist or is not a file")
+    }
+
+    /// Create a validator check if the path exists and is a directory.
+    pub fn is_dir() -> Self {
+        Self::new(Box::new(|val: &T| val.as_ref().is_dir()))
+            .with_desc("path does not exist or is not a directory")
+    }
+
+    /// Create a validator check if the path can be opened for reading.
+    pub fn is_readable() -> Self {
+        Self::new(Box::new(|val: &T| {
+            let path = val.as_ref();
+
+            if path.is_dir() {
+                std::fs::read_dir(path).is_ok()
+            } else {
+                std::fs::File::open(path).is_ok()
+            }
+        }))
+        .with_desc("path does not exist or is not readable")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "std")]
+    use std::path::PathBuf;
+
+    use super::ValValidator;
+
+    #[test]
+    fn test_all_any() {
+        let positive = ValValidator::range_from(1i64).with_desc("value must be positive");
+        let even = ValValidator::from_fn(|v: &i64| v % 2 == 0).with_desc("value must be even");
+        let small = ValValidator::range_to(100i64).with_desc("value must be less than 100");
+        let all = ValValidator::all(vec![positive, even, small]);
+
+        assert!(all.invoke(&42));
+        assert!(all.check(&42).is_ok());
+        assert!(!all.invoke(&43));
+        assert_eq!(all.check(&43), Err(String::from("value must be even")));
+        assert_eq!(all.check(&-2), Err(String::from("value must be positive")));
+
+        let zero = ValValidator::equal(0i64).with_desc("value must be zero");
+        let large = ValValidator::range_from(1000i64).with_desc("value must be large");
+        let any = ValValidator::any(vec![zero, large]);
+
+        assert!(any.invoke(&0));
+        assert!(any.invoke(&1024));
+        assert!(any.check(&1024).is_ok());
+        assert!(!any.invoke(&42));
+        assert_eq!(
+            any.check(&42),
+            Err(String::from(
+                "none of the checks passed: value must be zero; value must be large"
+            ))
+        );
+
+        let any = ValValidator::any(vec![ValValidator::equal(0i64)]).with_desc("expect zero");
+
+        assert_eq!(any.check(&42), Err(String::from("expect zero")));
+        assert_eq!(
+            ValValidator::<i64>::all(vec![ValValidator::equal(1)]).check(&2),
+            Err(String::from("value is invalid"))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_path() {
+        assert!(test_path_impl().is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    fn test_path_impl() -> Result<(), std::io::Error> {
+        let dir = std::env::temp_dir().join(format!("aopt_test_path_{}", std::process::id()));
+        let file = dir.join("file.txt");
+        let missing = dir.join("missing.txt");
+
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(&file, "aopt")?;
+
+        let exists = ValValidator::<PathBuf>::path_exists();
+        let is_file = ValValidator::<PathBuf>::is_file();
+        let is_dir = ValValidator::<PathBuf>::is_dir();
+        let is_readable = ValValidator::<PathBuf>::is_readable();
+
+        assert!(exists.invoke(&file));
+        assert!(exists.invoke(&dir));
+        assert!(is_file.invoke(&file));
+        assert!(!is_file.invoke(&dir));
+        assert!(is_dir.invoke(&dir));
+        assert!(!is_dir.invoke(&file));
+        assert!(is_readable.invoke(&file));
+        assert!(is_readable.invoke(&dir));
+        assert_eq!(
+            exists.check(&missing),
+            Err(String::from("path does not exist"))
+        );
+        assert_eq!(
+            is_file.check(&dir),
+            Err(String::from("path does not exist or is not a file"))
+        );
+        assert_eq!(
+            is_dir.check(&missing),
+            Err(String::from("path does not exist or is not a directory"))
+        );
+        assert!(!is_readable.invoke(&missing));
+        assert!(ValValidator::<String>::is_file().invoke(&file.to_string_lossy().to_string()));
+
+        #[cfg(unix)]
+        {
+            use std::ffi::OsStr;
+            use std::os::unix::ffi::OsStrExt;
+
+            let non_utf8 = PathBuf::from(OsStr::from_bytes(b"aopt_\xff\xfe"));
+
+            assert!(!exists.invoke(&non_utf8));
+            assert!(!is_readable.invoke(&non_utf8));
+        }
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        assert!(test_invalid_value_impl().is_ok());
+    }
+
+    fn test_invalid_value_impl() -> Result<(), crate::Error> {
+        use crate::prelude::*;
+
+        let mut policy = AFwdPolicy::default();
+        let mut set = policy.default_set();
+        let mut inv = policy.default_inv();
+        let mut ser = policy.default_ser();
+
+        set.add_opt("-n;--num=i")?
+            .set_validator_t(ValValidator::from_reason_fn(|v: &i64| {
+                (*v > 0).then_some(()).ok_or(format!("{v} is not positive"))
+            }))
+            .run()?;
+        set.add_opt("--name=s")?
+            .set_validator_t(ValValidator::equal(String::from("foo")).with_desc("expect foo"))
+            .run()?;
+        for opt in set.iter_mut() {
+            opt.init()?;
+        }
+
+        let ret = policy.parse(
+            &mut set,
+            &mut inv,
+            &mut ser,
+            Args::from(["app", "-n", "-42"]),
+        )?;
+        let failure = ret.failure().map(|v| format!("{v:?}")).unwrap_or_default();
+
+        assert!(failure.contains("invalid value `-42` for option `-n`: -42 is not positive"));
+
+        let args = Args::from(["app", "--name=bar"]);
+        let ret = policy.parse(&mut set, &mut inv, &mut ser, args)?;
+        let failure = ret.failure().map(|v| format!("{v:?}")).unwrap_or_default();
+
+        assert!(failure.contains("invalid value `bar` for option `--name`: expect foo"));
+        Ok(())
     }
 }
@@ -14,20 +14,38 @@ pub enum ArgKind {
 
     Help,
 
+    Env,
+
+    Group,
+
     Value,
 
     Values,
 
+    DefaultFn,
+
     Alias,
 
+    DeprecatedAlias,
+
+    Short,
+
+    Long,
+
     Index,
 
     Force,
 
+    Priority,
+
+    DisplayOrder,
+
     Action,
 
     Validator,
 
+    Choices,
+
     On,
 
     Fallback,
@@ -42,6 +60,28 @@ pub enum ArgKind {
 
     Count,
 
+    Standalone,
+
+    OptionalValue,
+
+    NumValues,
+
+    Greedy,
+
+    IgCase,
+
+    Last,
+
+    Forward,
+
+    Secret,
+
+    HideDefault,
+
+    Info,
+
+    ExactlyOne,
+
     MethodCall(String),
 }
 
@@ -57,13 +97,22 @@ impl Kind for ArgKind {
                 "ty" => (Self::Type, true),
                 "hint" => (Self::Hint, true),
                 "help" => (Self::Help, true),
+                "env" => (Self::Env, true),
+                "group" => (Self::Group, true),
                 "value" => (Self::Value, true),
                 "values" => (Self::Values, true),
+                "default_fn" => (Self::DefaultFn, true),
                 "alias" => (Self::Alias, true),
+                "deprecated_alias" => (Self::DeprecatedAlias, true),
+                "short" => (Self::Short, true),
+                "long" => (Self::Long, true),
                 "index" => (Self::Index, true),
                 "force" => (Self::Force, true),
+                "priority" => (Self::Priority, true),
+                "display_order" => (Self::DisplayOrder, true),
                 "action" => (Self::Action, true),
                 "valid" => (Self::Validator, true),
+                "choices" => (Self::Choices, input.peek(syn::Token![=])),
                 "on" => (Self::On, true),
                 "fallback" => (Self::Fallback, true),
                 "then" => (Self::Then, true),
@@ -71,6 +120,17 @@ impl Kind for ArgKind {
                 "fetch" => (Self::Fetch, true),
                 "append" => (Self::Append, false),
                 "count" => (Self::Count, false),
+                "standalone" => (Self::Standalone, false),
+                "optional_value" => (Self::OptionalValue, true),
+                "num_values" => (Self::NumValues, true),
+                "greedy" => (Self::Greedy, false),
+                "igcase" => (Self::IgCase, false),
+                "last" => (Self::Last, false),
+                "forward" => (Self::Forward, false),
+                "secret" => (Self::Secret, false),
+                "hide_default" => (Self::HideDefault, false),
+                "info" => (Self::Info, true),
+                "exactly_one" => (Self::ExactlyOne, true),
                 method => (Self::MethodCall(method.to_owned()), true),
             })
         } else {
@@ -95,25 +155,43 @@ impl ArgKind {
             ArgKind::Help => Ok(quote! {
                 cote::prelude::ConfigValue::set_help(&mut #ident, #val);
             }),
+            ArgKind::Env => Ok(quote! {
+                cote::prelude::ConfigValue::set_env(&mut #ident, #val);
+            }),
+            ArgKind::Group => Ok(quote! {
+                cote::prelude::ConfigValue::set_group(&mut #ident, #val);
+            }),
             ArgKind::Value => Ok(quote! {
                 cote::prelude::ConfigValue::set_initializer(&mut #ident, cote::prelude::ValInitializer::new_value(#val));
             }),
             ArgKind::Values => Ok(quote! {
                 cote::prelude::ConfigValue::set_initializer(&mut #ident, cote::prelude::ValInitializer::new_values(#val));
             }),
+            ArgKind::DefaultFn => Ok(quote! {
+                cote::prelude::ConfigValue::set_initializer(&mut #ident, cote::prelude::ValInitializer::with_fn(#val));
+            }),
             ArgKind::Alias => Ok(quote! {
                 cote::prelude::ConfigValue::add_alias(&mut #ident, #val);
             }),
+            ArgKind::DeprecatedAlias => Ok(quote! {
+                cote::prelude::ConfigValue::add_deprecated_alias(&mut #ident, #val);
+            }),
             ArgKind::Index => Ok(quote! {
                 cote::prelude::ConfigValue::set_index(&mut #ident, <cote::prelude::Index as std::convert::TryFrom::<_>>::try_from(#val)?);
             }),
             ArgKind::Force => Ok(quote! {
                 cote::prelude::ConfigValue::set_force(&mut #ident, #val);
             }),
+            ArgKind::Priority => Ok(quote! {
+                cote::prelude::ConfigValue::set_priority(&mut #ident, #val);
+            }),
+            ArgKind::DisplayOrder => Ok(quote! {
+                cote::prelude::ConfigValue::set_display_order(&mut #ident, #val);
+            }),
             ArgKind::Action => Ok(quote! {
                 cote::prelude::ConfigValue::set_action(&mut #ident, #val);
             }),
-            ArgKind::Validator => Ok(quote! {
+            ArgKind::Validator | ArgKind::Choices => Ok(quote! {
                 cote::prelude::ConfigValue::set_storer(&mut #ident, #val);
             }),
             ArgKind::Append => Ok(quote! {
@@ -122,6 +200,28 @@ impl ArgKind {
             ArgKind::Count => Ok(quote! {
                 cote::prelude::ConfigValue::set_action(&mut #ident, cote::prelude::Action::Cnt);
             }),
+            ArgKind::Standalone => Ok(quote! {
+                cote::prelude::ConfigValue::set_standalone(&mut #ident, true);
+            }),
+            ArgKind::OptionalValue => Ok(quote! {
+                cote::prelude::ConfigValue::set_optional_value(&mut #ident, #val);
+            }),
+            ArgKind::NumValues => Ok(quote! {
+                cote::prelude::ConfigValue::set_value_count(&mut #ident, #val);
+            }),
+            ArgKind::Greedy => Ok(quote! {
+                cote::prelude::ConfigValue::set_greedy(&mut #ident, true);
+            }),
+            ArgKind::IgCase => Ok(quote! {
+                cote::prelude::ConfigValue::set_ignore_case(&mut #ident, true);
+            }),
+            ArgKind::Secret => Ok(quote! {
+                cote::prelude::ConfigValue::set_secret(&mut #ident, true);
+            }),
+            ArgKind::Last | ArgKind::Forward => Ok(quote! {
+                cote::prelude::ConfigValue::set_index(&mut #ident, cote::prelude::Index::Null);
+                cote::prelude::ConfigValue::set_trailing_raw(&mut #ident, true);
+            }),
             _ => Err(crate::error(ident.span(), "")),
         }
     }
@@ -7,6 +7,8 @@ use super::Kind;
 pub enum CoteKind {
     Policy,
 
+    PolicyInit,
+
     Name,
 
     Help,
@@ -17,6 +19,10 @@ pub enum CoteKind {
 
     Foot,
 
+    BeforeHelp,
+
+    AfterHelp,
+
     HelpWidth,
 
     UsageWidth,
@@ -39,6 +45,16 @@ pub enum CoteKind {
 
     Overload,
 
+    Intermixed,
+
+    NameFromArg0,
+
+    SubcommandRequired,
+
+    NoAutoHelp,
+
+    Defaults,
+
     MethodCall(String),
 }
 
@@ -51,14 +67,19 @@ impl Kind for CoteKind {
 
             Ok(match kind_str.as_str() {
                 "policy" => (Self::Policy, true),
+                "policy_init" => (Self::PolicyInit, true),
                 "name" => (Self::Name, true),
                 "help" => (Self::Help, false),
                 "helpopt" => (Self::HelpOpt, true),
                 "head" => (Self::Head, true),
                 "foot" => (Self::Foot, true),
+                "before_help" => (Self::BeforeHelp, true),
+                "after_help" => (Self::AfterHelp, true),
                 "width" => (Self::HelpWidth, true),
                 "usagew" => (Self::UsageWidth, true),
                 "aborthelp" => (Self::AbortHelp, false),
+                "no_auto_help" => (Self::NoAutoHelp, false),
+                "defaults" => (Self::Defaults, true),
                 "on" => (Self::On, true),
                 "fallback" => (Self::Fallback, true),
                 "then" => (Self::Then, true),
@@ -67,6 +88,9 @@ impl Kind for CoteKind {
                 "embedded" => (Self::EmbeddedPlus, false),
                 "flag" => (Self::Flag, false),
                 "overload" => (Self::Overload, false),
+                "intermixed" => (Self::Intermixed, true),
+                "name_from_arg0" => (Self::NameFromArg0, false),
+                "subcommand_required" => (Self::SubcommandRequired, false),
                 method => (Self::MethodCall(method.to_owned()), true),
             })
         } else {
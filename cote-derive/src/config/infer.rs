@@ -29,6 +29,8 @@ pub enum InferKind {
 
     Type,
 
+    Hint,
+
     Map,
 
     Mutable,
@@ -58,6 +60,7 @@ impl Kind for InferKind {
             "valid" => Ok((Self::Valid, true)),
             "init" => Ok((Self::Init, true)),
             "ty" => Ok((Self::Type, true)),
+            "hint" => Ok((Self::Hint, true)),
             "map" => Ok((Self::Map, true)),
             "mutable" => Ok((Self::Mutable, true)),
             "tweak" => Ok((Self::Tweak, true)),
@@ -20,6 +20,10 @@ pub enum SubKind {
 
     Foot,
 
+    HeadAppend,
+
+    FootAppend,
+
     Ref,
 
     Mut,
@@ -44,6 +48,8 @@ impl Kind for SubKind {
                 "help" => (Self::Help, true),
                 "head" => (Self::Head, true),
                 "foot" => (Self::Foot, true),
+                "head_append" => (Self::HeadAppend, true),
+                "foot_append" => (Self::FootAppend, true),
                 "refopt" => (Self::Ref, false),
                 "mutopt" => (Self::Mut, false),
                 "force" => (Self::Force, true),
@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{spanned::Spanned, Field, Ident, Type};
+use syn::{spanned::Spanned, Expr, ExprAssign, Field, Ident, Type};
 
-use crate::{config::ArgKind, error};
+use crate::{config::ArgKind, error, value::Value};
 
 use super::{AttrKind, Utils};
 use super::{FieldCfg, OptUpdate};
@@ -11,6 +11,8 @@ use super::{FieldCfg, OptUpdate};
 pub struct ArgGenerator<'a> {
     name: TokenStream,
 
+    short: Option<String>,
+
     ident: Ident,
 
     uid_ident: Ident,
@@ -24,22 +26,44 @@ impl<'a> ArgGenerator<'a> {
     pub fn new(field: &'a Field, id: u64, kind: AttrKind) -> syn::Result<Self> {
         let config = FieldCfg::new(id, field, kind)?;
         let index = config.has_cfg(ArgKind::Index);
+        let last = config.has_cfg(ArgKind::Last) || config.has_cfg(ArgKind::Forward);
         let ident = Utils::id2opt_ident(id, field.span());
         let uid_ident = Utils::id2opt_uid_ident(id, field.span());
+        let short = config
+            .find_value(ArgKind::Short)
+            .map(|v| Self::gen_short_name(field, v))
+            .transpose()?;
+        let long = config
+            .find_value(ArgKind::Long)
+            .map(|v| Self::gen_long_name(field, v))
+            .transpose()?;
         let name = config
             .find_value(ArgKind::Name)
             .map(|v| v.to_token_stream())
+            .or_else(|| long.as_ref().map(|v| v.to_token_stream()))
             .unwrap_or_else(|| {
                 let ident_str = config.ident().to_string();
 
-                if kind.is_cmd() || kind.is_pos() || kind.is_main() || index {
+                if kind.is_cmd() || kind.is_pos() || kind.is_main() || index || last {
                     ident_str.to_token_stream()
                 } else {
                     Utils::ident2opt_name(&ident_str).to_token_stream()
                 }
             });
+        // the short name is same as the name generated from single character identifier
+        let short = short.filter(|v| v.to_token_stream().to_string() != name.to_string());
 
-        if (kind.is_cmd() || kind.is_main()) && config.has_cfg(ArgKind::Index) {
+        if (short.is_some() || long.is_some()) && (!kind.is_arg() || index || last) {
+            Err(error(
+                field.span(),
+                "`short` and `long` are only supported by `arg` without `index`, `last` or `forward` attribute",
+            ))
+        } else if long.is_some() && config.has_cfg(ArgKind::Name) {
+            Err(error(
+                field.span(),
+                "`long` is a shortcut of `name`, please remove one from attributes",
+            ))
+        } else if (kind.is_cmd() || kind.is_main()) && config.has_cfg(ArgKind::Index) {
             Err(error(
                 field.span(),
                 format!(
@@ -47,6 +71,16 @@ impl<'a> ArgGenerator<'a> {
                     config.ident()
                 ),
             ))
+        } else if last && (!kind.is_arg() || config.has_cfg(ArgKind::Index)) {
+            Err(error(
+                field.span(),
+                "`last` and `forward` are only supported by `arg` without `index` attribute",
+            ))
+        } else if config.has_cfg(ArgKind::Choices) && config.has_cfg(ArgKind::Validator) {
+            Err(error(
+                field.span(),
+                "`choices` is a shortcut of `valid`, please remove one from attributes",
+            ))
         } else if config.has_cfg(ArgKind::Action)
             && (config.has_cfg(ArgKind::Append) || config.has_cfg(ArgKind::Count))
         {
@@ -57,6 +91,7 @@ impl<'a> ArgGenerator<'a> {
         } else {
             Ok(Self {
                 name,
+                short,
                 index: None,
                 config,
                 ident,
@@ -65,6 +100,34 @@ impl<'a> ArgGenerator<'a> {
         }
     }
 
+    fn gen_short_name(field: &Field, value: &Value) -> syn::Result<String> {
+        match value {
+            Value::Literal(syn::Lit::Char(ch)) => Ok(format!("-{}", ch.value())),
+            _ => Err(error(
+                field.span(),
+                "`short` need a character literal, such as `short = 'o'`",
+            )),
+        }
+    }
+
+    fn gen_long_name(field: &Field, value: &Value) -> syn::Result<String> {
+        match value {
+            Value::Literal(syn::Lit::Str(str)) => {
+                let str = str.value();
+
+                Ok(if str.starts_with('-') {
+                    str
+                } else {
+                    format!("--{}", str)
+                })
+            }
+            _ => Err(error(
+                field.span(),
+                "`long` need a string literal, such as `long = \"output\"`",
+            )),
+        }
+    }
+
     pub fn uid(&self) -> u64 {
         self.config.id()
     }
@@ -140,25 +203,60 @@ impl<'a> ArgGenerator<'a> {
         let cfg_ident = Ident::new("cfg", field_span);
         let mut codes = vec![];
         let mut value = None;
+        let mut default_fn = false;
 
         codes.push(ArgKind::Name.simple(&cfg_ident, self.name.clone())?);
+        if let Some(short) = &self.short {
+            codes.push(ArgKind::Alias.simple(&cfg_ident, short.to_token_stream())?);
+        }
         for cfg in field_cfg.configs().iter() {
             let kind = cfg.kind();
             let cfg_value = cfg.value();
 
             match kind {
                 ArgKind::Hint
+                | ArgKind::Env
+                | ArgKind::Group
                 | ArgKind::Alias
                 | ArgKind::Force
+                | ArgKind::Priority
+                | ArgKind::DisplayOrder
                 | ArgKind::Action
                 | ArgKind::Count
                 | ArgKind::Index
-                | ArgKind::Append => {
+                | ArgKind::Append
+                | ArgKind::Standalone
+                | ArgKind::OptionalValue
+                | ArgKind::NumValues
+                | ArgKind::Greedy
+                | ArgKind::IgCase
+                | ArgKind::Last
+                | ArgKind::Forward
+                | ArgKind::Secret => {
                     let value = cfg_value.to_token_stream();
 
                     codes.push(kind.simple(&cfg_ident, value)?);
                 }
-                ArgKind::Name => {
+                ArgKind::DeprecatedAlias => {
+                    if let Value::Call(args) = cfg_value {
+                        for arg in args {
+                            if let Expr::Assign(ExprAssign { left, right, .. }) = arg {
+                                codes.push(kind.simple(&cfg_ident, quote! { #left, #right })?);
+                            } else {
+                                return Err(error(
+                                    arg,
+                                    "excepted `\"alias\" = \"message\"` in deprecated_alias",
+                                ));
+                            }
+                        }
+                    } else {
+                        return Err(error(
+                            field_span,
+                            "deprecated_alias need arguments, such as `deprecated_alias(\"--old\" = \"use --new\")`",
+                        ));
+                    }
+                }
+                ArgKind::Name | ArgKind::Short | ArgKind::Long => {
                     // already processed
                 }
                 ArgKind::Type | ArgKind::Help => {
@@ -169,12 +267,38 @@ impl<'a> ArgGenerator<'a> {
                 ArgKind::Validator => codes.push(kind.simple(
                     &cfg_ident,
                     quote! {{
-                        let validator = cote::prelude::ValValidator::from_fn( |value| {
+                        let mut validator = cote::prelude::ValValidator::from_fn( |value| {
                             cote::valid::Validate::check(& #cfg_value, value)
                         });
+                        if let Some(desc) = cote::valid::Validate::<InferedOptVal<#field_ty>>::message(& #cfg_value) {
+                            validator = validator.with_desc(desc);
+                        }
                         cote::prelude::ValStorer::new_validator::<InferedOptVal<#field_ty>>(validator)
                     }},
                 )?),
+                ArgKind::Choices => codes.push({
+                    // using all the variants if no choices given
+                    let choices = if matches!(cfg_value, Value::Null) {
+                        quote! { <InferedOptVal<#field_ty> as cote::valid::Variants>::variants() }
+                    } else {
+                        quote! { &#cfg_value }
+                    };
+
+                    kind.simple(
+                        &cfg_ident,
+                        quote! {{
+                            let rule = cote::valid::variants(#choices);
+                            let desc = cote::valid::Validate::<InferedOptVal<#field_ty>>::message(&rule).map(String::from);
+                            let mut validator = cote::prelude::ValValidator::from_fn(move |value| {
+                                cote::valid::Validate::check(&rule, value)
+                            });
+                            if let Some(desc) = desc {
+                                validator = validator.with_desc(desc);
+                            }
+                            cote::prelude::ValStorer::new_validator::<InferedOptVal<#field_ty>>(validator)
+                        }},
+                    )?
+                }),
                 ArgKind::MethodCall(method) => {
                     let method = Ident::new(method.as_str(), field_span);
                     let value = cfg_value.clone();
@@ -204,16 +328,39 @@ impl<'a> ArgGenerator<'a> {
                         ),
                     )?);
                 }
+                ArgKind::DefaultFn => {
+                    default_fn = true;
+                    codes.push(kind.simple(
+                        &cfg_ident,
+                        quote! {
+                            || {
+                                let mut value = cote::prelude::AnyValue::new();
+
+                                value.set(vec![<InferedOptVal<#field_ty>>::from(#cfg_value()?)]);
+                                Ok::<_, cote::Error>(value)
+                            }
+                        },
+                    )?);
+                }
                 ArgKind::NoDelay => {
                     // will process in policy settings 
                 },
                 ArgKind::Fetch => {
                     // will process in try extract
                 },
+                ArgKind::Info => {
+                    // will process after parsing
+                },
+                ArgKind::ExactlyOne => {
+                    // will process in parser update
+                },
+                ArgKind::HideDefault => {
+                    // will process when generating help
+                },
             }
         }
         // if we have value, set the force to false
-        if value.is_some() {
+        if value.is_some() || default_fn {
             codes.push(ArgKind::Force.simple(&cfg_ident, false.to_token_stream())?);
         }
         if let Some(help) = field_cfg
@@ -221,17 +368,21 @@ impl<'a> ArgGenerator<'a> {
             .map(|v| quote! { String::from(#v.trim()) })
             .or_else(|| field_cfg.collect_help_msgs())
         {
-            codes.push(ArgKind::Help.simple(
-                &cfg_ident,
-                if let Some(value) = value.as_ref() {
-                    let value = value.to_token_stream();
-
-                    // using Debug for default value, better?
-                    quote! { format!("{} [{:?}]", #help, #value) }
-                } else {
-                    help
-                },
-            )?);
+            codes.push(
+                ArgKind::Help.simple(
+                    &cfg_ident,
+                    if let Some(value) = value
+                        .as_ref()
+                        .filter(|_| !field_cfg.has_cfg(ArgKind::HideDefault))
+                    {
+                        let value = value.to_token_stream();
+
+                        quote! { format!("{} [{}]", #help, cote::prelude::format_default(&#value)) }
+                    } else {
+                        help
+                    },
+                )?,
+            );
         }
         if let Some(index) = self.pos_index() {
             if !self.config.has_cfg(ArgKind::Index) {
@@ -253,6 +404,15 @@ impl<'a> ArgGenerator<'a> {
                 <#ty as cote::prelude::InferOverride>::infer_fill_info(&mut #cfg_ident)?;
                 <#ty as cote::prelude::Infer>::infer_fill_info(&mut #cfg_ident)?;
             }
+        } else if self.config.has_cfg(ArgKind::Forward) {
+            // the forwarded arguments are collected as raw values, and parsed when extracting
+            AttrKind::Pos.gen_infer(
+                &cfg_ident,
+                &syn::parse_quote! { Option<Vec<std::ffi::OsString>> },
+            )?
+        } else if self.config.has_cfg(ArgKind::Last) {
+            // the trailing arguments are collected by positional argument
+            AttrKind::Pos.gen_infer(&cfg_ident, field_ty)?
         } else {
             self.kind().gen_infer(&cfg_ident, field_ty)?
         });
@@ -276,6 +436,24 @@ impl<'a> ArgGenerator<'a> {
                     #ident: #func::<#field_ty, Set>(#uid_literal, set)?
                 },
             ))
+        } else if self.config.has_cfg(ArgKind::Forward) {
+            let name = &self.name;
+
+            Ok((
+                false,
+                if Utils::check_in_ty(field_ty, "Option")? {
+                    quote! {
+                        #ident: cote::prelude::fetch_forward_impl(#uid_literal, set)?
+                    }
+                } else {
+                    quote! {
+                        #ident: match cote::prelude::fetch_forward_impl(#uid_literal, set)? {
+                            Some(value) => value,
+                            None => <#field_ty as cote::CoteApp>::parse_from([#name])?,
+                        }
+                    }
+                },
+            ))
         } else {
             Ok((
                 false,
@@ -286,6 +464,23 @@ impl<'a> ArgGenerator<'a> {
         }
     }
 
+    /// Return the name of exactly one group.
+    pub fn exactly_one_group(&self) -> Option<&Value> {
+        self.config.find_value(ArgKind::ExactlyOne)
+    }
+
+    pub fn gen_info_check(&self) -> Option<TokenStream> {
+        let uid_literal = Utils::id2uid_literal(self.uid());
+
+        self.config.find_value(ArgKind::Info).map(|text| {
+            quote! {
+                if info.is_none() && cote::prelude::Opt::matched(cote::prelude::SetExt::opt(set, #uid_literal)?) {
+                    info = Some(String::from(#text));
+                }
+            }
+        })
+    }
+
     pub fn gen_nodelay_setting(&self) -> syn::Result<Option<TokenStream>> {
         let name = &self.name;
         Ok(self.config.has_cfg(ArgKind::NoDelay).then_some({
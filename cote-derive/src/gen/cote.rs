@@ -10,6 +10,7 @@ use syn::DeriveInput;
 use syn::Field;
 use syn::Fields;
 use syn::GenericParam;
+use syn::Lit;
 use syn::Type;
 use syn::{Data, Generics};
 
@@ -192,6 +193,8 @@ impl<'a> CoteGenerator<'a> {
         let (impl_esd, _, where_esd) = esd_generics.split_for_impl_esd(&used_generics);
         let mut pi_generics = GenericsModifier::new(generics.clone());
         let (impl_pi, _, where_pi) = pi_generics.split_for_impl_pi(&used_generics);
+        let mut app_generics = GenericsModifier::new(generics.clone());
+        let (impl_app, _, where_app) = app_generics.split_for_impl_app(&used_generics);
         let parser_update = self.gen_parser_update()?;
         let try_extract = self.gen_try_extract()?;
         let parser_interface = self.gen_parser_interface(&used_generics)?;
@@ -215,6 +218,17 @@ impl<'a> CoteGenerator<'a> {
             impl #impl_pi #orig_ident #type_generics #where_pi {
                 #parser_interface
             }
+
+            #[doc=concat!("Automatic generated by cote-derive for [`", stringify!(#orig_ident), "`].")]
+            impl #impl_app cote::CoteApp for #orig_ident #type_generics #where_app {
+                fn parse_app(args: cote::prelude::Args) -> cote::Result<Self> {
+                    Self::parse(args)
+                }
+
+                fn usage() -> Option<String> {
+                    Self::usage_line().ok()
+                }
+            }
         })
     }
 
@@ -262,6 +276,8 @@ impl<'a> CoteGenerator<'a> {
         if let Some(up) = self.gen_help_option()? {
             append(up);
         }
+        let exactly_one = self.gen_exactly_one();
+
         Ok(quote! {
             // a convenient type for option value
             type InferedOptVal<T> = <T as cote::prelude::Infer>::Val;
@@ -272,17 +288,49 @@ impl<'a> CoteGenerator<'a> {
             #(#creates)*
             #(#inserts)*
             #(#handlers)*
+            #(#exactly_one)*
             Ok(())
         })
     }
 
+    /// Collect the options have same `exactly_one` group name.
+    pub fn gen_exactly_one(&self) -> Vec<TokenStream> {
+        let mut groups: Vec<(String, TokenStream, Vec<Lit>)> = vec![];
+
+        for fg in self.field_generators.iter().filter(|v| v.is_arg()) {
+            let arg = fg.as_arg();
+
+            if let Some(name) = arg.exactly_one_group() {
+                let uid = Utils::id2uid_literal(arg.uid());
+                let key = name.to_token_stream().to_string();
+
+                match groups.iter_mut().find(|v| v.0 == key) {
+                    Some((_, _, uids)) => uids.push(uid),
+                    None => groups.push((key, name.to_token_stream(), vec![uid])),
+                }
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(_, name, uids)| {
+                quote! {
+                    parser.require_exactly_one(#name, &[#(#uids),*]);
+                }
+            })
+            .collect()
+    }
+
     pub fn gen_main_and_help_uid(&mut self) -> syn::Result<()> {
         // we need help uid in handler of sub
         if self.configs.has_cfg(CoteKind::Fallback) || self.configs.has_cfg(CoteKind::On) {
             self.main_uid
                 .get_or_insert(self.field_generators.len() as u64);
         }
-        self.configs.has_cfg(CoteKind::Help).then(|| {
+        // the help option is managed by user if `no_auto_help` set
+        let auto_help =
+            self.configs.has_cfg(CoteKind::Help) && !self.configs.has_cfg(CoteKind::NoAutoHelp);
+
+        auto_help.then(|| {
             let total = self.field_generators.len() + if self.main_uid.is_some() { 1 } else { 0 };
             *self.help_uid.get_or_insert(total as u64)
         });
@@ -327,16 +375,13 @@ impl<'a> CoteGenerator<'a> {
     }
 
     pub fn gen_help_option(&self) -> syn::Result<Option<OptUpdate>> {
-        Ok(if self.configs.find_cfg(CoteKind::Help).is_some() {
+        Ok(if let Some(help_uid) = self.help_uid() {
             let span = self.orig_ident.span();
             let help_opt = self
                 .configs
                 .find_cfg(CoteKind::HelpOpt)
                 .map(|v| v.to_token_stream())
                 .unwrap_or_else(|| HELP_OPTION.to_token_stream());
-
-            // safe here
-            let help_uid = self.help_uid().unwrap();
             let ident = Utils::id2opt_ident(help_uid, span);
             let uid_ident = Utils::id2opt_uid_ident(help_uid, span);
             let literal = Utils::id2uid_literal(help_uid);
@@ -353,6 +398,9 @@ impl<'a> CoteGenerator<'a> {
             };
             let insert = Utils::gen_opt_insert(&ident, &uid_ident, &literal)?;
             let handler = quote! {
+                let help_name = cote::prelude::Opt::name(cote::prelude::SetExt::opt(parser.optset(), #uid_ident)?).to_string();
+
+                parser.set_help_option(Some(help_name));
                 // we save the original option text to `Ser`, it will use in handler of `sub`
                 parser.entry(#uid_ident)?.on(
                     move |_: &mut cote::prelude::Parser<'inv, Set, Ser>, ser: &mut Ser, ctx: &cote::prelude::Ctx| {
@@ -360,6 +408,18 @@ impl<'a> CoteGenerator<'a> {
                         let index = ctx.idx()?;
 
                         ser.sve_insert::<std::ffi::OsString>(args[index].to_os_string());
+                        // the topic is the value of help option such as `net` of `--help=net`,
+                        // or the argument after help option such as `net` of `--help net`
+                        if let Ok(rctx) = ser.sve_val_mut::<cote::prelude::RunningCtx>() {
+                            let value = ctx.arg()?.and_then(|v| v.to_str().map(String::from));
+                            // the value is `true` if the help option has no value
+                            let topic = match value.filter(|v| v != cote::aopt::opt::BOOL_TRUE) {
+                                Some(value) => Some(value),
+                                None => args.get(index + 1).and_then(|v| v.to_str()).filter(|v| !v.starts_with('-')).map(String::from),
+                            };
+
+                            rctx.set_help_topic(topic);
+                        }
                         Ok(Some(true))
                     }
                 );
@@ -389,6 +449,14 @@ impl<'a> CoteGenerator<'a> {
             .unwrap_or_else(|| quote! {
                 format!("Create by {} v{}", env!("CARGO_PKG_AUTHORS"), env!("CARGO_PKG_VERSION"))
             });
+        let before = self
+            .configs
+            .find_value(CoteKind::BeforeHelp)
+            .map(|v| quote! { .with_before(#v) });
+        let after = self
+            .configs
+            .find_value(CoteKind::AfterHelp)
+            .map(|v| quote! { .with_after(#v) });
         let width = self
             .configs
             .find_value(CoteKind::HelpWidth)
@@ -408,6 +476,8 @@ impl<'a> CoteGenerator<'a> {
                 .with_foot(#foot)
                 .with_width(#width)
                 .with_usagew(#usage_width)
+                #before
+                #after
         })
     }
 
@@ -417,10 +487,22 @@ impl<'a> CoteGenerator<'a> {
         let policy_def_ty = self.gen_policy_ty(true)?;
         let policy_ret_ty = self.gen_policy_ty(false)?;
         let policy_setting_mod = self.gen_policy_setting_mod()?;
+        let policy_init = self
+            .configs
+            .find_value(CoteKind::PolicyInit)
+            .map(|v| quote! { #v() })
+            .unwrap_or_else(|| quote! { Default::default() });
         let method_calls = self.gen_method_call()?;
         let parser_name = &self.name;
         let abort = self.configs.find_cfg(CoteKind::AbortHelp);
-        let help = self.configs.find_cfg(CoteKind::Help);
+        let (runtime_name, rename_parser) = if self.configs.has_cfg(CoteKind::NameFromArg0) {
+            (
+                quote! { cote::prelude::arg0_name(args.first().map(|v| v.as_os_str())).unwrap_or_else(|| #parser_name) },
+                Some(quote! { parser.set_name(parser_name.clone()); }),
+            )
+        } else {
+            (quote! { #parser_name }, None)
+        };
         let infer_override = GenericsModifier::gen_inferoverride_for_ty(used);
         let fetch_generics = GenericsModifier::gen_fetch_for_ty(used, quote!(Set));
         let fetch_code = {
@@ -428,10 +510,24 @@ impl<'a> CoteGenerator<'a> {
 
             quote! { #infer_override  #fetch }
         };
+        let info_check = self.gen_info_check();
+        let extract = match self.configs.find_value(CoteKind::Defaults) {
+            Some(defaults) => quote! {
+                let mut value = <Self as cote::ExtractFromSetDerive::<cote::prelude::ASet>>::try_extract(parser.optset_mut())?;
+
+                // fill the default values depend on other fields
+                #defaults(&mut value)?;
+                Ok(value)
+            },
+            None => quote! {
+                <Self as cote::ExtractFromSetDerive::<cote::prelude::ASet>>::try_extract(parser.optset_mut())
+            },
+        };
+        let subcommand_check = self.gen_subcommand_check();
         let sync_rctx_from_ret = Utils::gen_sync_ret(
             self.has_sub_command(),
             abort.is_some(),
-            help.is_some(),
+            self.help_uid().is_some(),
             self.help_uid(),
         )?;
         let where_clause = quote! {
@@ -456,12 +552,26 @@ impl<'a> CoteGenerator<'a> {
                 #help_context
             }
 
+            #[doc(hidden)]
+            pub fn info_text<Set>(set: &Set) -> cote::Result<Option<String>>
+                where Set: cote::prelude::SetValueFindExt,
+                    cote::prelude::SetCfg<Set>: cote::prelude::ConfigValue + Default {
+                #info_check
+            }
+
             #[doc(hidden)]
             pub fn sync_rctx<'a, Set, Ret>(rctx: &'a mut cote::prelude::RunningCtx, ret: &cote::Result<Ret>, set: &Set, sub_parser: bool)
             -> cote::Result<&'a mut cote::prelude::RunningCtx>
                 where Set: cote::prelude::SetValueFindExt, Ret: cote::prelude::Status,
                     cote::prelude::SetCfg<Set>: cote::prelude::ConfigValue + Default {
                 #sync_rctx_from_ret
+                // the help takes precedence over the info option
+                if !rctx.display_help() && rctx.info().is_none() && ret.is_ok() {
+                    if let Some(info) = Self::info_text(set)? {
+                        rctx.set_info(Some(info));
+                        rctx.set_exit(true);
+                    }
+                }
                 Ok(rctx)
             }
 
@@ -490,7 +600,7 @@ impl<'a> CoteGenerator<'a> {
             }
 
             pub fn into_policy_with<'inv, Set, Ser>() -> #policy_ret_ty {
-                let mut policy: #policy_ret_ty = Default::default();
+                let mut policy: #policy_ret_ty = #policy_init;
                 Self::apply_policy_settings(&mut policy);
                 policy
             }
@@ -502,45 +612,92 @@ impl<'a> CoteGenerator<'a> {
 
             pub fn parse_args_with<'inv, Set, Ser, P>(args: cote::prelude::Args, policy: &mut P)
                 -> cote::Result<cote::prelude::CoteRes<&mut P, P>> where #where_clause {
+                Self::parse_args_with_help(args, policy, false).map(|(res, _)| res)
+            }
+
+            /// Parsing the arguments, return the help message or the text of info option
+            /// instead of displaying it if `capture` is true.
+            #[doc(hidden)]
+            pub fn parse_args_with_help<'inv, Set, Ser, P>(args: cote::prelude::Args, policy: &mut P, capture: bool)
+                -> cote::Result<(cote::prelude::CoteRes<&mut P, P>, Option<cote::prelude::ParseOutcome<Self>>)> where #where_clause {
                 let mut parser = Self::into_parser_with::<'inv, Set, Ser>()?;
 
                 // call on parser or policy set by user
                 #(#method_calls)* // todo! do we need apply this in sub handler ?
 
-                // setup a new running ctx, set name of parser
-                parser.set_rctx(cote::prelude::RunningCtx::default().with_name(#parser_name));
+                // setup a new running ctx, set name of parser, the bin name set by user takes precedence
+                let parser_name = parser.bin_name().map(String::from).unwrap_or_else(|| #runtime_name);
+
+                #rename_parser
+                parser.set_rctx(cote::prelude::RunningCtx::default().with_name(parser_name.clone()));
 
                 let ret = cote::prelude::PolicyParser::parse_policy(&mut parser, args, policy);
+
+                #subcommand_check
+
                 let mut rctx = parser.take_rctx()?;
 
                 // process help
                 if !rctx.display_help() {
                     Self::sync_rctx::<Set, _>(&mut rctx, &ret, parser.optset(), false)?;
                     if rctx.display_help() {
-                        rctx.set_help_context(Self::new_help_context());
+                        let topic = rctx.help_topic().map(String::from);
+
+                        rctx.set_help_context(Self::new_help_context().with_name(parser_name).with_topic(topic));
                     }
                 }
 
+                let mut help = None;
+
                 // display help
                 if rctx.display_help() {
-                    let names: Vec<_> = std::iter::once(rctx.name())
-                           .chain(rctx.frames().iter().map(|v|v.name.as_str())).collect();
+                    let names = rctx.command_path();
                     // we set the help context if we need display help, so just unwrap it
                     let help_context = rctx.help_context().unwrap();
                     let exit = rctx.exit();
 
-                    parser.display_sub_help(names, &help_context)?;
+                    if capture {
+                        let mut buf = vec![];
 
-                    // process exit, or force not exit
-                    if exit {
-                        std::process::exit(0);
+                        parser.write_sub_help(names, &help_context, &mut buf)?;
+                        // the help message is returned to caller, never exit
+                        if exit {
+                            help = Some(cote::prelude::ParseOutcome::Help(String::from_utf8_lossy(&buf).into_owned()));
+                        }
+                    }
+                    else {
+                        parser.display_sub_help(names, &help_context)?;
+
+                        // process exit, or force not exit
+                        if exit {
+                            std::process::exit(0);
+                        }
+                    }
+                }
+                // display the text of info option if the help not displayed
+                else if let Some(info) = rctx.take_info() {
+                    let exit = rctx.exit();
+
+                    if capture {
+                        // the text is returned to caller, never exit
+                        if exit {
+                            help = Some(cote::prelude::ParseOutcome::Info(info));
+                        }
+                    }
+                    else {
+                        println!("{}", info);
+
+                        // process exit, or force not exit
+                        if exit {
+                            std::process::exit(0);
+                        }
                     }
                 }
 
                 // insert back running ctx
                 parser.set_rctx(rctx);
 
-                Ok(cote::prelude::CoteRes{ ret: ret?, parser, policy })
+                Ok((cote::prelude::CoteRes{ ret: ret?, parser, policy }, help))
             }
 
             pub fn parse_args<'inv>(args: cote::prelude::Args) -> cote::Result<cote::prelude::CoteRes<#policy_def_ty, #policy_def_ty>>
@@ -553,13 +710,70 @@ impl<'a> CoteGenerator<'a> {
 
             pub fn parse(args: cote::prelude::Args) -> cote::Result<Self>
             where #fetch_code {
-                let cote::prelude::CoteRes { mut ret, mut parser, .. } = Self::parse_args(args)?;
+                Self::from_cote_res(Self::parse_args(args)?)
+            }
+
+            /// Same as [`parse`](Self::parse), but never exit the process,
+            /// return [`Help`](cote::prelude::ParseOutcome::Help) with the help message if it need be displayed,
+            /// or [`Info`](cote::prelude::ParseOutcome::Info) with the text of info option if it is set.
+            pub fn try_parse(args: cote::prelude::Args) -> cote::prelude::ParseOutcome<Self>
+            where #fetch_code {
+                let mut policy = Self::into_policy();
+
+                match Self::parse_args_with_help(args, &mut policy, true) {
+                    Ok((_, Some(outcome))) => outcome,
+                    Ok((cote::prelude::CoteRes { ret, parser, .. }, None)) => {
+                        Self::from_cote_res(cote::prelude::CoteRes{ ret, parser, policy }).into()
+                    }
+                    Err(e) => cote::prelude::ParseOutcome::Err(e),
+                }
+            }
+
+            pub fn try_parse_from<I, T>(iter: I) -> cote::prelude::ParseOutcome<Self>
+            where I: IntoIterator<Item = T>, T: Into<std::ffi::OsString>, #fetch_code {
+                Self::try_parse(cote::prelude::Args::from(iter))
+            }
+
+            pub fn try_parse_env() -> cote::prelude::ParseOutcome<Self>
+            where #fetch_code {
+                Self::try_parse(cote::prelude::Args::from_env())
+            }
+
+            /// Return the help message instead of displaying it, such as `--help` displays.
+            pub fn help_string<'inv>() -> cote::Result<String>
+            where #fetch_code {
+                let parser = Self::into_parser()?;
+                let name = parser.name().clone();
+                let mut buf = vec![];
+
+                parser.write_sub_help(vec![name.as_str()], &Self::new_help_context(), &mut buf)?;
+                Ok(String::from_utf8_lossy(&buf).into_owned())
+            }
+
+            /// Return the short usage line, such as `Usage: app [--debug]`.
+            pub fn usage_line<'inv>() -> cote::Result<String>
+            where #fetch_code {
+                let parser = Self::into_parser()?;
+                let name = parser.name().clone();
+                let ctx = Self::new_help_context().with_page_template("{usage}");
+                let mut buf = vec![];
+
+                parser.write_sub_help(vec![name.as_str()], &ctx, &mut buf)?;
+                Ok(String::from_utf8_lossy(&buf).trim_end().to_owned())
+            }
+
+            #[doc(hidden)]
+            pub fn from_cote_res<'inv>(res: cote::prelude::CoteRes<#policy_def_ty, #policy_def_ty>) -> cote::Result<Self>
+            where #fetch_code {
+                let cote::prelude::CoteRes { mut ret, mut parser, .. } = res;
 
                 if let Some(mut error) = ret.take_failure() {
                     let mut rctx = parser.take_rctx()?;
+                    let names = rctx.command_path().into_iter().map(String::from).collect::<Vec<_>>();
+                    let path = names.join(" ");
                     let mut failures = rctx.frames_mut().iter_mut().map(|v|v.failure.as_mut().unwrap());
                     let ctx = ret.take_ctx();
-                    let mut cmd = None;
+                    let mut in_cmd = false;
                     let mut ret = Some(&mut ret);
 
                     // chain the error in the frames
@@ -567,20 +781,20 @@ impl<'a> CoteGenerator<'a> {
                         if let Some(sub_error) = failure.retval.take_failure() {
                             error = error.cause_by(sub_error);
                         }
-                        cmd = Some(failure.cmd.as_str());
+                        in_cmd = true;
                         ret = Some(&mut failure.retval);
                     }
 
                     // construct error message
                     let e = {
-                        let args = ctx.orig[1..].iter()
+                        let args = ctx.orig.iter().skip(1)
                                     .map(|v|std::path::Path::new(v).display())
                                     .map(|v|v.to_string())
                                     .collect::<Vec<_>>()
                                     .join(", ");
                         let guess = ctx.guess;
-                        let failed_msg = if let Some(cmd) = cmd {
-                            format!("Parsing command `{}`", cmd)
+                        let failed_msg = if in_cmd {
+                            format!("Parsing command `{}`", path)
                         }
                         else {
                             format!("Parsing arguments `{}`", args)
@@ -591,17 +805,37 @@ impl<'a> CoteGenerator<'a> {
                             "None".to_owned()
                         };
 
-                        // return failure with more detail error message
-                        cote::prelude::raise_failure!("{} failed: {}", failed_msg, guess).cause_by(error)
+                        // return failure with more detail error message, keep the kind of error
+                        let e = cote::Error::new(error.specific_kind())
+                            .with_desp(format!("{} failed: {}", failed_msg, guess))
+                            .cause_by(error);
+
+                        // display the usage of deepest command which failed
+                        if in_cmd {
+                            let ctx = Self::new_help_context().with_page_template("{usage}");
+                            let mut buf = vec![];
+
+                            match parser.write_sub_help(names.iter().map(|v|v.as_str()).collect(), &ctx, &mut buf) {
+                                Ok(_) => e.with_usage(String::from_utf8_lossy(&buf).trim_end().to_owned()),
+                                Err(_) => e,
+                            }
+                        }
+                        else { e }
                     };
 
                     Err(e)
                 }
                 else {
-                    <Self as cote::ExtractFromSetDerive::<cote::prelude::ASet>>::try_extract(parser.optset_mut())
+                    parser.invoke_on_parsed()?;
+                    #extract
                 }
             }
 
+            pub fn parse_from<I, T>(iter: I) -> cote::Result<Self>
+            where I: IntoIterator<Item = T>, T: Into<std::ffi::OsString>, #fetch_code {
+                Self::parse(cote::prelude::Args::from(iter))
+            }
+
             pub fn parse_env_args_with<'inv, Set, Ser, P>(policy: &mut P) -> cote::Result<cote::prelude::CoteRes<&mut P, P>>
                 where #where_clause {
                 Self::parse_args_with(cote::prelude::Args::from_env(), policy)
@@ -640,6 +874,60 @@ impl<'a> CoteGenerator<'a> {
         }))
     }
 
+    /// Return the text of first info option set.
+    pub fn gen_info_check(&self) -> TokenStream {
+        let checks: Vec<_> = self
+            .field_generators
+            .iter()
+            .filter(|v| v.is_arg())
+            .filter_map(|v| v.as_arg().gen_info_check())
+            .collect();
+
+        if checks.is_empty() {
+            quote! { Ok(None) }
+        } else {
+            quote! {
+                let mut info: Option<String> = None;
+
+                #(#checks)*
+                Ok(info)
+            }
+        }
+    }
+
+    /// Raise an error if none of sub commands matched, unless the help or info option is set.
+    pub fn gen_subcommand_check(&self) -> Option<TokenStream> {
+        if !self.has_sub_command() || !self.configs.has_cfg(CoteKind::SubcommandRequired) {
+            return None;
+        }
+        let help_set = self.help_uid().map(|uid| {
+            let uid_literal = Utils::id2uid_literal(uid);
+
+            quote! {
+                cote::prelude::OptValueExt::val::<bool>(cote::prelude::SetExt::opt(set, #uid_literal)?).ok() == Some(&true)
+            }
+        });
+        let help_set = help_set.unwrap_or_else(|| quote! { false });
+
+        Some(quote! {
+            let ret = match ret {
+                Ok(ret) => {
+                    let set = parser.optset();
+                    let matched = set.iter().any(|opt| {
+                        cote::prelude::Opt::mat_style(opt, cote::prelude::Style::Cmd) && cote::prelude::Opt::matched(opt)
+                    });
+
+                    if matched || #help_set || Self::info_text(set)?.is_some() {
+                        Ok(ret)
+                    } else {
+                        Err(cote::Error::new(cote::ErrorKind::CmdRequired).with_desp(String::from("a subcommand is required")))
+                    }
+                }
+                Err(e) => Err(e),
+            };
+        })
+    }
+
     pub fn gen_policy_ty(&self, default: bool) -> syn::Result<TokenStream> {
         let policy_cfg = self.configs.find_cfg(CoteKind::Policy);
         let ty_generator: fn(&str) -> Option<TokenStream> = if default {
@@ -649,7 +937,7 @@ impl<'a> CoteGenerator<'a> {
         };
 
         Ok(if let Some(policy_cfg) = policy_cfg {
-            let policy_name = policy_cfg.value().to_token_stream().to_string();
+            let policy_name = Utils::policy_name(policy_cfg.value());
             let policy_ty = policy_cfg.value();
 
             ty_generator(&policy_name).unwrap_or_else(|| {
@@ -688,6 +976,11 @@ impl<'a> CoteGenerator<'a> {
                 cote::prelude::PolicySettings::set_strict(policy, #v);
             }
         });
+        let mod_intermixed = self.configs.find_value(CoteKind::Intermixed).map(|v| {
+            quote! {
+                cote::prelude::PolicySettings::set_intermixed(policy, #v);
+            }
+        });
         let mut nodelays = vec![];
 
         for fg in self.field_generators.iter().filter(|v| v.is_arg()) {
@@ -702,6 +995,7 @@ impl<'a> CoteGenerator<'a> {
             #enable_flag
             #enable_overload
             #mod_strict
+            #mod_intermixed
             #(#nodelays)*
         })
     }
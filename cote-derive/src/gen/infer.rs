@@ -107,6 +107,11 @@ impl<'a> InferGenerator<'a> {
                         #value
                     }
                 },
+                InferKind::Hint => quote! {
+                    fn infer_hint_template() -> Option<String> {
+                        #value
+                    }
+                },
                 InferKind::Map => quote! {
                     fn infer_map(val: Self::Val) -> Self {
                         (#value)(val)
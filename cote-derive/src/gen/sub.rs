@@ -97,6 +97,7 @@ impl<'a> SubGenerator<'a> {
 
     pub fn gen_opt_handler(&self, help_uid: Option<u64>) -> syn::Result<Option<TokenStream>> {
         let inner_ty = self.inner_ty();
+        let help_context = self.gen_help_context();
         let policy_new = self.gen_sub_policy_new()?;
         let uid_ident = self.uid_ident();
         // using for access sub parser
@@ -144,11 +145,12 @@ impl<'a> SubGenerator<'a> {
                     else {
                         // clone a running ctx, make a new frame
                         let frame_len = rctx.frames().len();
+                        let exts = rctx.take_exts();
                         let mut rctx = rctx.reset_at(sub_level as u8);
                         let mut frame = cote::prelude::Frame::new(name);
 
                         // incrment sub level and push frame to running ctx
-                        rctx.inc_sub_level().push_frame(frame);
+                        rctx.inc_sub_level().push_frame(frame).set_exts(exts);
                         // set running ctx
                         parser.set_rctx(rctx);
 
@@ -165,7 +167,7 @@ impl<'a> SubGenerator<'a> {
                         if !rctx.display_help() {
                             <#inner_ty>::sync_rctx(&mut rctx, &ret, parser.optset(), true)?;
                             if rctx.display_help() {
-                                rctx.set_help_context(<#inner_ty>::new_help_context());
+                                rctx.set_help_context(#help_context);
                             }
                         }
 
@@ -185,6 +187,10 @@ impl<'a> SubGenerator<'a> {
                                 // replace the running ctx with current one
                                 ser.sve_insert(rctx);
                             }
+                            else {
+                                // give back the extension storage
+                                ser.sve_val_mut::<cote::prelude::RunningCtx>()?.set_exts(rctx.take_exts());
+                            }
                             None
                         }
                     };
@@ -195,6 +201,38 @@ impl<'a> SubGenerator<'a> {
         }))
     }
 
+    /// Generate the help context of sub command, the `head` and `foot` replace the text of sub command,
+    /// the `head_append` and `foot_append` append the text to the inherited text of parent.
+    pub fn gen_help_context(&self) -> TokenStream {
+        let inner_ty = self.inner_ty();
+        let gen_text = |kind: SubKind, append_kind: SubKind, getter: TokenStream| {
+            let text = self.config.find_value(kind);
+            let append = self.config.find_value(append_kind);
+
+            match (text, append) {
+                (None, None) => None,
+                (Some(text), None) => Some(quote! { String::from(#text) }),
+                (Some(text), Some(append)) => Some(quote! { format!("{}\n{}", #text, #append) }),
+                (None, Some(append)) => Some(quote! {
+                    format!("{}\n{}", Self::new_help_context().#getter(), #append)
+                }),
+            }
+        };
+        let head = gen_text(SubKind::Head, SubKind::HeadAppend, quote!(head))
+            .map(|v| quote! { help_context.set_head(#v); });
+        let foot = gen_text(SubKind::Foot, SubKind::FootAppend, quote!(foot))
+            .map(|v| quote! { help_context.set_foot(#v); });
+
+        quote! {{
+            let mut help_context = <#inner_ty>::new_help_context();
+
+            #head
+            #foot
+            help_context.set_topic(rctx.help_topic().map(String::from));
+            help_context
+        }}
+    }
+
     pub fn gen_opt_create(&self) -> syn::Result<TokenStream> {
         let field_span = self.ident().span();
         let field_cfg = &self.config;
@@ -293,7 +331,7 @@ impl<'a> SubGenerator<'a> {
 
         Ok(policy_cfg
             .map(|policy_cfg| {
-                let policy_name = policy_cfg.value().to_token_stream().to_string();
+                let policy_name = Utils::policy_name(policy_cfg.value());
                 let policy_ty = policy_cfg.value();
 
                 Utils::gen_policy_ty(&policy_name)
@@ -40,9 +40,9 @@ impl AttrKind {
         matches!(self, AttrKind::Sub)
     }
 
-    // pub fn is_arg(&self) -> bool {
-    //     matches!(self, AttrKind::Arg)
-    // }
+    pub fn is_arg(&self) -> bool {
+        matches!(self, AttrKind::Arg)
+    }
 
     pub fn is_cmd(&self) -> bool {
         matches!(self, AttrKind::Cmd)
@@ -323,6 +323,14 @@ impl Utils {
         }
     }
 
+    /// The name of policy, the string literal such as `"pre"` is same as `pre`.
+    pub fn policy_name(value: &Value) -> String {
+        match value {
+            Value::Literal(Lit::Str(lit)) => lit.value(),
+            value => value.to_token_stream().to_string(),
+        }
+    }
+
     pub fn gen_policy_ty(policy_name: &str) -> Option<TokenStream> {
         match policy_name {
             POLICY_PRE => Some(quote! {
@@ -447,7 +455,7 @@ impl GenericsModifier {
     pub fn split_for_impl_ipd(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_ipd(used);
         self.0.split_for_impl()
     }
@@ -472,7 +480,7 @@ impl GenericsModifier {
     pub fn split_for_impl_esd(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_esd(used);
         self.0.split_for_impl()
     }
@@ -493,11 +501,33 @@ impl GenericsModifier {
     pub fn split_for_impl_pi(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_pi(used);
         self.0.split_for_impl()
     }
 
+    pub fn mod_for_app(&mut self, used: &[&Ident]) -> &mut Self {
+        let orig_where = self.0.where_clause.as_ref().map(|v| &v.predicates);
+        let new_where: WhereClause = parse_quote! {
+            where
+                #(#used: cote::prelude::Infer + cote::prelude::ErasedTy,)*
+                #(#used: cote::prelude::InferOverride + cote::prelude::Fetch<cote::prelude::ASet>,)*
+                #(<#used as cote::prelude::Infer>::Val: cote::prelude::RawValParser,)*
+                #orig_where
+        };
+
+        self.0.where_clause = Some(new_where);
+        self
+    }
+
+    pub fn split_for_impl_app(
+        &mut self,
+        used: &[&Ident],
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
+        self.mod_for_app(used);
+        self.0.split_for_impl()
+    }
+
     pub fn mod_for_fetch(&mut self, used: &[&Ident]) -> &mut Self {
         let orig_where = self.0.where_clause.as_ref().map(|v| &v.predicates);
         let fetch = Self::gen_fetch_for_ty(used, quote!(Set));
@@ -518,7 +548,7 @@ impl GenericsModifier {
     pub fn split_for_impl_fetch(
         &mut self,
         used: &[&Ident],
-    ) -> (ImplGenerics, TypeGenerics, Option<&WhereClause>) {
+    ) -> (ImplGenerics<'_>, TypeGenerics<'_>, Option<&WhereClause>) {
         self.mod_for_fetch(used);
         self.0.split_for_impl()
     }
@@ -51,6 +51,7 @@ impl<'a> ValueGenerator<'a> {
         let map_raw_cfg = self.configs.find_value(ValueKind::MapRaw);
         let map_str_cfg = self.configs.find_value(ValueKind::MapStr);
         let igcase = self.configs.has_cfg(ValueKind::IgCase);
+        let mut variants_code = quote! {};
         let impl_code = if let (Some(forward), Some(map)) = (forward_cfg, map_cfg) {
             if map_raw_cfg.is_some() || map_str_cfg.is_some() || igcase {
                 return Err(error(
@@ -85,6 +86,8 @@ impl<'a> ValueGenerator<'a> {
                 quote! { #value( #str_convert ) }
             } else {
                 let mut branches = vec![];
+                let mut names = vec![];
+                let mut name_arms = vec![];
 
                 for (var, config) in self.variants.iter().zip(self.var_configs.iter()) {
                     let var_ident = &var.ident;
@@ -99,11 +102,39 @@ impl<'a> ValueGenerator<'a> {
                         .unwrap_or(var_name.to_token_stream());
                     let alias_cfg = config.find_values(ValueKind::Alias);
 
+                    names.push(name_cfg.clone());
+                    name_arms.push(quote! {
+                        #ident::#var_ident => #name_cfg,
+                    });
                     branches.push(quote! {
                         #name_cfg #(| #alias_cfg)* => Ok(#ident::#var_ident),
                     })
                 }
 
+                let vars = self.variants.iter().map(|v| &v.ident);
+
+                // list the variants for the choices, see `#[arg(choices)]`
+                if !self.variants.is_empty() {
+                    variants_code = quote! {
+                        impl cote::valid::Variants for #ident {
+                            fn variants() -> &'static [Self] {
+                                &[#(#ident::#vars),*]
+                            }
+
+                            fn name(&self) -> &'static str {
+                                match self {
+                                    #(#name_arms)*
+                                }
+                            }
+                        }
+
+                        impl cote::prelude::FormatDefault for #ident {
+                            fn format_default(&self) -> String {
+                                cote::valid::Variants::name(self).to_owned()
+                            }
+                        }
+                    };
+                }
                 quote! {
                     let name = #str_convert;
                     let uid = ctx.uid()?;
@@ -111,7 +142,7 @@ impl<'a> ValueGenerator<'a> {
                     match name.as_ref() {
                         #(#branches)*
 
-                        _ => Err(cote::prelude::raise_failure!("Unknow value for enum type `{}`: {}", #ty_name, name).with_uid(uid)),
+                        _ => Err(cote::prelude::raise_failure!("Unknown value for enum type `{}`: {}, must be one of {}", #ty_name, name, <[&str]>::join(&[#(#names),*], "/")).with_uid(uid)),
                     }
                 }
             }
@@ -125,6 +156,8 @@ impl<'a> ValueGenerator<'a> {
                     #impl_code
                 }
             }
+
+            #variants_code
         })
     }
 
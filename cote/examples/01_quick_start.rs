@@ -26,7 +26,7 @@ pub struct Cli {
     list: Option<List>,
 }
 
-#[derive(Debug, Cote)]
+#[derive(Debug, Clone, Cote)]
 #[cote(help)]
 pub struct Search {
     /// Set the depth of search
@@ -36,7 +36,7 @@ pub struct Search {
     dest: Option<PathBuf>,
 }
 
-#[derive(Debug, Cote)]
+#[derive(Debug, Clone, Cote)]
 #[cote(help)]
 pub struct List {
     /// Enable recursive mode
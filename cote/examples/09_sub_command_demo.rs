@@ -13,7 +13,7 @@ pub struct Cli {
     qux: Option<Qux>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct Baz {
     grault: bool,
@@ -21,7 +21,7 @@ pub struct Baz {
     waldo: Option<String>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct Qux {
     garply: bool,
@@ -15,7 +15,7 @@ pub struct Cli {
     qux: Option<Qux>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help)]
 pub struct Bar {
     #[arg(force = false, fallback = debug_of_bar)]
@@ -25,7 +25,7 @@ pub struct Bar {
     quux: String,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, fallback = process_qux, then = unreachable_storer)]
 pub struct Qux {
     #[cmd(name = "c")]
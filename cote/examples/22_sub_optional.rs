@@ -15,7 +15,7 @@ pub struct Cli {
     sport: Option<Sport>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct Eat {
     /// Which meal did you have?
@@ -27,7 +27,7 @@ pub struct Eat {
     what: Option<String>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct Sport {
     /// Go for a walk.
@@ -39,14 +39,14 @@ pub struct Sport {
     play: Option<Play>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct Walk {
     #[arg(name = "-d", value = 3usize)]
     distance: usize,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct Play {
     /// Which game do you want to play?
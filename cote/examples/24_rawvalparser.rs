@@ -19,7 +19,7 @@ pub struct Cli {
 #[infer(val = i32, map = Speed)]
 pub struct Speed(i32);
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Direction {
     Up,
     Down,
@@ -58,14 +58,14 @@ impl RawValParser for Direction {
             "left" => Ok(Direction::Left),
             "right" => Ok(Direction::Right),
             _ => Err(
-                raise_failure!("Unknow value for enum type `{0}`: {1}", "Direction", name)
+                raise_failure!("Unknown value for enum type `{0}`: {1}", "Direction", name)
                     .with_uid(uid),
             ),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, CoteVal, CoteOpt)]
+#[derive(Debug, Clone, PartialEq, Eq, CoteVal, CoteOpt)]
 #[coteval(igcase)]
 pub enum Way {
     Walk,
@@ -8,7 +8,7 @@ pub struct Cli {
     flag: Option<Flag>,
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Flag;
 
 impl Infer for Flag {
@@ -10,7 +10,7 @@ pub struct Cli {
     widget_pos: Option<WidgetPos>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct WidgetOpt {
     cnt: i64,
@@ -18,7 +18,7 @@ pub struct WidgetOpt {
     name: String,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help, aborthelp)]
 pub struct WidgetPos {
     #[pos()]
@@ -49,7 +49,7 @@
 //! - Output of `cli --help`:
 //!
 //! ```plaintext
-//! Usage: cli [-h,-?,--help] [-d,--debug] [-c,--config [CFG]] <COMMAND>
+//! Usage: cli [OPTIONS] <COMMAND>
 //!
 //! Generate help message for command line program
 //!
@@ -60,7 +60,7 @@
 //! Options:
 //!   -h,-?,--help           Display help message
 //!   -d,--debug             Print debug message
-//!   -c,--config [CFG]      Set the configuration path ["default.json"]
+//!   -c,--config [CFG]      Set the configuration path [default.json]
 //!
 //! Create by araraloren <blackcatoverwall@gmail.com> v0.1.8
 //! ```
@@ -68,7 +68,7 @@
 //! - Output of `cli ls --help`:
 //!
 //! ```plaintext
-//! Usage: cli ls [-h,-?,--help] [--recursive] [ARGS]
+//! Usage: cli ls [OPTIONS] [ARGS]
 //!
 //! List the given directory
 //!
@@ -77,11 +77,16 @@
 //!   --recursive       Enable recursive mode
 //!
 //! Args:
-//!   dest@1      Set the list directory ["."]
+//!   dest@1      Set the list directory [.]
 //!
 //! Create by araraloren <blackcatoverwall@gmail.com> v0.1.8
 //! ```
 //!
+//! - Man page:
+//!
+//! Using [`man::generate`](crate::man::generate) generate the man page in roff format,
+//! the name, head and foot are taken from [`HelpContext`](crate::prelude::HelpContext).
+//!
 //! ### Running
 //!
 //! Output of `cli se --depth 2`:
@@ -100,7 +105,7 @@
 //! Output of `cli se --depth www` or `cli se --depth`:
 //!
 //! ```plaintext
-//! Usage: cli [-h,-?,--help] [-d,--debug] [-c,--config [CFG]] <COMMAND>
+//! Usage: cli [OPTIONS] <COMMAND>
 //!
 //! Generate help message for command line program
 //!
@@ -111,11 +116,11 @@
 //! Options:
 //!   -h,-?,--help           Display help message
 //!   -d,--debug             Print debug message
-//!   -c,--config [CFG]      Set the configuration path ["default.json"]
+//!   -c,--config [CFG]      Set the configuration path [default.json]
 //!
 //! Create by araraloren <blackcatoverwall@gmail.com> v0.1.8
 //! Error:
-//!    0: Parsing command `se` failed: InnerCtx { uid: 1, name: Some(--depth), style: Style::Argument, arg: Some("www"), index: 1, total: 3 }
+//!    0: Parsing command `cli se` failed: InnerCtx { uid: 1, name: Some(--depth), style: Style::Argument, arg: Some("www"), index: 1, total: 3 }
 //!    1: Can not find option `--depth`
 //!    2: Can not convert value `www` to usize
 //!    3: invalid digit found in string
@@ -128,6 +133,47 @@
 //! Run with RUST_BACKTRACE=full to include source snippets.
 //! ```
 //!
+//! #### `CoteApp`
+//!
+//! Using [`CoteApp::run`](crate::prelude::CoteApp::run) parse the arguments and call the main function,
+//! it print the error to stderr and exit with a non-zero code if parsing failed or main function returned an error.
+//! [`run_with_args`](crate::prelude::CoteApp::run_with_args) return the exit code instead of exit the process.
+//! The exit code of parsing failure is [`Error::exit_code`](crate::Error::exit_code), `2` for usage errors and `1` for others,
+//! using [`run_with_mapper`](crate::prelude::CoteApp::run_with_mapper) map the error to custom exit code.
+//!
+//! ```rust
+#![doc = include_str!("../tests/24_run.rs")]
+//! ```
+//!
+//! The errors caused by bad flags or values are [usage errors](crate::Error::is_usage),
+//! the short usage line is printed after them, the errors returned by main function are printed as a bare message.
+//! Using [`format_error`](crate::prelude::CoteApp::format_error) get the message printed for a parsing failure:
+//!
+//! ```rust
+#![doc = include_str!("../tests/59_usage_error.rs")]
+//! ```
+//!
+//! Using `parse_from` parse the arguments from any iterator of strings, such as an array:
+//!
+//! ```rust
+#![doc = include_str!("../tests/29_parse_from.rs")]
+//! ```
+//!
+//! Using `try_parse` or `try_parse_from` if you don't want the process exit when displaying help message,
+//! it return a [`ParseOutcome`](crate::prelude::ParseOutcome) and let the caller decide what to do,
+//! the help message is returned in [`Help`](crate::prelude::ParseOutcome::Help) instead of printing to stdout.
+//!
+//! ```rust
+#![doc = include_str!("../tests/36_try_parse.rs")]
+//! ```
+//!
+//! Using `help_string` get the help message as a `String` without parsing, such as for tests and GUIs,
+//! or [`Parser::help_string`](crate::prelude::Parser::help_string) render the help of parser with a [`HelpContext`](crate::prelude::HelpContext).
+//!
+//! ```rust
+#![doc = include_str!("../tests/60_help_string.rs")]
+//! ```
+//!
 //! ## Configurating Struct
 //!
 //! ### Configurating Policy
@@ -168,7 +214,7 @@
 //! The help message output like this:
 //!
 //! ```plaintext
-//! Usage: app [-h,-?,--help] [--debug] <--name>
+//! Usage: app [OPTIONS] <--name>
 //!        <COMMAND> [ARGS]
 //!
 //! The head message display in help message
@@ -305,7 +351,7 @@
 //! Running the code, it's output should be:
 //!
 //! ```plaintext
-//! Usage: cli [-h,-?,--help] <-b,--baz> <COMMAND> [ARGS]
+//! Usage: cli [OPTIONS] <-b,--baz> <COMMAND> [ARGS]
 //!
 //! Generate help message for command line program
 //!
@@ -448,6 +494,16 @@
 #![doc = include_str!("../examples/17_arg_validator.rs")]
 //! ```
 //!
+//! The [`valid`](mod@crate::valid) module has common rules with error messages, such as
+//! [`range`](crate::valid::range), [`choices`](crate::valid::choices), [`length`](crate::valid::length),
+//! [`non_empty`](crate::valid::non_empty), [`path_exists`](crate::valid::path_exists) and
+//! `regex` (need feature `regex`).
+//! Compose them using [`and`](crate::valid::and) and [`or`](crate::valid::or).
+//!
+//! ```rust
+#![doc = include_str!("../tests/35_rules.rs")]
+//! ```
+//!
 //! ### Add "no delay" option
 //!
 //! When using [`DelayPolicy`](crate::DelayPolicy), the option process(invoke handler)
@@ -475,7 +531,7 @@
 //! _Without_ `policy = pre`, you will got output when running `cli -g=42 sport walk -d 4`:
 //!
 //! ```plaintext
-//! Usage: cli sport [-h,-?,--help] <COMMAND>
+//! Usage: cli sport [OPTIONS] <COMMAND>
 //! Generate help message for command line program
 //!
 //! Commands:
@@ -487,7 +543,7 @@
 //!
 //! Create by araraloren <blackcatoverwall@gmail.com> v0.1.8
 //! Error:
-//!     0: Parsing command `sport` failed: None
+//!     0: Parsing command `cli sport` failed: None
 //!     1: Command `eat@1 | sport@1` are force required (uid = 1)
 //!     2: Can not find option `-d`
 //!
@@ -504,6 +560,26 @@
 //! You are going to walk 4 kilometers
 //! ```
 //!
+//! ### Accessing the command path
+//!
+//! The name of parser and the sub commands entered are recorded in [`RunningCtx`](crate::prelude::RunningCtx),
+//! using [`command_path`](crate::prelude::RunningCtx::command_path) get them.
+//! The error message of sub command failure also contains the path.
+//!
+//! ```rust
+#![doc = include_str!("../tests/33_command_path.rs")]
+//! ```
+//!
+//! ### Sharing data between handlers
+//!
+//! The [`RunningCtx`](crate::prelude::RunningCtx) has a typed extension storage,
+//! the handlers can stash the user data with [`insert_ext`](crate::prelude::RunningCtx::insert_ext)
+//! and retrieve it later with [`get_ext`](crate::prelude::RunningCtx::get_ext).
+//!
+//! ```rust
+#![doc = include_str!("../tests/38_rctx_ext.rs")]
+//! ```
+//!
 //! ### Configurating name and alias
 //!
 //! Using `name` and `alias` you can configure the name and alias of sub commands in `sub` attribute.
@@ -517,7 +593,7 @@
 //! The output of commands `cli -g22 e --help` is:
 //!
 //! ```plaintext
-//! Usage: cli e [-h,-?,--help] <-m,--meal> [ARGS]
+//! Usage: cli e [OPTIONS] <-m,--meal> [ARGS]
 //!
 //! Generate help message for command line program
 //!
@@ -526,7 +602,7 @@
 //!   -m,--meal         Which meal did you have?
 //!
 //! Args:
-//!   what@1      What did you wat? ["rice"]
+//!   what@1      What did you wat? [rice]
 //!
 //! Create by araraloren <blackcatoverwall@gmail.com> v0.1.8
 //! ```
@@ -543,7 +619,7 @@
 //! The output of commands `cli -g8 sport --help` is:
 //!
 //! ```plaintext
-//! Usage: cli sport [-h,-?,--help] <COMMAND>
+//! Usage: cli sport [OPTIONS] <COMMAND>
 //!
 //! This is head message of sport sub command.
 //!
@@ -593,11 +669,23 @@
 #![doc = include_str!("../examples/31_modify_prefix.rs")]
 //! ```
 //!
+//! ### Run logic after parsing
+//!
+//! Using [`on_parsed`](crate::prelude::Parser::on_parsed) set a hook invoked after the arguments parsed successfully,
+//! but before the values extracted, such as initialize logging base on `--verbose`.
+//! Using `cote` attribute setup a method call on `parser` context variable.
+//!
+//! ```
+#![doc = include_str!("../tests/55_on_parsed.rs")]
+//! ```
+//!
 //! ## How it works
 //!
 //! ### Traits
 //!
 //! Implement follow traits, you can using the type in the struct filed.
+//! The type of values and sub commands must be [`Clone`], it is required by
+//! [`snapshot`](aopt::parser::HCOptSet::snapshot).
 //!
 //! - [`Infer`](crate::prelude::Infer)
 //!
@@ -611,6 +699,8 @@
 //! - [`RawValParser`](crate::prelude::RawValParser)
 //!
 //! `Cote` using [`parse`](crate::prelude::RawValParser::parse) parsing the value from command line arguments.
+//! The `time` feature implements it for `time::OffsetDateTime` in RFC3339 format and `time::Date` in `[year]-[month]-[day]` format,
+//! using [`set_time_format`](crate::prelude::ConfigValue::set_time_format) parsing them in a custom format.
 //!
 //! - [`InferOverride`](crate::prelude::InferOverride)
 //!
@@ -624,6 +714,7 @@
 //!| `Vec<T>` | [`Action::App`](crate::prelude::Action::App) | `true` | `false` |
 //!| `Option<Vec<T>>` | [`Action::App`](crate::prelude::Action::App) | `false` | `false` |
 //!| `Result<Vec<T>, _>` | [`Action::App`](crate::prelude::Action::App) | `false` | `false` |
+//!| `VecDeque<T>`, `HashSet<T>`, `BTreeSet<T>` | [`Action::App`](crate::prelude::Action::App) | `true` | `false` |
 //!| [`Pos<T>`](crate::prelude::Pos) | [`Action::Set`](crate::prelude::Action::Set) | `true` | `false` |
 //!| `bool` | [`Action::Set`](crate::prelude::Action::Set) | `false` | `false` |
 //!| [`Cmd`](crate::prelude::Cmd) | [`Action::Set`](crate::prelude::Action::Set) | `true` | `true` |
@@ -649,11 +740,14 @@
 //!| name      | need value | available value |
 //!|-----------|------------|-----------|
 //!| `policy`  |  true      | `"pre"`, `"fwd"`, `"delay"`, or type |
+//!|`policy_init`| true     | function |
 //!| `name`    |  true      | string literal |
 //!| `help`    |  false     | |
 //!| `helpopt` |  true      | string literal |
 //!| `head`    |  true      | string literal |
 //!| `foot`    |  true      | string literal |
+//!|`before_help`| true     | string literal |
+//!|`after_help`| true      | string literal |
 //!| `width`   |  true      | integer |
 //!| `usagew`  |  true      | integer |
 //!|`aborthelp`|  false     | |
@@ -661,9 +755,15 @@
 //!| `fallback`|  true      | function or closure |
 //!| `then`    |  true      | function or closure |
 //!| `strict`  |  true      | boolean |
+//!|`intermixed`| true      | boolean |
 //!| `combine` |  false     | |
 //!| `embedded`|  false     | |
 //!| `flag`    |  false     | |
+//!|`overload` |  false     | |
+//!|`name_from_arg0`| false | |
+//!|`subcommand_required`| false | |
+//!|`no_auto_help`| false | |
+//!| `defaults`|  true      | function |
 //! * `policy`
 //!
 //! Configure the policy of current struct, its value should be `fwd`, `pre` or `delay`.
@@ -672,6 +772,15 @@
 #![doc = include_str!("../tests/01_policy.rs")]
 //! ```
 //!
+//! * `policy_init`
+//!
+//! Create the policy instance by calling the function instead of [`Default::default`].
+//! The function should return the type of `policy`, other settings such as `strict` or `combine` are applied on the returned policy.
+//!
+//! ```rust
+#![doc = include_str!("../tests/50_policy_init.rs")]
+//! ```
+//!
 //! * `name`
 //!
 //! The name is display in usage information.
@@ -696,6 +805,14 @@
 #![doc = include_str!("../tests/02_head_foot.rs")]
 //! ```
 //!
+//! * `before_help`, `after_help`
+//!
+//! The text displayed before and after the options, between the `head` and `foot`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/30_before_after_help.rs")]
+//! ```
+//!
 //! * `width`, `usagew`
 //!
 //! `width` set the maximum length of option help message. `usagew` set the maximum count of options in usage.
@@ -730,10 +847,66 @@
 #![doc = include_str!("../tests/03_strict.rs")]
 //! ```
 //!
+//! * `intermixed`
+//!
+//! Set if the options can be set after positional arguments by calling the [`set_intermixed`](crate::PolicySettings::set_intermixed).
+//! If it is false, the first positional argument, include the sub command, stops the option parsing of current struct.
+//!
+//! ```rust
+#![doc = include_str!("../tests/26_intermixed.rs")]
+//! ```
+//!
 //! * `combine`, `embedded`, `flag`
 //!
 //! Enable some extra [`user style`](crate::UserStyle) of policy. See also [`Configurating User Style`](#configurating-user-style).
 //!
+//! * `overload`
+//!
+//! Enable the option overload by calling the [`set_overload`](crate::PolicySettings::set_overload),
+//! the repeated option can not be used more than once, such as the option of scalar value,
+//! keeps the value of last occurrence instead of raising a failure.
+//!
+//! ```rust
+#![doc = include_str!("../tests/31_overload.rs")]
+//! ```
+//!
+//! * `name_from_arg0`
+//!
+//! Using the file stem of first argument as the name of program, such as `foo` for `/usr/bin/foo`.
+//! It is useful for the multi-call binary, the name from `name` or `CARGO_PKG_NAME` is used if the argument is empty.
+//!
+//! ```rust
+#![doc = include_str!("../tests/40_name_from_arg0.rs")]
+//! ```
+//!
+//! * `subcommand_required`
+//!
+//! Raise an error `a subcommand is required` if none of sub commands matched, unless the help option is set.
+//! Unlike the `force` of sub command, it works with `#[sub(force = false)]` and the help is displayed if `aborthelp` is set.
+//!
+//! ```rust
+#![doc = include_str!("../tests/52_subcommand_required.rs")]
+//! ```
+//!
+//! * `no_auto_help`
+//!
+//! Do not add the help option `-h`/`--help` of `help`, the configurations of help message still work.
+//! It is useful if you manage the help option yourself, using [`write_sub_help`](crate::prelude::Parser::write_sub_help) display the help message.
+//!
+//! ```rust
+#![doc = include_str!("../tests/58_no_auto_help.rs")]
+//! ```
+//!
+//! * `defaults`
+//!
+//! Call the function with the extracted struct after parsing, the function can fill the `None` fields,
+//! such as the default value of one field computed from another.
+//! The signature of function should be `fn(&mut Self) -> cote::Result<()>`.
+//!
+//! ```rust
+#![doc = include_str!("../tests/62_defaults.rs")]
+//! ```
+//!
 //! #### `arg`, `pos`, `cmd`
 //!
 //!| name      | need value | available value |
@@ -742,13 +915,22 @@
 //!| `ty`      |  true      | type |
 //!| `hint`    |  true      | string literal |
 //!| `help`    |  true      | string literal |
+//!| `env`     |  true      | string literal |
+//!| `group`   |  true      | string literal |
 //!| `value`   |  true      | value expression |
 //!| `values`  |  true      | values expression |
+//!|`default_fn`| true      | function |
 //!| `alias`   |  true      | string literal |
+//!|`deprecated_alias`| true | `"alias" = "message"` |
+//!| `short`   |  true      | char literal |
+//!| `long`    |  true      | string literal |
 //!| `index`   |  true      | range or integer |
 //!| `force`   |  true      | boolean |
+//!|`priority` |  true      | integer |
+//!|`display_order`| true  | integer |
 //!| `action`  |  true      | [`Action`](crate::prelude::Action) |
 //!| `valid`   |  true      | [`valid!`](crate::valid!) |
+//!| `choices` |  false     | array expression |
 //!| `on`      |  true      | function or closure |
 //!| `fallback`|  true      | function or closure |
 //!| `then`    |  true      | function or closure |
@@ -756,16 +938,58 @@
 //!| `fetch`   |  true      | function |
 //!| `append`  |  false     | |
 //!| `count`   |  false     | |
+//!|`standalone`| false     | |
+//!|`optional_value`| true | string literal |
+//!|`num_values`| true     | range expression |
+//!| `greedy`  |  false     | |
+//!| `igcase`  |  false     | |
+//!| `last`    |  false     | |
+//!| `forward` |  false     | |
+//!| `secret`  |  false     | |
+//!|`hide_default`| false  | |
+//!| `info`    |  true      | string literal |
+//!|`exactly_one`| true     | string literal |
 //!
 //! * `name`, `alias`
 //!
 //! Configure the name and alias of current option. See also [`Configurating the name and alias`](#configurating-the-name-and-alias).
 //!
+//! * `short`, `long`
+//!
+//! Override the name generated from the field identifier, such as `#[arg(short = 'o', long = "output")]`
+//! register the option `--output` with alias `-o`.
+//! Only the `short` is set, the name is still generated from the identifier.
+//!
+//! ```rust
+#![doc = include_str!("../tests/68_short_long.rs")]
+//! ```
+//!
+//! * `deprecated_alias`
+//!
+//! Configure an alias still working but deprecated, a warning is saved in the [`warnings`](crate::prelude::Return::warnings)
+//! of parsing result when the alias is used, call [`print_warnings`](crate::prelude::Return::print_warnings) print them to stderr.
+//! The deprecated alias will not display in the help message.
+//!
+//! ```rust
+#![doc = include_str!("../tests/21_deprecated_alias.rs")]
+//! ```
+//!
 //! * `hint`, `help`
 //!
 //! Configure the name and help message of option.
 //! See also [`Configurating the hint, help and default value`](#configurating-the-hint-help-and-default-value).
 //!
+//! * `env`
+//!
+//! Configure the name of environment variable associated with option,
+//! the value is parsed from the variable if it is set, otherwise the default value is used.
+//! It is appended to the help message of option, such as `[env: API_TOKEN]`.
+//! Using [`HelpContext::with_show_env`](crate::prelude::HelpContext::with_show_env) hide it.
+//!
+//! ```rust
+#![doc = include_str!("../tests/69_env.rs")]
+//! ```
+//!
 //! * `value`, `values`
 //!
 //! Configure the default value of option, `cote-derive` using [`From`] convert given value to option value.
@@ -774,6 +998,15 @@
 #![doc = include_str!("../tests/06_value.rs")]
 //! ```
 //!
+//! * `default_fn`
+//!
+//! Configure a function computes the default value when the option is initialized,
+//! it is called before every parsing.
+//!
+//! ```rust
+#![doc = include_str!("../tests/20_default_fn.rs")]
+//! ```
+//!
 //! * `index`
 //!
 //! Configure the index of option, it is using for `pos`([`Pos`](crate::prelude::Pos)) attribute generally.
@@ -790,6 +1023,24 @@
 #![doc = include_str!("../tests/08_force.rs")]
 //! ```
 //!
+//! * `priority`
+//!
+//! Configure the priority of option handler, the handler with higher priority is invoked first.
+//! It only works with [`DelayPolicy`](crate::prelude::DelayPolicy), which invoke the handlers after matching.
+//!
+//! ```rust
+#![doc = include_str!("../tests/23_priority.rs")]
+//! ```
+//!
+//! * `display_order`
+//!
+//! Configure the order of option in help message, the option with lower order is displayed first.
+//! The default order is `0`, the options with same order keep the declaration order.
+//!
+//! ```rust
+#![doc = include_str!("../tests/37_display_order.rs")]
+//! ```
+//!
 //! * `action`, `ty`, `append`, `count`
 //!
 //! `action` can configure the [`Action`](crate::prelude::Action) which responsible for saving value of option.
@@ -818,6 +1069,17 @@
 #![doc = include_str!("../tests/11_valid.rs")]
 //! ```
 //!
+//! * `choices`
+//!
+//! Check the value is one of the given variants of the enum type derived [`CoteVal`](#coteval-configurations-list),
+//! the failure message lists the names used when parsing, such as `value must be one of fast/slow`.
+//! All the variants are used if no value given, such as `#[arg(choices)]`.
+//! See [`Variants`](crate::valid::Variants), using `valid = choices([...])` for other types.
+//!
+//! ```rust
+#![doc = include_str!("../tests/45_choices.rs")]
+//! ```
+//!
 //! * `on`, `fallback`, `then`
 //!
 //! Using `then` you can configure a handler which is responsible for storing the option value.
@@ -839,6 +1101,120 @@
 //! Only work for [`DelayPolicy`](crate::DelayPolicy) currently.
 //! See also [`Add "no delay" option`](#add-no-delay-option).
 //!
+//! * `standalone`
+//!
+//! The option can not be used together with any other arguments, such as `--version`.
+//! A failure is returned if the option is set while any other option or positional argument present.
+//!
+//! ```rust
+#![doc = include_str!("../tests/28_standalone.rs")]
+//! ```
+//!
+//! * `optional_value`
+//!
+//! The option can be used without a value, such as `--color`, the given value is stored in that case.
+//! The value must be given by `=` such as `--color=never`, the next argument is never consumed by the option.
+//!
+//! ```rust
+#![doc = include_str!("../tests/32_optional_value.rs")]
+//! ```
+//!
+//! * `greedy`
+//!
+//! The option consumes the following arguments until next option or the end, such as `--files a b c`.
+//! The option terminator `--` also stops it if it exists.
+//!
+//! ```rust
+#![doc = include_str!("../tests/34_greedy.rs")]
+//! ```
+//!
+//! * `num_values`
+//!
+//! The range of value count accepted by the option, such as `2..=3`.
+//! A failure is returned if the option is set and the count of values is out of the range.
+//!
+//! ```rust
+#![doc = include_str!("../tests/42_num_values.rs")]
+//! ```
+//!
+//! * `igcase`
+//!
+//! Match the name and alias of option case-insensitively, such as `--Force` matched by `--force`.
+//! The help message still display them as given.
+//!
+//! ```rust
+#![doc = include_str!("../tests/39_igcase.rs")]
+//! ```
+//!
+//! * `last`
+//!
+//! Collect the arguments after the option terminator `--` verbatim, like `cargo run -- <args>`.
+//! The arguments are not matched by the options or positional arguments, use `Option` if `--` may be absent.
+//!
+//! ```rust
+#![doc = include_str!("../tests/44_last.rs")]
+//! ```
+//!
+//! * `forward`
+//!
+//! Same as `last`, but the arguments after `--` are parsed into a nested type derived by `Cote`,
+//! like `cargo run -- <args>` where the `args` are parsed by the program.
+//! The field is `None` if `--` is absent when using `Option`, otherwise the type is parsed without arguments.
+//!
+//! ```rust
+#![doc = include_str!("../tests/61_forward.rs")]
+//! ```
+//!
+//! * `secret`
+//!
+//! Mask the value of option by `***` in [`normalized_args`](crate::prelude::Return::normalized_args)
+//! and [`rebuild_command_line`](crate::prelude::Return::rebuild_command_line), such as passwords and tokens.
+//! The value extracted is not changed.
+//!
+//! ```rust
+#![doc = include_str!("../tests/63_secret.rs")]
+//! ```
+//!
+//! * `hide_default`
+//!
+//! Do not append the default value set by `value` or `values` to the help message of option,
+//! the default value is still applied.
+//!
+//! ```rust
+#![doc = include_str!("../tests/64_hide_default.rs")]
+//! ```
+//!
+//! * `info`
+//!
+//! Print the given text and exit if the option is set, such as `--license` or `--version`, it works in sub command too.
+//! The help option takes precedence if both of them are set.
+//! The `try_parse` return the text in [`Info`](crate::prelude::ParseOutcome::Info) instead of printing it.
+//!
+//! ```rust
+#![doc = include_str!("../tests/54_info.rs")]
+//! ```
+//!
+//! * `exactly_one`
+//!
+//! The options have same group name are mutually exclusive, and exactly one of them is required, like a radio button.
+//! The parsing failed if none or more than one of them are set.
+//! See also [`require_exactly_one`](crate::prelude::Parser::require_exactly_one).
+//!
+//! ```rust
+#![doc = include_str!("../tests/56_exactly_one.rs")]
+//! ```
+//!
+//! * `group`
+//!
+//! Display the option in a separate block titled by the group name in the help message.
+//! The value or the argument after help option is taken as the topic, such as `--help=net` or `--help net`,
+//! only the options of the group are displayed if it names a group.
+//! It displays the help of sub command if it names a sub command, or all the options otherwise.
+//!
+//! ```rust
+#![doc = include_str!("../tests/41_help_topic.rs")]
+//! ```
+//!
 //! #### `sub`
 //!
 //!| name      | need value | available value |
@@ -849,17 +1225,24 @@
 //!| `help`    |  true      | string literal |
 //!| `head`    |  true      | string literal |
 //!| `foot`    |  true      | string literal |
+//!| `head_append` |  true  | string literal |
+//!| `foot_append` |  true  | string literal |
 //!| `alias`   |  true      | string literal |
 //!| `force`   |  true      | boolean |
 //!
 //! * `policy`
 //!
-//! Override the `policy` of sub command.
+//! Override the `policy` of sub command, it is independent of the policy of parent.
+//! The name can be written as identifier or string literal, such as `policy = delay` or `policy = "delay"`.
 //!
 //! ```rust
 #![doc = include_str!("../tests/13_policy.rs")]
 //! ```
 //!
+//! ```rust
+#![doc = include_str!("../tests/46_sub_policy.rs")]
+//! ```
+//!
 //! * `name`, `alias`
 //!
 //! Configure the name and alias of sub command.
@@ -876,6 +1259,14 @@
 #![doc = include_str!("../tests/14_help.rs")]
 //! ```
 //!
+//! * `head_append`, `foot_append`
+//!
+//! Append the text to the head or foot inherited from parent command, the text is placed after the parent text.
+//!
+//! ```rust
+#![doc = include_str!("../tests/25_sub_help_append.rs")]
+//! ```
+//!
 //! * `force`
 //!
 //! Configure the sub command optional, in default one of sub commands must be set.
@@ -904,6 +1295,7 @@
 //!| `valid`   |  true      | Option<[`ValValidator`](crate::prelude::ValValidator)\<[`Val`](crate::prelude::Infer::Val)\>> |
 //!| `init`    |  true      | Option<[`ValInitializer`](crate::prelude::ValInitializer)> |
 //!| `ty`      |  true      | [`TypeId`](std::any::TypeId) |
+//!| `hint`    |  true      | Option<[`String`]> |
 //!| `tweak`   |  true      | function |
 //!| `fill`    |  true      | function |
 //!| `override`|  false
@@ -1,9 +1,19 @@
+use aopt::opt::default_hint;
+use aopt::opt::Cid;
 use aopt::opt::Opt;
 use aopt::opt::Style;
 use aopt::set::Set;
+use aopt::set::SetOpt;
 use aopt_help::block::Block;
+use aopt_help::prelude::DefaultAppPolicy;
+use aopt_help::prelude::HelpPolicy;
+use aopt_help::prelude::UsageStyle;
+use aopt_help::prelude::USAGE_LABEL;
 use aopt_help::store::Store;
+use aopt_help::AppHelp;
+use std::any::TypeId;
 use std::borrow::Cow;
+use std::io::Write;
 
 #[derive(Debug, Clone, Default)]
 pub struct HelpContext {
@@ -13,9 +23,35 @@ pub struct HelpContext {
 
     foot: String,
 
+    before: String,
+
+    after: String,
+
     width: usize,
 
     usagew: usize,
+
+    name_column: usize,
+
+    subcommand_limit: Option<usize>,
+
+    show_env: Option<bool>,
+
+    topic: Option<String>,
+
+    page_template: Option<String>,
+
+    required_marker: Option<String>,
+
+    usage_label: Option<String>,
+
+    options_label: Option<String>,
+
+    commands_label: Option<String>,
+
+    usage_style: UsageStyle,
+
+    type_metavars: Vec<(TypeId, String)>,
 }
 
 impl HelpContext {
@@ -34,6 +70,18 @@ impl HelpContext {
         self
     }
 
+    /// Set the text displayed before the options, it is different from head.
+    pub fn with_before(mut self, before: impl Into<String>) -> Self {
+        self.before = before.into();
+        self
+    }
+
+    /// Set the text displayed after the options, it is different from foot.
+    pub fn with_after(mut self, after: impl Into<String>) -> Self {
+        self.after = after.into();
+        self
+    }
+
     pub fn with_width(mut self, width: usize) -> Self {
         self.width = width;
         self
@@ -44,6 +92,85 @@ impl HelpContext {
         self
     }
 
+    /// Set the width of option name column, independent of the `width`.
+    /// The name longer than it is placed on its own line, and the help starts from next line.
+    pub fn with_name_column(mut self, name_column: usize) -> Self {
+        self.name_column = name_column;
+        self
+    }
+
+    /// List at most `limit` sub commands in help message, the rest are summarized in one line,
+    /// which refers to the help of sub command displayed by `--help <COMMAND>`.
+    pub fn with_subcommand_limit(mut self, limit: Option<usize>) -> Self {
+        self.subcommand_limit = limit;
+        self
+    }
+
+    /// Show the environment variable name in the help message of option,
+    /// default is true when any option has an environment variable.
+    pub fn with_show_env(mut self, show_env: bool) -> Self {
+        self.show_env = Some(show_env);
+        self
+    }
+
+    /// Only display the options of the group `topic`,
+    /// all the options are displayed if no option belongs to the group.
+    pub fn with_topic(mut self, topic: Option<String>) -> Self {
+        self.topic = topic;
+        self
+    }
+
+    /// Set the template of whole help page, see [`PageTemplatePolicy`] for the placeholders.
+    /// The built-in layout is used if it is not set.
+    pub fn with_page_template(mut self, template: impl Into<String>) -> Self {
+        self.page_template = Some(template.into());
+        self
+    }
+
+    /// Append the `marker`, such as `(required)` or `*`, to the help of force required options.
+    /// The options are not marked if it is not set.
+    pub fn with_required_marker(mut self, marker: impl Into<String>) -> Self {
+        self.required_marker = Some(marker.into());
+        self
+    }
+
+    /// Set the label printed before the usage, default is `Usage:`.
+    pub fn with_usage_label(mut self, label: impl Into<String>) -> Self {
+        self.usage_label = Some(label.into());
+        self
+    }
+
+    /// Set the title of options block, default is `Options:`.
+    pub fn with_options_label(mut self, label: impl Into<String>) -> Self {
+        self.options_label = Some(label.into());
+        self
+    }
+
+    /// Set the title of sub commands block, default is `Commands:`.
+    pub fn with_commands_label(mut self, label: impl Into<String>) -> Self {
+        self.commands_label = Some(label.into());
+        self
+    }
+
+    /// Set how the options are displayed in the usage, default is [`UsageStyle::Short`]
+    /// which collapses the optional options into `[OPTIONS]`.
+    pub fn with_usage_style(mut self, usage_style: UsageStyle) -> Self {
+        self.usage_style = usage_style;
+        self
+    }
+
+    /// Append the metavar to the hint of options take a value base on the type code,
+    /// such as `[("i", "INT"), ("s", "STR")]` display `-c, --count INT`.
+    /// The type codes are the names of built-in creator, see [`Cid`], the unknown codes are ignored.
+    pub fn with_type_metavars<K, V>(mut self, metavars: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.set_type_metavars(metavars);
+        self
+    }
+
     pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
         self.name = name.into();
         self
@@ -59,6 +186,16 @@ impl HelpContext {
         self
     }
 
+    pub fn set_before(&mut self, before: impl Into<String>) -> &mut Self {
+        self.before = before.into();
+        self
+    }
+
+    pub fn set_after(&mut self, after: impl Into<String>) -> &mut Self {
+        self.after = after.into();
+        self
+    }
+
     pub fn set_width(&mut self, width: usize) -> &mut Self {
         self.width = width;
         self
@@ -69,6 +206,75 @@ impl HelpContext {
         self
     }
 
+    pub fn set_name_column(&mut self, name_column: usize) -> &mut Self {
+        self.name_column = name_column;
+        self
+    }
+
+    pub fn set_subcommand_limit(&mut self, limit: Option<usize>) -> &mut Self {
+        self.subcommand_limit = limit;
+        self
+    }
+
+    pub fn set_show_env(&mut self, show_env: bool) -> &mut Self {
+        self.show_env = Some(show_env);
+        self
+    }
+
+    pub fn set_topic(&mut self, topic: Option<String>) -> &mut Self {
+        self.topic = topic;
+        self
+    }
+
+    pub fn set_page_template(&mut self, template: Option<String>) -> &mut Self {
+        self.page_template = template;
+        self
+    }
+
+    pub fn set_required_marker(&mut self, marker: Option<String>) -> &mut Self {
+        self.required_marker = marker;
+        self
+    }
+
+    pub fn set_usage_label(&mut self, label: Option<String>) -> &mut Self {
+        self.usage_label = label;
+        self
+    }
+
+    pub fn set_options_label(&mut self, label: Option<String>) -> &mut Self {
+        self.options_label = label;
+        self
+    }
+
+    pub fn set_commands_label(&mut self, label: Option<String>) -> &mut Self {
+        self.commands_label = label;
+        self
+    }
+
+    pub fn set_usage_style(&mut self, usage_style: UsageStyle) -> &mut Self {
+        self.usage_style = usage_style;
+        self
+    }
+
+    pub fn set_type_metavars<K, V>(
+        &mut self,
+        metavars: impl IntoIterator<Item = (K, V)>,
+    ) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.type_metavars = metavars
+            .into_iter()
+            .filter_map(|(code, metavar)| {
+                Cid::from(code.into())
+                    .type_id()
+                    .map(|type_id| (type_id, metavar.into()))
+            })
+            .collect();
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -81,6 +287,14 @@ impl HelpContext {
         &self.foot
     }
 
+    pub fn before(&self) -> &String {
+        &self.before
+    }
+
+    pub fn after(&self) -> &String {
+        &self.after
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -88,6 +302,241 @@ impl HelpContext {
     pub fn usagew(&self) -> usize {
         self.usagew
     }
+
+    pub fn name_column(&self) -> usize {
+        self.name_column
+    }
+
+    pub fn subcommand_limit(&self) -> Option<usize> {
+        self.subcommand_limit
+    }
+
+    pub fn show_env(&self) -> Option<bool> {
+        self.show_env
+    }
+
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+
+    pub fn page_template(&self) -> Option<&str> {
+        self.page_template.as_deref()
+    }
+
+    pub fn required_marker(&self) -> Option<&str> {
+        self.required_marker.as_deref()
+    }
+
+    pub fn usage_label(&self) -> &str {
+        self.usage_label.as_deref().unwrap_or(USAGE_LABEL)
+    }
+
+    pub fn options_label(&self) -> &str {
+        self.options_label.as_deref().unwrap_or("Options:")
+    }
+
+    pub fn commands_label(&self) -> &str {
+        self.commands_label.as_deref().unwrap_or("Commands:")
+    }
+
+    pub fn usage_style(&self) -> UsageStyle {
+        self.usage_style
+    }
+
+    /// Return the metavar of value type `type_id`, see [`with_type_metavars`](Self::with_type_metavars).
+    pub fn type_metavar(&self, type_id: &TypeId) -> Option<&str> {
+        self.type_metavars
+            .iter()
+            .find(|(id, _)| id == type_id)
+            .map(|(_, metavar)| metavar.as_str())
+    }
+}
+
+/// Render the help page by a template, the placeholders are replaced by the sections of help:
+///
+/// * `{usage}`: the usage line, such as `Usage: app [--debug]`.
+/// * `{head}`, `{foot}`: the head and foot of help.
+/// * `{before}`, `{after}`: the text displayed before and after the options.
+/// * `{options}`: the blocks of options, groups and positional arguments.
+/// * `{subcommands}`: the block of sub commands.
+pub struct PageTemplatePolicy<'a, W> {
+    template: String,
+
+    inner: DefaultAppPolicy<'a, AppHelp<'a, W>>,
+}
+
+impl<'a, W> PageTemplatePolicy<'a, W> {
+    pub fn new(template: impl Into<String>, inner: DefaultAppPolicy<'a, AppHelp<'a, W>>) -> Self {
+        Self {
+            template: template.into(),
+            inner,
+        }
+    }
+}
+
+impl<'a, W: Write> HelpPolicy<'a, AppHelp<'a, W>> for PageTemplatePolicy<'a, W> {
+    fn format(&self, app: &AppHelp<'a, W>) -> Option<Cow<'a, str>> {
+        let block_spacing = "\n".repeat(1 + app.style().block_spacing);
+        let mut options = vec![];
+        let mut subcommands = vec![];
+
+        for block in app.block().iter() {
+            let help = self.inner.get_block_help(block, app);
+
+            if !help.is_empty() {
+                subcommands.push(help);
+            }
+        }
+        for block in app.global().block() {
+            let help = self.inner.get_global_help(block, app.global(), app);
+
+            if !help.is_empty() {
+                if block.name() == COMMAND_BLOCK {
+                    subcommands.push(help);
+                } else {
+                    options.push(help);
+                }
+            }
+        }
+        let page = self
+            .template
+            .replace("{usage}", self.inner.get_app_usage(app).trim_end())
+            .replace("{head}", &app.head())
+            .replace("{foot}", &app.foot())
+            .replace("{before}", &app.before())
+            .replace("{after}", &app.after())
+            .replace("{options}", &options.join(&block_spacing))
+            .replace("{subcommands}", &subcommands.join(&block_spacing));
+
+        Some(Cow::Owned(page))
+    }
+}
+
+/// Return the help message of option, append the environment variable name if `show_env` is true.
+pub fn opt_help_text<O: Opt>(opt: &O, show_env: bool) -> Cow<'_, str> {
+    match opt.env() {
+        Some(env) if show_env => {
+            if opt.help().is_empty() {
+                Cow::Owned(format!("[env: {env}]"))
+            } else {
+                Cow::Owned(format!("{} [env: {env}]", opt.help()))
+            }
+        }
+        _ => Cow::from(opt.help()),
+    }
+}
+
+/// Append the `marker` to the help message if the option is [`force`](Opt::force) required.
+fn mark_required<'a, O: Opt>(opt: &O, help: Cow<'a, str>, marker: Option<&str>) -> Cow<'a, str> {
+    match marker {
+        Some(marker) if opt.force() => {
+            if help.is_empty() {
+                Cow::Owned(marker.to_owned())
+            } else {
+                Cow::Owned(format!("{help} {marker}"))
+            }
+        }
+        _ => help,
+    }
+}
+
+/// Format the default value displayed in help message, see [`format_default`].
+///
+/// It is implemented for the common value types, and the enum derived by
+/// [`CoteVal`](crate::prelude::CoteVal) using the name of variant used when parsing.
+pub trait FormatDefault {
+    fn format_default(&self) -> String;
+}
+
+macro_rules! impl_format_default_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FormatDefault for $ty {
+                fn format_default(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_format_default_display!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, str,
+    String,
+);
+
+impl FormatDefault for std::path::Path {
+    fn format_default(&self) -> String {
+        self.display().to_string()
+    }
+}
+
+impl FormatDefault for std::path::PathBuf {
+    fn format_default(&self) -> String {
+        self.display().to_string()
+    }
+}
+
+impl FormatDefault for std::ffi::OsStr {
+    fn format_default(&self) -> String {
+        self.to_string_lossy().into_owned()
+    }
+}
+
+impl FormatDefault for std::ffi::OsString {
+    fn format_default(&self) -> String {
+        self.to_string_lossy().into_owned()
+    }
+}
+
+impl<T: FormatDefault + ?Sized> FormatDefault for &T {
+    fn format_default(&self) -> String {
+        T::format_default(self)
+    }
+}
+
+impl<T: FormatDefault> FormatDefault for Option<T> {
+    fn format_default(&self) -> String {
+        self.as_ref().map(T::format_default).unwrap_or_default()
+    }
+}
+
+impl<T: FormatDefault> FormatDefault for [T] {
+    fn format_default(&self) -> String {
+        self.iter()
+            .map(T::format_default)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl<T: FormatDefault, const N: usize> FormatDefault for [T; N] {
+    fn format_default(&self) -> String {
+        self.as_slice().format_default()
+    }
+}
+
+impl<T: FormatDefault> FormatDefault for Vec<T> {
+    fn format_default(&self) -> String {
+        self.as_slice().format_default()
+    }
+}
+
+/// Format the default value displayed in help message using [`FormatDefault`],
+/// the items of list are joined by `,`.
+///
+/// # Example
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// #
+/// assert_eq!(format_default(&"default.json"), "default.json");
+/// assert_eq!(format_default(&vec!["a", "b", "c"]), "a,b,c");
+/// assert_eq!(format_default(&[1, 2]), "1,2");
+/// assert_eq!(format_default(&true), "true");
+/// ```
+pub fn format_default<T: FormatDefault + ?Sized>(value: &T) -> String {
+    value.format_default()
 }
 
 pub fn display_set_help<'a, T: Set>(
@@ -98,40 +547,191 @@ pub fn display_set_help<'a, T: Set>(
     max_width: usize,
     usage_width: usize,
 ) -> Result<(), aopt_help::Error> {
+    display_set_help_env(set, name, head, foot, max_width, usage_width, None)
+}
+
+/// Same as [`display_set_help`], `show_env` controls if the environment variable name is shown,
+/// default is true when any option has an environment variable.
+pub fn display_set_help_env<'a, T: Set>(
+    set: &T,
+    name: impl Into<Cow<'a, str>>,
+    head: impl Into<Cow<'a, str>>,
+    foot: impl Into<Cow<'a, str>>,
+    max_width: usize,
+    usage_width: usize,
+    show_env: Option<bool>,
+) -> Result<(), aopt_help::Error> {
+    let ctx = HelpContext {
+        name: name.into().into_owned(),
+        head: head.into().into_owned(),
+        foot: foot.into().into_owned(),
+        width: max_width,
+        usagew: usage_width,
+        show_env,
+        ..Default::default()
+    };
+
+    display_set_help_ctx(set, &ctx)
+}
+
+/// Display the help message of `set` to stdout, all the configurations are taken from `ctx`.
+pub fn display_set_help_ctx<T: Set>(set: &T, ctx: &HelpContext) -> Result<(), aopt_help::Error> {
+    write_set_help(set, ctx, std::io::stdout())
+}
+
+/// Return the file stem of `arg0` as the program name, such as `foo` for `/usr/bin/foo`,
+/// or `None` if it is empty.
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// # use std::ffi::OsStr;
+/// #
+/// assert_eq!(arg0_name(Some(OsStr::new("/usr/bin/foo"))).as_deref(), Some("foo"));
+/// assert_eq!(arg0_name(Some(OsStr::new("bar.exe"))).as_deref(), Some("bar"));
+/// assert_eq!(arg0_name(Some(OsStr::new(""))), None);
+/// ```
+pub fn arg0_name(arg0: Option<&std::ffi::OsStr>) -> Option<String> {
+    arg0.and_then(|v| std::path::Path::new(v).file_stem())
+        .map(|v| v.to_string_lossy().into_owned())
+        .filter(|v| !v.is_empty())
+}
+
+/// Return the options sorted by [`display_order`](Opt::display_order), keep the declaration order if same.
+pub(crate) fn display_ordered<T: Set>(set: &T) -> Vec<&SetOpt<T>> {
+    let mut opts: Vec<_> = set.iter_ordered().collect();
+
+    opts.sort_by_key(|opt| opt.display_order());
+    opts
+}
+
+/// The marker of command collecting the arguments after it verbatim,
+/// see [`trailing_raw`](Opt::trailing_raw).
+const TRAILING_RAW: &str = "[-- <args>...]";
+
+fn trailing_raw<O: Opt>(opt: &O) -> bool {
+    opt.mat_style(Style::Cmd) && opt.trailing_raw()
+}
+
+/// The name of block displaying the sub commands.
+const COMMAND_BLOCK: &str = "command";
+
+/// The name of block displaying the options of `group`.
+fn group_block(group: &str) -> String {
+    format!("group.{group}")
+}
+
+/// Same as [`display_set_help_ctx`], but write the help message to `writer`.
+///
+/// The options belonging to a [`group`](Opt::group) are displayed in a separate block
+/// titled by the group name.
+/// If the [`topic`](HelpContext::topic) of `ctx` names a group, only the options of that group are displayed.
+/// If the [`page_template`](HelpContext::page_template) of `ctx` is set, the page is rendered by [`PageTemplatePolicy`].
+/// If the [`required_marker`](HelpContext::required_marker) of `ctx` is set, it is appended to the help of force required options.
+pub fn write_set_help<T: Set, W: Write>(
+    set: &T,
+    ctx: &HelpContext,
+    writer: W,
+) -> Result<(), aopt_help::Error> {
+    let show_env = ctx
+        .show_env()
+        .unwrap_or_else(|| set.iter().any(|opt| opt.env().is_some()));
+    let topic = ctx
+        .topic()
+        .filter(|topic| set.iter().any(|opt| opt.group() == Some(topic)));
+    let mut groups = vec![];
+
+    for opt in display_ordered(set) {
+        if let Some(group) = opt.group() {
+            if !groups.contains(&group) && (topic.is_none() || topic == Some(group)) {
+                groups.push(group);
+            }
+        }
+    }
+    let total = set.iter().filter(|opt| opt.mat_style(Style::Cmd)).count();
+    let limit = ctx.subcommand_limit().unwrap_or(total);
+    let mut cmd_foot = String::default();
+    let cmd_hint = if set.iter().any(trailing_raw) {
+        format!("<COMMAND> {TRAILING_RAW}")
+    } else {
+        String::from("<COMMAND>")
+    };
+
+    if total > limit {
+        cmd_foot = format!(
+            "... and {} more (see `{} --help <COMMAND>`)",
+            total - limit,
+            ctx.name()
+        );
+    }
     let mut app_help = aopt_help::AppHelp::new(
-        name.into(),
-        head.into(),
-        foot.into(),
+        ctx.name().as_str(),
+        ctx.head().as_str(),
+        ctx.foot().as_str(),
         aopt_help::prelude::Style::default(),
-        std::io::stdout(),
-        max_width,
-        usage_width,
-    );
+        writer,
+        ctx.width(),
+        ctx.usagew(),
+    )
+    .with_before(ctx.before().as_str())
+    .with_after(ctx.after().as_str())
+    .with_name_column(ctx.name_column())
+    .with_usage_label(ctx.usage_label())
+    .with_usage_style(ctx.usage_style());
     let global = app_help.global_mut();
 
-    global.add_block(Block::new("command", "<COMMAND>", "", "Commands:", ""))?;
-    global.add_block(Block::new("option", "", "", "Options:", ""))?;
+    global.add_block(Block::new(
+        COMMAND_BLOCK,
+        cmd_hint.as_str(),
+        "",
+        ctx.commands_label(),
+        cmd_foot.as_str(),
+    ))?;
+    global.add_block(Block::new("option", "", "", ctx.options_label(), ""))?;
+    for group in groups.iter() {
+        global.add_block(Block::new(
+            Cow::from(group_block(group)),
+            Cow::default(),
+            Cow::default(),
+            Cow::from(format!("{group}:")),
+            Cow::default(),
+        ))?;
+    }
     global.add_block(Block::new("args", "[ARGS]", "", "Args:", ""))?;
-    for opt in set.iter() {
+    let mut cmds = 0;
+
+    for opt in display_ordered(set) {
+        if topic.is_some() && opt.group() != topic {
+            continue;
+        }
         if opt.mat_style(Style::Pos) {
             global.add_store(
                 "args",
                 Store::new(
                     Cow::from(opt.name()),
                     Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
+                    opt_help_text(opt, show_env),
                     Cow::default(),
                     !opt.force(),
                     true,
                 ),
             )?;
         } else if opt.mat_style(Style::Cmd) {
+            cmds += 1;
+            if cmds > limit {
+                continue;
+            }
+            let hint = if trailing_raw(opt) {
+                Cow::from(format!("{} {TRAILING_RAW}", opt.hint()))
+            } else {
+                Cow::from(opt.hint())
+            };
+
             global.add_store(
                 "command",
                 Store::new(
                     Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
+                    hint,
+                    opt_help_text(opt, show_env),
                     Cow::default(),
                     !opt.force(),
                     true,
@@ -142,12 +742,22 @@ pub fn display_set_help<'a, T: Set>(
             || opt.mat_style(Style::Combined)
             || opt.mat_style(Style::Flag)
         {
+            let block = opt.group().map(group_block);
+            // the custom hint or the hint of number format already has a metavar
+            let default = opt.hint() == default_hint(opt.name(), opt.index(), opt.alias());
+            let hint = match ctx.type_metavar(opt.r#type()) {
+                Some(metavar) if default && opt.mat_style(Style::Argument) => {
+                    Cow::from(format!("{} {metavar}", opt.hint()))
+                }
+                _ => Cow::from(opt.hint()),
+            };
+
             global.add_store(
-                "option",
+                block.unwrap_or_else(|| String::from("option")),
                 Store::new(
                     Cow::from(opt.name()),
-                    Cow::from(opt.hint()),
-                    Cow::from(opt.help()),
+                    hint,
+                    mark_required(opt, opt_help_text(opt, show_env), ctx.required_marker()),
                     Cow::default(),
                     !opt.force(),
                     false,
@@ -156,7 +766,17 @@ pub fn display_set_help<'a, T: Set>(
         }
     }
 
-    app_help.display(true)?;
+    match ctx.page_template() {
+        Some(template) => {
+            let policy = DefaultAppPolicy::new(vec![], ctx.width(), true, ctx.usagew())
+                .with_name_column(ctx.name_column())
+                .with_usage_label(ctx.usage_label())
+                .with_usage_style(ctx.usage_style());
+
+            app_help.display_with(PageTemplatePolicy::new(template, policy))?;
+        }
+        None => app_help.display(true)?,
+    }
 
     Ok(())
 }
@@ -164,6 +784,30 @@ pub fn display_set_help<'a, T: Set>(
 /// Using for cote-derive display help message.
 #[macro_export]
 macro_rules! display_help {
+    ($set:ident, ctx = $ctx:expr) => {{
+        fn __check_set<S: aopt::prelude::Set>(a: &S) -> &S {
+            a
+        }
+
+        $crate::help::display_set_help_ctx(__check_set($set), $ctx)
+            .map_err(|e| aopt::Error::raise_error(format!("can not show help message: {:?}", e)))
+    }};
+    ($set:ident, $name:expr, $head:expr, $foot:expr, $width:expr, $usage_width:expr, env = $show_env:expr) => {{
+        fn __check_set<S: aopt::prelude::Set>(a: &S) -> &S {
+            a
+        }
+
+        $crate::help::display_set_help_env(
+            __check_set($set),
+            $name,
+            $head,
+            $foot,
+            $width,
+            $usage_width,
+            $show_env,
+        )
+        .map_err(|e| aopt::Error::raise_error(format!("can not show help message: {:?}", e)))
+    }};
     ($set:ident, $name:expr, $head:expr, $foot:expr, $width:expr, $usage_width:expr) => {{
         fn __check_set<S: aopt::prelude::Set>(a: &S) -> &S {
             a
@@ -191,3 +835,349 @@ macro_rules! display_help {
             .map_err(|e| aopt::Error::raise_error(format!("can not show help message: {:?}", e)))
     }};
 }
+
+#[cfg(test)]
+mod test {
+    use super::format_default;
+    use super::opt_help_text;
+    use super::write_set_help;
+    use super::HelpContext;
+    use super::UsageStyle;
+    use aopt::prelude::*;
+
+    #[test]
+    fn test_opt_help_env() {
+        assert!(test_opt_help_env_impl().is_ok());
+    }
+
+    fn test_opt_help_env_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        let token = set
+            .add_opt("--token=s")?
+            .set_help("Api token")
+            .set_env("API_TOKEN")
+            .run()?;
+        let name = set.add_opt("--name=s")?.set_help("User name").run()?;
+
+        let token = set.opt(token)?;
+        let name = set.opt(name)?;
+
+        assert_eq!(opt_help_text(token, true), "Api token [env: API_TOKEN]");
+        assert_eq!(opt_help_text(token, false), "Api token");
+        assert_eq!(opt_help_text(name, true), "User name");
+        assert_eq!(opt_help_text(name, false), "User name");
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_column() {
+        assert!(test_name_column_impl().is_ok());
+    }
+
+    fn test_name_column_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+        set.add_opt("--a-very-long-option-name=s")?
+            .set_help("Option with long name")
+            .run()?;
+
+        let ctx = HelpContext::default().with_name("app").with_name_column(12);
+        let mut output = vec![];
+
+        write_set_help(&set, &ctx, &mut output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+
+        let output = String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+        let lines: Vec<&str> = output.lines().collect();
+        let column = |help: &str| {
+            lines
+                .iter()
+                .find_map(|line| line.find(help))
+                .unwrap_or_default()
+        };
+
+        assert!(lines
+            .iter()
+            .any(|line| line.trim_end() == "  --a-very-long-option-name"));
+        assert_eq!(column("Print debug message"), 2 + 12 + 4 + 2);
+        assert_eq!(
+            column("Option with long name"),
+            column("Print debug message")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_subcommand_limit() {
+        assert!(test_subcommand_limit_impl().is_ok());
+    }
+
+    fn test_subcommand_limit_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        for name in ["add", "build", "check", "doc", "fetch"] {
+            set.add_opt(format!("{name}=c"))?
+                .set_help(format!("Run {name} command"))
+                .run()?;
+        }
+
+        let help = |limit| -> Result<String, aopt::Error> {
+            let ctx = HelpContext::default()
+                .with_name("app")
+                .with_subcommand_limit(limit);
+            let mut output = vec![];
+
+            write_set_help(&set, &ctx, &mut output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+            String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))
+        };
+        let output = help(Some(2))?;
+
+        assert!(output.contains("Run add command"));
+        assert!(output.contains("Run build command"));
+        assert!(!output.contains("Run check command"));
+        assert!(!output.contains("Run fetch command"));
+        assert!(output.contains("... and 3 more (see `app --help <COMMAND>`)"));
+
+        let output = help(None)?;
+
+        assert!(output.contains("Run fetch command"));
+        assert!(!output.contains("more (see"));
+
+        let output = help(Some(5))?;
+
+        assert!(output.contains("Run fetch command"));
+        assert!(!output.contains("more (see"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_default() {
+        assert_eq!(format_default(&"default.json"), "default.json");
+        assert_eq!(format_default(&String::from("say \"hi\"")), "say \"hi\"");
+        assert_eq!(format_default(&vec!["a", "b", "c"]), "a,b,c");
+        assert_eq!(format_default(&vec!["a,b", "c"]), "a,b,c");
+        assert_eq!(format_default(&Vec::<String>::new()), "");
+        assert_eq!(format_default(&true), "true");
+        assert_eq!(format_default(&42i64), "42");
+        assert_eq!(format_default(&'x'), "x");
+        assert_eq!(format_default(&std::path::PathBuf::from("/tmp")), "/tmp");
+        assert_eq!(format_default(&Some("foo")), "foo");
+        assert_eq!(format_default(&None::<i64>), "");
+    }
+
+    #[test]
+    fn test_trailing_raw_usage() {
+        assert!(test_trailing_raw_usage_impl().is_ok());
+    }
+
+    fn test_trailing_raw_usage_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+        set.add_opt("run=c")?
+            .set_help("Run the program with arguments")
+            .set_trailing_raw(true)
+            .run()?;
+        set.add_opt("list=c")?.set_help("List the programs").run()?;
+
+        let ctx = HelpContext::default().with_name("app");
+        let mut output = vec![];
+
+        write_set_help(&set, &ctx, &mut output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+
+        let output = String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+        let usage = output.lines().find(|v| v.starts_with("Usage:")).unwrap();
+
+        assert!(usage.contains("<COMMAND> [-- <args>...]"));
+        assert!(output.contains("run@1 [-- <args>...]"));
+        assert!(!output.contains("list@1 [-- <args>...]"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_page_template() {
+        assert!(test_page_template_impl().is_ok());
+    }
+
+    fn test_page_template_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+        set.add_opt("list=c")?.set_help("List the programs").run()?;
+
+        let ctx = HelpContext::default()
+            .with_name("app")
+            .with_head("A simple application")
+            .with_foot("Create by araraloren")
+            .with_page_template("{head}\n{subcommands}\n{options}\n{usage}\n{foot}");
+        let mut output = vec![];
+
+        write_set_help(&set, &ctx, &mut output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+
+        let output = String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+        let position = |text: &str| output.find(text).unwrap();
+
+        assert!(output.starts_with("A simple application\n"));
+        assert!(output.ends_with("Create by araraloren\n"));
+        assert!(position("Commands:") < position("Options:"));
+        assert!(position("List the programs") < position("Print debug message"));
+        assert!(position("Print debug message") < position("Usage: app [OPTIONS]"));
+        assert!(!output.contains('{'));
+        Ok(())
+    }
+
+    #[test]
+    fn test_required_marker() {
+        assert!(test_required_marker_impl().is_ok());
+    }
+
+    fn test_required_marker_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--input=s")?
+            .set_help("Input file")
+            .set_force(true)
+            .run()?;
+        set.add_opt("--output=s")?.set_force(true).run()?;
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+
+        let help = |ctx: HelpContext| -> Result<String, aopt::Error> {
+            let mut output = vec![];
+
+            write_set_help(&set, &ctx, &mut output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+            String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))
+        };
+        let line = |output: &str, name: &str| {
+            output
+                .lines()
+                .find(|v| v.trim_start().starts_with(name))
+                .map(|v| v.trim_end().to_owned())
+                .unwrap_or_default()
+        };
+        let output = help(
+            HelpContext::default()
+                .with_name("app")
+                .with_required_marker("(required)"),
+        )?;
+
+        assert!(line(&output, "--input").ends_with("Input file (required)"));
+        assert!(line(&output, "--output").ends_with("(required)"));
+        assert!(line(&output, "--debug").ends_with("Print debug message"));
+
+        let output = help(
+            HelpContext::default()
+                .with_name("app")
+                .with_required_marker("*"),
+        )?;
+
+        assert!(line(&output, "--input").ends_with("Input file *"));
+        assert!(!line(&output, "--debug").contains('*'));
+
+        let output = help(HelpContext::default().with_name("app"))?;
+
+        assert!(line(&output, "--input").ends_with("Input file"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_labels() {
+        assert!(test_labels_impl().is_ok());
+    }
+
+    fn test_labels_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+        set.add_opt("--name=s")?.set_help("Set the name").run()?;
+        set.add_opt("list=c")?.set_help("List the programs").run()?;
+
+        let help = |ctx: HelpContext| -> Result<String, aopt::Error> {
+            let mut output = vec![];
+
+            write_set_help(&set, &ctx, &mut output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+            String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))
+        };
+        let output = help(HelpContext::default().with_name("app"))?;
+
+        assert!(output.contains("Usage: app [OPTIONS]"));
+        assert!(output.contains("Commands:"));
+        assert!(output.contains("Options:"));
+
+        let output = help(
+            HelpContext::default()
+                .with_name("app")
+                .with_usagew(1)
+                .with_usage_style(UsageStyle::Full)
+                .with_usage_label("Uso:")
+                .with_options_label("Opciones:")
+                .with_commands_label("Comandos:"),
+        )?;
+
+        assert!(output.contains("Uso: app [--debug]\n     [--name]"));
+        assert!(output.contains("Comandos:"));
+        assert!(output.contains("Opciones:"));
+        assert!(!output.contains("Usage:"));
+        assert!(!output.contains("Options:"));
+        assert!(!output.contains("Commands:"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_usage_style() {
+        assert!(test_usage_style_impl().is_ok());
+    }
+
+    fn test_usage_style_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+        set.add_opt("--name=s")?.set_help("Set the name").run()?;
+        set.add_opt("--input=s")?
+            .set_help("Input file")
+            .set_force(true)
+            .run()?;
+        set.add_opt("--jobs=i")?.set_help("Set the jobs").run()?;
+        set.add_opt("list=c")?.set_help("List the programs").run()?;
+
+        let usage = |ctx: HelpContext| -> Result<String, aopt::Error> {
+            let mut output = vec![];
+
+            write_set_help(&set, &ctx.with_name("app"), &mut output)
+                .map_err(|e| aopt::raise_error!("{e:?}"))?;
+            let output = String::from_utf8(output).map_err(|e| aopt::raise_error!("{e:?}"))?;
+
+            Ok(output
+                .lines()
+                .find(|v| v.starts_with("Usage:"))
+                .map(|v| v.trim_end().to_owned())
+                .unwrap_or_default())
+        };
+
+        assert_eq!(
+            usage(HelpContext::default())?,
+            "Usage: app [OPTIONS] <--input> <COMMAND>"
+        );
+        assert_eq!(
+            usage(HelpContext::default().with_usage_style(UsageStyle::Short))?,
+            "Usage: app [OPTIONS] <--input> <COMMAND>"
+        );
+        assert_eq!(
+            usage(HelpContext::default().with_usage_style(UsageStyle::Full))?,
+            "Usage: app [--debug] [--name] <--input> [--jobs] <COMMAND>"
+        );
+        Ok(())
+    }
+}
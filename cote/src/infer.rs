@@ -1,3 +1,6 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::ffi::OsString;
 use std::io::Stdin;
 use std::path::PathBuf;
@@ -96,6 +99,10 @@ infer_override!(usize);
 infer_override!(String);
 infer_override!(PathBuf);
 infer_override!(OsString);
+#[cfg(feature = "time")]
+infer_override!(time::OffsetDateTime);
+#[cfg(feature = "time")]
+infer_override!(time::Date);
 
 infer_override!(());
 
@@ -112,3 +119,9 @@ impl<T, E> InferOverride for Result<T, E> {
 }
 
 impl<T> InferOverride for Vec<T> {}
+
+impl<T> InferOverride for VecDeque<T> {}
+
+impl<T> InferOverride for HashSet<T> {}
+
+impl<T> InferOverride for BTreeSet<T> {}
@@ -7,10 +7,12 @@ pub(crate) mod parser;
 pub(crate) mod rctx;
 pub(crate) mod value;
 
+pub mod man;
 pub mod valid;
 
 pub use aopt;
 pub use aopt::Error;
+pub use aopt::ErrorKind;
 pub use aopt_help;
 pub use cote_derive;
 
@@ -31,6 +33,7 @@ pub mod prelude {
     pub use aopt::prelude::ASer;
     pub use aopt::prelude::ASet;
     pub use aopt::prelude::Action;
+    pub use aopt::prelude::AnyValue;
     pub use aopt::prelude::Args;
     pub use aopt::prelude::Commit;
     pub use aopt::prelude::ConfigBuild;
@@ -76,6 +79,8 @@ pub mod prelude {
     pub use aopt::prelude::VecStore;
     pub use aopt::raise_error;
     pub use aopt::raise_failure;
+    pub use aopt::value::raw2os;
+    pub use aopt::value::raw2path;
     pub use aopt::value::raw2str;
     pub use aopt::value::Placeholder;
     pub use aopt::value::Stop;
@@ -85,26 +90,38 @@ pub mod prelude {
     pub use cote_derive::CoteOpt;
     pub use cote_derive::CoteVal;
 
+    pub use crate::help::arg0_name;
     pub use crate::help::display_set_help;
+    pub use crate::help::display_set_help_ctx;
+    pub use crate::help::display_set_help_env;
+    pub use crate::help::format_default;
+    pub use crate::help::write_set_help;
+    pub use crate::help::FormatDefault;
     pub use crate::help::HelpContext;
+    pub use crate::help::PageTemplatePolicy;
     pub use crate::infer::InferOverride;
     pub use crate::meta::OptionMeta;
+    pub use crate::parser::OnParsed;
     pub use crate::parser::Parser;
     pub use crate::rctx::Failure;
     pub use crate::rctx::Frame;
     pub use crate::rctx::RunningCtx;
     pub use crate::valid;
+    pub use crate::value::fetch_forward_impl;
     pub use crate::value::fetch_uid_impl;
     pub use crate::value::fetch_vec_uid_impl;
     pub use crate::value::Fetch;
+    pub use crate::CoteApp;
     pub use crate::CoteRes;
     pub use crate::DelayPolicy;
     pub use crate::ExtractFromSetDerive;
     pub use crate::FwdPolicy;
     pub use crate::IntoParserDerive;
     pub use crate::NullPolicy;
+    pub use crate::ParseOutcome;
     pub use crate::PrePolicy;
     pub use crate::Status;
+    pub use aopt_help::prelude::UsageStyle;
 }
 
 use crate::prelude::Parser;
@@ -114,7 +131,9 @@ use aopt::ext::APolicyExt;
 use aopt::parser::DefaultSetChecker;
 use aopt::parser::Policy;
 use aopt::parser::PolicySettings;
+use aopt::parser::PromptHandler;
 use aopt::parser::Return;
+use aopt::parser::UnknownHandler;
 use aopt::parser::UserStyle;
 use aopt::prelude::ConfigValue;
 use aopt::prelude::OptParser;
@@ -123,6 +142,8 @@ use aopt::prelude::OptValidator;
 use aopt::prelude::ServicesValExt;
 use aopt::prelude::SetCfg;
 use aopt::prelude::SetValueFindExt;
+use std::ffi::OsString;
+use std::fmt::Display;
 use std::marker::PhantomData;
 
 pub trait IntoParserDerive<'inv, Set, Ser>
@@ -148,6 +169,131 @@ where
         Self: Sized;
 }
 
+/// Parse the command line arguments and run the main function of application,
+/// print the error to stderr and exit with a non-zero code if any failed.
+///
+/// It is implemented by `Cote` derive macro.
+/// The help message is displayed during parsing, and exit with `0` if needed.
+pub trait CoteApp: Sized {
+    fn parse_app(args: Args) -> Result<Self>;
+
+    /// Parse the arguments from any iterator, the first item is the name of program.
+    fn parse_from<I, T>(iter: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        Self::parse_app(Args::from(iter))
+    }
+
+    /// Return the short usage line of application, such as `Usage: app [--debug]`.
+    fn usage() -> Option<String> {
+        None
+    }
+
+    /// Format the error returned by [`parse_app`](CoteApp::parse_app) with its causes,
+    /// the [`usage`](CoteApp::usage) line is appended if it is a [usage error](Error::is_usage),
+    /// such as bad flags or invalid values.
+    /// The [usage of sub command](Error::usage) is preferred if the error occurred in sub command.
+    fn format_error(error: &Error) -> String {
+        let mut cause = error.caused_by();
+        let mut message = error.to_string();
+
+        while let Some(error) = cause {
+            message.push_str(&format!("\n  caused by: {error}"));
+            cause = error.caused_by();
+        }
+        if error.is_usage() {
+            if let Some(usage) = error.usage().map(String::from).or_else(Self::usage) {
+                message.push_str(&format!("\n\n{usage}"));
+            }
+        }
+        message
+    }
+
+    /// Parse the arguments from [`Args::from_env`] and call `main`.
+    ///
+    /// Exit with [`Error::exit_code`] if parsing failed, or `1` if `main` returned an error.
+    fn run<F, E>(main: F) -> !
+    where
+        F: FnOnce(Self) -> std::result::Result<(), E>,
+        E: Display,
+    {
+        Self::run_with_mapper(Error::exit_code, main)
+    }
+
+    /// Same as [`run`](CoteApp::run), exit with `code` if any failed.
+    fn run_with_code<F, E>(code: i32, main: F) -> !
+    where
+        F: FnOnce(Self) -> std::result::Result<(), E>,
+        E: Display,
+    {
+        std::process::exit(Self::run_with_args(Args::from_env(), code, main))
+    }
+
+    /// Same as [`run`](CoteApp::run), exit with the code returned by `mapper` if parsing failed.
+    fn run_with_mapper<M, F, E>(mapper: M, main: F) -> !
+    where
+        M: FnOnce(&Error) -> i32,
+        F: FnOnce(Self) -> std::result::Result<(), E>,
+        E: Display,
+    {
+        std::process::exit(Self::run_with_args_mapper(Args::from_env(), mapper, main))
+    }
+
+    /// Parse the `args` and call `main`, return the exit code instead of exit the process.
+    ///
+    /// Return `0` if everything is ok, otherwise print the error and return `code`,
+    /// the `code` `0` is replaced with `1`.
+    fn run_with_args<F, E>(args: Args, code: i32, main: F) -> i32
+    where
+        F: FnOnce(Self) -> std::result::Result<(), E>,
+        E: Display,
+    {
+        let code = failure_code(code);
+
+        match Self::parse_app(args) {
+            Ok(app) => main(app).map_or_else(|e| print_error(&e, code), |_| 0),
+            Err(e) => print_error(&Self::format_error(&e), code),
+        }
+    }
+
+    /// Parse the `args` and call `main`, return the exit code instead of exit the process.
+    ///
+    /// Return `0` if everything is ok, otherwise print the error and return the code returned by `mapper`
+    /// if parsing failed, or `1` if `main` returned an error.
+    /// The code `0` returned by `mapper` is replaced with `1`.
+    fn run_with_args_mapper<M, F, E>(args: Args, mapper: M, main: F) -> i32
+    where
+        M: FnOnce(&Error) -> i32,
+        F: FnOnce(Self) -> std::result::Result<(), E>,
+        E: Display,
+    {
+        match Self::parse_app(args) {
+            Ok(app) => main(app).map_or_else(|e| print_error(&e, 1), |_| 0),
+            Err(e) => {
+                let code = failure_code(mapper(&e));
+
+                print_error(&Self::format_error(&e), code)
+            }
+        }
+    }
+}
+
+/// The exit code of failure should be non-zero.
+fn failure_code(code: i32) -> i32 {
+    if code == 0 {
+        1
+    } else {
+        code
+    }
+}
+
+fn print_error<E: Display>(error: &E, code: i32) -> i32 {
+    eprintln!("error: {error}");
+    code
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CoteRes<P, Policy>
 where
@@ -160,6 +306,60 @@ where
     pub parser: Policy::Set,
 }
 
+/// The result of `try_parse` generated by `Cote` derive macro,
+/// the help message or the text of info option is returned instead of displaying it and exiting the process.
+#[derive(Debug)]
+pub enum ParseOutcome<T> {
+    /// Parsing successful.
+    Parsed(T),
+
+    /// The help message need to be displayed.
+    Help(String),
+
+    /// The text of info option, such as `--license` or `--version`, need to be displayed.
+    Info(String),
+
+    /// Parsing failed.
+    Err(Error),
+}
+
+impl<T> ParseOutcome<T> {
+    pub fn is_parsed(&self) -> bool {
+        matches!(self, Self::Parsed(_))
+    }
+
+    pub fn is_help(&self) -> bool {
+        matches!(self, Self::Help(_))
+    }
+
+    pub fn is_info(&self) -> bool {
+        matches!(self, Self::Info(_))
+    }
+
+    pub fn is_err(&self) -> bool {
+        matches!(self, Self::Err(_))
+    }
+
+    /// Return the parsed value, or an error if parsing is not finished.
+    pub fn into_result(self) -> Result<T> {
+        match self {
+            Self::Parsed(value) => Ok(value),
+            Self::Help(_) => Err(aopt::raise_error!("parsing stopped by help message")),
+            Self::Info(_) => Err(aopt::raise_error!("parsing stopped by info message")),
+            Self::Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T> From<Result<T>> for ParseOutcome<T> {
+    fn from(value: Result<T>) -> Self {
+        match value {
+            Ok(value) => Self::Parsed(value),
+            Err(e) => Self::Err(e),
+        }
+    }
+}
+
 pub trait Status {
     fn status(&self) -> bool;
 }
@@ -266,6 +466,38 @@ impl<Set, Ser> PolicySettings for NullPolicy<'_, Set, Ser> {
     fn set_overload(&mut self, _: bool) -> &mut Self {
         self
     }
+
+    fn set_max_positionals(&mut self, _: Option<usize>) -> &mut Self {
+        self
+    }
+
+    fn set_allow_abbrev_subcommand(&mut self, _: bool) -> &mut Self {
+        self
+    }
+
+    fn set_intermixed(&mut self, _: bool) -> &mut Self {
+        self
+    }
+
+    fn set_option_terminator(&mut self, _: Option<String>) -> &mut Self {
+        self
+    }
+
+    fn set_diagnostics(&mut self, _: bool) -> &mut Self {
+        self
+    }
+
+    fn set_unknown_handler(&mut self, _: Option<UnknownHandler>) -> &mut Self {
+        self
+    }
+
+    fn set_prompt_handler(&mut self, _: Option<PromptHandler>) -> &mut Self {
+        self
+    }
+
+    fn set_parallel_checks(&mut self, _: bool) -> &mut Self {
+        self
+    }
 }
 
 impl<'inv, Set, Ser> APolicyExt<NullPolicy<'inv, Set, Ser>> for NullPolicy<'inv, Set, Ser>
@@ -374,7 +606,7 @@ mod test {
         use crate as cote;
         use aopt::opt::Pos;
 
-        #[derive(Debug, Cote)]
+        #[derive(Debug, Clone, Cote)]
         #[cote(policy = delay, help, on = find_main, name = "find")]
         pub struct Find {
             /// Do not follow symbolic link
@@ -472,7 +704,7 @@ mod test {
             find: Option<Find>,
         }
 
-        #[derive(Debug, Cote, PartialEq)]
+        #[derive(Debug, Clone, Cote, PartialEq)]
         #[cote(help)]
         pub struct List {
             #[arg(help = "list all the file")]
@@ -485,7 +717,7 @@ mod test {
             path: Pos<PathBuf>,
         }
 
-        #[derive(Debug, Cote, PartialEq)]
+        #[derive(Debug, Clone, Cote, PartialEq)]
         #[cote(help)]
         pub struct Find {
             recursive: bool,
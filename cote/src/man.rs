@@ -0,0 +1,212 @@
+//! Generate the man page in roff format.
+//!
+//! The output contains the `NAME`, `SYNOPSIS`, `DESCRIPTION`, `OPTIONS`, `ARGS` and `SUBCOMMANDS`
+//! sections, each option is written as a `.TP` paragraph with its hint and help message.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use cote::prelude::*;
+//! #
+//! #[derive(Debug, Cote)]
+//! #[cote(help)]
+//! pub struct Cli {
+//!     /// Print debug message
+//!     debug: bool,
+//!
+//!     /// Set the name of user
+//!     #[pos()]
+//!     name: String,
+//! }
+//!
+//! # fn main() -> color_eyre::Result<()> {
+//! let parser = Cli::into_parser()?;
+//! let ctx = HelpContext::default()
+//!     .with_name("cli")
+//!     .with_head("A simple command line tool");
+//! let page = cote::man::generate(&parser, &ctx);
+//!
+//! assert!(page.starts_with(".TH \"CLI\" 1\n"));
+//! assert!(page.contains(".SH OPTIONS\n"));
+//! assert!(page.contains("Print debug message\n"));
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Write;
+
+use aopt::opt::Opt;
+use aopt::opt::Style;
+use aopt::set::Set;
+
+use crate::help::display_ordered;
+use crate::help::opt_help_text;
+use crate::help::HelpContext;
+use crate::parser::Parser;
+
+/// Generate the man page of `parser`, the name, head and foot are taken from `ctx`.
+pub fn generate<Set, Ser>(parser: &Parser<'_, Set, Ser>, ctx: &HelpContext) -> String
+where
+    Set: aopt::set::Set,
+{
+    generate_set(parser.optset(), ctx)
+}
+
+/// Generate the man page of the options in `set`, the name, head and foot are taken from `ctx`.
+pub fn generate_set<T: Set>(set: &T, ctx: &HelpContext) -> String {
+    let show_env = ctx
+        .show_env()
+        .unwrap_or_else(|| set.iter().any(|opt| opt.env().is_some()));
+    let name = ctx.name();
+    let mut options = vec![];
+    let mut args = vec![];
+    let mut cmds = vec![];
+
+    for opt in display_ordered(set) {
+        if opt.mat_style(Style::Pos) {
+            args.push(opt);
+        } else if opt.mat_style(Style::Cmd) {
+            cmds.push(opt);
+        } else if opt.mat_style(Style::Argument)
+            || opt.mat_style(Style::Boolean)
+            || opt.mat_style(Style::Combined)
+            || opt.mat_style(Style::Flag)
+        {
+            options.push(opt);
+        }
+    }
+
+    let mut page = String::default();
+    let mut synopsis = format!("\\fB{}\\fR", escape(name));
+
+    if !options.is_empty() {
+        synopsis.push_str(" [OPTIONS]");
+    }
+    if !cmds.is_empty() {
+        synopsis.push_str(" <COMMAND>");
+    }
+    if !args.is_empty() {
+        synopsis.push_str(" [ARGS]");
+    }
+
+    // ignore the error, write to String never fail
+    let _ = writeln!(page, ".TH \"{}\" 1", escape(&name.to_uppercase()));
+    let _ = writeln!(page, ".SH NAME");
+    match ctx.head().lines().next().filter(|v| !v.trim().is_empty()) {
+        Some(summary) => {
+            let _ = writeln!(page, "{} \\- {}", escape(name), escape(summary.trim()));
+        }
+        None => {
+            let _ = writeln!(page, "{}", escape(name));
+        }
+    }
+    let _ = writeln!(page, ".SH SYNOPSIS\n{synopsis}");
+    if !ctx.head().trim().is_empty() {
+        let _ = writeln!(page, ".SH DESCRIPTION");
+        write_text(&mut page, ctx.head());
+    }
+    for (title, opts) in [("OPTIONS", options), ("ARGS", args), ("SUBCOMMANDS", cmds)] {
+        if !opts.is_empty() {
+            let _ = writeln!(page, ".SH {title}");
+            for opt in opts {
+                let tag = if opt.mat_style(Style::Cmd) {
+                    opt.name()
+                } else {
+                    opt.hint()
+                };
+
+                let _ = writeln!(page, ".TP\n\\fB{}\\fR", escape(tag));
+                write_text(&mut page, &opt_help_text(opt, show_env));
+            }
+        }
+    }
+    if !ctx.foot().trim().is_empty() {
+        let _ = writeln!(page, ".SH NOTES");
+        write_text(&mut page, ctx.foot());
+    }
+    page
+}
+
+fn write_text(page: &mut String, text: &str) {
+    let mut paragraph = false;
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            paragraph = true;
+        } else {
+            if paragraph {
+                page.push_str(".PP\n");
+                paragraph = false;
+            }
+            page.push_str(&escape(line.trim()));
+            page.push('\n');
+        }
+    }
+}
+
+/// Escape the roff special characters of `text`.
+pub fn escape(text: &str) -> String {
+    let mut ret = String::with_capacity(text.len());
+
+    if text.starts_with('.') || text.starts_with('\'') {
+        ret.push_str("\\&");
+    }
+    for ch in text.chars() {
+        match ch {
+            '\\' => ret.push_str("\\e"),
+            '-' => ret.push_str("\\-"),
+            _ => ret.push(ch),
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::escape;
+    use super::generate_set;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_man() {
+        assert!(test_man_impl().is_ok());
+    }
+
+    fn test_man_impl() -> Result<(), aopt::Error> {
+        let mut set = ASet::default();
+
+        set.add_opt("--count=i")?
+            .add_alias("-c")
+            .set_hint("-c, --count <NUM>")
+            .set_help("Set the count of repeat")
+            .run()?;
+        set.add_opt("--debug=b")?
+            .set_help("Print debug message")
+            .run()?;
+        set.add_opt("--token=s")?
+            .set_help("Api token")
+            .set_env("API_TOKEN")
+            .run()?;
+        set.add_opt("file=p@1")?.set_help("Input file").run()?;
+        set.add_opt("list=c")?.set_help("List all the item").run()?;
+
+        let ctx = HelpContext::default()
+            .with_name("app")
+            .with_head("Simple application\n\nA longer description.")
+            .with_foot("Create by araraloren v0.1.0");
+        let page = generate_set(&set, &ctx);
+
+        assert!(page.starts_with(".TH \"APP\" 1\n.SH NAME\napp \\- Simple application\n"));
+        assert!(page.contains(".SH SYNOPSIS\n\\fBapp\\fR [OPTIONS] <COMMAND> [ARGS]\n"));
+        assert!(page.contains(".SH DESCRIPTION\nSimple application\n.PP\nA longer description.\n"));
+        assert!(page.contains(".SH OPTIONS\n"));
+        assert!(page.contains(".TP\n\\fB\\-c, \\-\\-count <NUM>\\fR\nSet the count of repeat\n"));
+        assert!(page.contains(".TP\n\\fB\\-\\-token\\fR\nApi token [env: API_TOKEN]\n"));
+        assert!(page.contains(".SH ARGS\n.TP\n\\fBfile@1\\fR\nInput file\n"));
+        assert!(page.contains(".SH SUBCOMMANDS\n.TP\n\\fBlist\\fR\nList all the item\n"));
+        assert!(page.contains(".SH NOTES\nCreate by araraloren v0.1.0\n"));
+        assert_eq!(page.matches(".TP\n").count(), 5);
+        assert_eq!(escape(".foo-bar\\"), "\\&.foo\\-bar\\e");
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@ use aopt::prelude::Policy;
 use aopt::prelude::PolicyParser;
 use aopt::prelude::SetCfg;
 use aopt::prelude::SetOpt;
+use aopt::prelude::Style;
 use aopt::raise_error;
 use aopt::ser::ServicesValExt;
 use aopt::set::PrefixedValidator;
@@ -27,14 +28,47 @@ use aopt::Uid;
 use crate::prelude::HelpContext;
 use crate::prelude::RunningCtx;
 use crate::ExtractFromSetDerive;
+use crate::Status;
+
+/// The hook invoked after the arguments parsed successfully, see [`on_parsed`](Parser::on_parsed).
+#[cfg(feature = "sync")]
+pub type OnParsed<'a, Set, Ser> =
+    Box<dyn FnOnce(&Parser<'a, Set, Ser>) -> Result<(), Error> + Send + Sync + 'a>;
+
+/// The hook invoked after the arguments parsed successfully, see [`on_parsed`](Parser::on_parsed).
+#[cfg(not(feature = "sync"))]
+pub type OnParsed<'a, Set, Ser> = Box<dyn FnOnce(&Parser<'a, Set, Ser>) -> Result<(), Error> + 'a>;
 
-#[derive(Debug)]
 pub struct Parser<'a, Set, Ser> {
     name: String,
+    bin_name: Option<String>,
     set: Set,
     ser: Option<Ser>,
     inv: Option<Invoker<'a, Self, Ser>>,
     sub_parsers: Vec<Self>,
+    on_parsed: Option<OnParsed<'a, Set, Ser>>,
+    exactly_one: Vec<(String, Vec<Uid>)>,
+    help_option: Option<String>,
+}
+
+impl<Set, Ser> std::fmt::Debug for Parser<'_, Set, Ser>
+where
+    Set: std::fmt::Debug,
+    Ser: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Parser")
+            .field("name", &self.name)
+            .field("bin_name", &self.bin_name)
+            .field("set", &self.set)
+            .field("ser", &self.ser)
+            .field("inv", &self.inv)
+            .field("sub_parsers", &self.sub_parsers)
+            .field("on_parsed", &self.on_parsed.is_some())
+            .field("exactly_one", &self.exactly_one)
+            .field("help_option", &self.help_option)
+            .finish()
+    }
 }
 
 impl<Set, Ser> Default for Parser<'_, Set, Ser>
@@ -45,10 +79,14 @@ where
     fn default() -> Self {
         Self {
             name: String::from("CoteParser"),
+            bin_name: None,
             set: Default::default(),
             ser: Some(Ser::default()),
             inv: Some(Invoker::default()),
             sub_parsers: Default::default(),
+            on_parsed: None,
+            exactly_one: vec![],
+            help_option: None,
         }
     }
 }
@@ -57,10 +95,14 @@ impl<'a, Set, Ser> Parser<'a, Set, Ser> {
     pub fn new(name: impl Into<String>, set: Set) -> Self {
         Self {
             name: name.into(),
+            bin_name: None,
             set,
             ser: None,
             inv: None,
             sub_parsers: vec![],
+            on_parsed: None,
+            exactly_one: vec![],
+            help_option: None,
         }
     }
 
@@ -78,6 +120,37 @@ impl<'a, Set, Ser> Parser<'a, Set, Ser> {
         self
     }
 
+    /// Return the name displayed in help message, `None` if it is not overridden.
+    pub fn bin_name(&self) -> Option<&str> {
+        self.bin_name.as_deref()
+    }
+
+    /// Override the name displayed in help message and usage, such as the binary is invoked by a symlink.
+    /// The sub parsers are still matched by their own name.
+    pub fn with_bin_name(mut self, name: impl Into<String>) -> Self {
+        self.bin_name = Some(name.into());
+        self
+    }
+
+    /// Override the name displayed in help message and usage, see [`with_bin_name`](Self::with_bin_name).
+    pub fn set_bin_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.bin_name = Some(name.into());
+        self
+    }
+
+    /// Set the name of help option, the [`HelpRequested`](aopt::parser::ParseStatus::HelpRequested)
+    /// is set if it matched, see [`help_option`](aopt::set::Set::help_option).
+    pub fn with_help_option(mut self, name: impl Into<String>) -> Self {
+        self.help_option = Some(name.into());
+        self
+    }
+
+    /// Set the name of help option, see [`with_help_option`](Self::with_help_option).
+    pub fn set_help_option(&mut self, name: Option<String>) -> &mut Self {
+        self.help_option = name;
+        self
+    }
+
     pub fn optset(&self) -> &Set {
         &self.set
     }
@@ -164,6 +237,64 @@ impl<'a, Set, Ser> Parser<'a, Set, Ser> {
         self.sub_parsers.push(parser);
         self
     }
+
+    /// Visit current parser and all the sub parsers recursively in depth first order,
+    /// the `visitor` is called with the command path and the parser, such as `["app", "db", "migrate"]`.
+    pub fn walk(&self, visitor: &mut impl FnMut(&[&str], &Self)) {
+        fn walk_impl<'a, 'b, Set, Ser>(
+            parser: &'b Parser<'a, Set, Ser>,
+            path: &mut Vec<&'b str>,
+            visitor: &mut impl FnMut(&[&str], &Parser<'a, Set, Ser>),
+        ) {
+            path.push(parser.name());
+            visitor(path, parser);
+            for sub_parser in parser.parsers() {
+                walk_impl(sub_parser, path, visitor);
+            }
+            path.pop();
+        }
+
+        walk_impl(self, &mut vec![], visitor);
+    }
+
+    /// Set the `hook` invoked after the arguments parsed successfully and the checks passed,
+    /// but before the values are extracted, such as initialize logging base on `--verbose`.
+    #[cfg(feature = "sync")]
+    pub fn on_parsed(
+        &mut self,
+        hook: impl FnOnce(&Self) -> Result<(), Error> + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.on_parsed = Some(Box::new(hook));
+        self
+    }
+
+    /// Set the `hook` invoked after the arguments parsed successfully and the checks passed,
+    /// but before the values are extracted, such as initialize logging base on `--verbose`.
+    #[cfg(not(feature = "sync"))]
+    pub fn on_parsed(&mut self, hook: impl FnOnce(&Self) -> Result<(), Error> + 'a) -> &mut Self {
+        self.on_parsed = Some(Box::new(hook));
+        self
+    }
+
+    /// Require exactly one of the options `uids` in `group` is set, it is checked after parsing successfully.
+    /// The parsing failed if none or more than one of them are set, like a radio button.
+    pub fn require_exactly_one(&mut self, group: impl Into<String>, uids: &[Uid]) -> &mut Self {
+        self.exactly_one.push((group.into(), uids.to_vec()));
+        self
+    }
+
+    /// The groups of options set by [`require_exactly_one`](Self::require_exactly_one).
+    pub fn exactly_one_groups(&self) -> &[(String, Vec<Uid>)] {
+        &self.exactly_one
+    }
+
+    /// Invoke the hook set by [`on_parsed`](Self::on_parsed), the hook is invoked at most once.
+    pub fn invoke_on_parsed(&mut self) -> Result<(), Error> {
+        match self.on_parsed.take() {
+            Some(hook) => hook(self),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<Set, Ser> Parser<'_, Set, Ser>
@@ -227,6 +358,110 @@ where
         }
         Ok(())
     }
+
+    /// Return the names and aliases of sub commands similar to `input`, sorted by closeness.
+    ///
+    /// The candidates start with `input` come first, then the candidates have small edit distance with `input`.
+    /// It does not affect the parsing.
+    pub fn suggest_subcommands(&self, input: &str) -> Vec<&str> {
+        let limit = (input.chars().count() / 3).max(1);
+        let mut candidates = vec![];
+
+        for opt in self.optset().iter().filter(|v| v.mat_style(Style::Cmd)) {
+            let alias = opt.alias().into_iter().flatten().map(|v| v.as_str());
+
+            for name in std::iter::once(opt.name()).chain(alias) {
+                let closeness = if name.starts_with(input) {
+                    Some((0, name.len() - input.len()))
+                } else {
+                    Some(edit_distance(input, name))
+                        .filter(|v| *v <= limit)
+                        .map(|v| (1, v))
+                };
+
+                if let Some(closeness) = closeness {
+                    if !candidates.iter().any(|(_, v)| *v == name) {
+                        candidates.push((closeness, name));
+                    }
+                }
+            }
+        }
+        candidates.sort_by_key(|v| v.0);
+        candidates.into_iter().map(|(_, v)| v).collect()
+    }
+
+    /// Return the names of options declared in the parser, in declaration order.
+    /// It does not include the options of sub parsers.
+    pub fn option_names(&self) -> Vec<String> {
+        self.optset()
+            .iter_ordered()
+            .map(|opt| opt.name().to_owned())
+            .collect()
+    }
+
+    /// Return the aliases of option `name`, or empty if the option has no alias.
+    pub fn aliases_of(&self, name: &str) -> Result<Vec<String>, Error> {
+        Ok(self.declared(name)?.alias().cloned().unwrap_or_default())
+    }
+
+    /// Return true if the option `name` is [`force`](Opt::force) required.
+    pub fn is_force(&self, name: &str) -> Result<bool, Error> {
+        Ok(self.declared(name)?.force())
+    }
+
+    fn declared(&self, name: &str) -> Result<&SetOpt<Set>, Error> {
+        self.optset()
+            .iter()
+            .find(|opt| opt.name() == name)
+            .ok_or_else(|| Error::sp_not_found(name))
+    }
+}
+
+impl<Set, Ser> Parser<'_, Set, Ser>
+where
+    Set: aopt::set::Set,
+{
+    /// Check the groups set by [`require_exactly_one`](Self::require_exactly_one).
+    pub fn check_exactly_one(&self) -> Result<(), Error> {
+        for (group, uids) in self.exactly_one.iter() {
+            let mut names = vec![];
+            let mut count = 0;
+
+            for uid in uids {
+                let opt = self
+                    .set
+                    .get(*uid)
+                    .ok_or_else(|| raise_error!("can not find option with uid {}", uid))?;
+
+                if opt.matched() {
+                    count += 1;
+                }
+                names.push(opt.name().to_string());
+            }
+            if count != 1 {
+                return Err(Error::sp_exactly_one(group, names, count));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Return the Levenshtein distance between `a` and `b`.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
 }
 
 impl<'a, Set, Ser> Parser<'a, Set, Ser>
@@ -240,7 +475,7 @@ where
         uid: Uid,
     ) -> Result<HandlerEntry<'a, '_, Invoker<'a, Self, Ser>, Self, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut Self, &mut Ser, &Ctx) -> Result<Option<O>, Error> + Send + Sync + 'a,
     {
         Ok(HandlerEntry::new(self.inv.as_mut().unwrap(), uid))
@@ -253,7 +488,7 @@ where
         uid: Uid,
     ) -> Result<HandlerEntry<'a, '_, Invoker<'a, Self, Ser>, Self, Ser, H, O>, Error>
     where
-        O: ErasedTy,
+        O: ErasedTy + Clone,
         H: FnMut(&mut Self, &mut Ser, &Ctx) -> Result<Option<O>, Error> + 'a,
     {
         Ok(HandlerEntry::new(self.inv.as_mut().unwrap(), uid))
@@ -328,9 +563,31 @@ where
         Set::iter_mut(&mut self.set)
     }
 
+    fn iter_ordered(&self) -> impl Iterator<Item = &SetOpt<Self>> {
+        Set::iter_ordered(&self.set)
+    }
+
     fn insert(&mut self, opt: SetOpt<Self>) -> Uid {
         Set::insert(&mut self.set, opt)
     }
+
+    fn remove(&mut self, uid: Uid) -> Option<SetOpt<Self>> {
+        Set::remove(&mut self.set, uid)
+    }
+
+    fn check_duplicate(&self) -> bool {
+        Set::check_duplicate(&self.set)
+    }
+
+    fn skip_claimed_pos(&self) -> bool {
+        Set::skip_claimed_pos(&self.set)
+    }
+
+    fn help_option(&self) -> Option<&str> {
+        self.help_option
+            .as_deref()
+            .or_else(|| Set::help_option(&self.set))
+    }
 }
 
 impl<Set, Ser> OptParser for Parser<'_, Set, Ser>
@@ -374,6 +631,10 @@ where
     fn unreg_prefix(&mut self, val: &str) -> Result<(), Self::Error> {
         PrefixedValidator::unreg_prefix(&mut self.set, val)
     }
+
+    fn prefixes(&self) -> &[String] {
+        PrefixedValidator::prefixes(&self.set)
+    }
 }
 
 impl<Set, Ser> SetValueFindExt for Parser<'_, Set, Ser>
@@ -401,6 +662,7 @@ impl<'a, P, Set, Ser> PolicyParser<P> for Parser<'a, Set, Ser>
 where
     Set: aopt::set::Set + OptParser + OptValidator,
     P: Policy<Set = Self, Ser = Ser, Inv<'a> = Invoker<'a, Self, Ser>>,
+    P::Ret: Status,
 {
     type Error = Error;
 
@@ -424,7 +686,12 @@ where
         self.inv = Some(inv);
         self.ser = Some(ser);
 
-        ret
+        let ret = ret?;
+
+        if ret.status() {
+            self.check_exactly_one()?;
+        }
+        Ok(ret)
     }
 }
 
@@ -474,6 +741,7 @@ where
     ) -> Result<R, Error>
     where
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
         F: FnMut(P::Ret, &mut Self) -> Result<R, Error>,
     {
         let ret = self.parse_policy(args.into(), policy)?;
@@ -485,6 +753,7 @@ where
     pub fn run_mut<R, F, P>(&mut self, policy: &mut P, r: F) -> Result<R, Error>
     where
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
         F: FnMut(P::Ret, &mut Self) -> Result<R, Error>,
     {
         self.run_mut_with(Args::from_env(), policy, r)
@@ -532,6 +801,7 @@ where
     where
         F: AsyncFnMut(P::Ret, &mut Self) -> Result<R, Error>,
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
     {
         match self.parse_policy(args.into(), policy) {
             Ok(ret) => r(ret, self).await,
@@ -544,6 +814,7 @@ where
     where
         F: AsyncFnMut(P::Ret, &mut Self) -> Result<R, Error>,
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
     {
         self.run_async_mut_with(Args::from_env(), policy, r).await
     }
@@ -587,6 +858,7 @@ where
     ) -> Result<R, Error>
     where
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
         F: FnMut(P::Ret, &Self) -> Result<R, Error>,
     {
         let ret = self.parse_policy(args.into(), policy)?;
@@ -598,6 +870,7 @@ where
     pub fn run<R, F, P>(&mut self, policy: &mut P, r: F) -> Result<R, Error>
     where
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
         F: FnMut(P::Ret, &Self) -> Result<R, Error>,
     {
         self.run_with(Args::from_env(), policy, r)
@@ -645,6 +918,7 @@ where
     where
         F: AsyncFnMut(P::Ret, &Self) -> Result<R, Error>,
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
     {
         match self.parse_policy(args.into(), policy) {
             Ok(ret) => r(ret, self).await,
@@ -657,6 +931,7 @@ where
     where
         F: AsyncFnMut(P::Ret, &Self) -> Result<R, Error>,
         P: Policy<Set = Self, Inv<'a> = Invoker<'a, Self, Ser>, Ser = Ser>,
+        P::Ret: Status,
     {
         self.run_async_with(Args::from_env(), policy, r).await
     }
@@ -676,7 +951,7 @@ where
         description: &str,
     ) -> Result<(), Error> {
         let set = self.optset();
-        let name = self.name.as_str();
+        let name = self.bin_name().unwrap_or(&self.name);
 
         crate::display_help!(
             set,
@@ -690,51 +965,82 @@ where
     }
 
     pub fn display_help_ctx(&self, ctx: HelpContext) -> Result<(), Error> {
-        let set = self.optset();
+        print!("{}", self.help_string(ctx)?);
+        Ok(())
+    }
 
-        crate::display_help!(
-            set,
-            ctx.name(),
-            ctx.head(),
-            ctx.foot(),
-            ctx.width(),
-            ctx.usagew()
-        )
+    /// Return the help message rendered by `ctx` instead of displaying it,
+    /// see [`display_help_ctx`](Self::display_help_ctx).
+    pub fn help_string(&self, ctx: HelpContext) -> Result<String, Error> {
+        let mut buf = vec![];
+
+        crate::help::write_set_help(self.optset(), &ctx, &mut buf)
+            .map_err(|e| raise_error!("can not show help message: {:?}", e))?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
     }
 
     pub fn display_sub_help(&self, names: Vec<&str>, ctx: &HelpContext) -> Result<(), Error> {
-        self.display_sub_help_impl(names, ctx, 0)
+        self.write_sub_help(names, ctx, std::io::stdout())
     }
 
-    fn display_sub_help_impl(
+    /// Same as [`display_sub_help`](Self::display_sub_help), but write the help message to `writer`.
+    pub fn write_sub_help<W: std::io::Write>(
         &self,
         names: Vec<&str>,
         ctx: &HelpContext,
+        writer: W,
+    ) -> Result<(), Error> {
+        // display the bin name instead of the name of parser
+        let names = names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| match self.bin_name() {
+                Some(bin_name) if i == 0 && name == self.name() => bin_name,
+                _ => name,
+            })
+            .collect();
+
+        self.write_sub_help_impl(names, ctx, writer, 0)
+    }
+
+    fn write_sub_help_impl<W: std::io::Write>(
+        &self,
+        names: Vec<&str>,
+        ctx: &HelpContext,
+        writer: W,
         i: usize,
     ) -> Result<(), Error> {
         if !names.is_empty() {
             let max = names.len() - 1;
 
             if let Some(name) = names.get(i) {
-                if i == max && (i > 0 || name == self.name()) {
-                    let name = names.join(" ");
-                    let optset = self.optset();
-
-                    return crate::display_help!(
-                        optset,
-                        &name,
-                        ctx.head(),
-                        ctx.foot(),
-                        ctx.width(),
-                        ctx.usagew()
-                    );
-                } else if i < max && name == self.name() {
+                let matched = name == self.name() || (i == 0 && Some(*name) == self.bin_name());
+
+                if i == max && (i > 0 || matched) {
+                    // the topic names a sub command rather than a group, display help of sub command
+                    if let Some(topic) = ctx
+                        .topic()
+                        .filter(|v| self.optset().iter().all(|opt| opt.group() != Some(v)))
+                    {
+                        if let Some(sub_parser) = self.parsers().iter().find(|v| v.name() == topic)
+                        {
+                            let names = names.iter().copied().chain([topic]).collect();
+                            let ctx = ctx.clone().with_topic(None);
+
+                            return sub_parser.write_sub_help_impl(names, &ctx, writer, i + 1);
+                        }
+                    }
+                    let ctx = ctx.clone().with_name(names.join(" "));
+
+                    return crate::help::write_set_help(self.optset(), &ctx, writer)
+                        .map_err(|e| raise_error!("can not show help message: {:?}", e));
+                } else if i < max && matched {
                     if let Some(name) = names.get(i + 1) {
                         let sub_parsers = self.parsers();
 
                         for sub_parser in sub_parsers {
                             if sub_parser.name() == name {
-                                return sub_parser.display_sub_help_impl(names, ctx, i + 1);
+                                return sub_parser.write_sub_help_impl(names, ctx, writer, i + 1);
                             }
                         }
                     }
@@ -780,13 +1086,14 @@ where
             if *help_option {
                 let set = self.optset();
 
-                crate::help::display_set_help(
+                crate::help::display_set_help_env(
                     set,
                     ctx.name(),
                     ctx.head(),
                     ctx.foot(),
                     ctx.width(),
                     ctx.usagew(),
+                    ctx.show_env(),
                 )
                 .map_err(|e| aopt::raise_error!("can not show help message: {:?}", e))?;
                 return Ok(true);
@@ -808,7 +1115,7 @@ where
 
         if let Ok(help_option) = set.find_val::<bool>(option) {
             if *help_option {
-                let name = self.name.as_str();
+                let name = self.bin_name().unwrap_or(&self.name);
 
                 crate::display_help!(
                     set,
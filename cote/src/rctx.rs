@@ -1,3 +1,6 @@
+use aopt::map::AnyMap;
+use aopt::map::ErasedTy;
+
 use crate::prelude::HelpContext;
 use crate::Return;
 
@@ -41,7 +44,12 @@ impl Failure {
 }
 
 /// Collect running information when do parsing.
-#[derive(Debug, Clone, Default)]
+///
+/// It also provide a typed extension storage, the handlers can stash and retrieve
+/// user data during a parse run using [`insert_ext`](RunningCtx::insert_ext)
+/// and [`get_ext`](RunningCtx::get_ext).
+/// The extension storage is shared with the sub parsers, but not cloned with the ctx.
+#[derive(Debug, Default)]
 pub struct RunningCtx {
     name: String,
 
@@ -56,6 +64,29 @@ pub struct RunningCtx {
     display_help: bool,
 
     help_context: Option<HelpContext>,
+
+    help_topic: Option<String>,
+
+    info: Option<String>,
+
+    exts: AnyMap,
+}
+
+impl Clone for RunningCtx {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            frames: self.frames.clone(),
+            sub_level: self.sub_level,
+            sub_parser: self.sub_parser,
+            exit: self.exit,
+            display_help: self.display_help,
+            help_context: self.help_context.clone(),
+            help_topic: self.help_topic.clone(),
+            info: self.info.clone(),
+            exts: AnyMap::default(),
+        }
+    }
 }
 
 impl RunningCtx {
@@ -88,6 +119,23 @@ impl RunningCtx {
         self.help_context.as_ref()
     }
 
+    /// The topic passed to help option, such as `net` of `--help net`.
+    pub fn help_topic(&self) -> Option<&str> {
+        self.help_topic.as_deref()
+    }
+
+    /// The text of info option set, such as `--license`.
+    pub fn info(&self) -> Option<&str> {
+        self.info.as_deref()
+    }
+
+    /// The name of parser and the sub commands entered, such as `["app", "db", "migrate"]`.
+    pub fn command_path(&self) -> Vec<&str> {
+        std::iter::once(self.name())
+            .chain(self.frames.iter().map(|v| v.name.as_str()))
+            .collect()
+    }
+
     // Mut api, automate generated by api-gen ...
     pub fn frames_mut(&mut self) -> &mut Vec<Frame> {
         &mut self.frames
@@ -133,6 +181,16 @@ impl RunningCtx {
         self
     }
 
+    pub fn set_help_topic(&mut self, value: Option<String>) -> &mut Self {
+        self.help_topic = value;
+        self
+    }
+
+    pub fn set_info(&mut self, value: Option<String>) -> &mut Self {
+        self.info = value;
+        self
+    }
+
     // With api, automate generated by api-gen ...
     pub fn with_name(mut self, value: String) -> Self {
         self.name = value;
@@ -169,6 +227,16 @@ impl RunningCtx {
         self
     }
 
+    pub fn with_help_topic(mut self, value: Option<String>) -> Self {
+        self.help_topic = value;
+        self
+    }
+
+    pub fn with_info(mut self, value: Option<String>) -> Self {
+        self.info = value;
+        self
+    }
+
     pub fn take_frames(&mut self) -> Vec<Frame> {
         std::mem::take(&mut self.frames)
     }
@@ -177,6 +245,10 @@ impl RunningCtx {
         self.help_context.take()
     }
 
+    pub fn take_info(&mut self) -> Option<String> {
+        self.info.take()
+    }
+
     pub fn clear_frames(&mut self) {
         self.frames.clear();
     }
@@ -203,6 +275,9 @@ impl RunningCtx {
             exit: false,
             display_help: false,
             help_context: None,
+            help_topic: None,
+            info: None,
+            exts: AnyMap::default(),
         }
     }
 
@@ -216,6 +291,36 @@ impl RunningCtx {
         self
     }
 
+    /// Insert the user data `value` into extension storage, return the previous value of type `T`.
+    pub fn insert_ext<T: ErasedTy>(&mut self, value: T) -> Option<T> {
+        self.exts.insert(value)
+    }
+
+    pub fn get_ext<T: ErasedTy>(&self) -> Option<&T> {
+        self.exts.value::<T>()
+    }
+
+    pub fn get_ext_mut<T: ErasedTy>(&mut self) -> Option<&mut T> {
+        self.exts.value_mut::<T>()
+    }
+
+    pub fn remove_ext<T: ErasedTy>(&mut self) -> Option<T> {
+        self.exts.remove::<T>()
+    }
+
+    pub fn exts(&self) -> &AnyMap {
+        &self.exts
+    }
+
+    pub fn set_exts(&mut self, exts: AnyMap) -> &mut Self {
+        self.exts = exts;
+        self
+    }
+
+    pub fn take_exts(&mut self) -> AnyMap {
+        std::mem::take(&mut self.exts)
+    }
+
     pub fn chain_error(&mut self) -> Option<aopt::Error> {
         let mut iter = self.frames.iter_mut();
 
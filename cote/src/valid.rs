@@ -1,5 +1,9 @@
+use std::ffi::OsString;
+use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::RangeBounds;
+use std::path::Path;
+use std::path::PathBuf;
 
 use aopt::prelude::ErasedTy;
 use aopt::value::ValValidator;
@@ -10,6 +14,11 @@ where
     T: ErasedTy,
 {
     fn check(&self, value: &T) -> bool;
+
+    /// The message used when value check failed.
+    fn message(&self) -> Option<&str> {
+        None
+    }
 }
 
 pub struct Value<K>(K);
@@ -124,6 +133,237 @@ where
     }
 }
 
+/// Value has a length, such as the number of characters in string or the number of items in vector.
+pub trait Length {
+    fn length(&self) -> usize;
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for OsString {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for PathBuf {
+    fn length(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Validator with an error message, created by the functions of this module.
+pub struct Rule<F> {
+    check: F,
+
+    message: String,
+}
+
+impl<F> Rule<F> {
+    pub fn new(check: F, message: impl Into<String>) -> Self {
+        Self {
+            check,
+            message: message.into(),
+        }
+    }
+
+    /// Replace the error message of validator.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl<T, F> Validate<T> for Rule<F>
+where
+    T: ErasedTy,
+    F: Fn(&T) -> bool,
+{
+    fn check(&self, value: &T) -> bool {
+        (self.check)(value)
+    }
+
+    fn message(&self) -> Option<&str> {
+        Some(&self.message)
+    }
+}
+
+/// Check the value is in the `range`.
+///
+/// # Example
+/// ```rust
+/// # use cote::prelude::*;
+/// # use cote::valid::range;
+/// #
+/// #[derive(Debug, Cote)]
+/// pub struct Cli {
+///     #[arg(valid = range(1..=10))]
+///     level: i64,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     assert!(Cli::parse(Args::from(["app", "--level=4"])).is_ok());
+///
+///     assert!(Cli::parse(Args::from(["app", "--level=11"])).is_err());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn range<T, R>(range: R) -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy + PartialOrd,
+    R: RangeBounds<T> + Debug,
+{
+    let message = format!("value must be in range {:?}", range);
+
+    Rule::new(move |value: &T| range.contains(value), message)
+}
+
+/// Check the value is one of the `choices`.
+pub fn choices<const N: usize, T, K>(choices: [K; N]) -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy,
+    K: PartialEq<T> + Debug,
+{
+    let message = format!("value must be one of {:?}", choices);
+
+    Rule::new(
+        move |value: &T| choices.iter().any(|v| PartialEq::eq(v, value)),
+        message,
+    )
+}
+
+/// The enum type can list its variants, it is implemented by derive [`CoteVal`](crate::prelude::CoteVal).
+pub trait Variants: Sized + 'static {
+    /// Return all the variants in declaration order.
+    fn variants() -> &'static [Self];
+
+    /// Return the name of variant used when parsing, such as `fast` of `Mode::Fast`.
+    fn name(&self) -> &'static str;
+}
+
+/// Check the value is one of the `choices`, the message lists the names used when parsing,
+/// such as ``value must be one of fast/slow``.
+pub fn variants<T>(choices: &[T]) -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy + Variants,
+{
+    let names: Vec<&'static str> = choices.iter().map(Variants::name).collect();
+    let message = format!("value must be one of {}", names.join("/"));
+
+    Rule::new(move |value: &T| names.contains(&value.name()), message)
+}
+
+/// Check the [`Length`] of value is in the `range`.
+pub fn length<T, R>(range: R) -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy + Length,
+    R: RangeBounds<usize> + Debug,
+{
+    let message = format!("length of value must be in range {:?}", range);
+
+    Rule::new(move |value: &T| range.contains(&value.length()), message)
+}
+
+/// Check the [`Length`] of value is not zero.
+pub fn non_empty<T>() -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy + Length,
+{
+    Rule::new(|value: &T| value.length() > 0, "value must not be empty")
+}
+
+/// Check the path exists.
+pub fn path_exists<T>() -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy + AsRef<Path>,
+{
+    Rule::new(|value: &T| value.as_ref().exists(), "path does not exist")
+}
+
+/// Check the value matches the regular expression `pattern`.
+///
+/// # Panics
+///
+/// Panics if `pattern` is not a valid regular expression.
+#[cfg(feature = "regex")]
+pub fn regex<T>(pattern: &str) -> Rule<impl Fn(&T) -> bool>
+where
+    T: ErasedTy + AsRef<str>,
+{
+    let regex = regex::Regex::new(pattern)
+        .unwrap_or_else(|e| panic!("invalid regular expression `{pattern}`: {e}"));
+    let message = format!("value does not match pattern `{pattern}`");
+
+    Rule::new(move |value: &T| regex.is_match(value.as_ref()), message)
+}
+
+/// Check the value pass both validator `a` and `b`,
+/// the error message is taken from `a` first.
+pub fn and<T, A, B>(a: A, b: B) -> And<A, B>
+where
+    T: ErasedTy,
+    A: Validate<T>,
+    B: Validate<T>,
+{
+    And(a, b)
+}
+
+/// Check the value pass any of validator `a` and `b`,
+/// the error message is taken from `a` first.
+pub fn or<T, A, B>(a: A, b: B) -> Or<A, B>
+where
+    T: ErasedTy,
+    A: Validate<T>,
+    B: Validate<T>,
+{
+    Or(a, b)
+}
+
+pub struct And<A, B>(A, B);
+
+impl<T, A, B> Validate<T> for And<A, B>
+where
+    T: ErasedTy,
+    A: Validate<T>,
+    B: Validate<T>,
+{
+    fn check(&self, value: &T) -> bool {
+        self.0.check(value) && self.1.check(value)
+    }
+
+    fn message(&self) -> Option<&str> {
+        self.0.message().or(self.1.message())
+    }
+}
+
+pub struct Or<A, B>(A, B);
+
+impl<T, A, B> Validate<T> for Or<A, B>
+where
+    T: ErasedTy,
+    A: Validate<T>,
+    B: Validate<T>,
+{
+    fn check(&self, value: &T) -> bool {
+        self.0.check(value) || self.1.check(value)
+    }
+
+    fn message(&self) -> Option<&str> {
+        self.0.message().or(self.1.message())
+    }
+}
+
 pub struct Validator<T>(ValidatorHandler<T>);
 
 impl<T> Validator<T>
@@ -150,6 +390,19 @@ where
     }
 }
 
+impl<T> Validate<T> for ValValidator<T>
+where
+    T: ErasedTy,
+{
+    fn check(&self, value: &T) -> bool {
+        self.invoke(value)
+    }
+
+    fn message(&self) -> Option<&str> {
+        self.desc()
+    }
+}
+
 impl<T> From<Validator<T>> for ValValidator<T>
 where
     T: ErasedTy,
@@ -181,8 +434,50 @@ where
 ///     Ok(())
 /// }
 /// ```
+///
+/// Using `path_exists()`, `is_file()`, `is_dir()` or `is_readable()` check the path value,
+/// see [`ValValidator::is_file`].
+///
+/// ```rust
+/// # use cote::prelude::*;
+/// # use std::path::PathBuf;
+/// #
+/// #[derive(Debug, Cote)]
+/// #[cote(help)]
+/// pub struct Cli {
+///     #[arg(valid = valid!(is_dir()))]
+///     dir: PathBuf,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let dir = std::env::temp_dir();
+///     let missing = dir.join("cote-missing-directory");
+///
+///     assert!(Cli::parse(Args::from(["app".into(), "--dir".into(), dir])).is_ok());
+///
+///     assert!(Cli::parse(Args::from(["app".into(), "--dir".into(), missing])).is_err());
+///
+///     Ok(())
+/// }
+/// ```
 #[macro_export]
 macro_rules! valid {
+    (path_exists()) => {
+        $crate::prelude::ValValidator::path_exists()
+    };
+
+    (is_file()) => {
+        $crate::prelude::ValValidator::is_file()
+    };
+
+    (is_dir()) => {
+        $crate::prelude::ValValidator::is_dir()
+    };
+
+    (is_readable()) => {
+        $crate::prelude::ValValidator::is_readable()
+    };
+
     ($value:literal) => {
         $crate::valid::Value::new($value)
     };
@@ -1,3 +1,9 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::hash::Hash;
+
 use aopt::opt::ConfigBuild;
 use aopt::opt::ConfigValue;
 use aopt::opt::OptValueExt;
@@ -12,6 +18,7 @@ use crate::prelude::RawValParser;
 use crate::prelude::Set;
 use crate::prelude::SetValueFindExt;
 use crate::prelude::Uid;
+use crate::CoteApp;
 
 pub fn fetch_uid_impl<T, S: Set>(uid: Uid, set: &mut S) -> Result<T, aopt::Error>
 where
@@ -46,6 +53,29 @@ where
     ))
 }
 
+/// Parse the arguments forwarded after the option terminator `--` into `T`,
+/// the name of option is used as the program name of `T`.
+/// Return `None` if there is no `--` in the arguments.
+///
+/// It is used by the `forward` attribute of `arg`.
+pub fn fetch_forward_impl<T, S: Set>(uid: Uid, set: &mut S) -> Result<Option<T>, aopt::Error>
+where
+    T: CoteApp,
+    SetCfg<S>: ConfigValue + Default,
+{
+    let opt = crate::prelude::SetExt::opt_mut(set, uid)?;
+    let name = opt.name().to_owned();
+
+    match opt.vals_mut::<OsString>() {
+        Ok(args) => {
+            let args = std::mem::take(args);
+
+            T::parse_from(std::iter::once(OsString::from(name)).chain(args)).map(Some)
+        }
+        Err(_) => Ok(None),
+    }
+}
+
 /// Using for generate code for procedural macro.
 pub trait Fetch<S>
 where
@@ -60,6 +90,11 @@ where
     fn fetch_uid(uid: Uid, set: &mut S) -> Result<Self, aopt::Error> {
         fetch_uid_impl::<<Self as Infer>::Val, S>(uid, set).map(<Self as Infer>::infer_map)
     }
+
+    /// Fetch the value of option, return `default` if the option not found or has no value.
+    fn fetch_or(name: impl ConfigBuild<SetCfg<S>>, set: &mut S, default: Self) -> Self {
+        Self::fetch(name, set).unwrap_or(default)
+    }
 }
 
 #[macro_export]
@@ -125,6 +160,12 @@ impl_fetch!(std::path::PathBuf);
 
 impl_fetch!(std::ffi::OsString);
 
+#[cfg(feature = "time")]
+impl_fetch!(time::OffsetDateTime);
+
+#[cfg(feature = "time")]
+impl_fetch!(time::Date);
+
 impl_fetch!(std::io::Stdin);
 
 impl_fetch!(aopt::value::Stop);
@@ -152,7 +193,7 @@ where
 {
 }
 
-impl<S, T: RawValParser + ErasedTy> Fetch<S> for crate::prelude::MutOpt<T>
+impl<S, T: RawValParser + ErasedTy + Clone> Fetch<S> for crate::prelude::MutOpt<T>
 where
     S: SetValueFindExt,
     SetCfg<S>: ConfigValue + Default,
@@ -174,11 +215,25 @@ where
     SetCfg<S>: ConfigValue + Default,
 {
     fn fetch(name: impl ConfigBuild<SetCfg<S>>, set: &mut S) -> Result<Self, aopt::Error> {
-        Ok(<T as Fetch<S>>::fetch(name, set).ok())
+        match set.find_uid(name) {
+            Ok(uid) => Self::fetch_uid(uid, set),
+            Err(_) => Ok(None),
+        }
     }
 
+    /// Return `None` if the option has no value, i.e. it is not set by user and has no default value.
+    /// The error is returned if the option is set by user but the value can not be taken.
     fn fetch_uid(uid: Uid, set: &mut S) -> Result<Self, aopt::Error> {
-        Ok(<T as Fetch<S>>::fetch_uid(uid, set).ok())
+        match <T as Fetch<S>>::fetch_uid(uid, set) {
+            Ok(val) => Ok(Some(val)),
+            Err(e) => {
+                if crate::prelude::SetExt::opt(set, uid)?.matched() {
+                    Err(e)
+                } else {
+                    Ok(None)
+                }
+            }
+        }
     }
 }
 
@@ -197,18 +252,30 @@ where
     }
 }
 
-impl<S, T> Fetch<S> for Vec<T>
-where
-    T: Fetch<S>,
-    S: SetValueFindExt,
-    SetCfg<S>: ConfigValue + Default,
-{
-    fn fetch(name: impl ConfigBuild<SetCfg<S>>, set: &mut S) -> Result<Self, aopt::Error> {
-        Self::fetch_uid(set.find_uid(name)?, set)
-    }
+macro_rules! impl_fetch_for_collection {
+    ($coll:ident $(, $bound:path)*) => {
+        impl<S, T> Fetch<S> for $coll<T>
+        where
+            T: Fetch<S> $(+ $bound)*,
+            S: SetValueFindExt,
+            SetCfg<S>: ConfigValue + Default,
+        {
+            fn fetch(name: impl ConfigBuild<SetCfg<S>>, set: &mut S) -> Result<Self, aopt::Error> {
+                Self::fetch_uid(set.find_uid(name)?, set)
+            }
 
-    fn fetch_uid(uid: Uid, set: &mut S) -> Result<Self, aopt::Error> {
-        fetch_vec_uid_impl::<<T as Infer>::Val, S>(uid, set)
-            .map(|v| v.into_iter().map(<T as Infer>::infer_map).collect())
-    }
+            fn fetch_uid(uid: Uid, set: &mut S) -> Result<Self, aopt::Error> {
+                fetch_vec_uid_impl::<<T as Infer>::Val, S>(uid, set)
+                    .map(|v| v.into_iter().map(<T as Infer>::infer_map).collect())
+            }
+        }
+    };
 }
+
+impl_fetch_for_collection!(Vec);
+
+impl_fetch_for_collection!(VecDeque);
+
+impl_fetch_for_collection!(HashSet, Eq, Hash);
+
+impl_fetch_for_collection!(BTreeSet, Ord);
@@ -2,11 +2,11 @@ use cote::prelude::*;
 use std::any::Any;
 
 // The default policy is `fwd`(FwdPolicy)
-#[derive(Debug, Cote)]
+#[derive(Debug, Clone, Cote)]
 pub struct Widget;
 
 // Configure the policy with built-in `delay`, `pre` or `fwd`
-#[derive(Debug, Cote)]
+#[derive(Debug, Clone, Cote)]
 #[cote(policy = delay)]
 pub struct Windows;
 
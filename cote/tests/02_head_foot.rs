@@ -17,7 +17,7 @@ fn head_foot_impl() -> Result<(), Box<dyn std::error::Error>> {
     assert!(Cli::parse(Args::from(["app", "-h"].into_iter())).is_err());
     // Output:
     //
-    // Usage: cli [-h,--help]
+    // Usage: cli [OPTIONS]
     //
     // Set the head message here
     //
@@ -26,7 +26,7 @@ pub struct Cli {
     query: Option<Query>,
 }
 
-#[derive(Debug, Cote)]
+#[derive(Debug, Clone, Cote)]
 pub struct Query {
     #[allow(unused)]
     #[arg(nodelay, on = order!(1, usize))]
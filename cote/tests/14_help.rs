@@ -13,7 +13,7 @@ pub struct Cli {
     query: Option<Query>,
 }
 
-#[derive(Debug, Cote)]
+#[derive(Debug, Clone, Cote)]
 #[cote(help)]
 pub struct Query {
     #[allow(unused)]
@@ -35,7 +35,7 @@ fn help_impl() -> color_eyre::Result<()> {
     color_eyre::install()?;
     Cli::parse(Args::from(["app", "q", "--help"]))?;
     // Output:
-    // Usage: cli q [-h,--help] <--row <usize>> [ARGS]
+    // Usage: cli q [OPTIONS] <--row <usize>> [ARGS]
     //
     // Head message of sub command query
     //
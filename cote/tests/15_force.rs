@@ -10,7 +10,7 @@ pub struct Cli {
     query: Option<Query>,
 }
 
-#[derive(Debug, Cote, PartialEq, Eq)]
+#[derive(Debug, Clone, Cote, PartialEq, Eq)]
 #[cote(help)]
 pub struct Query {
     #[allow(unused)]
@@ -15,9 +15,19 @@ fn fetch_impl() -> color_eyre::Result<()> {
     let mut parser = AFwdParser::default();
 
     parser.add_opt("--speed".infer::<i32>())?;
-    parser.parse(Args::from(["app", "--speed=42"]))?;
+    parser.add_opt("--max-speed".infer::<i32>())?;
+    parser.add_opt("--min-speed".infer::<i32>())?;
+    parser.parse(Args::from(["app", "--speed=42", "--max-speed=99"]))?;
 
     assert_eq!(Speed::fetch("--speed", parser.optset_mut())?, Speed(42));
+    assert_eq!(
+        Speed::fetch_or("--max-speed", parser.optset_mut(), Speed(60)),
+        Speed(99)
+    );
+    assert_eq!(
+        Speed::fetch_or("--min-speed", parser.optset_mut(), Speed(1)),
+        Speed(1)
+    );
 
     Ok(())
 }
@@ -1,11 +1,11 @@
 use aopt::prelude::AFwdParser;
 use cote::prelude::*;
 
-#[derive(Debug, PartialEq, Eq, CoteOpt, CoteVal)]
+#[derive(Debug, Clone, PartialEq, Eq, CoteOpt, CoteVal)]
 #[coteval(forward = i32, map = Speed)]
 pub struct Speed(i32);
 
-#[derive(Debug, CoteVal, CoteOpt, PartialEq)]
+#[derive(Debug, Clone, CoteVal, CoteOpt, PartialEq)]
 #[coteval(igcase)]
 pub enum IM {
     #[coteval(alias = "qq")]
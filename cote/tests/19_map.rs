@@ -4,7 +4,7 @@ use aopt::prelude::AFwdParser;
 use cote::prelude::*;
 use regex::Regex;
 
-#[derive(Debug, CoteVal, CoteOpt, PartialEq)]
+#[derive(Debug, Clone, CoteVal, CoteOpt, PartialEq)]
 #[coteval(mapstr = Meal::new)]
 pub enum Meal {
     BreakFast,
@@ -27,7 +27,7 @@ impl Meal {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, CoteOpt, CoteVal)]
+#[derive(Debug, Clone, PartialEq, Eq, CoteOpt, CoteVal)]
 #[coteval(mapraw = Point::new)]
 pub struct Point {
     x: i32,
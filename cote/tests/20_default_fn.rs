@@ -0,0 +1,31 @@
+use cote::prelude::*;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
+
+static SEQ: AtomicI64 = AtomicI64::new(0);
+
+fn next_seq() -> cote::Result<i64> {
+    Ok(SEQ.fetch_add(1, Ordering::SeqCst))
+}
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(default_fn = next_seq)]
+    seq: i64,
+}
+
+#[test]
+fn default_fn() {
+    assert!(default_fn_impl().is_ok());
+}
+
+fn default_fn_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let first = Cli::parse(Args::from(["app"]))?;
+    let second = Cli::parse(Args::from(["app"]))?;
+
+    assert_ne!(first.seq, second.seq);
+    assert_eq!(Cli::parse(Args::from(["app", "--seq", "42"]))?.seq, 42);
+    Ok(())
+}
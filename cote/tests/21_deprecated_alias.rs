@@ -0,0 +1,40 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(deprecated_alias("--old" = "use --new instead"))]
+    new: Option<i64>,
+}
+
+#[test]
+fn deprecated_alias() {
+    assert!(deprecated_alias_impl().is_ok());
+}
+
+fn deprecated_alias_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let CoteRes { ret, parser, .. } = Cli::parse_args(Args::from(["app", "--old", "42"]))?;
+    let opt = parser.find_opt("--new")?;
+
+    assert!(ret.status());
+    assert_eq!(ret.warnings().len(), 1);
+    assert_eq!(
+        ret.warnings()[0].to_string(),
+        "`--old` is a deprecated alias of `--new`, use --new instead"
+    );
+    assert_eq!(opt.hint(), "--new");
+    assert_eq!(opt.mat_deprecated_alias("--old"), Some("use --new instead"));
+    assert_eq!(opt.mat_deprecated_alias("--new"), None);
+    assert_eq!(
+        Cli::parse(Args::from(["app", "--old", "42"]))?.new,
+        Some(42)
+    );
+
+    let CoteRes { ret, parser, .. } = Cli::parse_args(Args::from(["app", "--new", "42"]))?;
+
+    assert!(ret.status());
+    assert!(ret.warnings().is_empty());
+    assert_eq!(parser.find_val::<i64>("--new")?, &42);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use cote::prelude::*;
+use cote::ErrorKind;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[allow(unused)]
+    name: String,
+
+    #[allow(unused)]
+    #[arg(valid = valid!([1, 2, 3]))]
+    level: Option<i64>,
+}
+
+#[test]
+fn error_kind() {
+    assert!(error_kind_impl().is_ok());
+}
+
+fn error_kind_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let kind_of = |args: &[&str]| Cli::parse(Args::from(args)).err().map(|e| *e.kind());
+
+    assert_eq!(kind_of(&["app", "--name", "foo", "--level", "1"]), None);
+    assert_eq!(kind_of(&["app"]), Some(ErrorKind::OptRequired));
+    assert_eq!(kind_of(&["app", "--name"]), Some(ErrorKind::MissingValue));
+    assert_eq!(
+        kind_of(&["app", "--name", "foo", "--unknown"]),
+        Some(ErrorKind::OptionNotFound)
+    );
+    assert_eq!(
+        kind_of(&["app", "--name", "foo", "--level", "4"]),
+        Some(ErrorKind::Validation)
+    );
+    assert_eq!(
+        kind_of(&["app", "--name", "foo", "--level", "x"]),
+        Some(ErrorKind::RawValParse)
+    );
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use cote::prelude::*;
+use std::sync::Mutex;
+
+static ORDER: Mutex<Vec<&'static str>> = Mutex::new(vec![]);
+
+#[derive(Debug, Cote)]
+#[cote(policy = delay)]
+pub struct Cli {
+    #[arg(on = record_verbose)]
+    verbose: bool,
+
+    #[arg(priority = 10, on = record_config)]
+    config: Option<String>,
+}
+
+#[test]
+fn priority() {
+    assert!(priority_impl().is_ok());
+}
+
+fn priority_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--verbose", "--config", "app.toml"]))?;
+
+    assert!(cli.verbose);
+    assert_eq!(cli.config.as_deref(), Some("app.toml"));
+    // `--config` is invoked first though it is after `--verbose`
+    assert_eq!(ORDER.lock().unwrap().as_slice(), ["--config", "--verbose"]);
+    Ok(())
+}
+
+fn record_verbose<Set, Ser>(_: &mut Set, _: &mut Ser, ctx: &Ctx) -> cote::Result<Option<bool>> {
+    ORDER.lock().unwrap().push("--verbose");
+    Ok(Some(ctx.value::<bool>()?))
+}
+
+fn record_config<Set, Ser>(_: &mut Set, _: &mut Ser, ctx: &Ctx) -> cote::Result<Option<String>> {
+    ORDER.lock().unwrap().push("--config");
+    Ok(Some(ctx.value::<String>()?))
+}
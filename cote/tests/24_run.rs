@@ -0,0 +1,79 @@
+use cote::prelude::*;
+use cote::Error;
+use cote::ErrorKind;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(alias = "-c")]
+    count: i64,
+
+    #[pos()]
+    name: Option<String>,
+}
+
+#[test]
+fn run() {
+    assert!(run_impl().is_ok());
+}
+
+fn run_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let check = |cli: Cli| -> Result<(), String> {
+        if cli.name.is_some() {
+            Ok(())
+        } else {
+            Err(format!("missing name, count = {}", cli.count))
+        }
+    };
+
+    // parsing ok and main returned ok
+    assert_eq!(
+        Cli::run_with_args(Args::from(["app", "-c", "2", "foo"]), 1, check),
+        0
+    );
+    // main returned an error
+    assert_eq!(
+        Cli::run_with_args(Args::from(["app", "-c", "2"]), 3, check),
+        3
+    );
+    // parsing failed, `--count` is force required
+    assert_eq!(Cli::run_with_args(Args::from(["app", "foo"]), 2, check), 2);
+    // parsing failed, invalid value of `--count`
+    assert_eq!(
+        Cli::run_with_args(Args::from(["app", "-c", "bar"]), 1, check),
+        1
+    );
+    // the failure exit code is non-zero
+    assert_eq!(Cli::run_with_args(Args::from(["app", "foo"]), 0, check), 1);
+    assert_eq!(
+        Cli::run_with_args(Args::from(["app", "-c", "2"]), 0, check),
+        1
+    );
+    // parsing failed, using the exit code of error
+    assert_eq!(
+        Cli::run_with_args_mapper(Args::from(["app", "foo"]), Error::exit_code, check),
+        2
+    );
+    assert_eq!(
+        Cli::run_with_args_mapper(Args::from(["app", "-c", "2"]), Error::exit_code, check),
+        1
+    );
+    // using custom mapping
+    assert_eq!(
+        Cli::run_with_args_mapper(
+            Args::from(["app", "-c", "bar"]),
+            |e| match e.specific_kind() {
+                ErrorKind::RawValParse => 64,
+                _ => e.exit_code(),
+            },
+            check
+        ),
+        64
+    );
+    assert_eq!(
+        Cli::run_with_args_mapper(Args::from(["app", "foo"]), |_| 0, check),
+        1
+    );
+    Ok(())
+}
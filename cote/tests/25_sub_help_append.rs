@@ -0,0 +1,72 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(
+    help,
+    head = "A simple version control tool",
+    foot = "Report bugs at https://github.com/araraloren/getopt_rs"
+)]
+pub struct Cli {
+    /// Clone a repository
+    #[allow(unused)]
+    #[sub(foot_append = "See `cli clone --depth` for shallow clone")]
+    clone: Option<Clone>,
+
+    /// Commit the changes
+    #[allow(unused)]
+    #[sub(head = "Record changes", head_append = "to the repository")]
+    commit: Option<Commit>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help, head = "Clone a repository into a new directory")]
+pub struct Clone {
+    #[allow(unused)]
+    depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help, foot = "Commit foot")]
+pub struct Commit {
+    #[allow(unused)]
+    message: Option<String>,
+}
+
+#[test]
+fn sub_help_append() {
+    assert!(sub_help_append_impl().is_ok());
+}
+
+fn sub_help_append_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app", "clone", "--help"]))?;
+    let help_context = parser.rctx()?.help_context().unwrap();
+
+    // Output:
+    // Usage: cote clone [OPTIONS]
+    //
+    // Clone a repository into a new directory
+    //
+    // Options:
+    //   --depth
+    //   -h, --help      Display help message
+    //
+    // Report bugs at https://github.com/araraloren/getopt_rs
+    // See `cli clone --depth` for shallow clone
+    assert_eq!(
+        help_context.head(),
+        "Clone a repository into a new directory"
+    );
+    assert_eq!(
+        help_context.foot(),
+        "Report bugs at https://github.com/araraloren/getopt_rs\nSee `cli clone --depth` for shallow clone"
+    );
+
+    let CoteRes { parser, .. } = Cli::parse_args(Args::from(["app", "commit", "--help"]))?;
+    let help_context = parser.rctx()?.help_context().unwrap();
+
+    assert_eq!(help_context.head(), "Record changes\nto the repository");
+    assert_eq!(help_context.foot(), "Commit foot");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, intermixed = false)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    #[sub()]
+    build: Option<Build>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Build {
+    /// Build in release mode
+    release: bool,
+
+    #[pos()]
+    target: String,
+}
+
+#[test]
+fn intermixed() {
+    assert!(intermixed_impl().is_ok());
+}
+
+fn intermixed_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    // sub command `build` is intermixed in default
+    let cli = Cli::parse(Args::from(["app", "--debug", "build", "foo", "--release"]))?;
+
+    assert!(cli.debug);
+    assert!(cli.build.as_ref().is_some_and(|v| v.release));
+    assert_eq!(cli.build.as_ref().map(|v| v.target.as_str()), Some("foo"));
+
+    // `--debug` after `build` is left to sub command
+    let ret = Cli::parse(Args::from(["app", "build", "foo", "--debug"]));
+
+    assert!(ret.is_err());
+    Ok(())
+}
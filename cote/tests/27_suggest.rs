@@ -0,0 +1,48 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    #[allow(unused)]
+    #[sub(alias = "co")]
+    checkout: Option<Empty>,
+
+    #[allow(unused)]
+    #[sub()]
+    cherry: Option<Empty>,
+
+    #[allow(unused)]
+    #[sub(alias = "ci")]
+    commit: Option<Empty>,
+
+    #[allow(unused)]
+    #[sub()]
+    push: Option<Empty>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Empty;
+
+#[test]
+fn suggest() {
+    assert!(suggest_impl().is_ok());
+}
+
+fn suggest_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let parser = Cli::into_parser()?;
+
+    // prefix
+    assert_eq!(parser.suggest_subcommands("che"), ["cherry", "checkout"]);
+    assert_eq!(
+        parser.suggest_subcommands("c"),
+        ["co", "ci", "cherry", "commit", "checkout"]
+    );
+    // typo
+    assert_eq!(parser.suggest_subcommands("chekout"), ["checkout"]);
+    assert_eq!(parser.suggest_subcommands("pish"), ["push"]);
+    assert_eq!(parser.suggest_subcommands("comit"), ["commit"]);
+    assert!(parser.suggest_subcommands("status").is_empty());
+    Ok(())
+}
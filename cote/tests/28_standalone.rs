@@ -0,0 +1,44 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print the version
+    #[arg(alias = "-V", standalone)]
+    version: bool,
+
+    /// Print debug message
+    debug: bool,
+
+    #[pos()]
+    file: Option<String>,
+}
+
+#[test]
+fn standalone() {
+    assert!(standalone_impl().is_ok());
+}
+
+fn standalone_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--version"]))?;
+
+    assert!(cli.version);
+    assert!(!cli.debug);
+
+    let cli = Cli::parse(Args::from(["app", "foo"]))?;
+
+    assert!(!cli.version);
+    assert_eq!(cli.file.as_deref(), Some("foo"));
+
+    for args in [["app", "--version", "--debug"], ["app", "--version", "foo"]] {
+        let CoteRes { ret, .. } = Cli::parse_args(Args::from(args))?;
+
+        assert!(!ret.status());
+        assert_eq!(
+            ret.failure().map(|v| v.to_string()).as_deref(),
+            Some("option `--version` can not be used with other arguments")
+        );
+    }
+    Ok(())
+}
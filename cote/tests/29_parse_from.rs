@@ -0,0 +1,36 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// Set the count
+    #[arg(name = "--n")]
+    n: i64,
+
+    /// Print debug message
+    debug: bool,
+}
+
+#[test]
+fn parse_from() {
+    assert!(parse_from_impl().is_ok());
+}
+
+fn parse_from_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse_from(["app", "--n", "1"])?;
+
+    assert_eq!(cli.n, 1);
+    assert!(!cli.debug);
+
+    let args = vec![
+        String::from("app"),
+        String::from("--debug"),
+        String::from("--n=42"),
+    ];
+    let cli = <Cli as CoteApp>::parse_from(args)?;
+
+    assert_eq!(cli.n, 42);
+    assert!(cli.debug);
+    assert!(Cli::parse_from(["app"]).is_err());
+    Ok(())
+}
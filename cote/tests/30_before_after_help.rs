@@ -0,0 +1,54 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(
+    help,
+    head = "Set the head message here",
+    foot = "Set the foot message here",
+    before_help = "Display before the options",
+    after_help = "Display after the options"
+)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+}
+
+#[test]
+fn before_after_help() {
+    assert!(before_after_help_impl().is_ok());
+}
+
+fn before_after_help_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let parser = Cli::into_parser()?;
+    let ctx = Cli::new_help_context().with_name("cli");
+    let mut output = vec![];
+
+    write_set_help(parser.optset(), &ctx, &mut output)?;
+
+    let help = String::from_utf8(output)?;
+    let pos = |text: &str| help.find(text).unwrap();
+
+    assert!(pos("Set the head message here") < pos("Display before the options"));
+    assert!(pos("Display before the options") < pos("Options:"));
+    assert!(pos("Print debug message") < pos("Display after the options"));
+    assert!(pos("Display after the options") < pos("Set the foot message here"));
+    // Output:
+    //
+    // Usage: cli [OPTIONS]
+    //
+    // Set the head message here
+    //
+    // Display before the options
+    //
+    // Options:
+    //   -h,--help      Display help message
+    //   --debug        Print debug message
+    //
+    // Display after the options
+    //
+    // Set the foot message here
+    //
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use cote::prelude::*;
+
+/// The scalar value can not be used more than once by default
+#[derive(Debug, PartialEq, Eq, CoteOpt)]
+#[infer(val = i64, map = Count, action = Action::Set)]
+pub struct Count(i64);
+
+#[derive(Debug, Cote)]
+#[cote(overload)]
+pub struct Cli {
+    /// Set the count
+    #[arg(name = "--n")]
+    n: Count,
+
+    /// Append the values
+    #[arg(name = "--list")]
+    list: Vec<i64>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Plain {
+    #[arg(name = "--n")]
+    n: Count,
+}
+
+#[test]
+fn overload() {
+    assert!(overload_impl().is_ok());
+}
+
+fn overload_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    assert!(Cli::into_policy().overload());
+    assert!(!Plain::into_policy().overload());
+
+    let cli = Cli::parse_from(["app", "--n", "1", "--n", "2", "--list=1", "--list=2"])?;
+
+    // the repeated scalar option `--n` keeps the last value
+    assert_eq!(cli.n, Count(2));
+    assert_eq!(cli.list, vec![1, 2]);
+
+    // `--n` can not be used more than once without overload
+    assert!(Plain::parse_from(["app", "--n", "1", "--n", "2"]).is_err());
+
+    let cli = Plain::parse_from(["app", "--n", "1"])?;
+
+    assert_eq!(cli.n, Count(1));
+    Ok(())
+}
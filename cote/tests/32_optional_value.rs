@@ -0,0 +1,39 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// When to use color
+    #[arg(optional_value = "auto")]
+    color: Option<String>,
+
+    #[pos(index = 1..)]
+    args: Option<Vec<String>>,
+}
+
+#[test]
+fn optional_value() {
+    assert!(optional_value_impl().is_ok());
+}
+
+fn optional_value_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--color"]))?;
+
+    assert_eq!(cli.color.as_deref(), Some("auto"));
+
+    let cli = Cli::parse(Args::from(["app", "--color=never"]))?;
+
+    assert_eq!(cli.color.as_deref(), Some("never"));
+
+    let cli = Cli::parse(Args::from(["app", "--color", "always"]))?;
+
+    assert_eq!(cli.color.as_deref(), Some("auto"));
+    assert_eq!(cli.args, Some(vec!["always".to_owned()]));
+
+    let cli = Cli::parse(Args::from(["app", "foo"]))?;
+
+    assert_eq!(cli.color, None);
+    assert_eq!(cli.args, Some(vec!["foo".to_owned()]));
+    Ok(())
+}
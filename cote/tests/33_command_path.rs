@@ -0,0 +1,46 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app", help)]
+pub struct Cli {
+    #[allow(unused)]
+    #[sub(policy = pre)]
+    db: Option<Db>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Db {
+    #[allow(unused)]
+    #[sub()]
+    migrate: Option<Migrate>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Migrate {
+    #[allow(unused)]
+    #[arg(alias = "-s")]
+    step: usize,
+}
+
+#[test]
+fn command_path() {
+    assert!(command_path_impl().is_ok());
+}
+
+fn command_path_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let CoteRes { ret, parser, .. } =
+        Cli::parse_args(Args::from(["app", "db", "migrate", "-s", "2"]))?;
+
+    assert!(ret.status());
+    assert_eq!(parser.rctx()?.command_path(), ["app", "db", "migrate"]);
+
+    let error = Cli::parse(Args::from(["app", "db", "migrate", "-s", "two"])).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .starts_with("Parsing command `app db migrate` failed"));
+    Ok(())
+}
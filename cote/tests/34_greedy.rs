@@ -0,0 +1,36 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Input files
+    #[arg(greedy)]
+    files: Vec<String>,
+
+    /// Print debug message
+    debug: bool,
+
+    #[pos(index = 1..)]
+    args: Option<Vec<String>>,
+}
+
+#[test]
+fn greedy() {
+    assert!(greedy_impl().is_ok());
+}
+
+fn greedy_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--files", "a", "b", "c", "--debug"]))?;
+
+    assert_eq!(cli.files, ["a", "b", "c"]);
+    assert!(cli.debug);
+    assert_eq!(cli.args, None);
+
+    let cli = Cli::parse(Args::from(["app", "--debug", "b", "--files=a", "c"]))?;
+
+    assert_eq!(cli.files, ["a", "c"]);
+    assert!(cli.debug);
+    assert_eq!(cli.args, Some(vec!["b".to_owned()]));
+    Ok(())
+}
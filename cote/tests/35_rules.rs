@@ -0,0 +1,58 @@
+use cote::prelude::*;
+use cote::valid;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    #[arg(valid = valid::range(1..=10))]
+    level: i64,
+
+    #[arg(valid = valid::non_empty())]
+    name: String,
+
+    #[arg(valid = valid::choices(["fast", "slow"]))]
+    mode: Option<String>,
+
+    #[arg(valid = valid::and(valid::non_empty(), valid::length(..=3)))]
+    tags: Vec<String>,
+}
+
+#[test]
+fn rules() {
+    assert!(rules_impl().is_ok());
+}
+
+fn rules_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let parse = |args: &[&str]| {
+        Cli::parse(Args::from(
+            ["app", "--level=1", "--name=foo"].iter().chain(args.iter()),
+        ))
+    };
+    let cli = parse(&["--level=10", "--mode=slow", "--tags=a", "--tags=abc"])?;
+
+    assert_eq!(cli.level, 10);
+    assert_eq!(cli.name, "foo");
+    assert_eq!(cli.mode.as_deref(), Some("slow"));
+    assert_eq!(cli.tags, ["a", "abc"]);
+
+    assert!(parse(&["--level=0"]).is_err());
+    assert!(parse(&["--level=11"]).is_err());
+    assert!(parse(&["--name="]).is_err());
+    assert!(parse(&["--mode=medium"]).is_err());
+    assert!(parse(&["--tags="]).is_err());
+    assert!(parse(&["--tags=abcd"]).is_err());
+
+    let error = parse(&["--level=42"]).unwrap_err();
+    let mut messages = vec![error.to_string()];
+    let mut cause = error.caused_by();
+
+    while let Some(error) = cause {
+        messages.push(error.to_string());
+        cause = error.caused_by();
+    }
+    assert!(messages
+        .iter()
+        .any(|v| v.contains("value must be in range 1..=10")));
+    Ok(())
+}
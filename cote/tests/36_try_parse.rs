@@ -0,0 +1,41 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the count of repeat
+    count: Option<usize>,
+}
+
+#[test]
+fn try_parse() {
+    assert!(try_parse_impl().is_ok());
+}
+
+fn try_parse_impl() -> Result<(), Box<dyn std::error::Error>> {
+    match Cli::try_parse_from(["app", "--help"]) {
+        ParseOutcome::Help(help) => {
+            assert!(help.contains("Usage:"));
+            assert!(help.contains("Print debug message"));
+            assert!(help.contains("Set the count of repeat"));
+        }
+        outcome => panic!("expect help message, got {outcome:?}"),
+    }
+
+    match Cli::try_parse_from(["app", "--debug", "--count", "3"]) {
+        ParseOutcome::Parsed(cli) => {
+            assert!(cli.debug);
+            assert_eq!(cli.count, Some(3));
+        }
+        outcome => panic!("expect parsed value, got {outcome:?}"),
+    }
+
+    assert!(Cli::try_parse_from(["app", "--count", "foo"]).is_err());
+    assert!(Cli::try_parse_from(["app", "--help"])
+        .into_result()
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the name of user
+    name: Option<String>,
+
+    /// Set the configuration path
+    #[arg(display_order = -1)]
+    config: Option<String>,
+
+    /// Display the version
+    #[arg(display_order = 10)]
+    version: bool,
+}
+
+#[test]
+fn display_order() {
+    assert!(display_order_impl().is_ok());
+}
+
+fn display_order_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let ParseOutcome::Help(help) = Cli::try_parse_from(["app", "--help"]) else {
+        panic!("expect help message");
+    };
+    let pos = |text: &str| help.find(text).unwrap();
+
+    // Options:
+    //   --config        Set the configuration path
+    //   --debug         Print debug message
+    //   --name          Set the name of user
+    //   -h, --help      Display help message
+    //   --version       Display the version
+    assert!(pos("Set the configuration path") < pos("Print debug message"));
+    assert!(pos("Print debug message") < pos("Set the name of user"));
+    assert!(pos("Set the name of user") < pos("Display help message"));
+    assert!(pos("Display help message") < pos("Display the version"));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use cote::prelude::*;
+
+#[derive(Debug, Default)]
+pub struct Warnings(Vec<String>);
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(policy = delay)]
+pub struct Cli {
+    #[arg(on = warn_legacy)]
+    legacy: bool,
+
+    #[arg(on = check_jobs)]
+    jobs: Option<usize>,
+}
+
+#[test]
+fn rctx_ext() {
+    assert!(rctx_ext_impl().is_ok());
+}
+
+fn rctx_ext_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let CoteRes { ret, parser, .. } = Cli::parse_args(Args::from(["app", "--legacy", "--jobs=0"]))?;
+
+    assert!(ret.status());
+
+    let warnings = parser.rctx()?.get_ext::<Warnings>().unwrap();
+
+    assert_eq!(
+        warnings.0,
+        ["`--legacy` is deprecated", "`--jobs` is 0, use 1 instead"]
+    );
+
+    let cli = Cli::parse(Args::from(["app", "--legacy", "--jobs=0"]))?;
+
+    assert_eq!(cli.jobs, Some(1));
+
+    let mut rctx = RunningCtx::default();
+
+    assert_eq!(rctx.insert_ext(42usize), None);
+    assert_eq!(rctx.insert_ext(1usize), Some(42));
+    *rctx.get_ext_mut::<usize>().unwrap() += 1;
+    assert_eq!(rctx.get_ext::<usize>(), Some(&2));
+    // the extension storage is not cloned
+    assert_eq!(rctx.clone().get_ext::<usize>(), None);
+    assert_eq!(rctx.remove_ext::<usize>(), Some(2));
+    assert!(rctx.exts().is_empty());
+    Ok(())
+}
+
+fn warn_legacy<Set, Ser>(_: &mut Set, ser: &mut Ser, ctx: &Ctx) -> cote::Result<Option<bool>>
+where
+    Ser: ServicesValExt,
+{
+    let rctx = ser.sve_val_mut::<RunningCtx>()?;
+
+    if rctx.get_ext::<Warnings>().is_none() {
+        rctx.insert_ext(Warnings::default());
+    }
+    if let Some(warnings) = rctx.get_ext_mut::<Warnings>() {
+        warnings.0.push("`--legacy` is deprecated".to_owned());
+    }
+    Ok(Some(ctx.value::<bool>()?))
+}
+
+fn check_jobs<Set, Ser>(_: &mut Set, ser: &mut Ser, ctx: &Ctx) -> cote::Result<Option<usize>>
+where
+    Ser: ServicesValExt,
+{
+    let jobs = ctx.value::<usize>()?;
+    let rctx = ser.sve_val_mut::<RunningCtx>()?;
+
+    // the warnings are stored by the handler of `--legacy`
+    if let (0, Some(warnings)) = (jobs, rctx.get_ext_mut::<Warnings>()) {
+        warnings.0.push("`--jobs` is 0, use 1 instead".to_owned());
+        return Ok(Some(1));
+    }
+    Ok(Some(jobs))
+}
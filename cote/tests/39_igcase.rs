@@ -0,0 +1,42 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Overwrite the existing file
+    #[arg(alias = "--Force", igcase)]
+    overwrite: bool,
+
+    /// Set the output file
+    #[arg(alias = "-O")]
+    output: Option<String>,
+}
+
+#[test]
+fn igcase() {
+    assert!(igcase_impl().is_ok());
+}
+
+fn igcase_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--force"]))?;
+
+    assert!(cli.overwrite);
+
+    let cli = Cli::parse(Args::from(["app", "--OVERWRITE", "-O", "out.txt"]))?;
+
+    assert!(cli.overwrite);
+    assert_eq!(cli.output.as_deref(), Some("out.txt"));
+
+    // the option without `igcase` is still case-sensitive
+    assert!(Cli::parse(Args::from(["app", "-o", "out.txt"])).is_err());
+
+    let parser = Cli::into_parser()?;
+
+    // the alias is displayed as given
+    assert_eq!(parser.find("--overwrite")?.hint(), "--Force, --overwrite");
+    // the option is found by name or alias case-insensitively
+    assert_eq!(parser.find("--FORCE")?.name(), "--overwrite");
+    assert!(parser.find("-o").is_err());
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help, name_from_arg0)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+}
+
+#[test]
+fn name_from_arg0() {
+    assert!(name_from_arg0_impl().is_ok());
+}
+
+fn name_from_arg0_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    for (arg0, name) in [
+        ("/usr/bin/foo", "foo"),
+        ("bar.exe", "bar"),
+        ("./baz", "baz"),
+        ("", env!("CARGO_PKG_NAME")),
+    ] {
+        match Cli::try_parse_from([arg0, "--help"]) {
+            ParseOutcome::Help(help) => {
+                assert!(help.starts_with(&format!("Usage: {name} ")));
+            }
+            outcome => panic!("expect help message, got {outcome:?}"),
+        }
+    }
+
+    let CoteRes { ret, parser, .. } = Cli::parse_args(Args::from(["/bin/qux", "--debug"]))?;
+
+    assert!(ret.status());
+    assert_eq!(parser.name(), "qux");
+    assert_eq!(parser.rctx()?.command_path(), ["qux"]);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the host of server
+    #[arg(group = "net")]
+    host: Option<String>,
+
+    /// Set the port of server
+    #[arg(group = "net")]
+    port: Option<u16>,
+
+    /// Set the path of cache
+    #[arg(group = "cache")]
+    cache: Option<String>,
+}
+
+#[test]
+fn help_topic() {
+    assert!(help_topic_impl().is_ok());
+}
+
+fn help_topic_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let mut policy = Cli::into_policy();
+    let (_, help) =
+        Cli::parse_args_with_help(Args::from(["app", "--help", "net"]), &mut policy, true)?;
+    let Some(ParseOutcome::Help(help)) = help else {
+        panic!("expect help message");
+    };
+
+    assert!(help.contains("net:"));
+    assert!(help.contains("--host"));
+    assert!(help.contains("--port"));
+    assert!(!help.contains("--debug"));
+    assert!(!help.contains("--cache"));
+
+    // the topic can be the value of help option
+    let mut policy = Cli::into_policy();
+    let (_, help) =
+        Cli::parse_args_with_help(Args::from(["app", "--help=cache"]), &mut policy, true)?;
+    let Some(ParseOutcome::Help(help)) = help else {
+        panic!("expect help message");
+    };
+
+    assert!(help.contains("--cache"));
+    assert!(!help.contains("--host"));
+    assert!(!help.contains("--debug"));
+
+    let mut policy = Cli::into_policy();
+    let (_, help) = Cli::parse_args_with_help(Args::from(["app", "--help"]), &mut policy, true)?;
+    let Some(ParseOutcome::Help(help)) = help else {
+        panic!("expect help message");
+    };
+
+    // all the options are displayed, grouped by the name of group
+    for text in ["--debug", "net:", "--host", "--port", "cache:", "--cache"] {
+        assert!(help.contains(text));
+    }
+    assert!(help.find("net:") < help.find("cache:"));
+
+    let parser = Cli::into_parser()?;
+    let mut buf = vec![];
+    let ctx = HelpContext::default()
+        .with_name("app")
+        .with_topic(Some("cache".to_owned()));
+
+    parser.write_sub_help(vec![parser.name().as_str()], &ctx, &mut buf)?;
+
+    let help = String::from_utf8(buf)?;
+
+    assert!(help.contains("--cache"));
+    assert!(!help.contains("--host"));
+
+    // the unknown topic display all the options
+    let mut buf = vec![];
+    let ctx = ctx.with_topic(Some("unknown".to_owned()));
+
+    parser.write_sub_help(vec![parser.name().as_str()], &ctx, &mut buf)?;
+
+    let help = String::from_utf8(buf)?;
+
+    assert!(help.contains("--cache"));
+    assert!(help.contains("--host"));
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// The coordinates of point
+    #[arg(greedy, num_values = 2..=3)]
+    coords: Option<Vec<f64>>,
+
+    /// Print debug message
+    debug: bool,
+}
+
+#[test]
+fn num_values() {
+    assert!(num_values_impl().is_ok());
+}
+
+fn num_values_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--coords", "1", "2", "--debug"]))?;
+
+    assert_eq!(cli.coords, Some(vec![1.0, 2.0]));
+    assert!(cli.debug);
+
+    let cli = Cli::parse(Args::from(["app", "--coords", "1", "2", "3"]))?;
+
+    assert_eq!(cli.coords, Some(vec![1.0, 2.0, 3.0]));
+
+    let cli = Cli::parse(Args::from(["app", "--debug"]))?;
+
+    assert_eq!(cli.coords, None);
+
+    let CoteRes { ret, .. } = Cli::parse_args(Args::from(["app", "--coords", "1", "--debug"]))?;
+
+    assert!(!ret.status());
+    assert_eq!(
+        ret.failure().map(|v| v.to_string()).as_deref(),
+        Some("option `--coords` expected 2 to 3 values, but found 1")
+    );
+
+    let CoteRes { ret, .. } = Cli::parse_args(Args::from(["app", "--coords", "1", "2", "3", "4"]))?;
+
+    assert!(!ret.status());
+    assert_eq!(
+        ret.failure().map(|v| v.to_string()).as_deref(),
+        Some("option `--coords` expected 2 to 3 values, but found 4")
+    );
+    Ok(())
+}
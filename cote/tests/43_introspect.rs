@@ -0,0 +1,44 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    #[arg(alias = "-d")]
+    debug: bool,
+
+    /// Set the output file
+    #[arg(alias = "-o", alias = "--out")]
+    output: String,
+
+    /// Set the count of retry
+    retry: Option<u64>,
+
+    #[pos()]
+    file: String,
+}
+
+#[test]
+fn introspect() {
+    assert!(introspect_impl().is_ok());
+}
+
+fn introspect_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let parser = Cli::into_parser()?;
+    let names = parser.option_names();
+
+    for name in ["--debug", "--output", "--retry", "file"] {
+        assert!(names.iter().any(|v| v == name), "missing option {name}");
+    }
+    assert_eq!(parser.aliases_of("--debug")?, ["-d"]);
+    assert_eq!(parser.aliases_of("--output")?, ["-o", "--out"]);
+    assert!(parser.aliases_of("--retry")?.is_empty());
+    assert!(parser.aliases_of("--missing").is_err());
+    assert!(!parser.is_force("--debug")?);
+    assert!(parser.is_force("--output")?);
+    assert!(!parser.is_force("--retry")?);
+    assert!(parser.is_force("file")?);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// The action to run
+    #[pos()]
+    action: String,
+
+    /// The arguments passed to the action
+    #[arg(last)]
+    args: Option<Vec<String>>,
+}
+
+#[test]
+fn last() {
+    assert!(last_impl().is_ok());
+}
+
+fn last_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "build", "--", "--release", "x"]))?;
+
+    assert_eq!(cli.action, "build");
+    assert_eq!(
+        cli.args,
+        Some(["--release", "x"].map(String::from).to_vec())
+    );
+    assert!(!cli.debug);
+
+    let cli = Cli::parse(Args::from(["app", "--debug", "test", "--", "--debug"]))?;
+
+    assert_eq!(cli.action, "test");
+    assert_eq!(cli.args, Some(vec![String::from("--debug")]));
+    assert!(cli.debug);
+
+    let cli = Cli::parse(Args::from(["app", "build", "x"]))?;
+
+    assert_eq!(cli.action, "build");
+    assert_eq!(cli.args, None);
+    Ok(())
+}
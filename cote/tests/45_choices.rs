@@ -0,0 +1,89 @@
+use cote::prelude::*;
+use cote::valid::Variants;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CoteOpt, CoteVal)]
+#[coteval(igcase)]
+pub enum Mode {
+    Fast,
+
+    Slow,
+
+    #[coteval(alias = "default")]
+    Auto,
+}
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the running mode
+    #[arg(value = Mode::Auto)]
+    mode: Mode,
+
+    /// Set the mode of network, auto is not allowed
+    #[arg(choices = [Mode::Fast, Mode::Slow])]
+    net: Option<Mode>,
+
+    /// Set the mode of disk
+    #[arg(choices)]
+    disk: Option<Mode>,
+}
+
+fn messages(error: &cote::Error) -> Vec<String> {
+    let mut messages = vec![error.to_string()];
+    let mut cause = error.caused_by();
+
+    while let Some(error) = cause {
+        messages.push(error.to_string());
+        cause = error.caused_by();
+    }
+    messages
+}
+
+#[test]
+fn choices() {
+    assert!(choices_impl().is_ok());
+}
+
+fn choices_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--mode", "fast"]))?;
+
+    assert_eq!(cli.mode, Mode::Fast);
+    assert_eq!(cli.net, None);
+
+    let cli = Cli::parse(Args::from(["app", "--mode=SLOW", "--net=fast"]))?;
+
+    assert_eq!(cli.mode, Mode::Slow);
+    assert_eq!(cli.net, Some(Mode::Fast));
+
+    let cli = Cli::parse(Args::from(["app", "--mode=default"]))?;
+
+    assert_eq!(cli.mode, Mode::Auto);
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.mode, Mode::Auto);
+
+    let err = Cli::parse(Args::from(["app", "--mode=turbo"])).unwrap_err();
+
+    assert!(messages(&err)
+        .iter()
+        .any(|v| v
+            .contains("Unknown value for enum type `Mode`: turbo, must be one of fast/slow/auto")));
+
+    let err = Cli::parse(Args::from(["app", "--net=auto"])).unwrap_err();
+
+    assert!(messages(&err)
+        .iter()
+        .any(|v| v.contains("value must be one of fast/slow")));
+
+    // the choices derived from all the variants
+    let cli = Cli::parse(Args::from(["app", "--disk=auto"]))?;
+
+    assert_eq!(cli.disk, Some(Mode::Auto));
+    assert_eq!(Mode::variants(), &[Mode::Fast, Mode::Slow, Mode::Auto]);
+    assert_eq!(Mode::Auto.name(), "auto");
+    // the default value displayed in help is the name used when parsing
+    assert_eq!(format_default(&Mode::Auto), "auto");
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+
+use cote::prelude::*;
+
+static ORDER: Mutex<Vec<&str>> = Mutex::new(vec![]);
+
+macro_rules! record {
+    ($n:literal, $t:ident) => {
+        |_: &mut Parser<'_, Set, Ser>, _: &mut Ser, ctx: &Ctx| {
+            let val = ctx.value::<$t>()?;
+            ORDER.lock().unwrap().push($n);
+            Ok(Some(val))
+        }
+    };
+}
+
+#[derive(Debug, Cote)]
+#[cote(policy = "pre", help)]
+pub struct Cli {
+    #[arg(on = record!("verbose", bool))]
+    verbose: bool,
+
+    #[sub(policy = "delay")]
+    build: Option<Build>,
+
+    #[sub(policy = "fwd")]
+    clean: Option<Clean>,
+}
+
+#[derive(Debug, Clone, Cote)]
+pub struct Build {
+    /// `nodelay` option is processed immediately when using `DelayPolicy`
+    #[arg(nodelay, on = record!("jobs", usize))]
+    jobs: usize,
+
+    #[arg(on = record!("target", String))]
+    target: String,
+
+    #[pos(on = record!("path", String))]
+    path: String,
+}
+
+#[derive(Debug, Clone, Cote)]
+pub struct Clean {
+    #[arg(on = record!("all", bool))]
+    all: bool,
+
+    #[pos(on = record!("dir", String))]
+    dir: String,
+}
+
+#[test]
+fn sub_policy() {
+    assert!(sub_policy_impl().is_ok());
+}
+
+fn sub_policy_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--verbose",
+        "build",
+        "--target=x86",
+        "--jobs=4",
+        "src",
+    ]))?;
+    let build = cli.build.unwrap();
+
+    assert!(cli.verbose);
+    assert_eq!(build.jobs, 4);
+    assert_eq!(build.target, "x86");
+    assert_eq!(build.path, "src");
+    // the positional argument is processed before the delayed option `target`
+    assert_eq!(
+        std::mem::take(&mut *ORDER.lock().unwrap()),
+        ["verbose", "jobs", "path", "target"]
+    );
+
+    let cli = Cli::parse(Args::from(["app", "clean", "--all", "out"]))?;
+    let clean = cli.clean.unwrap();
+
+    assert!(!cli.verbose);
+    assert!(clean.all);
+    assert_eq!(clean.dir, "out");
+    assert_eq!(std::mem::take(&mut *ORDER.lock().unwrap()), ["all", "dir"]);
+    Ok(())
+}
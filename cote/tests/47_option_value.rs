@@ -0,0 +1,39 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    opt: Option<i64>,
+
+    /// The option has a default value, so it is always `Some`
+    #[arg(value = 3i64)]
+    level: Option<i64>,
+
+    #[pos()]
+    file: Option<String>,
+}
+
+#[test]
+fn option_value() {
+    assert!(option_value_impl().is_ok());
+}
+
+fn option_value_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--opt", "5"]))?;
+
+    assert_eq!(cli.opt, Some(5));
+    assert_eq!(cli.level, Some(3));
+    assert_eq!(cli.file, None);
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.opt, None);
+    assert_eq!(cli.level, Some(3));
+
+    let cli = Cli::parse(Args::from(["app", "--level=8", "--opt=0", "foo"]))?;
+
+    assert_eq!(cli.opt, Some(0));
+    assert_eq!(cli.level, Some(8));
+    assert_eq!(cli.file.as_deref(), Some("foo"));
+    Ok(())
+}
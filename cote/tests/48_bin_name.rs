@@ -0,0 +1,50 @@
+use cote::prelude::*;
+
+fn rename_bin<Set, Ser>(parser: &mut Parser<'_, Set, Ser>, name: &str) -> cote::Result<()> {
+    parser.set_bin_name(name);
+    Ok(())
+}
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(name = "app", help, rename_bin(parser, "tool"))]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    #[sub()]
+    list: Option<List>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct List {
+    /// List all the items
+    all: bool,
+}
+
+#[test]
+fn bin_name_test() {
+    assert!(bin_name_impl().is_ok());
+}
+
+fn bin_name_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    match Cli::try_parse_from(["app", "list", "--help"]) {
+        ParseOutcome::Help(help) => {
+            assert!(help.starts_with("Usage: tool list "));
+        }
+        outcome => panic!("expect help message, got {outcome:?}"),
+    }
+
+    let mut parser = Cli::into_parser()?;
+    let ctx = HelpContext::default();
+    let mut buf = vec![];
+
+    parser.set_bin_name("mytool");
+    parser.write_sub_help(vec!["app"], &ctx, &mut buf)?;
+    assert_eq!(parser.name(), "app");
+    assert!(String::from_utf8(buf)?.starts_with("Usage: mytool "));
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use cote::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, CoteOpt)]
+#[infer(val = String, map = Input, hint = Some("{names}=<FILE>".to_owned()))]
+pub struct Input(String);
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the input file
+    #[arg(alias = "-i")]
+    input: Option<Input>,
+
+    /// Set the output file
+    #[arg(hint = "--output <PATH>")]
+    output: Option<Input>,
+}
+
+#[test]
+fn hint_template() {
+    assert!(hint_template_impl().is_ok());
+}
+
+fn hint_template_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let ParseOutcome::Help(help) = Cli::try_parse_from(["app", "--help"]) else {
+        panic!("expect help message");
+    };
+
+    assert!(help.contains("-i, --input=<FILE>"));
+    assert!(help.contains("--output <PATH>"));
+    assert!(!help.contains("--debug=<FILE>"));
+
+    let cli = Cli::parse(Args::from(["app", "-i", "data.txt"]))?;
+
+    assert_eq!(cli.input, Some(Input("data.txt".to_owned())));
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use cote::prelude::*;
+
+// Create the policy instance, the settings in `cote` still apply on top of it
+fn combined_policy<'inv, Set, Ser>() -> FwdPolicy<'inv, Set, Ser> {
+    let mut policy = FwdPolicy::default();
+
+    policy.style_manager_mut().push(UserStyle::CombinedOption);
+    policy
+}
+
+#[derive(Debug, Cote)]
+#[cote(policy_init = combined_policy)]
+pub struct Cli {
+    #[arg(alias = "-a")]
+    all: bool,
+
+    #[arg(alias = "-l")]
+    list: bool,
+}
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+pub struct Plain {
+    #[arg(alias = "-a")]
+    all: bool,
+
+    #[arg(alias = "-l")]
+    list: bool,
+}
+
+#[test]
+fn policy_init() {
+    assert!(policy_init_impl().is_ok());
+}
+
+fn policy_init_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(Args::from(["app", "-al"]))?;
+
+    assert!(cli.all);
+    assert!(cli.list);
+
+    let policy = Cli::into_policy();
+
+    assert!(policy.style_manager().contains(&UserStyle::CombinedOption));
+    assert!(Plain::parse(Args::from(["app", "-al"])).is_err());
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the name
+    name: Option<String>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Sport {
+    #[sub()]
+    walk: Option<Walk>,
+
+    #[sub()]
+    run: Option<Run>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Cote)]
+pub struct Walk {
+    #[arg(value = 2)]
+    distance: i32,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Cote)]
+pub struct Run {
+    #[arg(value = 42)]
+    distance: i32,
+}
+
+#[test]
+fn empty_args() {
+    assert!(empty_args_impl().is_ok());
+}
+
+fn empty_args_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let empty: [&str; 0] = [];
+
+    // parsing nothing
+    for args in [Args::from(empty), Args::from(["app"])] {
+        let cli = Cli::parse(args)?;
+
+        assert!(!cli.debug);
+        assert_eq!(cli.name, None);
+    }
+    assert!(matches!(
+        Cli::try_parse(Args::from(empty)),
+        ParseOutcome::Parsed(_)
+    ));
+
+    let cli = Cli::parse(Args::from(["app", "--name", "cote"]))?;
+
+    assert!(!cli.debug);
+    assert_eq!(cli.name.as_deref(), Some("cote"));
+
+    // the failure of missing sub command is formatted with the arguments
+    for args in [Args::from(empty), Args::from(["app"])] {
+        let err = Sport::parse(args).unwrap_err();
+
+        assert!(err.to_string().starts_with("Parsing arguments `` failed"));
+    }
+
+    let sport = Sport::parse(Args::from(["app", "run"]))?;
+
+    assert!(sport.walk.is_none());
+    assert!(sport.run.is_some());
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, aborthelp, subcommand_required)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Search the packages
+    #[sub(force = false)]
+    search: Option<Search>,
+
+    /// Install the packages
+    #[sub(force = false)]
+    install: Option<Install>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Search {
+    #[pos()]
+    name: String,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Install {
+    #[pos()]
+    name: String,
+}
+
+#[test]
+fn subcommand_required() {
+    assert!(subcommand_required_impl().is_ok());
+}
+
+fn subcommand_required_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let ParseOutcome::Err(e) = Cli::try_parse_from(["app"]) else {
+        panic!("expect error without sub command");
+    };
+
+    assert_eq!(e.kind(), &cote::ErrorKind::CmdRequired);
+    assert_eq!(e.to_string(), "a subcommand is required");
+    assert!(Cli::parse(Args::from(["app", "--debug"])).is_err());
+
+    let ParseOutcome::Help(help) = Cli::try_parse_from(["app", "--help"]) else {
+        panic!("expect help message");
+    };
+
+    assert!(help.contains("search"));
+    assert!(help.contains("install"));
+
+    let cli = Cli::parse(Args::from(["app", "--debug", "search", "cote"]))?;
+
+    assert!(cli.debug);
+    assert_eq!(cli.search.map(|v| v.name), Some("cote".to_owned()));
+    assert!(cli.install.is_none());
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use cote::prelude::*;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// Add the tag, duplicate tags are ignored
+    #[arg(alias = "-t")]
+    tag: HashSet<String>,
+
+    /// Add the port, the ports are sorted
+    #[arg(alias = "-p")]
+    port: BTreeSet<i64>,
+
+    /// The queue of jobs
+    job: Option<VecDeque<String>>,
+}
+
+#[test]
+fn collections() {
+    assert!(collections_impl().is_ok());
+}
+
+fn collections_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(Args::from([
+        "app", "-t", "web", "-p", "8080", "--tag=db", "-p", "22", "-t", "web", "-p", "8080",
+        "--job", "build", "--job", "test",
+    ]))?;
+
+    assert_eq!(cli.tag, HashSet::from(["web".to_owned(), "db".to_owned()]));
+    assert_eq!(cli.port.into_iter().collect::<Vec<_>>(), vec![22, 8080]);
+    assert_eq!(
+        cli.job,
+        Some(VecDeque::from(["build".to_owned(), "test".to_owned()]))
+    );
+
+    let cli = Cli::parse(Args::from(["app", "-t", "web", "-p", "1"]))?;
+
+    assert_eq!(cli.tag.len(), 1);
+    assert_eq!(cli.job, None);
+    assert!(Cli::parse(Args::from(["app", "-t", "web"])).is_err());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Print the license and exit
+    #[arg(info = "MPL-2.0")]
+    license: bool,
+
+    /// Set the input file
+    #[pos()]
+    input: Option<String>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Tool {
+    /// Print the license and exit
+    #[arg(info = "MIT")]
+    license: bool,
+
+    /// Manage the database
+    #[sub()]
+    db: Option<Db>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Db {
+    /// Print the version of database and exit
+    #[arg(info = "db 1.0")]
+    about: bool,
+}
+
+#[test]
+fn info() {
+    assert!(info_impl().is_ok());
+}
+
+fn info_impl() -> Result<(), Box<dyn std::error::Error>> {
+    let ParseOutcome::Info(text) = Cli::try_parse_from(["app", "--license"]) else {
+        panic!("expect text of info option");
+    };
+
+    assert_eq!(text, "MPL-2.0");
+
+    let cli = Cli::try_parse_from(["app", "--debug", "data.txt"]).into_result()?;
+
+    assert!(cli.debug);
+    assert!(!cli.license);
+    assert_eq!(cli.input.as_deref(), Some("data.txt"));
+
+    // the help takes precedence over the info option
+    let ParseOutcome::Help(_) = Cli::try_parse_from(["app", "--license", "--help"]) else {
+        panic!("expect help message");
+    };
+
+    // the info option of sub command
+    let ParseOutcome::Info(text) = Tool::try_parse_from(["app", "db", "--about"]) else {
+        panic!("expect text of info option in sub command");
+    };
+
+    assert_eq!(text, "db 1.0");
+
+    let ParseOutcome::Info(text) = Tool::try_parse_from(["app", "--license"]) else {
+        panic!("expect text of info option");
+    };
+
+    assert_eq!(text, "MIT");
+    Ok(())
+}
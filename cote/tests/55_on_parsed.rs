@@ -0,0 +1,62 @@
+use cote::prelude::*;
+use std::sync::Mutex;
+
+static EVENTS: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+fn push_event(event: impl Into<String>) {
+    EVENTS.lock().unwrap().push(event.into());
+}
+
+#[derive(Debug, Cote)]
+#[cote(init_logging(parser))]
+pub struct Cli {
+    /// Print more message
+    verbose: bool,
+
+    /// Set the input file
+    #[arg(fetch = fetch_input)]
+    input: String,
+}
+
+#[test]
+fn on_parsed() {
+    assert!(on_parsed_impl().is_ok());
+}
+
+fn on_parsed_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--verbose", "--input", "data.txt"]))?;
+
+    assert!(cli.verbose);
+    assert_eq!(cli.input, "data.txt");
+    assert_eq!(
+        *EVENTS.lock().unwrap(),
+        ["logging verbose = true", "extract input"]
+    );
+    Ok(())
+}
+
+// The hook is invoked after parsing and before extracting the values
+fn init_logging<Set, Ser>(parser: &mut Parser<'_, Set, Ser>) -> cote::Result<()>
+where
+    Set: SetValueFindExt,
+    SetCfg<Set>: ConfigValue + Default,
+{
+    parser.on_parsed(|parser| {
+        let verbose = *parser.find_val::<bool>("--verbose")?;
+
+        push_event(format!("logging verbose = {verbose}"));
+        Ok(())
+    });
+    Ok(())
+}
+
+fn fetch_input<T, S>(uid: Uid, set: &mut S) -> cote::Result<T>
+where
+    T: ErasedTy + Default,
+    S: Set + SetValueFindExt,
+    SetCfg<S>: ConfigValue + Default,
+{
+    push_event("extract input");
+    Ok(std::mem::take(set.opt_mut(uid)?.val_mut::<T>()?))
+}
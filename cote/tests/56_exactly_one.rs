@@ -0,0 +1,48 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Output in json format
+    #[arg(exactly_one = "format")]
+    json: bool,
+
+    /// Output in yaml format
+    #[arg(exactly_one = "format")]
+    yaml: bool,
+
+    /// Output in toml format
+    #[arg(exactly_one = "format")]
+    toml: bool,
+}
+
+#[test]
+fn exactly_one() {
+    assert!(exactly_one_impl().is_ok());
+}
+
+fn exactly_one_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let err = Cli::parse(Args::from(["app", "--debug"])).unwrap_err();
+
+    assert!(err.to_string().contains("--json, --yaml, --toml"));
+    assert!(err.to_string().contains("in group `format`"));
+    assert!(err.to_string().contains("none is set"));
+
+    let cli = Cli::parse(Args::from(["app", "--yaml"]))?;
+
+    assert!(!cli.json);
+    assert!(cli.yaml);
+    assert!(!cli.toml);
+
+    let err = Cli::parse(Args::from(["app", "--json", "--toml"])).unwrap_err();
+
+    assert!(err.to_string().contains("--json, --yaml, --toml"));
+    assert!(err.to_string().contains("in group `format`"));
+    assert!(err.to_string().contains("2 are set"));
+    Ok(())
+}
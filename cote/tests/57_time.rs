@@ -0,0 +1,38 @@
+#![cfg(feature = "time")]
+use cote::prelude::*;
+use time::Date;
+use time::Month;
+use time::OffsetDateTime;
+
+#[derive(Debug, Cote)]
+#[cote()]
+pub struct Cli {
+    /// Set the start time in RFC3339 format
+    start: OffsetDateTime,
+
+    /// Set the end date
+    end: Option<Date>,
+}
+
+#[test]
+fn time() {
+    assert!(time_impl().is_ok());
+}
+
+fn time_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from([
+        "app",
+        "--start",
+        "2024-05-01T08:30:00Z",
+        "--end=2024-05-02",
+    ]))?;
+
+    assert_eq!(cli.start.unix_timestamp(), 1714552200);
+    assert_eq!(
+        cli.end,
+        Some(Date::from_calendar_date(2024, Month::May, 2)?)
+    );
+    assert!(Cli::parse(Args::from(["app", "--start", "2024/05/01"])).is_err());
+    Ok(())
+}
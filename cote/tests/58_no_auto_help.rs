@@ -0,0 +1,45 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help, no_auto_help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Print the manual of app
+    #[arg(alias = "-h")]
+    help: bool,
+}
+
+#[test]
+fn no_auto_help() {
+    assert!(no_auto_help_impl().is_ok());
+}
+
+fn no_auto_help_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let ParseOutcome::Parsed(cli) = Cli::try_parse_from(["app", "-h"]) else {
+        panic!("the help option is managed by user");
+    };
+
+    assert!(cli.help);
+    assert!(!cli.debug);
+
+    let cli = Cli::parse(Args::from(["app", "--debug"]))?;
+
+    assert!(!cli.help);
+    assert!(cli.debug);
+
+    // display the help manually
+    let parser = Cli::into_parser()?;
+    let mut buf = vec![];
+    let ctx = HelpContext::default().with_name("app");
+
+    parser.write_sub_help(vec![parser.name().as_str()], &ctx, &mut buf)?;
+
+    let help = String::from_utf8(buf)?;
+
+    assert_eq!(help.matches("--help").count(), 1);
+    assert!(help.contains("Print the manual of app"));
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the name of user
+    #[allow(unused)]
+    name: String,
+
+    /// Set the level, should be one of 1, 2, 3
+    #[allow(unused)]
+    #[arg(valid = valid!([1, 2, 3]))]
+    level: Option<i64>,
+}
+
+#[test]
+fn usage_error() {
+    assert!(usage_error_impl().is_ok());
+}
+
+fn usage_error_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let usage = Cli::usage_line()?;
+    let err = Cli::parse(Args::from(["app", "--name", "foo", "--level", "4"])).unwrap_err();
+    let message = Cli::format_error(&err);
+
+    assert!(usage.starts_with("Usage:"));
+    assert!(usage.contains("--name"));
+    assert!(err.is_usage());
+    assert_eq!(err.exit_code(), 2);
+    assert!(message.contains("--level"));
+    assert!(message.ends_with(&usage));
+
+    // the logic error is displayed without usage
+    let err = cote::Error::raise_error("can not open the database");
+    let message = Cli::format_error(&err);
+
+    assert!(!err.is_usage());
+    assert_eq!(message, "can not open the database");
+    Ok(())
+}
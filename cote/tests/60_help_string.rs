@@ -0,0 +1,37 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help, head = "A tool for serving files")]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the port of server
+    port: Option<u16>,
+}
+
+#[test]
+fn help_string() {
+    assert!(help_string_impl().is_ok());
+}
+
+fn help_string_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let help = Cli::help_string()?;
+
+    assert!(help.contains("A tool for serving files"));
+    assert!(help.contains("Usage:"));
+    assert!(help.contains("Options:"));
+    assert!(help.contains("--debug"));
+    assert!(help.contains("--port"));
+    assert!(help.contains("Set the port of server"));
+
+    let parser = Cli::into_parser()?;
+    let help = parser.help_string(HelpContext::default().with_name("serve"))?;
+
+    assert!(help.contains("Usage: serve"));
+    assert!(help.contains("--debug"));
+    assert!(help.contains("Print debug message"));
+    Ok(())
+}
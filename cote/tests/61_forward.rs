@@ -0,0 +1,62 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print more message
+    verbose: bool,
+
+    /// The arguments forwarded to the program
+    #[arg(forward)]
+    prog: Option<Prog>,
+}
+
+#[derive(Debug, Cote)]
+pub struct Prog {
+    /// Set the inner value
+    inner: Option<i64>,
+
+    /// Print debug message of program
+    debug: bool,
+}
+
+#[derive(Debug, Cote)]
+pub struct Wrapper {
+    #[arg(forward)]
+    prog: Prog,
+}
+
+#[test]
+fn forward() {
+    assert!(forward_impl().is_ok());
+}
+
+fn forward_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app", "--verbose", "--", "--inner", "5"]))?;
+    let prog = cli.prog.unwrap();
+
+    assert!(cli.verbose);
+    assert_eq!(prog.inner, Some(5));
+    assert!(!prog.debug);
+
+    // the arguments after `--` are not matched by outer options
+    let cli = Cli::parse(Args::from(["app", "--", "--debug", "--verbose"]));
+
+    assert!(cli.is_err());
+
+    let cli = Cli::parse(Args::from(["app", "--verbose"]))?;
+
+    assert!(cli.verbose);
+    assert!(cli.prog.is_none());
+
+    // parsing the program without arguments if `--` is absent
+    let wrapper = Wrapper::parse(Args::from(["app"]))?;
+
+    assert_eq!(wrapper.prog.inner, None);
+
+    let wrapper = Wrapper::parse(Args::from(["app", "--", "--debug"]))?;
+
+    assert!(wrapper.prog.debug);
+    Ok(())
+}
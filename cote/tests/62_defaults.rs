@@ -0,0 +1,43 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(defaults = fill_defaults)]
+pub struct Cli {
+    /// Set the number of threads
+    threads: Option<usize>,
+
+    /// Set the size of cache, default is 64 for each thread
+    cache: Option<usize>,
+}
+
+// the function is called after parsing with the extracted struct
+fn fill_defaults(cli: &mut Cli) -> cote::Result<()> {
+    let threads = *cli.threads.get_or_insert(4);
+
+    cli.cache.get_or_insert(threads * 64);
+    Ok(())
+}
+
+#[test]
+fn defaults() {
+    assert!(defaults_impl().is_ok());
+}
+
+fn defaults_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.threads, Some(4));
+    assert_eq!(cli.cache, Some(256));
+
+    let cli = Cli::parse(Args::from(["app", "--threads", "2"]))?;
+
+    assert_eq!(cli.threads, Some(2));
+    assert_eq!(cli.cache, Some(128));
+
+    let cli = Cli::parse(Args::from(["app", "--threads", "2", "--cache", "10"]))?;
+
+    assert_eq!(cli.threads, Some(2));
+    assert_eq!(cli.cache, Some(10));
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+pub struct Cli {
+    /// Set the name of user
+    user: String,
+
+    /// Set the token of user, it is masked when logging
+    #[arg(secret)]
+    token: String,
+}
+
+#[test]
+fn secret() {
+    assert!(secret_impl().is_ok());
+}
+
+fn secret_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let args = Args::from(["app", "--user", "lily", "--token", "s3cr3t"]);
+    let CoteRes { ret, parser, .. } = Cli::parse_args(args)?;
+
+    assert_eq!(
+        ret.normalized_args(parser.optset()),
+        ["--user=lily", "--token=***"]
+    );
+    assert_eq!(
+        ret.rebuild_command_line(parser.optset()),
+        ["app", "--user", "lily", "--token", "***"]
+    );
+    assert_eq!(parser.find_val::<String>("--token")?, "s3cr3t");
+
+    let cli = Cli::parse(Args::from(["app", "--user", "lily", "--token", "s3cr3t"]))?;
+
+    assert_eq!(cli.user, "lily");
+    assert_eq!(cli.token, "s3cr3t");
+    Ok(())
+}
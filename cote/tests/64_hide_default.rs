@@ -0,0 +1,34 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the port of server
+    #[arg(value = 8080u16)]
+    port: u16,
+
+    /// Set the secret key of session
+    #[arg(value = "2f1a9c0e7d", hide_default)]
+    key: String,
+}
+
+#[test]
+fn hide_default() {
+    assert!(hide_default_impl().is_ok());
+}
+
+fn hide_default_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.port, 8080);
+    assert_eq!(cli.key, "2f1a9c0e7d");
+
+    let help = Cli::help_string()?;
+
+    assert!(help.contains("Set the port of server [8080]"));
+    assert!(help.contains("Set the secret key of session"));
+    assert!(!help.contains("2f1a9c0e7d"));
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app", help)]
+pub struct Cli {
+    #[allow(unused)]
+    #[sub()]
+    db: Option<Db>,
+
+    #[allow(unused)]
+    #[sub()]
+    serve: Option<Serve>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Db {
+    #[allow(unused)]
+    #[sub()]
+    migrate: Option<Migrate>,
+
+    #[allow(unused)]
+    #[sub()]
+    seed: Option<Seed>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Migrate {
+    #[allow(unused)]
+    step: usize,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Seed {
+    #[allow(unused)]
+    file: Option<String>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Serve {
+    #[allow(unused)]
+    port: Option<u16>,
+}
+
+#[test]
+fn walk() {
+    assert!(walk_impl().is_ok());
+}
+
+fn walk_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let parser = Cli::into_parser()?;
+    let mut paths = vec![];
+
+    parser.walk(&mut |path, parser| {
+        paths.push((path.join(" "), parser.option_names().len()));
+    });
+
+    assert_eq!(paths.len(), 5);
+    assert_eq!(
+        paths
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>(),
+        [
+            "app",
+            "app db",
+            "app db migrate",
+            "app db seed",
+            "app serve"
+        ]
+    );
+    assert!(paths.iter().all(|(_, count)| *count > 0));
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Print debug message
+    debug: bool,
+
+    /// Set the count of workers
+    #[arg(alias = "-c")]
+    count: Option<i64>,
+
+    /// Set the name of server
+    name: Option<String>,
+
+    /// Set the port of server
+    port: Option<u16>,
+
+    /// Set the size of buffer
+    #[arg(hint = "--size <BYTES>")]
+    size: Option<i64>,
+}
+
+#[test]
+fn type_metavars() {
+    assert!(type_metavars_impl().is_ok());
+}
+
+fn type_metavars_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let mut parser = Cli::into_parser()?;
+
+    parser
+        .add_opt("--mask=i")?
+        .set_number_format(cote::aopt::opt::NumberFormat::Hex)
+        .set_help("Set the mask")
+        .run()?;
+    let help = parser.help_string(HelpContext::default().with_name("app"))?;
+
+    assert!(help.contains("-c, --count "));
+    assert!(!help.contains("INT"));
+
+    let ctx = HelpContext::default().with_name("app").with_type_metavars([
+        ("i", "INT"),
+        ("s", "STR"),
+        ("unknown", "UNKNOWN"),
+    ]);
+    let help = parser.help_string(ctx)?;
+
+    assert!(help.contains("-c, --count INT"));
+    assert!(help.contains("--name STR"));
+    // the type is not mapped
+    assert!(help.contains("--port "));
+    assert!(!help.contains("--port STR"));
+    // the boolean option has no value
+    assert!(!help.contains("--debug INT"));
+    assert!(!help.contains("UNKNOWN"));
+    // the custom hint and the hint of number format are kept
+    assert!(help.contains("--size <BYTES> "));
+    assert!(!help.contains("--size <BYTES> INT"));
+    assert!(help.contains("--mask <HEX> "));
+    assert!(!help.contains("--mask <HEX> INT"));
+    Ok(())
+}
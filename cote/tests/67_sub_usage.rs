@@ -0,0 +1,47 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(name = "app", help)]
+pub struct Cli {
+    /// Print debug message
+    #[allow(unused)]
+    debug: bool,
+
+    #[allow(unused)]
+    #[sub()]
+    sub: Option<Sub>,
+}
+
+#[derive(Debug, Clone, Cote)]
+#[cote(help)]
+pub struct Sub {
+    /// Set the count of workers
+    #[allow(unused)]
+    count: Option<i64>,
+}
+
+#[test]
+fn sub_usage() {
+    assert!(sub_usage_impl().is_ok());
+}
+
+fn sub_usage_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let err = Cli::parse(Args::from(["app", "sub", "--count", "x"])).unwrap_err();
+    let message = Cli::format_error(&err);
+
+    assert!(err.is_usage());
+    assert!(err
+        .usage()
+        .is_some_and(|v| v.starts_with("Usage: app sub ")));
+    assert!(message.contains("Usage: app sub "));
+    assert!(message.contains("--count"));
+
+    // the usage of root is displayed if failed in root
+    let err = Cli::parse(Args::from(["app", "--debug=x"])).unwrap_err();
+    let message = Cli::format_error(&err);
+
+    assert!(err.usage().is_none());
+    assert!(!message.contains("Usage: app sub"));
+    Ok(())
+}
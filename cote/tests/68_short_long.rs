@@ -0,0 +1,61 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the output file
+    #[arg(short = 'o', long = "output")]
+    out: Option<String>,
+
+    /// Set the count of workers
+    #[arg(short = 'j')]
+    jobs: Option<i64>,
+
+    /// Print debug message
+    #[arg(long = "verbose")]
+    v: bool,
+
+    /// The short is same as the name
+    #[arg(short = 'q')]
+    q: bool,
+}
+
+#[test]
+fn short_long() {
+    assert!(short_long_impl().is_ok());
+}
+
+fn short_long_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse(Args::from([
+        "app",
+        "-o",
+        "a.txt",
+        "-j",
+        "4",
+        "--verbose",
+        "-q",
+    ]))?;
+
+    assert_eq!(cli.out.as_deref(), Some("a.txt"));
+    assert_eq!(cli.jobs, Some(4));
+    assert!(cli.v);
+    assert!(cli.q);
+
+    let cli = Cli::parse(Args::from(["app", "--output", "b.txt", "--jobs", "2"]))?;
+
+    assert_eq!(cli.out.as_deref(), Some("b.txt"));
+    assert_eq!(cli.jobs, Some(2));
+    assert!(!cli.v);
+    assert!(!cli.q);
+
+    // the name generated from identifier is overridden by `long`
+    assert!(Cli::parse(Args::from(["app", "--out", "c.txt"])).is_err());
+    assert!(Cli::parse(Args::from(["app", "-v"])).is_err());
+
+    let help = Cli::help_string()?;
+
+    assert!(help.contains("--output"));
+    assert!(help.contains("-o"));
+    Ok(())
+}
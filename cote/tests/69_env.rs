@@ -0,0 +1,51 @@
+use cote::prelude::*;
+
+#[derive(Debug, Cote)]
+#[cote(help)]
+pub struct Cli {
+    /// Set the count of workers
+    #[arg(env = "COTE_TEST_ENV_JOBS", value = 1i64)]
+    jobs: i64,
+
+    /// Set the log level
+    #[arg(env = "COTE_TEST_ENV_LEVEL", value = "info")]
+    level: String,
+
+    /// Set the api token
+    #[arg(env = "COTE_TEST_ENV_TOKEN")]
+    token: Option<String>,
+}
+
+#[test]
+fn env() {
+    assert!(env_impl().is_ok());
+}
+
+fn env_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    std::env::set_var("COTE_TEST_ENV_JOBS", "8");
+    std::env::set_var("COTE_TEST_ENV_TOKEN", "abc");
+    std::env::remove_var("COTE_TEST_ENV_LEVEL");
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.jobs, 8);
+    assert_eq!(cli.level, "info");
+    assert_eq!(cli.token.as_deref(), Some("abc"));
+
+    let cli = Cli::parse(Args::from(["app", "--jobs=2", "--token", "xyz"]))?;
+
+    assert_eq!(cli.jobs, 2);
+    assert_eq!(cli.token.as_deref(), Some("xyz"));
+
+    std::env::set_var("COTE_TEST_ENV_JOBS", "eight");
+    assert!(Cli::parse(Args::from(["app"])).is_err());
+    std::env::remove_var("COTE_TEST_ENV_JOBS");
+    std::env::remove_var("COTE_TEST_ENV_TOKEN");
+
+    let cli = Cli::parse(Args::from(["app"]))?;
+
+    assert_eq!(cli.jobs, 1);
+    assert_eq!(cli.token, None);
+    Ok(())
+}
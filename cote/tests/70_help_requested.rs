@@ -0,0 +1,37 @@
+use cote::prelude::*;
+
+#[allow(unused)]
+#[derive(Debug, Cote)]
+#[cote(help, helpopt = "--show-help;-s")]
+pub struct Cli {
+    /// Print debug message
+    #[arg(alias = "-h")]
+    verbose: bool,
+
+    /// Set the count of workers
+    jobs: Option<i64>,
+}
+
+#[test]
+fn help_requested() {
+    assert!(help_requested_impl().is_ok());
+}
+
+fn help_requested_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let mut policy = Cli::into_policy();
+    let (CoteRes { ret, .. }, outcome) =
+        Cli::parse_args_with_help(Args::from(["app", "-s"]), &mut policy, true)?;
+
+    assert!(ret.parse_status().is_help_requested());
+    assert!(matches!(outcome, Some(ParseOutcome::Help(_))));
+
+    // `-h` is not the help option of `Cli`
+    let mut policy = Cli::into_policy();
+    let (CoteRes { ret, .. }, outcome) =
+        Cli::parse_args_with_help(Args::from(["app", "-h", "--jobs=2"]), &mut policy, true)?;
+
+    assert!(ret.parse_status().is_completed());
+    assert!(outcome.is_none());
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use cote::prelude::*;
+
+#[test]
+fn skip_claimed_pos() {
+    assert!(skip_claimed_pos_impl().is_ok());
+}
+
+fn skip_claimed_pos_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let mut parser = Parser::new("app", ASet::default().with_skip_claimed_pos(true));
+
+    parser.set_invoker(Invoker::default());
+    parser.set_service(ASer::default());
+    parser.add_opt("cmd=p@1")?.set_pos_type::<String>();
+    parser.add_opt("dest=p@3")?.set_pos_type::<String>();
+    parser
+        .add_opt("file=p@*")?
+        .set_pos_type::<String>()
+        .set_action(Action::Set);
+
+    let mut policy = FwdPolicy::default();
+
+    PolicyParser::parse_policy(
+        &mut parser,
+        Args::from(["app", "copy", "a.txt", "dir", "b.txt"]),
+        &mut policy,
+    )?;
+
+    // `file` is bound to the first position not claimed by `cmd` and `dest`
+    assert_eq!(parser.find_val::<String>("cmd")?, "copy");
+    assert_eq!(parser.find_val::<String>("dest")?, "dir");
+    assert_eq!(parser.find_val::<String>("file")?, "a.txt");
+    Ok(())
+}